@@ -10,7 +10,7 @@ _just() {
     else
         cur="${COMP_WORDS[COMP_CWORD]}"
         prev="${COMP_WORDS[COMP_CWORD-1]}"
-        words=$COMP_WORDS
+        words=("${COMP_WORDS[@]}")
         cword=$COMP_CWORD
     fi
 
@@ -30,16 +30,33 @@ _just() {
 
     case "${cmd}" in
         "$1")
-            opts="-n -f -q -u -v -d -c -e -l -s -E -h -V --check --chooser --color --command-color --yes --dry-run --dump-format --highlight --list-heading --list-prefix --no-aliases --no-deps --no-dotenv --no-highlight --justfile --quiet --set --shell --shell-arg --shell-command --clear-shell-args --unsorted --unstable --verbose --working-directory --changelog --choose --command --completions --dump --edit --evaluate --fmt --init --list --man --show --summary --variables --dotenv-filename --dotenv-path --help --version [ARGUMENTS]..."
+            opts="-n -j -f -q -u -v -d -c -e -l -s -E -h -V --align --check --chooser --color --command-color --yes --dependency-argument --dry-run --dump-format --recipe --variable --exit-codes --highlight --jobs --json --list-flat --list-heading --list-prefix --locations --no-aliases --no-deps --no-dotenv --no-highlight --justfile --overrides-file --quiet --search-depth --set --shell --shell-arg --shell-command --silence-stderr --silence-stdout --stdin --stop-at-project-root --clear-shell-args --timings --trace-evaluation --transcript --unsorted --unstable --verbose --warn-as-error --working-directory --changelog --choose --command --completions --dump --dump-schema --edit --evaluate --find --fmt --groups --init --list --man --settings --show --summary --usage --variables --dotenv-filename --dotenv-path --help --version [ARGUMENTS]... [PASSTHROUGH]..."
+                local just_args=()
+                local recipe_cword=1
+
+                for ((i = 1; i < cword; i++)); do
+                    case "${words[i]}" in
+                        -f|--justfile|-d|--working-directory)
+                            just_args+=("${words[i]}" "${words[i+1]}")
+                            recipe_cword=$((i + 2))
+                            ((i++))
+                            ;;
+                        --justfile=*|--working-directory=*)
+                            just_args+=("${words[i]}")
+                            recipe_cword=$((i + 1))
+                            ;;
+                    esac
+                done
+
                 if [[ ${cur} == -* ]] ; then
                     COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                     return 0
-                elif [[ ${cword} -eq 1 ]]; then
-                    local recipes=$(just --summary 2> /dev/null)
+                elif [[ ${cword} -eq ${recipe_cword} ]]; then
+                    local recipes=$(just "${just_args[@]}" --summary 2> /dev/null)
 
                     if echo "${cur}" | \grep -qF '/'; then
                         local path_prefix=$(echo "${cur}" | sed 's/[/][^/]*$/\//')
-                        local recipes=$(just --summary 2> /dev/null -- "${path_prefix}")
+                        local recipes=$(just "${just_args[@]}" --summary 2> /dev/null -- "${path_prefix}")
                         local recipes=$(printf "${path_prefix}%s\t" $recipes)
                     fi
 
@@ -64,8 +81,32 @@ _just() {
                     COMPREPLY=($(compgen -W "black blue cyan green purple red yellow" -- "${cur}"))
                     return 0
                     ;;
+                --dependency-argument)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --dump-format)
-                    COMPREPLY=($(compgen -W "just json" -- "${cur}"))
+                    COMPREPLY=($(compgen -W "just json json-lossless" -- "${cur}"))
+                    return 0
+                    ;;
+                --recipe)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --variable)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --exit-codes)
+                    COMPREPLY=($(compgen -W "passthrough normalized categorized" -- "${cur}"))
+                    return 0
+                    ;;
+                --jobs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                -j)
+                    COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
                 --list-heading)
@@ -84,6 +125,14 @@ _just() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --overrides-file)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --search-depth)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --set)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -96,6 +145,10 @@ _just() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --transcript)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --working-directory)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -116,6 +169,10 @@ _just() {
                     COMPREPLY=($(compgen -W "bash elvish fish powershell zsh" -- "${cur}"))
                     return 0
                     ;;
+                --find)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --show)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -124,6 +181,10 @@ _just() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --usage)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --dotenv-filename)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
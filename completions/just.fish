@@ -1,5 +1,20 @@
+function __fish_just_args
+        set -l tokens (commandline -opc)
+        set -l i 2
+        while test $i -le (count $tokens)
+                switch $tokens[$i]
+                        case -f --justfile -d --working-directory
+                                set i (math $i + 1)
+                                printf '%s\n' $tokens[(math $i - 1)] $tokens[$i]
+                        case '--justfile=*' '--working-directory=*'
+                                printf '%s\n' $tokens[$i]
+                end
+                set i (math $i + 1)
+        end
+end
+
 function __fish_just_complete_recipes
-        just --list 2> /dev/null | tail -n +2 | awk '{
+        just (__fish_just_args) --list 2> /dev/null | tail -n +2 | awk '{
         command = $1;
         args = $0;
         desc = "";
@@ -38,42 +53,66 @@ complete -c just -a '(__fish_just_complete_recipes)'
 complete -c just -l chooser -d 'Override binary invoked by `--choose`' -r
 complete -c just -l color -d 'Print colorful output' -r -f -a "{auto	'',always	'',never	''}"
 complete -c just -l command-color -d 'Echo recipe lines in <COMMAND-COLOR>' -r -f -a "{black	'',blue	'',cyan	'',green	'',purple	'',red	'',yellow	''}"
-complete -c just -l dump-format -d 'Dump justfile as <FORMAT>' -r -f -a "{just	'',json	''}"
+complete -c just -l dependency-argument -d 'Pass <VALUE> as argument <PARAMETER> of <RECIPE> whenever it runs, including as a dependency' -r
+complete -c just -l dump-format -d 'Dump justfile as <FORMAT>' -r -f -a "{just	'',json	'',json-lossless	''}"
+complete -c just -l recipe -d 'Only dump <RECIPE>' -r
+complete -c just -l variable -d 'Only dump <VARIABLE>' -r
+complete -c just -l exit-codes -d 'Use <CONVENTION> to choose exit code on failure' -r -f -a "{passthrough	'',normalized	'',categorized	''}"
+complete -c just -s j -l jobs -d 'Run at most <N> recipes at once, defaulting to the number of CPUs' -r
 complete -c just -l list-heading -d 'Print <TEXT> before list' -r
 complete -c just -l list-prefix -d 'Print <TEXT> before each list item' -r
 complete -c just -s f -l justfile -d 'Use <JUSTFILE> as justfile' -r -F
+complete -c just -l overrides-file -d 'Override variables with values from JSON object in <PATH>' -r -F
+complete -c just -l search-depth -d 'Search at most <N> parent directories for a justfile' -r
 complete -c just -l set -d 'Override <VARIABLE> with <VALUE>' -r
 complete -c just -l shell -d 'Invoke <SHELL> to run recipes' -r
 complete -c just -l shell-arg -d 'Invoke shell with <SHELL-ARG> as an argument' -r
+complete -c just -l transcript -d 'Write a JSON record of recipes run to <TRANSCRIPT>' -r -F
 complete -c just -s d -l working-directory -d 'Use <WORKING-DIRECTORY> as working directory. --justfile must also be set' -r -F
 complete -c just -s c -l command -d 'Run an arbitrary command with the working directory, `.env`, overrides, and exports set' -r
 complete -c just -l completions -d 'Print shell completion script for <SHELL>' -r -f -a "{bash	'',elvish	'',fish	'',powershell	'',zsh	''}"
+complete -c just -l find -d 'Print the location of recipes whose name, documentation, or body contains <PATTERN>' -r
 complete -c just -s s -l show -d 'Show information about <RECIPE>' -r
+complete -c just -l usage -d 'Print usage information for <RECIPE>' -r
 complete -c just -l dotenv-filename -d 'Search for environment file named <DOTENV-FILENAME> instead of `.env`' -r
 complete -c just -s E -l dotenv-path -d 'Load <DOTENV-PATH> as environment file instead of searching for one' -r -F
-complete -c just -l check -d 'Run `--fmt` in \'check\' mode. Exits with 0 if justfile is formatted correctly. Exits with 1 and prints a diff if formatting is required.'
+complete -c just -l align -d 'Run `--fmt` with `:=` vertically aligned in contiguous blocks of assignments'
+complete -c just -l check -d 'Compile justfile without running recipes or evaluating assignments. Exits with 0 if justfile is valid and 1 otherwise. With `--fmt`, exits with 0 if justfile is formatted correctly, and with 1 and prints a diff if formatting is required.'
 complete -c just -l yes -d 'Automatically confirm all recipes.'
 complete -c just -s n -l dry-run -d 'Print what just would do without doing it'
 complete -c just -l highlight -d 'Highlight echoed recipe lines in bold'
+complete -c just -l json -d 'Print `--list`, `--settings`, or `--summary` output as JSON'
+complete -c just -l list-flat -d 'List recipes in submodules as `module::recipe` instead of in indented groups'
+complete -c just -l locations -d 'Print the `path:line` of each recipe in `--list` and `--dump` output'
 complete -c just -l no-aliases -d 'Don\'t show aliases in list'
 complete -c just -l no-deps -d 'Don\'t run recipe dependencies'
 complete -c just -l no-dotenv -d 'Don\'t load `.env` file'
 complete -c just -l no-highlight -d 'Don\'t highlight echoed recipe lines in bold'
 complete -c just -s q -l quiet -d 'Suppress all output'
 complete -c just -l shell-command -d 'Invoke <COMMAND> with the shell used to run recipe lines and backticks'
+complete -c just -l silence-stderr -d 'Discard standard error of recipe commands'
+complete -c just -l silence-stdout -d 'Discard standard output of recipe commands'
+complete -c just -l stdin -d 'Run `--fmt` on a justfile read from standard input, writing the result to standard output'
+complete -c just -l stop-at-project-root -d 'Don\'t search for a justfile above the nearest directory containing `.git` or another version control directory'
 complete -c just -l clear-shell-args -d 'Clear shell arguments'
+complete -c just -l timings -d 'Print how long each recipe took to run after running recipes'
+complete -c just -l trace-evaluation -d 'Print each variable, function call, and backtick evaluated, and its value'
 complete -c just -s u -l unsorted -d 'Return list and summary entries in source order'
 complete -c just -l unstable -d 'Enable unstable features'
 complete -c just -s v -l verbose -d 'Use verbose output'
+complete -c just -l warn-as-error -d 'Treat warnings as errors'
 complete -c just -l changelog -d 'Print changelog'
 complete -c just -l choose -d 'Select one or more recipes to run using a binary chooser. If `--chooser` is not passed the chooser defaults to the value of $JUST_CHOOSER, falling back to `fzf`'
 complete -c just -l dump -d 'Print justfile'
-complete -c just -s e -l edit -d 'Edit justfile with editor given by $VISUAL or $EDITOR, falling back to `vim`'
+complete -c just -l dump-schema -d 'Print JSON Schema describing the output of `--dump --dump-format json`'
+complete -c just -s e -l edit -d 'Edit justfile with editor given by $VISUAL or $EDITOR, falling back to `vim`. If a recipe is given, open the editor at its definition'
 complete -c just -l evaluate -d 'Evaluate and print all variables. If a variable name is given as an argument, only print that variable\'s value.'
 complete -c just -l fmt -d 'Format and overwrite justfile'
+complete -c just -l groups -d 'List recipe groups'
 complete -c just -l init -d 'Initialize new justfile in project root'
 complete -c just -s l -l list -d 'List available recipes and their arguments'
 complete -c just -l man -d 'Print man page'
+complete -c just -l settings -d 'Print effective settings'
 complete -c just -l summary -d 'List names of available recipes'
 complete -c just -l variables -d 'List names of variables'
 complete -c just -s h -l help -d 'Print help'
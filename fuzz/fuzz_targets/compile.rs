@@ -2,5 +2,5 @@
 use libfuzzer_sys::fuzz_target;
 
 fuzz_target!(|src: &str| {
-  let _ = just::fuzzing::compile(src);
+  just::fuzzing::compile(src);
 });
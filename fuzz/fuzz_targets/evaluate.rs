@@ -0,0 +1,6 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|src: &str| {
+  just::fuzzing::evaluate(src);
+});
@@ -2,7 +2,9 @@ use super::*;
 
 /// An alias, e.g. `name := target`
 #[derive(Debug, PartialEq, Clone, Serialize)]
-pub(crate) struct Alias<'src, T = Rc<Recipe<'src>>> {
+pub(crate) struct Alias<'src, T = Arc<Recipe<'src>>> {
+  #[serde(skip)]
+  pub(crate) attribute_lines: Vec<AttributeLine<'src>>,
   pub(crate) attributes: BTreeSet<Attribute<'src>>,
   pub(crate) name: Name<'src>,
   #[serde(
@@ -13,10 +15,9 @@ pub(crate) struct Alias<'src, T = Rc<Recipe<'src>>> {
 }
 
 impl<'src> Alias<'src, Name<'src>> {
-  pub(crate) fn resolve(self, target: Rc<Recipe<'src>>) -> Alias<'src> {
-    assert_eq!(self.target.lexeme(), target.name.lexeme());
-
+  pub(crate) fn resolve(self, target: Arc<Recipe<'src>>) -> Alias<'src> {
     Alias {
+      attribute_lines: self.attribute_lines,
       attributes: self.attributes,
       name: self.name,
       target,
@@ -38,6 +39,10 @@ impl<'src, T> Keyed<'src> for Alias<'src, T> {
 
 impl<'src> Display for Alias<'src, Name<'src>> {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    for line in &self.attribute_lines {
+      writeln!(f, "{line}")?;
+    }
+
     write!(
       f,
       "alias {} := {}",
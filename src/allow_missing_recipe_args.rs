@@ -0,0 +1,13 @@
+use super::*;
+
+/// What to do when a recipe is invoked without all of its required
+/// arguments, set with `set allow-missing-recipe-args`
+#[derive(
+  Clone, Copy, Debug, Display, EnumString, IntoStaticStr, PartialEq, Serialize, VariantNames,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum AllowMissingRecipeArgs {
+  Error,
+  Prompt,
+}
@@ -43,18 +43,24 @@ impl<'src> Analyzer<'src> {
      -> CompileResult<'src> {
       if let Some((first_type, original)) = definitions.get(name.lexeme()) {
         if !(*first_type == second_type && duplicates_allowed) {
-          let (original, redefinition) = if name.line < original.line {
+          let (original, redefinition) = if name.path == original.path && name.line < original.line
+          {
             (name, *original)
           } else {
             (*original, name)
           };
 
-          return Err(redefinition.token.error(Redefinition {
-            first_type,
-            second_type,
-            name: name.lexeme(),
-            first: original.line,
-          }));
+          return Err(
+            redefinition
+              .token
+              .error(Redefinition {
+                first_type,
+                second_type,
+                name: name.lexeme(),
+                first: original.line,
+              })
+              .with_related(original.token),
+          );
         }
       }
 
@@ -74,7 +80,7 @@ impl<'src> Analyzer<'src> {
           Item::Assignment(assignment) => {
             assignments.push(assignment);
           }
-          Item::Comment(_) => (),
+          Item::Comment(_) | Item::TrailingComment(_) => (),
           Item::Import { absolute, .. } => {
             if let Some(absolute) = absolute {
               stack.push(asts.get(absolute).unwrap());
@@ -92,7 +98,7 @@ impl<'src> Analyzer<'src> {
           Item::Recipe(recipe) => {
             if recipe.enabled() {
               Self::analyze_recipe(recipe)?;
-              recipes.push(recipe);
+              Self::add_recipe(&mut recipes, recipe);
             }
           }
           Item::Set(set) => {
@@ -105,17 +111,25 @@ impl<'src> Analyzer<'src> {
       warnings.extend(ast.warnings.iter().cloned());
     }
 
+    let default_setting = self.sets.get(Keyword::Default.lexeme()).map(|set| set.name);
+
     let settings = Settings::from_setting_iter(self.sets.into_iter().map(|(_, set)| set.value));
 
     let mut recipe_table: Table<'src, UnresolvedRecipe<'src>> = Table::default();
 
     for assignment in assignments {
-      if !settings.allow_duplicate_variables
-        && self.assignments.contains_key(assignment.name.lexeme())
-      {
-        return Err(assignment.name.token.error(DuplicateVariable {
-          variable: assignment.name.lexeme(),
-        }));
+      if !settings.allow_duplicate_variables {
+        if let Some(original) = self.assignments.get(assignment.name.lexeme()) {
+          return Err(
+            assignment
+              .name
+              .token
+              .error(DuplicateVariable {
+                variable: assignment.name.lexeme(),
+              })
+              .with_related(original.name.token),
+          );
+        }
       }
 
       if self
@@ -129,6 +143,16 @@ impl<'src> Analyzer<'src> {
 
     AssignmentResolver::resolve_assignments(&self.assignments)?;
 
+    if let Some(tempdir) = &settings.tempdir {
+      for variable in tempdir.variables() {
+        if !self.assignments.contains_key(variable.lexeme()) {
+          return Err(variable.error(UndefinedVariable {
+            variable: variable.lexeme(),
+          }));
+        }
+      }
+    }
+
     for recipe in recipes {
       define(recipe.name, "recipe", settings.allow_duplicate_recipes)?;
       if recipe_table
@@ -142,24 +166,40 @@ impl<'src> Analyzer<'src> {
     let recipes = RecipeResolver::resolve_recipes(recipe_table, &self.assignments)?;
 
     let mut aliases = Table::new();
-    while let Some(alias) = self.aliases.pop() {
-      aliases.insert(Self::resolve_alias(&recipes, alias)?);
+    for alias in self.aliases.values() {
+      aliases.insert(Self::resolve_alias(&recipes, &self.aliases, alias)?);
     }
 
     let root = paths.get(root).unwrap();
 
-    Ok(Justfile {
-      default: recipes
+    let default = if let Some(default) = &settings.default {
+      match recipes
+        .get(default.as_str())
+        .or_else(|| aliases.get(default.as_str()).map(|alias| &alias.target))
+      {
+        Some(recipe) => Some(Arc::clone(recipe)),
+        None => {
+          return Err(default_setting.unwrap().error(UnknownDefaultRecipe {
+            recipe: default.clone(),
+          }))
+        }
+      }
+    } else {
+      recipes
         .values()
         .filter(|recipe| recipe.name.path == root)
         .fold(None, |accumulator, next| match accumulator {
-          None => Some(Rc::clone(next)),
+          None => Some(Arc::clone(next)),
           Some(previous) => Some(if previous.line_number() < next.line_number() {
             previous
           } else {
-            Rc::clone(next)
+            Arc::clone(next)
           }),
-        }),
+        })
+    };
+
+    Ok(Justfile {
+      default,
       aliases,
       assignments: self.assignments,
       loaded: loaded.into(),
@@ -173,6 +213,27 @@ impl<'src> Analyzer<'src> {
     })
   }
 
+  /// Add an enabled recipe, unless a more specific recipe with the same name
+  /// is already present, replacing any less specific ones. Recipes which are
+  /// equally specific are kept, and reported as duplicates later.
+  fn add_recipe<'a>(
+    recipes: &mut Vec<&'a UnresolvedRecipe<'src>>,
+    recipe: &'a UnresolvedRecipe<'src>,
+  ) {
+    let specificity = recipe.specificity();
+
+    if recipes
+      .iter()
+      .any(|other| other.name() == recipe.name() && other.specificity() > specificity)
+    {
+      return;
+    }
+
+    recipes.retain(|other| other.name() != recipe.name() || other.specificity() >= specificity);
+
+    recipes.push(recipe);
+  }
+
   fn analyze_recipe(recipe: &UnresolvedRecipe<'src>) -> CompileResult<'src> {
     let mut parameters = BTreeSet::new();
     let mut passed_default = false;
@@ -200,6 +261,33 @@ impl<'src> Analyzer<'src> {
       }
     }
 
+    for attribute in &recipe.attributes {
+      if let Attribute::Secret(names) = attribute {
+        for name in names {
+          if !recipe
+            .parameters
+            .iter()
+            .any(|parameter| parameter.name.lexeme() == name.cooked)
+          {
+            return Err(recipe.name.token.error(UnknownSecretParameter {
+              recipe: recipe.name.lexeme(),
+              parameter: name.cooked.clone(),
+            }));
+          }
+        }
+      }
+    }
+
+    if recipe.attributes.contains(&Attribute::Quiet)
+      && recipe.attributes.contains(&Attribute::Verbose)
+    {
+      return Err(recipe.name.token.error(ConflictingAttributes {
+        recipe: recipe.name.lexeme(),
+        first: Attribute::Quiet.name(),
+        second: Attribute::Verbose.name(),
+      }));
+    }
+
     let mut continued = false;
     for line in &recipe.body {
       if !recipe.shebang && !continued {
@@ -235,18 +323,24 @@ impl<'src> Analyzer<'src> {
 
   fn analyze_set(&self, set: &Set<'src>) -> CompileResult<'src> {
     if let Some(original) = self.sets.get(set.name.lexeme()) {
-      return Err(set.name.error(DuplicateSet {
-        setting: original.name.lexeme(),
-        first: original.name.line,
-      }));
+      return Err(
+        set
+          .name
+          .error(DuplicateSet {
+            setting: original.name.lexeme(),
+            first: original.name.line,
+          })
+          .with_related(original.name.token),
+      );
     }
 
     Ok(())
   }
 
   fn resolve_alias(
-    recipes: &Table<'src, Rc<Recipe<'src>>>,
-    alias: Alias<'src, Name<'src>>,
+    recipes: &Table<'src, Arc<Recipe<'src>>>,
+    aliases: &Table<'src, Alias<'src, Name<'src>>>,
+    alias: &Alias<'src, Name<'src>>,
   ) -> CompileResult<'src, Alias<'src>> {
     // Make sure the alias doesn't conflict with any recipe
     if let Some(recipe) = recipes.get(alias.name.lexeme()) {
@@ -256,12 +350,35 @@ impl<'src> Analyzer<'src> {
       }));
     }
 
+    // Follow aliases which target other aliases
+    let mut circle = vec![alias.name.lexeme()];
+    let mut edges = vec![alias.target.token];
+    let mut target = alias.target;
+    while let Some(next) = aliases.get(target.lexeme()) {
+      let cycle = circle.contains(&target.lexeme());
+      circle.push(target.lexeme());
+      if cycle {
+        return Err(
+          alias
+            .name
+            .token
+            .error(CircularAliasTarget {
+              alias: alias.name.lexeme(),
+              circle,
+            })
+            .with_cycle(edges),
+        );
+      }
+      edges.push(next.target.token);
+      target = next.target;
+    }
+
     // Make sure the target recipe exists
-    match recipes.get(alias.target.lexeme()) {
-      Some(target) => Ok(alias.resolve(Rc::clone(target))),
+    match recipes.get(target.lexeme()) {
+      Some(recipe) => Ok(alias.clone().resolve(Arc::clone(recipe))),
       None => Err(alias.name.token.error(UnknownAliasTarget {
         alias: alias.name.lexeme(),
-        target: alias.target.lexeme(),
+        target: target.lexeme(),
       })),
     }
   }
@@ -279,6 +396,7 @@ mod tests {
     column: 6,
     width: 3,
     kind: Redefinition { first_type: "alias", second_type: "alias", name: "foo", first: 0 },
+    related: (6, 0, 6, 3),
   }
 
   analysis_error! {
@@ -291,6 +409,48 @@ mod tests {
     kind: UnknownAliasTarget {alias: "foo", target: "bar"},
   }
 
+  analysis_error! {
+    name: unknown_chained_alias_target,
+    input: "alias a := b\nalias b := c\n",
+    offset: 6,
+    line: 0,
+    column: 6,
+    width: 1,
+    kind: UnknownAliasTarget {alias: "a", target: "c"},
+  }
+
+  analysis_error! {
+    name: alias_targets_itself,
+    input: "alias foo := foo\n",
+    offset: 6,
+    line: 0,
+    column: 6,
+    width: 3,
+    kind: CircularAliasTarget {alias: "foo", circle: vec!["foo", "foo"]},
+    cycle: [(13, 0, 13, 3)],
+  }
+
+  analysis_error! {
+    name: circular_alias_target,
+    input: "alias a := b\nalias b := c\nalias c := a\n",
+    offset: 6,
+    line: 0,
+    column: 6,
+    width: 1,
+    kind: CircularAliasTarget {alias: "a", circle: vec!["a", "b", "c", "a"]},
+    cycle: [(11, 0, 11, 1), (24, 1, 11, 1), (37, 2, 11, 1)],
+  }
+
+  analysis_error! {
+    name: unknown_default_recipe,
+    input: "set default := 'bar'\nfoo:",
+    offset: 4,
+    line: 0,
+    column: 4,
+    width: 7,
+    kind: UnknownDefaultRecipe {recipe: "bar".into()},
+  }
+
   analysis_error! {
     name: alias_shadows_recipe_before,
     input: "bar: \n  echo bar\nalias foo := bar\nfoo:\n  echo foo",
@@ -299,6 +459,7 @@ mod tests {
     column: 0,
     width: 3,
     kind: Redefinition { first_type: "alias", second_type: "recipe", name: "foo", first: 2 },
+    related: (23, 2, 6, 3),
   }
 
   analysis_error! {
@@ -309,6 +470,7 @@ mod tests {
     column: 6,
     width: 3,
     kind: Redefinition { first_type: "alias", second_type: "recipe", name: "foo", first: 0 },
+    related: (0, 0, 0, 3),
   }
 
   analysis_error! {
@@ -349,6 +511,7 @@ mod tests {
     column: 0,
     width:  1,
     kind:   Redefinition { first_type: "recipe", second_type: "recipe", name: "a", first: 0 },
+    related: (0, 0, 0, 1),
   }
 
   analysis_error! {
@@ -359,6 +522,7 @@ mod tests {
     column: 0,
     width:  1,
     kind:   DuplicateVariable{variable: "a"},
+    related: (0, 0, 0, 1),
   }
 
   analysis_error! {
@@ -3,8 +3,24 @@ use super::*;
 /// An assignment, e.g `foo := bar`
 pub(crate) type Assignment<'src> = Binding<'src, Expression<'src>>;
 
+impl<'src> Assignment<'src> {
+  /// Write the lines preceding the assignment itself, its doc comment and
+  /// attribute lines, so that they are the same whether or not the `:=` of
+  /// the assignment is aligned with its neighbors
+  pub(crate) fn write_header(&self, f: &mut dyn fmt::Write) -> fmt::Result {
+    if let Some(doc) = self.doc {
+      writeln!(f, "# {doc}")?;
+    }
+    for line in &self.attribute_lines {
+      writeln!(f, "{line}")?;
+    }
+    Ok(())
+  }
+}
+
 impl<'src> Display for Assignment<'src> {
   fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+    self.write_header(f)?;
     if self.export {
       write!(f, "export ")?;
     }
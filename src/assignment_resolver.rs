@@ -3,6 +3,9 @@ use {super::*, CompileErrorKind::*};
 pub(crate) struct AssignmentResolver<'src: 'run, 'run> {
   assignments: &'run Table<'src, Assignment<'src>>,
   stack: Vec<&'src str>,
+  /// Variable references followed to reach each assignment on the stack after
+  /// the first
+  edges: Vec<Token<'src>>,
   evaluated: BTreeSet<&'src str>,
 }
 
@@ -12,6 +15,7 @@ impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
   ) -> CompileResult<'src> {
     let mut resolver = Self {
       stack: Vec::new(),
+      edges: Vec::new(),
       evaluated: BTreeSet::new(),
       assignments,
     };
@@ -66,7 +70,7 @@ impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
         self.resolve_expression(rhs)?;
         self.resolve_expression(error)
       }
-      Expression::Call { thunk } => match thunk {
+      Expression::Call { thunk, .. } => match thunk {
         Thunk::Nullary { .. } => Ok(()),
         Thunk::Unary { arg, .. } => self.resolve_expression(arg),
         Thunk::UnaryOpt { args: (a, b), .. } => {
@@ -76,6 +80,24 @@ impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
           }
           Ok(())
         }
+        Thunk::UnaryPlus {
+          name,
+          args: (a, rest),
+          ..
+        } => {
+          // Assignments are evaluated before any recipe runs, and a recipe
+          // run in a new process would evaluate them again, recursively
+          if name.lexeme() == "run" {
+            return Err(name.error(AssignmentCallsRun {
+              assignment: self.stack.last().copied().unwrap_or_default(),
+            }));
+          }
+          self.resolve_expression(a)?;
+          for arg in rest {
+            self.resolve_expression(arg)?;
+          }
+          Ok(())
+        }
         Thunk::Binary { args: [a, b], .. } => {
           self.resolve_expression(a)?;
           self.resolve_expression(b)
@@ -98,6 +120,14 @@ impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
           self.resolve_expression(b)?;
           self.resolve_expression(c)
         }
+        Thunk::Quaternary {
+          args: [a, b, c, d], ..
+        } => {
+          self.resolve_expression(a)?;
+          self.resolve_expression(b)?;
+          self.resolve_expression(c)?;
+          self.resolve_expression(d)
+        }
       },
       Expression::Concatenation { lhs, rhs } => {
         self.resolve_expression(lhs)?;
@@ -130,18 +160,31 @@ impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
         let variable = name.lexeme();
         if self.evaluated.contains(variable) {
           Ok(())
-        } else if self.stack.contains(&variable) {
-          self.stack.push(variable);
+        } else if let Some(start) = self.stack.iter().position(|name| *name == variable) {
           Err(
             self.assignments[variable]
               .name
               .error(CircularVariableDependency {
                 variable,
-                circle: self.stack.clone(),
-              }),
+                circle: self.stack[start..]
+                  .iter()
+                  .copied()
+                  .chain(iter::once(variable))
+                  .collect(),
+              })
+              .with_cycle(
+                self.edges[start..]
+                  .iter()
+                  .copied()
+                  .chain(iter::once(name.token))
+                  .collect(),
+              ),
           )
         } else if self.assignments.contains_key(variable) {
-          self.resolve_assignment(variable)
+          self.edges.push(name.token);
+          self.resolve_assignment(variable)?;
+          self.edges.pop();
+          Ok(())
         } else {
           Err(name.token.error(UndefinedVariable { variable }))
         }
@@ -162,6 +205,18 @@ mod tests {
     column: 0,
     width:  1,
     kind:   CircularVariableDependency{variable: "a", circle: vec!["a", "b", "a"]},
+    cycle:  [(5, 0, 5, 1), (12, 1, 5, 1)],
+  }
+
+  analysis_error! {
+    name:   circular_variable_dependency_with_lead_in,
+    input:  "a := b\nb := c\nc := b",
+    offset: 7,
+    line:   1,
+    column: 0,
+    width:  1,
+    kind:   CircularVariableDependency{variable: "b", circle: vec!["b", "c", "b"]},
+    cycle:  [(12, 1, 5, 1), (19, 2, 5, 1)],
   }
 
   analysis_error! {
@@ -172,6 +227,7 @@ mod tests {
     column: 0,
     width:  1,
     kind:   CircularVariableDependency{variable: "a", circle: vec!["a", "a"]},
+    cycle:  [(5, 0, 5, 1)],
   }
 
   analysis_error! {
@@ -213,4 +269,14 @@ mod tests {
     width:  2,
     kind:   UndefinedVariable{variable: "yy"},
   }
+
+  analysis_error! {
+    name:   run_in_assignment,
+    input:  "x := y\ny := 'a' + run('foo')\nfoo:",
+    offset:  18,
+    line:   1,
+    column: 11,
+    width:  3,
+    kind:   AssignmentCallsRun{assignment: "y"},
+  }
 }
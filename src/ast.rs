@@ -7,26 +7,109 @@ use super::*;
 pub(crate) struct Ast<'src> {
   /// Items in the justfile
   pub(crate) items: Vec<Item<'src>>,
+  /// Byte range in the source of each item in `items`
+  pub(crate) spans: Vec<Range<usize>>,
+  /// Source text of items, by index in `items`, which contain comments
+  /// inside expressions. These comments aren't part of the expression, so
+  /// such items are formatted as written.
+  pub(crate) verbatim: BTreeMap<usize, &'src str>,
   /// Non-fatal warnings encountered during parsing
   pub(crate) warnings: Vec<Warning>,
 }
 
-impl<'src> Display for Ast<'src> {
-  fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-    let mut iter = self.items.iter().peekable();
+impl<'src> Ast<'src> {
+  /// Format the justfile, vertically aligning the `:=` of contiguous
+  /// assignments if `align` is true, and preceding each recipe with a comment
+  /// containing its `path:line` if `locations` is true
+  pub(crate) fn format(&self, align: bool, locations: bool) -> String {
+    let mut formatted = String::new();
+    self
+      .write(&mut formatted, align, locations)
+      .expect("writing to a string cannot fail");
+    formatted
+  }
+
+  fn write(&self, w: &mut dyn fmt::Write, align: bool, locations: bool) -> fmt::Result {
+    let mut iter = self.items.iter().enumerate().peekable();
+    let mut block_width = None;
+
+    while let Some((i, item)) = iter.next() {
+      match item {
+        _ if self.verbatim.contains_key(&i) => {
+          block_width = None;
+          if let (Item::Recipe(recipe), true) = (item, locations) {
+            writeln!(w, "# {}", recipe.location())?;
+          }
+          write!(w, "{}", self.verbatim[&i])?;
+        }
+        Item::Assignment(assignment) if align => {
+          let width =
+            *block_width.get_or_insert_with(|| Self::assignment_block_width(&self.items[i..]));
+          let name = Self::assignment_name(assignment);
+          assignment.write_header(w)?;
+          write!(
+            w,
+            "{name}{:padding$} := {}",
+            "",
+            assignment.value,
+            padding = width - UnicodeWidthStr::width(name.as_str()),
+          )?;
+        }
+        Item::Recipe(recipe) if locations => {
+          block_width = None;
+          writeln!(w, "# {}", recipe.location())?;
+          write!(w, "{item}")?;
+        }
+        _ => {
+          block_width = None;
+          write!(w, "{item}")?;
+        }
+      }
+
+      if let Some((_, Item::TrailingComment(comment))) = iter.peek() {
+        write!(w, " {comment}")?;
+        iter.next();
+      }
 
-    while let Some(item) = iter.next() {
-      writeln!(f, "{item}")?;
+      writeln!(w)?;
 
-      if let Some(next_item) = iter.peek() {
+      if let Some((_, next_item)) = iter.peek() {
         if matches!(item, Item::Recipe(_))
-          || mem::discriminant(item) != mem::discriminant(next_item)
+          || matches!(next_item, Item::Assignment(assignment) if assignment.doc.is_some())
+          || mem::discriminant(item) != mem::discriminant(*next_item)
         {
-          writeln!(f)?;
+          writeln!(w)?;
         }
       }
     }
 
     Ok(())
   }
+
+  fn assignment_name(assignment: &Assignment) -> String {
+    if assignment.export {
+      format!("export {}", assignment.name)
+    } else {
+      assignment.name.to_string()
+    }
+  }
+
+  fn assignment_block_width(items: &[Item]) -> usize {
+    items
+      .iter()
+      .take_while(|item| matches!(item, Item::Assignment(_) | Item::TrailingComment(_)))
+      .filter_map(|item| match item {
+        Item::Assignment(assignment) => Some(Self::assignment_name(assignment)),
+        _ => None,
+      })
+      .map(|name| UnicodeWidthStr::width(name.as_str()))
+      .max()
+      .unwrap_or_default()
+  }
+}
+
+impl<'src> Display for Ast<'src> {
+  fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
+    self.write(f, false, false)
+  }
 }
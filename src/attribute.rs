@@ -1,22 +1,96 @@
 use super::*;
 
-#[derive(EnumString, PartialEq, Debug, Clone, Serialize, Ord, PartialOrd, Eq, IntoStaticStr)]
+#[derive(
+  EnumString, PartialEq, Debug, Clone, Serialize, Ord, PartialOrd, Eq, IntoStaticStr, VariantNames,
+)]
 #[strum(serialize_all = "kebab-case")]
 #[serde(rename_all = "kebab-case")]
 pub(crate) enum Attribute<'src> {
+  Allow(Vec<StringLiteral<'src>>),
+  Arch(Vec<StringLiteral<'src>>),
+  BodyFile(Option<StringLiteral<'src>>),
+  Choices(Vec<StringLiteral<'src>>),
   Confirm(Option<StringLiteral<'src>>),
+  Dotenv(Option<StringLiteral<'src>>),
+  ForwardArguments,
+  Freebsd,
+  Group(Option<StringLiteral<'src>>),
   Linux,
   Macos,
   NoCd,
   NoExitMessage,
+  NoList,
+  Openbsd,
+  Parallel,
   Private,
   NoQuiet,
+  Quiet,
+  Secret(Vec<StringLiteral<'src>>),
+  Tempdir(Option<StringLiteral<'src>>),
   Unix,
+  Verbatim,
+  Verbose,
   Windows,
+  Wsl,
 }
 
 impl<'src> Attribute<'src> {
-  pub(crate) fn from_name(name: Name) -> Option<Self> {
+  pub(crate) fn new(
+    name: Name<'src>,
+    mut arguments: Vec<StringLiteral<'src>>,
+  ) -> CompileResult<'src, Self> {
+    let attribute = Self::from_name(name).ok_or_else(|| {
+      name.error(CompileErrorKind::UnknownAttribute {
+        attribute: name.lexeme(),
+        suggestion: Self::suggest(name.lexeme()),
+      })
+    })?;
+
+    let found = arguments.len();
+    let range = attribute.argument_range();
+
+    if !range.contains(&found) {
+      let (min, max) = (*range.start(), *range.end());
+      return Err(name.error(if max == 0 {
+        CompileErrorKind::UnexpectedAttributeArgument { attribute }
+      } else if found == 0 && min == 1 {
+        CompileErrorKind::MissingAttributeArgument { attribute }
+      } else {
+        CompileErrorKind::AttributeArgumentCountMismatch {
+          attribute: name.lexeme(),
+          found,
+          min,
+          max,
+        }
+      }));
+    }
+
+    if let Self::Allow(_) = attribute {
+      for argument in &arguments {
+        if argument.cooked.parse::<WarningCode>().is_err() {
+          return Err(name.error(CompileErrorKind::UnknownWarningCode {
+            code: argument.cooked.clone(),
+            suggestion: WarningCode::suggest(&argument.cooked),
+          }));
+        }
+      }
+    }
+
+    Ok(match attribute {
+      Self::Allow(_) => Self::Allow(arguments),
+      Self::Arch(_) => Self::Arch(arguments),
+      Self::Choices(_) => Self::Choices(arguments),
+      Self::Secret(_) => Self::Secret(arguments),
+      Self::BodyFile(_) => Self::BodyFile(arguments.pop()),
+      Self::Confirm(_) => Self::Confirm(arguments.pop()),
+      Self::Dotenv(_) => Self::Dotenv(arguments.pop()),
+      Self::Group(_) => Self::Group(arguments.pop()),
+      Self::Tempdir(_) => Self::Tempdir(arguments.pop()),
+      attribute => attribute,
+    })
+  }
+
+  fn from_name(name: Name) -> Option<Self> {
     name.lexeme().parse().ok()
   }
 
@@ -24,22 +98,38 @@ impl<'src> Attribute<'src> {
     self.into()
   }
 
-  pub(crate) fn with_argument(
-    self,
-    name: Name<'src>,
-    argument: StringLiteral<'src>,
-  ) -> CompileResult<'src, Self> {
+  pub(crate) fn suggest(name: &str) -> Option<Suggestion<'static>> {
+    Suggestion::closest(name, Self::VARIANTS.iter().copied())
+  }
+
+  /// Whether this attribute may appear more than once on the same item
+  pub(crate) fn repeatable(&self) -> bool {
+    matches!(self, Self::Group(_))
+  }
+
+  /// The range of argument counts this attribute accepts
+  fn argument_range(&self) -> RangeInclusive<usize> {
     match self {
-      Self::Confirm(_) => Ok(Self::Confirm(Some(argument))),
-      _ => Err(name.error(CompileErrorKind::UnexpectedAttributeArgument { attribute: self })),
+      Self::Allow(_) | Self::Arch(_) | Self::Choices(_) => 1..=usize::MAX,
+      Self::Confirm(_) => 0..=1,
+      Self::Secret(_) => 0..=usize::MAX,
+      Self::BodyFile(_) | Self::Dotenv(_) | Self::Group(_) | Self::Tempdir(_) => 1..=1,
+      _ => 0..=0,
     }
   }
 
-  fn argument(&self) -> Option<&StringLiteral> {
-    if let Self::Confirm(prompt) = self {
-      prompt.as_ref()
-    } else {
-      None
+  fn arguments(&self) -> Vec<&StringLiteral> {
+    match self {
+      Self::Allow(arguments)
+      | Self::Arch(arguments)
+      | Self::Choices(arguments)
+      | Self::Secret(arguments) => arguments.iter().collect(),
+      Self::BodyFile(argument)
+      | Self::Confirm(argument)
+      | Self::Dotenv(argument)
+      | Self::Group(argument)
+      | Self::Tempdir(argument) => argument.iter().collect(),
+      _ => Vec::new(),
     }
   }
 }
@@ -48,8 +138,17 @@ impl<'src> Display for Attribute<'src> {
   fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
     write!(f, "{}", self.name())?;
 
-    if let Some(argument) = self.argument() {
-      write!(f, "({argument})")?;
+    let arguments = self.arguments();
+
+    if !arguments.is_empty() {
+      write!(f, "(")?;
+      for (i, argument) in arguments.iter().enumerate() {
+        if i > 0 {
+          write!(f, ", ")?;
+        }
+        write!(f, "{argument}")?;
+      }
+      write!(f, ")")?;
     }
 
     Ok(())
@@ -64,4 +163,16 @@ mod tests {
   fn name() {
     assert_eq!(Attribute::NoExitMessage.name(), "no-exit-message");
   }
+
+  #[test]
+  fn suggest() {
+    assert_eq!(
+      Attribute::suggest("no-exit-mesage"),
+      Some(Suggestion {
+        name: "no-exit-message",
+        target: None,
+      }),
+    );
+    assert_eq!(Attribute::suggest("unknown"), None);
+  }
 }
@@ -0,0 +1,33 @@
+use super::*;
+
+/// A line preceding an item, containing attributes, a comment, or both, kept
+/// as written so that formatting leaves comments where they were
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AttributeLine<'src> {
+  pub(crate) attributes: Vec<Attribute<'src>>,
+  pub(crate) comment: Option<&'src str>,
+}
+
+impl<'src> Display for AttributeLine<'src> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    if !self.attributes.is_empty() {
+      write!(f, "[")?;
+      for (i, attribute) in self.attributes.iter().enumerate() {
+        if i > 0 {
+          write!(f, ", ")?;
+        }
+        write!(f, "{attribute}")?;
+      }
+      write!(f, "]")?;
+    }
+
+    if let Some(comment) = self.comment {
+      if !self.attributes.is_empty() {
+        write!(f, " ")?;
+      }
+      write!(f, "{comment}")?;
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,10 @@
+use super::*;
+
+/// The attributes preceding an item, as parsed
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Attributes<'src> {
+  /// Attribute and comment lines, as written
+  pub(crate) lines: Vec<AttributeLine<'src>>,
+  /// Attributes from all lines
+  pub(crate) set: BTreeSet<Attribute<'src>>,
+}
@@ -3,12 +3,24 @@ use super::*;
 /// A binding of `name` to `value`
 #[derive(Debug, Clone, PartialEq, Serialize)]
 pub(crate) struct Binding<'src, V = String> {
+  /// Attribute lines preceding binding, as written
+  #[serde(skip)]
+  pub(crate) attribute_lines: Vec<AttributeLine<'src>>,
+  /// Values binding may be overridden with on the command line, if restricted
+  pub(crate) choices: Vec<StringLiteral<'src>>,
   /// Module depth where binding appears
   pub(crate) depth: u32,
+  /// Doc comment preceding binding
+  pub(crate) doc: Option<&'src str>,
   /// Export binding as an environment variable to child processes
   pub(crate) export: bool,
+  /// Name to export binding as, if not `name`
+  #[serde(skip)]
+  pub(crate) export_name: Option<Name<'src>>,
   /// Binding name
   pub(crate) name: Name<'src>,
+  /// Mask binding value in echoed commands and other output
+  pub(crate) secret: bool,
   /// Binding value
   pub(crate) value: V,
 }
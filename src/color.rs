@@ -2,6 +2,7 @@ use {
   super::*,
   ansi_term::{ANSIGenericString, Color::*, Prefix, Style, Suffix},
   atty::Stream,
+  std::fmt::Write as _,
 };
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -31,9 +32,11 @@ impl Color {
     }
   }
 
+  /// Use color if the output stream is a terminal, unless overridden by the
+  /// environment. See `UseColor::from_env`.
   pub(crate) fn auto() -> Self {
     Self {
-      use_color: UseColor::Auto,
+      use_color: UseColor::from_env(|name| env::var(name).ok()),
       ..Self::default()
     }
   }
@@ -120,6 +123,56 @@ impl Color {
     }
   }
 
+  /// The `--color` argument that selects this color mode
+  pub(crate) fn argument(&self) -> &'static str {
+    match self.use_color {
+      UseColor::Auto => "auto",
+      UseColor::Always => "always",
+      UseColor::Never => "never",
+    }
+  }
+
+  /// True if OSC 8 hyperlinks should be emitted. Unlike colors, hyperlinks
+  /// are never written to redirected output, even with `--color always`,
+  /// since they would be noise in files and pagers.
+  pub(crate) fn hyperlinks(&self) -> bool {
+    self.atty && self.use_color != UseColor::Never
+  }
+
+  /// Render `text` as an OSC 8 hyperlink to the `file://` URL of `path`, if
+  /// hyperlinks are enabled and `path` is absolute
+  pub(crate) fn hyperlink(&self, path: &Path, text: &str) -> String {
+    if self.hyperlinks() && path.is_absolute() {
+      format!("\x1b]8;;{}\x1b\\{text}\x1b]8;;\x1b\\", Self::file_url(path))
+    } else {
+      text.into()
+    }
+  }
+
+  fn file_url(path: &Path) -> String {
+    let mut path = path.to_string_lossy().into_owned();
+
+    if cfg!(windows) {
+      path = path.replace('\\', "/");
+    }
+
+    let mut url = String::from("file://");
+
+    if !path.starts_with('/') {
+      url.push('/');
+    }
+
+    for byte in path.bytes() {
+      if byte.is_ascii_alphanumeric() || b"-._~/:".contains(&byte) {
+        url.push(byte.into());
+      } else {
+        write!(url, "%{byte:02X}").expect("writing to a string cannot fail");
+      }
+    }
+
+    url
+  }
+
   pub(crate) fn paint<'a>(&self, text: &'a str) -> ANSIGenericString<'a, str> {
     self.effective_style().paint(text)
   }
@@ -142,3 +195,58 @@ impl Default for Color {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  #[cfg(unix)]
+  fn file_url() {
+    assert_eq!(
+      Color::file_url(Path::new("/foo/bar baz/justfile")),
+      "file:///foo/bar%20baz/justfile",
+    );
+  }
+
+  #[test]
+  #[cfg(windows)]
+  fn file_url_windows() {
+    assert_eq!(
+      Color::file_url(Path::new("C:\\foo\\justfile")),
+      "file:///C:/foo/justfile",
+    );
+  }
+
+  #[test]
+  #[cfg(unix)]
+  fn hyperlink() {
+    let color = Color {
+      atty: true,
+      ..Color::default()
+    };
+
+    assert_eq!(
+      color.hyperlink(Path::new("/justfile"), "foo"),
+      "\x1b]8;;file:///justfile\x1b\\foo\x1b]8;;\x1b\\",
+    );
+  }
+
+  #[test]
+  fn no_hyperlink_when_redirected() {
+    assert_eq!(
+      Color::always().hyperlink(Path::new("/justfile"), "foo"),
+      "foo"
+    );
+  }
+
+  #[test]
+  fn no_hyperlink_when_never() {
+    let color = Color {
+      atty: true,
+      ..Color::never()
+    };
+
+    assert_eq!(color.hyperlink(Path::new("/justfile"), "foo"), "foo");
+  }
+}
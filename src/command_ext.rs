@@ -1,31 +1,153 @@
 use super::*;
 
 pub(crate) trait CommandExt {
-  fn export(&mut self, settings: &Settings, dotenv: &BTreeMap<String, String>, scope: &Scope);
+  fn export(
+    &mut self,
+    config: &Config,
+    settings: &Settings,
+    dotenv: &BTreeMap<String, String>,
+    scope: &Scope,
+  );
 
-  fn export_scope(&mut self, settings: &Settings, scope: &Scope);
+  /// Pass shell overrides given on the command line to child processes, so
+  /// that recursive invocations of `just` use the same shell
+  fn export_shell(&mut self, config: &Config);
+
+  /// Run the command and wait for it to exit, deferring interrupts until it
+  /// does. Fails without the `native` feature.
+  fn spawn_status(&mut self) -> io::Result<ExitStatus>;
+
+  /// Run the command and collect its output. Fails without the `native`
+  /// feature.
+  fn spawn_output(&mut self) -> io::Result<process::Output>;
 }
 
 impl CommandExt for Command {
-  fn export(&mut self, settings: &Settings, dotenv: &BTreeMap<String, String>, scope: &Scope) {
-    for (name, value) in dotenv {
-      self.env(name, value);
+  fn export(
+    &mut self,
+    config: &Config,
+    settings: &Settings,
+    dotenv: &BTreeMap<String, String>,
+    scope: &Scope,
+  ) {
+    if settings.env_filtered() {
+      for (name, _) in env::vars_os() {
+        if !name
+          .to_str()
+          .map_or(false, |name| settings.env_allowed(name))
+        {
+          self.env_remove(name);
+        }
+      }
     }
 
-    if let Some(parent) = scope.parent() {
-      self.export_scope(settings, parent);
+    self.export_shell(config);
+
+    if config.dry_run {
+      self.env(config::DRY_RUN_ENV, "1");
     }
+
+    self.envs(exported_variables(settings, dotenv, scope));
   }
 
-  fn export_scope(&mut self, settings: &Settings, scope: &Scope) {
-    if let Some(parent) = scope.parent() {
-      self.export_scope(settings, parent);
+  fn export_shell(&mut self, config: &Config) {
+    if let Some(shell) = &config.shell {
+      self.env(config::SHELL_ENV, shell);
     }
 
-    for binding in scope.bindings() {
-      if settings.export || binding.export {
-        self.env(binding.name.lexeme(), &binding.value);
+    if let Some(shell_args) = &config.shell_args {
+      if let Ok(shell_args) = serde_json::to_string(shell_args) {
+        self.env(config::SHELL_ARGS_ENV, shell_args);
       }
     }
   }
+
+  #[cfg(feature = "native")]
+  fn spawn_status(&mut self) -> io::Result<ExitStatus> {
+    InterruptHandler::guard(|| self.status())
+  }
+
+  #[cfg(not(feature = "native"))]
+  fn spawn_status(&mut self) -> io::Result<ExitStatus> {
+    Err(Platform::unsupported("running commands"))
+  }
+
+  #[cfg(feature = "native")]
+  fn spawn_output(&mut self) -> io::Result<process::Output> {
+    self.output()
+  }
+
+  #[cfg(not(feature = "native"))]
+  fn spawn_output(&mut self) -> io::Result<process::Output> {
+    Err(Platform::unsupported("running commands"))
+  }
+}
+
+/// Variables set in the environment of commands run in `scope`, from dotenv
+/// files and exported variables and parameters
+fn exported_variables(
+  settings: &Settings,
+  dotenv: &BTreeMap<String, String>,
+  scope: &Scope,
+) -> BTreeMap<String, String> {
+  environment(settings, dotenv, scope, false)
+}
+
+/// Variables exported to commands, as returned by `exported_variables`, with
+/// the values of secrets and of variables loaded from dotenv files, which
+/// often hold credentials, replaced with `***`, for reporting in transcripts
+pub(crate) fn masked_exported_variables(
+  settings: &Settings,
+  dotenv: &BTreeMap<String, String>,
+  scope: &Scope,
+) -> BTreeMap<String, String> {
+  environment(settings, dotenv, scope, true)
+}
+
+fn environment(
+  settings: &Settings,
+  dotenv: &BTreeMap<String, String>,
+  scope: &Scope,
+  mask: bool,
+) -> BTreeMap<String, String> {
+  let mut variables = dotenv
+    .iter()
+    .filter(|(name, _)| settings.env_allowed(name))
+    .map(|(name, value)| {
+      (
+        name.clone(),
+        if mask { "***".into() } else { value.clone() },
+      )
+    })
+    .collect();
+
+  if let Some(parent) = scope.parent() {
+    export_scope(settings, parent, mask, &mut variables);
+  }
+
+  variables
+}
+
+fn export_scope(
+  settings: &Settings,
+  scope: &Scope,
+  mask: bool,
+  variables: &mut BTreeMap<String, String>,
+) {
+  if let Some(parent) = scope.parent() {
+    export_scope(settings, parent, mask, variables);
+  }
+
+  for binding in scope.bindings() {
+    if settings.export || binding.export {
+      variables.insert(
+        binding.export_name.unwrap_or(binding.name).lexeme().into(),
+        if mask && binding.secret {
+          "***".into()
+        } else {
+          binding.value.clone()
+        },
+      );
+    }
+  }
 }
@@ -4,6 +4,10 @@ use super::*;
 pub(crate) struct CompileError<'src> {
   pub(crate) token: Token<'src>,
   pub(crate) kind: Box<CompileErrorKind<'src>>,
+  pub(crate) related: Option<Box<Token<'src>>>,
+  /// Tokens of each edge of a dependency cycle, in order, displayed after
+  /// the error token
+  pub(crate) cycle: Vec<Token<'src>>,
 }
 
 impl<'src> CompileError<'src> {
@@ -15,6 +19,50 @@ impl<'src> CompileError<'src> {
     Self {
       token,
       kind: kind.into(),
+      related: None,
+      cycle: Vec::new(),
+    }
+  }
+
+  /// Attach the token of a related definition, for example the original
+  /// definition of a redefined recipe.
+  pub(crate) fn with_related(self, related: Token<'src>) -> CompileError<'src> {
+    Self {
+      related: Some(Box::new(related)),
+      ..self
+    }
+  }
+
+  /// Attach the tokens of each edge of a dependency cycle, for example the
+  /// dependencies of each recipe in a circular chain of recipes.
+  pub(crate) fn with_cycle(self, cycle: Vec<Token<'src>>) -> CompileError<'src> {
+    Self { cycle, ..self }
+  }
+
+  /// The related token, if it is in a different file than the error token
+  /// and so must be displayed separately.
+  pub(crate) fn foreign_related(&self) -> Option<Token<'src>> {
+    self
+      .related
+      .as_deref()
+      .copied()
+      .filter(|related| related.path != self.token.path)
+  }
+
+  fn lines(&self, first: usize) -> (String, String) {
+    match self.foreign_related() {
+      Some(related) => (
+        format!("line {} of `{}`", first.ordinal(), related.path.display()),
+        format!(
+          "line {} of `{}`",
+          self.token.line.ordinal(),
+          self.token.path.display()
+        ),
+      ),
+      None => (
+        format!("line {}", first.ordinal()),
+        format!("line {}", self.token.line.ordinal()),
+      ),
     }
   }
 }
@@ -45,7 +93,51 @@ impl Display for CompileError<'_> {
         self.token.line.ordinal(),
         recipe_line.ordinal(),
       ),
+      AssignmentCallsRun { assignment } => write!(
+        f,
+        "Assignment `{assignment}` calls `run()`, which may only be called from recipes"
+      ),
+      AssignmentInvalidAttribute {
+        assignment,
+        attribute,
+      } => write!(
+        f,
+        "Assignment `{assignment}` has invalid attribute `{}`",
+        attribute.name(),
+      ),
+      AttributeArgumentCountMismatch {
+        attribute,
+        found,
+        min,
+        max,
+      } => {
+        write!(
+          f,
+          "Attribute `{attribute}` got {found} {} but takes ",
+          Count("argument", *found),
+        )?;
+
+        if min == max {
+          let expected = min;
+          write!(f, "{expected} {}", Count("argument", *expected))
+        } else if found < min {
+          write!(f, "at least {min} {}", Count("argument", *min))
+        } else {
+          write!(f, "at most {max} {}", Count("argument", *max))
+        }
+      }
       BacktickShebang => write!(f, "Backticks may not start with `#!`"),
+      CircularAliasTarget { alias, ref circle } => {
+        if circle.len() == 2 {
+          write!(f, "Alias `{alias}` targets itself")
+        } else {
+          write!(
+            f,
+            "Alias `{alias}` has circular target `{}`",
+            circle.join(" -> ")
+          )
+        }
+      }
       CircularRecipeDependency { recipe, ref circle } => {
         if circle.len() == 2 {
           write!(f, "Recipe `{recipe}` depends on itself")
@@ -71,6 +163,14 @@ impl Display for CompileError<'_> {
           )
         }
       }
+      ConflictingAttributes {
+        recipe,
+        first,
+        second,
+      } => write!(
+        f,
+        "Recipe `{recipe}` has conflicting attributes `{first}` and `{second}`"
+      ),
       DependencyArgumentCountMismatch {
         dependency,
         found,
@@ -101,12 +201,13 @@ impl Display for CompileError<'_> {
       DuplicateParameter { recipe, parameter } => {
         write!(f, "Recipe `{recipe}` has duplicate parameter `{parameter}`")
       }
-      DuplicateSet { setting, first } => write!(
-        f,
-        "Setting `{setting}` first set on line {} is redefined on line {}",
-        first.ordinal(),
-        self.token.line.ordinal(),
-      ),
+      DuplicateSet { setting, first } => {
+        let (first, second) = self.lines(*first);
+        write!(
+          f,
+          "Setting `{setting}` first set on {first} is redefined on {second}",
+        )
+      }
       DuplicateVariable { variable } => {
         write!(f, "Variable `{variable}` has multiple definitions")
       }
@@ -160,6 +261,22 @@ impl Display for CompileError<'_> {
           _ => character.escape_default().collect(),
         }
       ),
+      InvalidParameterPattern { parameter, source } => {
+        write!(f, "Invalid pattern for parameter `{parameter}`: {source}")
+      }
+      InvalidSettingInteger { setting, value } => write!(
+        f,
+        "Invalid value `{value}` for setting `{setting}`, expected a non-negative integer",
+      ),
+      InvalidSettingValue {
+        setting,
+        value,
+        choices,
+      } => write!(
+        f,
+        "Invalid value `{value}` for setting `{setting}`, expected {}",
+        List::or_ticked(*choices),
+      ),
       MismatchedClosingDelimiter {
         open,
         open_line,
@@ -171,6 +288,9 @@ impl Display for CompileError<'_> {
         open.open(),
         open_line.ordinal(),
       ),
+      MissingAttributeArgument { attribute } => {
+        write!(f, "Attribute `{}` requires an argument", attribute.name())
+      }
       MixedLeadingWhitespace { whitespace } => write!(
         f,
         "Found a mix of tabs and spaces in leading whitespace: `{}`\nLeading whitespace may \
@@ -187,22 +307,19 @@ impl Display for CompileError<'_> {
         name,
         second_type,
       } => {
+        let (first, second) = self.lines(*first);
         if first_type == second_type {
           write!(
             f,
-            "{} `{name}` first defined on line {} is redefined on line {}",
+            "{} `{name}` first defined on {first} is redefined on {second}",
             capitalize(first_type),
-            first.ordinal(),
-            self.token.line.ordinal(),
           )
         } else {
           write!(
             f,
-            "{} `{name}` defined on line {} is redefined as {} {second_type} on line {}",
+            "{} `{name}` defined on {first} is redefined as {} {second_type} on {second}",
             capitalize(first_type),
-            first.ordinal(),
             if *second_type == "alias" { "an" } else { "a" },
-            self.token.line.ordinal(),
           )
         }
       }
@@ -232,15 +349,57 @@ impl Display for CompileError<'_> {
       UnknownAliasTarget { alias, target } => {
         write!(f, "Alias `{alias}` has an unknown target `{target}`")
       }
-      UnknownAttribute { attribute } => write!(f, "Unknown attribute `{attribute}`"),
+      UnknownAttribute {
+        attribute,
+        suggestion,
+      } => {
+        write!(f, "Unknown attribute `{attribute}`")?;
+        if let Some(suggestion) = suggestion {
+          write!(f, "\n{suggestion}")?;
+        }
+        Ok(())
+      }
+      UnknownDefaultRecipe { recipe } => {
+        write!(f, "Default recipe `{recipe}` does not exist")
+      }
       UnknownDependency { recipe, unknown } => {
         write!(f, "Recipe `{recipe}` has unknown dependency `{unknown}`")
       }
-      UnknownFunction { function } => write!(f, "Call to unknown function `{function}`"),
-      UnknownSetting { setting } => write!(f, "Unknown setting `{setting}`"),
+      UnknownFunction {
+        function,
+        suggestion,
+      } => {
+        write!(f, "Call to unknown function `{function}`")?;
+        if let Some(suggestion) = suggestion {
+          write!(f, "\n{suggestion}")?;
+        }
+        Ok(())
+      }
+      UnknownSecretParameter { recipe, parameter } => write!(
+        f,
+        "Recipe `{recipe}` has no parameter `{parameter}` to mark `secret`"
+      ),
+      UnknownSetting {
+        setting,
+        suggestion,
+      } => {
+        write!(f, "Unknown setting `{setting}`")?;
+        if let Some(suggestion) = suggestion {
+          write!(f, "\n{suggestion}")?;
+        }
+        Ok(())
+      }
       UnknownStartOfToken => write!(f, "Unknown start of token:"),
+      UnknownWarningCode { code, suggestion } => {
+        write!(f, "Unknown warning code `{code}`")?;
+        if let Some(suggestion) = suggestion {
+          write!(f, "\n{suggestion}")?;
+        }
+        Ok(())
+      }
       UnpairedCarriageReturn => write!(f, "Unpaired carriage return"),
       UnterminatedBacktick => write!(f, "Unterminated backtick"),
+      UnterminatedBlockComment => write!(f, "Unterminated block comment"),
       UnterminatedInterpolation => write!(f, "Unterminated interpolation"),
       UnterminatedString => write!(f, "Unterminated string"),
     }
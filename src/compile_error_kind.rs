@@ -10,7 +10,24 @@ pub(crate) enum CompileErrorKind<'src> {
     alias: &'src str,
     recipe_line: usize,
   },
+  AssignmentCallsRun {
+    assignment: &'src str,
+  },
+  AssignmentInvalidAttribute {
+    assignment: &'src str,
+    attribute: Attribute<'src>,
+  },
+  AttributeArgumentCountMismatch {
+    attribute: &'src str,
+    found: usize,
+    min: usize,
+    max: usize,
+  },
   BacktickShebang,
+  CircularAliasTarget {
+    alias: &'src str,
+    circle: Vec<&'src str>,
+  },
   CircularRecipeDependency {
     recipe: &'src str,
     circle: Vec<&'src str>,
@@ -19,6 +36,11 @@ pub(crate) enum CompileErrorKind<'src> {
     variable: &'src str,
     circle: Vec<&'src str>,
   },
+  ConflictingAttributes {
+    recipe: &'src str,
+    first: &'static str,
+    second: &'static str,
+  },
   DependencyArgumentCountMismatch {
     dependency: &'src str,
     found: usize,
@@ -67,11 +89,27 @@ pub(crate) enum CompileErrorKind<'src> {
   InvalidEscapeSequence {
     character: char,
   },
+  InvalidParameterPattern {
+    parameter: &'src str,
+    source: regex::Error,
+  },
+  InvalidSettingInteger {
+    setting: &'src str,
+    value: String,
+  },
+  InvalidSettingValue {
+    setting: &'src str,
+    value: String,
+    choices: &'static [&'static str],
+  },
   MismatchedClosingDelimiter {
     close: Delimiter,
     open: Delimiter,
     open_line: usize,
   },
+  MissingAttributeArgument {
+    attribute: Attribute<'src>,
+  },
   MixedLeadingWhitespace {
     whitespace: &'src str,
   },
@@ -107,6 +145,10 @@ pub(crate) enum CompileErrorKind<'src> {
   },
   UnknownAttribute {
     attribute: &'src str,
+    suggestion: Option<Suggestion<'src>>,
+  },
+  UnknownDefaultRecipe {
+    recipe: String,
   },
   UnknownDependency {
     recipe: &'src str,
@@ -114,13 +156,24 @@ pub(crate) enum CompileErrorKind<'src> {
   },
   UnknownFunction {
     function: &'src str,
+    suggestion: Option<Suggestion<'src>>,
+  },
+  UnknownSecretParameter {
+    recipe: &'src str,
+    parameter: String,
   },
   UnknownSetting {
     setting: &'src str,
+    suggestion: Option<Suggestion<'src>>,
   },
   UnknownStartOfToken,
+  UnknownWarningCode {
+    code: String,
+    suggestion: Option<Suggestion<'src>>,
+  },
   UnpairedCarriageReturn,
   UnterminatedBacktick,
+  UnterminatedBlockComment,
   UnterminatedInterpolation,
   UnterminatedString,
 }
@@ -11,100 +11,117 @@ impl Compiler {
     let mut asts = HashMap::<PathBuf, Ast>::new();
     let mut paths = HashMap::<PathBuf, PathBuf>::new();
     let mut srcs = HashMap::<PathBuf, &str>::new();
-    let mut loaded = Vec::new();
 
     let mut stack = Vec::new();
     stack.push(Source::root(root));
 
-    while let Some(current) = stack.pop() {
-      let (relative, src) = loader.load(root, &current.path)?;
-      loaded.push(relative.into());
-      let tokens = Lexer::lex(relative, src)?;
-      let mut ast = Parser::parse(
-        &current.path,
-        &current.namepath,
-        current.depth,
-        &tokens,
-        &current.working_directory,
-      )?;
-
-      paths.insert(current.path.clone(), relative.into());
-      srcs.insert(current.path.clone(), src);
-
-      for item in &mut ast.items {
-        match item {
-          Item::Module {
-            absolute,
-            name,
-            optional,
-            relative,
-          } => {
-            if !unstable {
-              return Err(Error::Unstable {
-                message: "Modules are currently unstable.".into(),
-              });
-            }
+    // Files are loaded and lexed concurrently, one level of imports and
+    // modules at a time, and parsed in the order they would be popped from
+    // the stack.
+    while !stack.is_empty() {
+      let batch = stack.drain(..).rev().collect::<Vec<Source>>();
+
+      let loaded = loader.load_all(
+        root,
+        &batch
+          .iter()
+          .map(|source| source.path.as_path())
+          .collect::<Vec<&Path>>(),
+      );
+
+      let lexed = Self::lex_all(&loaded);
+
+      for ((current, loaded), lexed) in batch.into_iter().zip(loaded).zip(lexed) {
+        let (relative, src) = loaded?;
+        let tokens = lexed.unwrap()?;
+        let mut ast = Parser::parse(
+          &current.path,
+          &current.namepath,
+          current.depth,
+          &tokens,
+          &current.working_directory,
+        )?;
+
+        paths.insert(current.path.clone(), relative.into());
+        srcs.insert(current.path.clone(), src);
+
+        for item in &mut ast.items {
+          match item {
+            Item::Module {
+              absolute,
+              name,
+              optional,
+              relative,
+            } => {
+              if !unstable {
+                return Err(Error::Unstable {
+                  message: "Modules are currently unstable.".into(),
+                });
+              }
 
-            let parent = current.path.parent().unwrap();
+              let parent = current.path.parent().unwrap();
 
-            let import = if let Some(relative) = relative {
-              let path = parent.join(Self::expand_tilde(&relative.cooked)?);
+              let import = if let Some(relative) = relative {
+                let path = parent.join(Self::expand_tilde(&relative.cooked)?);
 
-              if path.is_file() {
-                Some(path)
+                if path.is_file() {
+                  Some(path)
+                } else {
+                  None
+                }
               } else {
-                None
-              }
-            } else {
-              Self::find_module_file(parent, *name)?
-            };
-
-            if let Some(import) = import {
-              if srcs.contains_key(&import) {
-                return Err(Error::CircularImport {
-                  current: current.path,
-                  import,
-                });
+                Self::find_module_file(parent, *name)?
+              };
+
+              if let Some(import) = import {
+                if srcs.contains_key(&import) {
+                  return Err(Error::CircularImport {
+                    current: current.path,
+                    import,
+                  });
+                }
+                *absolute = Some(import.clone());
+                stack.push(current.module(*name, import));
+              } else if !*optional {
+                return Err(Error::MissingModuleFile { module: *name });
               }
-              *absolute = Some(import.clone());
-              stack.push(current.module(*name, import));
-            } else if !*optional {
-              return Err(Error::MissingModuleFile { module: *name });
             }
-          }
-          Item::Import {
-            relative,
-            absolute,
-            optional,
-            path,
-          } => {
-            let import = current
-              .path
-              .parent()
-              .unwrap()
-              .join(Self::expand_tilde(&relative.cooked)?)
-              .lexiclean();
-
-            if import.is_file() {
-              if srcs.contains_key(&import) {
-                return Err(Error::CircularImport {
-                  current: current.path,
-                  import,
-                });
+            Item::Import {
+              relative,
+              absolute,
+              optional,
+              path,
+            } => {
+              let import = current
+                .path
+                .parent()
+                .unwrap()
+                .join(Self::expand_tilde(&relative.cooked)?)
+                .lexiclean();
+
+              if import.is_file() {
+                if srcs.contains_key(&import) {
+                  return Err(Error::CircularImport {
+                    current: current.path,
+                    import,
+                  });
+                }
+                *absolute = Some(import.clone());
+                stack.push(current.import(import));
+              } else if !*optional {
+                return Err(Error::MissingImportFile { path: *path });
               }
-              *absolute = Some(import.clone());
-              stack.push(current.import(import));
-            } else if !*optional {
-              return Err(Error::MissingImportFile { path: *path });
             }
+            _ => {}
           }
-          _ => {}
         }
-      }
 
-      asts.insert(current.path, ast.clone());
+        asts.insert(current.path, ast.clone());
+      }
     }
 
+    let loaded = Self::load_order(root, &asts, &paths);
+
     let justfile = Analyzer::analyze(&loaded, &paths, &asts, root)?;
 
     Ok(Compilation {
@@ -115,6 +132,94 @@ impl Compiler {
     })
   }
 
+  /// Lex loaded sources concurrently, skipping those which failed to load
+  fn lex_all<'src>(
+    loaded: &[RunResult<'src, (&'src Path, &'src str)>],
+  ) -> Vec<Option<CompileResult<'src, Vec<Token<'src>>>>> {
+    let srcs = loaded
+      .iter()
+      .map(|loaded| loaded.as_ref().ok().copied())
+      .collect::<Vec<Option<(&Path, &str)>>>();
+
+    let lex =
+      |src: Option<(&'src Path, &'src str)>| src.map(|(relative, src)| Lexer::lex(relative, src));
+
+    if srcs.len() > 1 {
+      thread::scope(|scope| {
+        srcs
+          .into_iter()
+          .map(|src| scope.spawn(move || lex(src)))
+          .collect::<Vec<_>>()
+          .into_iter()
+          .map(|handle| handle.join().unwrap())
+          .collect()
+      })
+    } else {
+      srcs.into_iter().map(lex).collect()
+    }
+  }
+
+  /// Relative paths of the files in `asts`, in depth-first order, so that
+  /// recipes listed in source order do not depend on the order in which files
+  /// were loaded
+  fn load_order(
+    root: &Path,
+    asts: &HashMap<PathBuf, Ast>,
+    paths: &HashMap<PathBuf, PathBuf>,
+  ) -> Vec<PathBuf> {
+    let mut loaded = Vec::new();
+
+    let mut stack = vec![root];
+
+    while let Some(path) = stack.pop() {
+      loaded.push(paths[path].clone());
+
+      for item in &asts[path].items {
+        if let Item::Import {
+          absolute: Some(absolute),
+          ..
+        }
+        | Item::Module {
+          absolute: Some(absolute),
+          ..
+        } = item
+        {
+          stack.push(absolute);
+        }
+      }
+    }
+
+    loaded
+  }
+
+  /// Compile `src` as the root justfile at `path`. Imports and modules are
+  /// not loaded.
+  pub(crate) fn compile_src<'src>(
+    path: &'src Path,
+    src: &'src str,
+  ) -> CompileResult<'src, Justfile<'src>> {
+    let ast = Self::parse_src(path, src)?;
+    let root = path.to_owned();
+    let asts = HashMap::from([(root.clone(), ast)]);
+    let paths = HashMap::from([(root.clone(), root.clone())]);
+    Analyzer::analyze(&[], &paths, &asts, &root)
+  }
+
+  /// Parse `src` as the root justfile at `path`, without analyzing it
+  pub(crate) fn parse_src<'src>(
+    path: &'src Path,
+    src: &'src str,
+  ) -> CompileResult<'src, Ast<'src>> {
+    let tokens = Lexer::lex(path, src)?;
+    Parser::parse(
+      path,
+      &Namepath::default(),
+      0,
+      &tokens,
+      path.parent().unwrap_or(path),
+    )
+  }
+
   fn find_module_file<'src>(parent: &Path, module: Name<'src>) -> RunResult<'src, Option<PathBuf>> {
     let mut candidates = vec![format!("{module}.just"), format!("{module}/mod.just")]
       .into_iter()
@@ -225,6 +330,28 @@ recipe_b: recipe_c
     assert_eq!(compilation.root_src(), justfile_a);
   }
 
+  #[test]
+  fn imports_are_loaded_in_depth_first_order() {
+    let tmp = temptree! {
+      justfile: "import 'a'\nimport 'b'",
+      a: "import 'c'\na:",
+      b: "b:",
+      c: "c:",
+    };
+
+    let loader = Loader::new();
+
+    let compilation = Compiler::compile(false, &loader, &tmp.path().join("justfile")).unwrap();
+
+    assert_eq!(
+      compilation.justfile.loaded,
+      ["justfile", "b", "a", "c"]
+        .iter()
+        .map(PathBuf::from)
+        .collect::<Vec<PathBuf>>(),
+    );
+  }
+
   #[test]
   fn recursive_includes_fail() {
     let justfile_a = r#"
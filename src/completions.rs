@@ -1,5 +1,20 @@
-pub(crate) const FISH_RECIPE_COMPLETIONS: &str = r#"function __fish_just_complete_recipes
-        just --list 2> /dev/null | tail -n +2 | awk '{
+pub(crate) const FISH_RECIPE_COMPLETIONS: &str = r#"function __fish_just_args
+        set -l tokens (commandline -opc)
+        set -l i 2
+        while test $i -le (count $tokens)
+                switch $tokens[$i]
+                        case -f --justfile -d --working-directory
+                                set i (math $i + 1)
+                                printf '%s\n' $tokens[(math $i - 1)] $tokens[$i]
+                        case '--justfile=*' '--working-directory=*'
+                                printf '%s\n' $tokens[$i]
+                end
+                set i (math $i + 1)
+        end
+end
+
+function __fish_just_complete_recipes
+        just (__fish_just_args) --list 2> /dev/null | tail -n +2 | awk '{
         command = $1;
         args = $0;
         desc = "";
@@ -40,7 +55,22 @@ complete -c just -a '(__fish_just_complete_recipes)'
 pub(crate) const ZSH_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[
   (
     r#"    _arguments "${_arguments_options[@]}" \"#,
-    r"    local common=(",
+    r"    local -a just_args
+    local i
+
+    for ((i = 2; i < CURRENT; i++)); do
+        case ${words[i]} in
+            (-f|--justfile|-d|--working-directory)
+                just_args+=(${words[i]} ${words[i+1]})
+                (( i++ ))
+                ;;
+            (--justfile=*|--working-directory=*)
+                just_args+=(${words[i]})
+                ;;
+        esac
+    done
+
+    local common=(",
   ),
   (
     r"'*--set=[Override <VARIABLE> with <VALUE>]:VARIABLE: :VARIABLE: ' \",
@@ -52,6 +82,10 @@ pub(crate) const ZSH_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[
     r"'-s+[Show information about <RECIPE>]: :(_just_commands)' \
 '--show=[Show information about <RECIPE>]: :(_just_commands)' \",
   ),
+  (
+    r"'()--usage=[Print usage information for <RECIPE>]:RECIPE: ' \",
+    r"'--usage=[Print usage information for <RECIPE>]: :(_just_commands)' \",
+  ),
   (
     "'*::ARGUMENTS -- Overrides and recipe(s) to run, defaulting to the first recipe in the \
      justfile:' \\
@@ -71,7 +105,7 @@ pub(crate) const ZSH_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[
             local recipe
 
             local cmds; cmds=(
-                ${(s: :)$(_call_program commands just --summary)}
+                ${(s: :)$(_call_program commands just $just_args --summary)}
             )
 
             # Find first recipe name
@@ -90,7 +124,7 @@ pub(crate) const ZSH_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[
                 _message "value"
             elif [[ $recipe ]]; then
                 # Show usage message
-                _message "`just --show $recipe`"
+                _message "`just ${(Q)just_args} --show $recipe`"
                 # Or complete with other commands
                 #_arguments -s -S $common '*:: :_just_commands'
             else
@@ -107,10 +141,10 @@ pub(crate) const ZSH_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[
     r#"    [[ $PREFIX = -* ]] && return 1
     integer ret=1
     local variables; variables=(
-        ${(s: :)$(_call_program commands just --variables)}
+        ${(s: :)$(_call_program commands just $just_args --variables)}
     )
     local commands; commands=(
-        ${${${(M)"${(f)$(_call_program commands just --list)}":#    *}/ ##/}/ ##/:Args: }
+        ${${${(M)"${(f)$(_call_program commands just $just_args --list)}":#    *}/ ##/}/ ##/:Args: }
     )
 "#,
   ),
@@ -133,7 +167,7 @@ _just_variables() {
     [[ $PREFIX = -* ]] && return 1
     integer ret=1
     local variables; variables=(
-        ${(s: :)$(_call_program commands just --variables)}
+        ${(s: :)$(_call_program commands just $just_args --variables)}
     )
 
     if compset -P '*='; then
@@ -155,17 +189,21 @@ pub(crate) const POWERSHELL_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[(
   r#"$completions.Where{ $_.CompletionText -like "$wordToComplete*" } |
         Sort-Object -Property ListItemText"#,
   r#"function Get-JustFileRecipes([string[]]$CommandElements) {
-        $justFileIndex = $commandElements.IndexOf("--justfile");
+        $justArgs = @()
 
-        if ($justFileIndex -ne -1 && $justFileIndex + 1 -le $commandElements.Length) {
-            $justFileLocation = $commandElements[$justFileIndex + 1]
+        for ($i = 1; $i -lt $CommandElements.Length; $i++) {
+            $element = $CommandElements[$i]
+            if ($element -in @("-f", "--justfile", "-d", "--working-directory")) {
+                if ($i + 1 -lt $CommandElements.Length) {
+                    $justArgs += @($element, $CommandElements[$i + 1])
+                }
+                $i++
+            } elseif ($element -like "--justfile=*" -or $element -like "--working-directory=*") {
+                $justArgs += @($element)
+            }
         }
 
-        $justArgs = @("--summary")
-
-        if (Test-Path $justFileLocation) {
-            $justArgs += @("--justfile", $justFileLocation)
-        }
+        $justArgs += @("--summary")
 
         $recipes = $(just @justArgs) -split ' '
         return $recipes | ForEach-Object { [CompletionResult]::new($_) }
@@ -184,15 +222,32 @@ pub(crate) const BASH_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi"#,
-    r#"                if [[ ${cur} == -* ]] ; then
+    r#"                local just_args=()
+                local recipe_cword=1
+
+                for ((i = 1; i < cword; i++)); do
+                    case "${words[i]}" in
+                        -f|--justfile|-d|--working-directory)
+                            just_args+=("${words[i]}" "${words[i+1]}")
+                            recipe_cword=$((i + 2))
+                            ((i++))
+                            ;;
+                        --justfile=*|--working-directory=*)
+                            just_args+=("${words[i]}")
+                            recipe_cword=$((i + 1))
+                            ;;
+                    esac
+                done
+
+                if [[ ${cur} == -* ]] ; then
                     COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                     return 0
-                elif [[ ${COMP_CWORD} -eq 1 ]]; then
-                    local recipes=$(just --summary 2> /dev/null)
+                elif [[ ${cword} -eq ${recipe_cword} ]]; then
+                    local recipes=$(just "${just_args[@]}" --summary 2> /dev/null)
 
                     if echo "${cur}" | \grep -qF '/'; then
                         local path_prefix=$(echo "${cur}" | sed 's/[/][^/]*$/\//')
-                        local recipes=$(just --summary 2> /dev/null -- "${path_prefix}")
+                        local recipes=$(just "${just_args[@]}" --summary 2> /dev/null -- "${path_prefix}")
                         local recipes=$(printf "${path_prefix}%s\t" $recipes)
                     fi
 
@@ -219,16 +274,12 @@ pub(crate) const BASH_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[
     else
         cur="${COMP_WORDS[COMP_CWORD]}"
         prev="${COMP_WORDS[COMP_CWORD-1]}"
-        words=$COMP_WORDS
+        words=("${COMP_WORDS[@]}")
         cword=$COMP_CWORD
     fi
 "#,
   ),
   (r"for i in ${COMP_WORDS[@]}", r"for i in ${words[@]}"),
-  (
-    r"elif [[ ${COMP_CWORD} -eq 1 ]]; then",
-    r"elif [[ ${cword} -eq 1 ]]; then",
-  ),
   (
     r#"COMPREPLY=( $(compgen -W "${recipes}" -- "${cur}") )"#,
     r#"COMPREPLY=( $(compgen -W "${recipes}" -- "${cur}") )
@@ -1,14 +1,34 @@
 use {
   super::*,
   clap::{
-    builder::{styling::AnsiColor, FalseyValueParser, PossibleValuesParser, Styles},
+    builder::{
+      styling::AnsiColor, FalseyValueParser, PossibleValuesParser, RangedU64ValueParser, Styles,
+    },
     value_parser, Arg, ArgAction, ArgGroup, ArgMatches, Command,
   },
 };
 
 const CHOOSE_HELP: &str = "Select one or more recipes to run using a binary chooser. \
                            If `--chooser` is not passed the chooser defaults to the \
-                           value of $JUST_CHOOSER, falling back to `fzf`";
+                           value of $JUST_CHOOSER, then the `chooser` setting, falling \
+                           back to `fzf`. If arguments are given, only recipes whose names \
+                           match one of them, with `*` and `?` wildcards, are offered";
+
+/// Environment variable holding the path to the file containing the body of
+/// a `[body-file(COMMAND)]` recipe, set when running `COMMAND`
+pub(crate) const BODY_FILE_ENV: &str = "JUST_BODY_FILE";
+
+/// Environment variable set for child processes during `--dry-run`, and read
+/// by `--dry-run`, so that recursive invocations of `just` are also dry runs
+pub(crate) const DRY_RUN_ENV: &str = "JUST_DRY_RUN";
+
+/// Environment variable through which `--shell` is passed to child processes
+pub(crate) const SHELL_ENV: &str = "JUST_SHELL";
+
+/// Environment variable through which shell arguments given with
+/// `--shell-arg` or `--clear-shell-args` are passed to child processes, as a
+/// JSON array of strings
+pub(crate) const SHELL_ARGS_ENV: &str = "JUST_SHELL_ARGS";
 
 pub(crate) fn chooser_default(justfile: &Path) -> OsString {
   let mut chooser = OsString::new();
@@ -20,28 +40,43 @@ pub(crate) fn chooser_default(justfile: &Path) -> OsString {
 
 #[derive(Debug, PartialEq)]
 pub(crate) struct Config {
+  pub(crate) align: bool,
   pub(crate) check: bool,
   pub(crate) color: Color,
   pub(crate) command_color: Option<ansi_term::Color>,
+  pub(crate) dependency_arguments: BTreeMap<String, BTreeMap<String, String>>,
   pub(crate) dotenv_filename: Option<String>,
   pub(crate) dotenv_path: Option<PathBuf>,
   pub(crate) dry_run: bool,
   pub(crate) dump_format: DumpFormat,
+  pub(crate) exit_codes: ExitCodes,
   pub(crate) highlight: bool,
   pub(crate) invocation_directory: PathBuf,
+  pub(crate) jobs: usize,
+  pub(crate) json: bool,
+  pub(crate) list_flat: bool,
   pub(crate) list_heading: String,
   pub(crate) list_prefix: String,
   pub(crate) load_dotenv: bool,
+  pub(crate) locations: bool,
   pub(crate) no_aliases: bool,
   pub(crate) no_dependencies: bool,
   pub(crate) search_config: SearchConfig,
+  pub(crate) search_limit: SearchLimit,
   pub(crate) shell: Option<String>,
   pub(crate) shell_args: Option<Vec<String>>,
   pub(crate) shell_command: bool,
+  pub(crate) silence_stderr: bool,
+  pub(crate) silence_stdout: bool,
+  pub(crate) stdin: bool,
   pub(crate) subcommand: Subcommand,
+  pub(crate) timings: bool,
+  pub(crate) trace_evaluation: bool,
+  pub(crate) transcript: Option<PathBuf>,
   pub(crate) unsorted: bool,
   pub(crate) unstable: bool,
   pub(crate) verbosity: Verbosity,
+  pub(crate) warn_as_error: bool,
   pub(crate) yes: bool,
 }
 
@@ -51,14 +86,19 @@ mod cmd {
   pub(crate) const COMMAND: &str = "COMMAND";
   pub(crate) const COMPLETIONS: &str = "COMPLETIONS";
   pub(crate) const DUMP: &str = "DUMP";
+  pub(crate) const DUMP_SCHEMA: &str = "DUMP-SCHEMA";
   pub(crate) const EDIT: &str = "EDIT";
   pub(crate) const EVALUATE: &str = "EVALUATE";
+  pub(crate) const FIND: &str = "FIND";
   pub(crate) const FORMAT: &str = "FORMAT";
+  pub(crate) const GROUPS: &str = "GROUPS";
   pub(crate) const INIT: &str = "INIT";
   pub(crate) const LIST: &str = "LIST";
   pub(crate) const MAN: &str = "MAN";
+  pub(crate) const SETTINGS: &str = "SETTINGS";
   pub(crate) const SHOW: &str = "SHOW";
   pub(crate) const SUMMARY: &str = "SUMMARY";
+  pub(crate) const USAGE: &str = "USAGE";
   pub(crate) const VARIABLES: &str = "VARIABLES";
 
   pub(crate) const ALL: &[&str] = &[
@@ -67,49 +107,84 @@ mod cmd {
     COMMAND,
     COMPLETIONS,
     DUMP,
+    DUMP_SCHEMA,
     EDIT,
     EVALUATE,
+    FIND,
     FORMAT,
+    GROUPS,
     INIT,
     LIST,
     MAN,
+    SETTINGS,
     SHOW,
     SUMMARY,
+    USAGE,
     VARIABLES,
   ];
 
   pub(crate) const ARGLESS: &[&str] = &[
-    CHANGELOG, DUMP, EDIT, FORMAT, INIT, LIST, MAN, SUMMARY, VARIABLES,
+    CHANGELOG,
+    DUMP,
+    DUMP_SCHEMA,
+    FORMAT,
+    GROUPS,
+    INIT,
+    LIST,
+    MAN,
+    SETTINGS,
+    SUMMARY,
+    VARIABLES,
   ];
 }
 
 mod arg {
+  pub(crate) const ALIGN: &str = "ALIGN";
   pub(crate) const ARGUMENTS: &str = "ARGUMENTS";
   pub(crate) const CHECK: &str = "CHECK";
   pub(crate) const CHOOSER: &str = "CHOOSER";
   pub(crate) const CLEAR_SHELL_ARGS: &str = "CLEAR-SHELL-ARGS";
   pub(crate) const COLOR: &str = "COLOR";
   pub(crate) const COMMAND_COLOR: &str = "COMMAND-COLOR";
+  pub(crate) const DEPENDENCY_ARGUMENT: &str = "DEPENDENCY-ARGUMENT";
   pub(crate) const DOTENV_FILENAME: &str = "DOTENV-FILENAME";
   pub(crate) const DOTENV_PATH: &str = "DOTENV-PATH";
   pub(crate) const DRY_RUN: &str = "DRY-RUN";
   pub(crate) const DUMP_FORMAT: &str = "DUMP-FORMAT";
+  pub(crate) const DUMP_RECIPE: &str = "DUMP-RECIPE";
+  pub(crate) const DUMP_VARIABLE: &str = "DUMP-VARIABLE";
+  pub(crate) const EXIT_CODES: &str = "EXIT-CODES";
   pub(crate) const HIGHLIGHT: &str = "HIGHLIGHT";
+  pub(crate) const JOBS: &str = "JOBS";
+  pub(crate) const JSON: &str = "JSON";
+  pub(crate) const JSON_SUBCOMMAND: &str = "JSON-SUBCOMMAND";
   pub(crate) const JUSTFILE: &str = "JUSTFILE";
+  pub(crate) const LIST_FLAT: &str = "LIST-FLAT";
   pub(crate) const LIST_HEADING: &str = "LIST-HEADING";
   pub(crate) const LIST_PREFIX: &str = "LIST-PREFIX";
+  pub(crate) const LOCATIONS: &str = "LOCATIONS";
   pub(crate) const NO_ALIASES: &str = "NO-ALIASES";
   pub(crate) const NO_DEPS: &str = "NO-DEPS";
   pub(crate) const NO_DOTENV: &str = "NO-DOTENV";
   pub(crate) const NO_HIGHLIGHT: &str = "NO-HIGHLIGHT";
+  pub(crate) const OVERRIDES_FILE: &str = "OVERRIDES-FILE";
   pub(crate) const QUIET: &str = "QUIET";
+  pub(crate) const SEARCH_DEPTH: &str = "SEARCH-DEPTH";
   pub(crate) const SET: &str = "SET";
   pub(crate) const SHELL: &str = "SHELL";
   pub(crate) const SHELL_ARG: &str = "SHELL-ARG";
   pub(crate) const SHELL_COMMAND: &str = "SHELL-COMMAND";
+  pub(crate) const SILENCE_STDERR: &str = "SILENCE-STDERR";
+  pub(crate) const SILENCE_STDOUT: &str = "SILENCE-STDOUT";
+  pub(crate) const STDIN: &str = "STDIN";
+  pub(crate) const STOP_AT_PROJECT_ROOT: &str = "STOP-AT-PROJECT-ROOT";
+  pub(crate) const TIMINGS: &str = "TIMINGS";
+  pub(crate) const TRACE_EVALUATION: &str = "TRACE-EVALUATION";
+  pub(crate) const TRANSCRIPT: &str = "TRANSCRIPT";
   pub(crate) const UNSORTED: &str = "UNSORTED";
   pub(crate) const UNSTABLE: &str = "UNSTABLE";
   pub(crate) const VERBOSE: &str = "VERBOSE";
+  pub(crate) const WARN_AS_ERROR: &str = "WARN-AS-ERROR";
   pub(crate) const WORKING_DIRECTORY: &str = "WORKING-DIRECTORY";
   pub(crate) const YES: &str = "YES";
 
@@ -136,8 +211,22 @@ mod arg {
   ];
 
   pub(crate) const DUMP_FORMAT_JSON: &str = "json";
+  pub(crate) const DUMP_FORMAT_JSON_LOSSLESS: &str = "json-lossless";
   pub(crate) const DUMP_FORMAT_JUST: &str = "just";
-  pub(crate) const DUMP_FORMAT_VALUES: &[&str] = &[DUMP_FORMAT_JUST, DUMP_FORMAT_JSON];
+  pub(crate) const DUMP_FORMAT_VALUES: &[&str] = &[
+    DUMP_FORMAT_JUST,
+    DUMP_FORMAT_JSON,
+    DUMP_FORMAT_JSON_LOSSLESS,
+  ];
+
+  pub(crate) const EXIT_CODES_CATEGORIZED: &str = "categorized";
+  pub(crate) const EXIT_CODES_NORMALIZED: &str = "normalized";
+  pub(crate) const EXIT_CODES_PASSTHROUGH: &str = "passthrough";
+  pub(crate) const EXIT_CODES_VALUES: &[&str] = &[
+    EXIT_CODES_PASSTHROUGH,
+    EXIT_CODES_NORMALIZED,
+    EXIT_CODES_CATEGORIZED,
+  ];
 }
 
 impl Config {
@@ -159,12 +248,38 @@ impl Config {
             .literal(AnsiColor::Green.on_default())
             .placeholder(AnsiColor::Green.on_default())
       )
+      .arg(
+        Arg::new(arg::ALIGN)
+          .long("align")
+          .action(ArgAction::SetTrue)
+          .requires(cmd::FORMAT)
+          .help("Run `--fmt` with `:=` vertically aligned in contiguous blocks of assignments"),
+      )
       .arg(
         Arg::new(arg::CHECK)
           .long("check")
           .action(ArgAction::SetTrue)
-          .requires(cmd::FORMAT)
-          .help("Run `--fmt` in 'check' mode. Exits with 0 if justfile is formatted correctly. Exits with 1 and prints a diff if formatting is required."),
+          .conflicts_with_all([
+            cmd::CHANGELOG,
+            cmd::CHOOSE,
+            cmd::COMMAND,
+            cmd::COMPLETIONS,
+            cmd::DUMP,
+            cmd::DUMP_SCHEMA,
+            cmd::EDIT,
+            cmd::EVALUATE,
+            cmd::FIND,
+            cmd::GROUPS,
+            cmd::INIT,
+            cmd::LIST,
+            cmd::MAN,
+            cmd::SETTINGS,
+            cmd::SHOW,
+            cmd::SUMMARY,
+            cmd::USAGE,
+            cmd::VARIABLES,
+          ])
+          .help("Compile justfile without running recipes or evaluating assignments. Exits with 0 if justfile is valid and 1 otherwise. With `--fmt`, exits with 0 if justfile is formatted correctly, and with 1 and prints a diff if formatting is required."),
       )
       .arg(
         Arg::new(arg::CHOOSER)
@@ -189,11 +304,20 @@ impl Config {
           .help("Echo recipe lines in <COMMAND-COLOR>"),
       )
       .arg(Arg::new(arg::YES).long("yes").action(ArgAction::SetTrue).help("Automatically confirm all recipes."))
+      .arg(
+        Arg::new(arg::DEPENDENCY_ARGUMENT)
+          .long("dependency-argument")
+          .action(ArgAction::Append)
+          .value_name("RECIPE::PARAMETER=VALUE")
+          .help("Pass <VALUE> as argument <PARAMETER> of <RECIPE> whenever it runs, including as a dependency"),
+      )
       .arg(
         Arg::new(arg::DRY_RUN)
           .short('n')
           .long("dry-run")
+          .env(DRY_RUN_ENV)
           .action(ArgAction::SetTrue)
+          .value_parser(FalseyValueParser::new())
           .help("Print what just would do without doing it")
           .conflicts_with(arg::QUIET),
       )
@@ -206,6 +330,33 @@ impl Config {
           .value_name("FORMAT")
           .help("Dump justfile as <FORMAT>"),
       )
+      .arg(
+        Arg::new(arg::DUMP_RECIPE)
+          .long("recipe")
+          .action(ArgAction::Set)
+          .value_name("RECIPE")
+          .requires(cmd::DUMP)
+          .conflicts_with(arg::DUMP_VARIABLE)
+          .help("Only dump <RECIPE>"),
+      )
+      .arg(
+        Arg::new(arg::DUMP_VARIABLE)
+          .long("variable")
+          .action(ArgAction::Set)
+          .value_name("VARIABLE")
+          .requires(cmd::DUMP)
+          .help("Only dump <VARIABLE>"),
+      )
+      .arg(
+        Arg::new(arg::EXIT_CODES)
+          .long("exit-codes")
+          .env("JUST_EXIT_CODES")
+          .action(ArgAction::Set)
+          .value_parser(PossibleValuesParser::new(arg::EXIT_CODES_VALUES))
+          .default_value(arg::EXIT_CODES_PASSTHROUGH)
+          .value_name("CONVENTION")
+          .help("Use <CONVENTION> to choose exit code on failure"),
+      )
       .arg(
         Arg::new(arg::HIGHLIGHT)
           .long("highlight")
@@ -213,6 +364,29 @@ impl Config {
           .help("Highlight echoed recipe lines in bold")
           .overrides_with(arg::NO_HIGHLIGHT),
       )
+      .arg(
+        Arg::new(arg::JOBS)
+          .short('j')
+          .long("jobs")
+          .env("JUST_JOBS")
+          .action(ArgAction::Set)
+          .value_parser(RangedU64ValueParser::<usize>::new().range(1..))
+          .value_name("N")
+          .help("Run at most <N> recipes at once, defaulting to the number of CPUs"),
+      )
+      .arg(
+        Arg::new(arg::JSON)
+          .long("json")
+          .action(ArgAction::SetTrue)
+          .requires(arg::JSON_SUBCOMMAND)
+          .help("Print `--list`, `--settings`, or `--summary` output as JSON"),
+      )
+      .arg(
+        Arg::new(arg::LIST_FLAT)
+          .long("list-flat")
+          .action(ArgAction::SetTrue)
+          .help("List recipes in submodules as `module::recipe` instead of in indented groups"),
+      )
       .arg(
         Arg::new(arg::LIST_HEADING)
           .long("list-heading")
@@ -227,6 +401,12 @@ impl Config {
           .value_name("TEXT")
           .action(ArgAction::Set),
       )
+      .arg(
+        Arg::new(arg::LOCATIONS)
+          .long("locations")
+          .action(ArgAction::SetTrue)
+          .help("Print the `path:line` of each recipe in `--list` and `--dump` output"),
+      )
       .arg(
         Arg::new(arg::NO_ALIASES)
           .long("no-aliases")
@@ -257,10 +437,19 @@ impl Config {
         Arg::new(arg::JUSTFILE)
           .short('f')
           .long("justfile")
+          .env("JUST_JUSTFILE")
           .action(ArgAction::Set)
           .value_parser(value_parser!(PathBuf))
           .help("Use <JUSTFILE> as justfile"),
       )
+      .arg(
+        Arg::new(arg::OVERRIDES_FILE)
+          .long("overrides-file")
+          .action(ArgAction::Set)
+          .value_parser(value_parser!(PathBuf))
+          .value_name("PATH")
+          .help("Override variables with values from JSON object in <PATH>"),
+      )
       .arg(
         Arg::new(arg::QUIET)
           .short('q')
@@ -269,6 +458,14 @@ impl Config {
           .help("Suppress all output")
           .conflicts_with(arg::DRY_RUN),
       )
+      .arg(
+        Arg::new(arg::SEARCH_DEPTH)
+          .long("search-depth")
+          .action(ArgAction::Set)
+          .value_parser(value_parser!(usize))
+          .value_name("N")
+          .help("Search at most <N> parent directories for a justfile"),
+      )
       .arg(
         Arg::new(arg::SET)
           .long("set")
@@ -280,6 +477,7 @@ impl Config {
       .arg(
         Arg::new(arg::SHELL)
           .long("shell")
+          .env(SHELL_ENV)
           .action(ArgAction::Set)
           .help("Invoke <SHELL> to run recipes"),
       )
@@ -298,6 +496,34 @@ impl Config {
           .action(ArgAction::SetTrue)
           .help("Invoke <COMMAND> with the shell used to run recipe lines and backticks"),
       )
+      .arg(
+        Arg::new(arg::SILENCE_STDERR)
+          .long("silence-stderr")
+          .action(ArgAction::SetTrue)
+          .help("Discard standard error of recipe commands"),
+      )
+      .arg(
+        Arg::new(arg::SILENCE_STDOUT)
+          .long("silence-stdout")
+          .action(ArgAction::SetTrue)
+          .help("Discard standard output of recipe commands"),
+      )
+      .arg(
+        Arg::new(arg::STDIN)
+          .long("stdin")
+          .action(ArgAction::SetTrue)
+          .requires(cmd::FORMAT)
+          .help("Run `--fmt` on a justfile read from standard input, writing the result to standard output"),
+      )
+      .arg(
+        Arg::new(arg::STOP_AT_PROJECT_ROOT)
+          .long("stop-at-project-root")
+          .action(ArgAction::SetTrue)
+          .help(
+            "Don't search for a justfile above the nearest directory containing `.git` or another \
+             version control directory",
+          ),
+      )
       .arg(
         Arg::new(arg::CLEAR_SHELL_ARGS)
           .long("clear-shell-args")
@@ -305,6 +531,27 @@ impl Config {
           .overrides_with(arg::SHELL_ARG)
           .help("Clear shell arguments"),
       )
+      .arg(
+        Arg::new(arg::TIMINGS)
+          .long("timings")
+          .action(ArgAction::SetTrue)
+          .help("Print how long each recipe took to run after running recipes"),
+      )
+      .arg(
+        Arg::new(arg::TRACE_EVALUATION)
+          .long("trace-evaluation")
+          .env("JUST_TRACE_EVALUATION")
+          .action(ArgAction::SetTrue)
+          .value_parser(FalseyValueParser::new())
+          .help("Print each variable, function call, and backtick evaluated, and its value"),
+      )
+      .arg(
+        Arg::new(arg::TRANSCRIPT)
+          .long("transcript")
+          .action(ArgAction::Set)
+          .value_parser(value_parser!(PathBuf))
+          .help("Write a JSON record of recipes run to <TRANSCRIPT>"),
+      )
       .arg(
         Arg::new(arg::UNSORTED)
           .long("unsorted")
@@ -327,10 +574,19 @@ impl Config {
           .action(ArgAction::Count)
           .help("Use verbose output"),
       )
+      .arg(
+        Arg::new(arg::WARN_AS_ERROR)
+          .long("warn-as-error")
+          .env("JUST_WARN_AS_ERROR")
+          .action(ArgAction::SetTrue)
+          .value_parser(FalseyValueParser::new())
+          .help("Treat warnings as errors"),
+      )
       .arg(
         Arg::new(arg::WORKING_DIRECTORY)
           .short('d')
           .long("working-directory")
+          .env("JUST_WORKING_DIRECTORY")
           .action(ArgAction::Set)
           .value_parser(value_parser!(PathBuf))
           .help("Use <WORKING-DIRECTORY> as working directory. --justfile must also be set")
@@ -372,12 +628,21 @@ impl Config {
           .action(ArgAction::SetTrue)
           .help("Print justfile"),
       )
+      .arg(
+        Arg::new(cmd::DUMP_SCHEMA)
+          .long("dump-schema")
+          .action(ArgAction::SetTrue)
+          .help("Print JSON Schema describing the output of `--dump --dump-format json`"),
+      )
       .arg(
         Arg::new(cmd::EDIT)
           .short('e')
           .long("edit")
           .action(ArgAction::SetTrue)
-          .help("Edit justfile with editor given by $VISUAL or $EDITOR, falling back to `vim`"),
+          .help(
+            "Edit justfile with editor given by $VISUAL or $EDITOR, falling back to `vim`. If a \
+             recipe is given, open the editor at its definition",
+          ),
       )
       .arg(
         Arg::new(cmd::EVALUATE)
@@ -385,7 +650,19 @@ impl Config {
           .action(ArgAction::SetTrue)
           .help(
             "Evaluate and print all variables. If a variable name is given as an argument, only \
-             print that variable's value.",
+             print that variable's value. If a recipe name, variable name, and optional recipe \
+             arguments are given, print that variable's value in the recipe's scope.",
+          ),
+      )
+      .arg(
+        Arg::new(cmd::FIND)
+          .long("find")
+          .action(ArgAction::Set)
+          .value_name("PATTERN")
+          .conflicts_with(arg::ARGUMENTS)
+          .help(
+            "Print the location of recipes whose name, documentation, or body contains \
+             <PATTERN>",
           ),
       )
       .arg(
@@ -395,6 +672,12 @@ impl Config {
           .action(ArgAction::SetTrue)
           .help("Format and overwrite justfile"),
       )
+      .arg(
+        Arg::new(cmd::GROUPS)
+          .long("groups")
+          .action(ArgAction::SetTrue)
+          .help("List recipe groups"),
+      )
       .arg(
         Arg::new(cmd::INIT)
           .long("init")
@@ -415,6 +698,12 @@ impl Config {
           .action(ArgAction::SetTrue)
           .help("Print man page"),
       )
+      .arg(
+        Arg::new(cmd::SETTINGS)
+          .long("settings")
+          .action(ArgAction::SetTrue)
+          .help("Print effective settings"),
+      )
       .arg(
         Arg::new(cmd::SHOW)
           .short('s')
@@ -430,6 +719,14 @@ impl Config {
           .action(ArgAction::SetTrue)
           .help("List names of available recipes"),
       )
+      .arg(
+        Arg::new(cmd::USAGE)
+          .long("usage")
+          .action(ArgAction::Set)
+          .value_name("RECIPE")
+          .conflicts_with(arg::ARGUMENTS)
+          .help("Print usage information for <RECIPE>"),
+      )
       .arg(
         Arg::new(cmd::VARIABLES)
           .long("variables")
@@ -452,11 +749,15 @@ impl Config {
           .help("Load <DOTENV-PATH> as environment file instead of searching for one")
       )
       .group(ArgGroup::new("SUBCOMMAND").args(cmd::ALL))
+      .group(ArgGroup::new(arg::JSON_SUBCOMMAND).args([cmd::LIST, cmd::SETTINGS, cmd::SUMMARY]))
       .arg(
         Arg::new(arg::ARGUMENTS)
           .num_args(1..)
           .action(ArgAction::Append)
-          .help("Overrides and recipe(s) to run, defaulting to the first recipe in the justfile"),
+          .help(
+            "Overrides and recipe(s) to run, defaulting to the first recipe in the justfile. \
+            Arguments after `--` are passed verbatim to the preceding recipe",
+          ),
       )
   }
 
@@ -506,6 +807,7 @@ impl Config {
 
     match value.as_str() {
       arg::DUMP_FORMAT_JSON => Ok(DumpFormat::Json),
+      arg::DUMP_FORMAT_JSON_LOSSLESS => Ok(DumpFormat::JsonLossless),
       arg::DUMP_FORMAT_JUST => Ok(DumpFormat::Just),
       _ => Err(ConfigError::Internal {
         message: format!("Invalid argument `{value}` to --dump-format."),
@@ -513,6 +815,97 @@ impl Config {
     }
   }
 
+  fn exit_codes_from_matches(matches: &ArgMatches) -> ConfigResult<ExitCodes> {
+    let value =
+      matches
+        .get_one::<String>(arg::EXIT_CODES)
+        .ok_or_else(|| ConfigError::Internal {
+          message: "`--exit-codes` had no value".to_string(),
+        })?;
+
+    match value.as_str() {
+      arg::EXIT_CODES_CATEGORIZED => Ok(ExitCodes::Categorized),
+      arg::EXIT_CODES_NORMALIZED => Ok(ExitCodes::Normalized),
+      arg::EXIT_CODES_PASSTHROUGH => Ok(ExitCodes::Passthrough),
+      _ => Err(ConfigError::Internal {
+        message: format!("Invalid argument `{value}` to --exit-codes."),
+      }),
+    }
+  }
+
+  fn shell_args_from_env() -> ConfigResult<Option<Vec<String>>> {
+    let Some(value) = env::var_os(SHELL_ARGS_ENV) else {
+      return Ok(None);
+    };
+
+    value
+      .to_str()
+      .and_then(|value| serde_json::from_str(value).ok())
+      .map(Some)
+      .ok_or_else(|| ConfigError::ShellArgsEnv {
+        value: value.to_string_lossy().into(),
+      })
+  }
+
+  /// Parse `--dependency-argument RECIPE::PARAMETER=VALUE` values into a map
+  /// from recipe path to parameter name to value
+  fn dependency_arguments_from_matches(
+    matches: &ArgMatches,
+  ) -> ConfigResult<BTreeMap<String, BTreeMap<String, String>>> {
+    let mut dependency_arguments = BTreeMap::<String, BTreeMap<String, String>>::new();
+
+    for argument in matches
+      .get_many::<String>(arg::DEPENDENCY_ARGUMENT)
+      .into_iter()
+      .flatten()
+    {
+      let Some((recipe, parameter, value)) = argument
+        .split_once('=')
+        .and_then(|(path, value)| {
+          let (recipe, parameter) = path.rsplit_once("::")?;
+          Some((recipe, parameter, value))
+        })
+        .filter(|(recipe, parameter, _value)| !recipe.is_empty() && !parameter.is_empty())
+      else {
+        return Err(ConfigError::DependencyArgument {
+          argument: argument.clone(),
+        });
+      };
+
+      dependency_arguments
+        .entry(recipe.into())
+        .or_default()
+        .insert(parameter.into(), value.into());
+    }
+
+    Ok(dependency_arguments)
+  }
+
+  fn overrides_from_file(path: &Path) -> ConfigResult<BTreeMap<String, String>> {
+    let json = fs::read_to_string(path).context(config_error::OverridesFileIoContext { path })?;
+
+    let invalid = |message: String| ConfigError::OverridesFileInvalid {
+      path: path.into(),
+      message,
+    };
+
+    let object = match serde_json::from_str(&json).map_err(|err| invalid(err.to_string()))? {
+      serde_json::Value::Object(object) => object,
+      _ => return Err(invalid("expected a JSON object".into())),
+    };
+
+    object
+      .into_iter()
+      .map(|(name, value)| match value {
+        serde_json::Value::String(value) => Ok((name, value)),
+        serde_json::Value::Bool(_) | serde_json::Value::Number(_) => Ok((name, value.to_string())),
+        _ => Err(invalid(format!(
+          "value of `{name}` must be a string, number, or boolean"
+        ))),
+      })
+      .collect()
+  }
+
   pub(crate) fn from_matches(matches: &ArgMatches) -> ConfigResult<Self> {
     let invocation_directory = env::current_dir().context(config_error::CurrentDirContext)?;
 
@@ -525,18 +918,37 @@ impl Config {
     let color = Self::color_from_matches(matches)?;
     let command_color = Self::command_color_from_matches(matches)?;
 
-    let mut overrides = BTreeMap::new();
+    let mut overrides = match matches.get_one::<PathBuf>(arg::OVERRIDES_FILE) {
+      Some(path) => Self::overrides_from_file(path)?,
+      None => BTreeMap::new(),
+    };
+
     if let Some(mut values) = matches.get_many::<String>(arg::SET) {
       while let (Some(k), Some(v)) = (values.next(), values.next()) {
         overrides.insert(k.into(), v.into());
       }
     }
 
-    let positional = Positional::from_values(
-      matches
-        .get_many::<String>(arg::ARGUMENTS)
-        .map(|s| s.map(String::as_str)),
-    );
+    let mut arguments = matches
+      .get_many::<String>(arg::ARGUMENTS)
+      .map(|values| values.map(String::as_str).collect::<Vec<&str>>())
+      .unwrap_or_default();
+
+    // Values after the first `--` are captured by the trailing var arg, and
+    // are passed to the recipe verbatim
+    let passthrough = match arguments.iter().position(|argument| *argument == "--") {
+      Some(index) => {
+        let passthrough = arguments[index + 1..]
+          .iter()
+          .map(|argument| (*argument).to_owned())
+          .collect::<Vec<String>>();
+        arguments.truncate(index);
+        passthrough
+      }
+      None => Vec::new(),
+    };
+
+    let mut positional = Positional::from_values(Some(arguments));
 
     for (name, value) in positional.overrides {
       overrides.insert(name.clone(), value.clone());
@@ -572,6 +984,10 @@ impl Config {
       }
     };
 
+    if matches.get_flag(cmd::EVALUATE) || cmd::ARGLESS.iter().any(|cmd| matches.get_flag(cmd)) {
+      positional.arguments.extend(passthrough.iter().cloned());
+    }
+
     for subcommand in cmd::ARGLESS {
       if matches.get_flag(subcommand) {
         match (!overrides.is_empty(), !positional.arguments.is_empty()) {
@@ -605,6 +1021,7 @@ impl Config {
       Subcommand::Choose {
         chooser: matches.get_one::<String>(arg::CHOOSER).map(Into::into),
         overrides,
+        patterns: positional.arguments,
       }
     } else if let Some(values) = matches.get_many::<OsString>(cmd::COMMAND) {
       let mut arguments = values.map(Into::into).collect::<Vec<OsString>>();
@@ -616,13 +1033,50 @@ impl Config {
     } else if let Some(&shell) = matches.get_one::<clap_complete::Shell>(cmd::COMPLETIONS) {
       Subcommand::Completions { shell }
     } else if matches.get_flag(cmd::EDIT) {
-      Subcommand::Edit
+      if !overrides.is_empty() {
+        return Err(ConfigError::SubcommandOverrides {
+          subcommand: cmd::EDIT,
+          overrides,
+        });
+      }
+
+      let mut arguments = positional.arguments;
+
+      if arguments.len() > 1 {
+        return Err(ConfigError::SubcommandArguments {
+          arguments: arguments.split_off(1),
+          subcommand: cmd::EDIT,
+        });
+      }
+
+      Subcommand::Edit {
+        recipe: arguments.pop(),
+      }
+    } else if matches.get_flag(cmd::SETTINGS) {
+      Subcommand::Settings
     } else if matches.get_flag(cmd::SUMMARY) {
       Subcommand::Summary
     } else if matches.get_flag(cmd::DUMP) {
-      Subcommand::Dump
+      let recipe = matches.get_one::<String>(arg::DUMP_RECIPE).map(Into::into);
+      let variable = matches
+        .get_one::<String>(arg::DUMP_VARIABLE)
+        .map(Into::into);
+
+      if (recipe.is_some() || variable.is_some())
+        && Self::dump_format_from_matches(matches)? == DumpFormat::JsonLossless
+      {
+        return Err(ConfigError::DumpItemLossless);
+      }
+
+      Subcommand::Dump { recipe, variable }
+    } else if matches.get_flag(cmd::DUMP_SCHEMA) {
+      Subcommand::DumpSchema
+    } else if let Some(pattern) = matches.get_one::<String>(cmd::FIND).map(Into::into) {
+      Subcommand::Find { pattern }
     } else if matches.get_flag(cmd::FORMAT) {
       Subcommand::Format
+    } else if matches.get_flag(cmd::GROUPS) {
+      Subcommand::Groups
     } else if matches.get_flag(cmd::INIT) {
       Subcommand::Init
     } else if matches.get_flag(cmd::LIST) {
@@ -631,53 +1085,86 @@ impl Config {
       Subcommand::Man
     } else if let Some(name) = matches.get_one::<String>(cmd::SHOW).map(Into::into) {
       Subcommand::Show { name }
+    } else if let Some(name) = matches.get_one::<String>(cmd::USAGE).map(Into::into) {
+      Subcommand::Usage { name }
     } else if matches.get_flag(cmd::EVALUATE) {
-      if positional.arguments.len() > 1 {
+      let mut arguments = positional.arguments.into_iter();
+
+      match (arguments.next(), arguments.next()) {
+        (Some(recipe), Some(variable)) => Subcommand::Evaluate {
+          arguments: arguments.collect(),
+          overrides,
+          recipe: Some(recipe),
+          variable: Some(variable),
+        },
+        (variable, _) => Subcommand::Evaluate {
+          arguments: Vec::new(),
+          overrides,
+          recipe: None,
+          variable,
+        },
+      }
+    } else if matches.get_flag(cmd::VARIABLES) {
+      Subcommand::Variables
+    } else if matches.get_flag(arg::CHECK) {
+      if !overrides.is_empty() {
+        return Err(ConfigError::SubcommandOverrides {
+          subcommand: arg::CHECK,
+          overrides,
+        });
+      }
+
+      if !positional.arguments.is_empty() {
         return Err(ConfigError::SubcommandArguments {
-          subcommand: cmd::EVALUATE,
-          arguments: positional
-            .arguments
-            .into_iter()
-            .skip(1)
-            .collect::<Vec<String>>(),
+          arguments: positional.arguments,
+          subcommand: arg::CHECK,
         });
       }
 
-      Subcommand::Evaluate {
-        variable: positional.arguments.into_iter().next(),
-        overrides,
+      Subcommand::Check
+    } else if positional.arguments.len() == 2 && positional.arguments[1] == "--help" {
+      Subcommand::Usage {
+        name: positional.arguments[0].clone(),
       }
-    } else if matches.get_flag(cmd::VARIABLES) {
-      Subcommand::Variables
     } else {
       Subcommand::Run {
         arguments: positional.arguments,
         overrides,
+        passthrough,
       }
     };
 
     let shell_args = if matches.get_flag(arg::CLEAR_SHELL_ARGS) {
       Some(Vec::new())
+    } else if let Some(shell_args) = matches.get_many::<String>(arg::SHELL_ARG) {
+      Some(shell_args.map(Into::into).collect())
     } else {
-      matches
-        .get_many::<String>(arg::SHELL_ARG)
-        .map(|s| s.map(Into::into).collect())
+      Self::shell_args_from_env()?
     };
 
     let unstable = matches.get_flag(arg::UNSTABLE);
 
     Ok(Self {
+      align: matches.get_flag(arg::ALIGN),
       check: matches.get_flag(arg::CHECK),
       color,
       command_color,
+      dependency_arguments: Self::dependency_arguments_from_matches(matches)?,
       dotenv_filename: matches
         .get_one::<String>(arg::DOTENV_FILENAME)
         .map(Into::into),
       dotenv_path: matches.get_one::<PathBuf>(arg::DOTENV_PATH).map(Into::into),
       dry_run: matches.get_flag(arg::DRY_RUN),
       dump_format: Self::dump_format_from_matches(matches)?,
+      exit_codes: Self::exit_codes_from_matches(matches)?,
       highlight: !matches.get_flag(arg::NO_HIGHLIGHT),
       invocation_directory,
+      jobs: matches
+        .get_one::<usize>(arg::JOBS)
+        .copied()
+        .unwrap_or_else(num_cpus::get),
+      json: matches.get_flag(arg::JSON),
+      list_flat: matches.get_flag(arg::LIST_FLAT),
       list_heading: matches
         .get_one::<String>(arg::LIST_HEADING)
         .map_or_else(|| "Available recipes:\n".into(), Into::into),
@@ -685,16 +1172,28 @@ impl Config {
         .get_one::<String>(arg::LIST_PREFIX)
         .map_or_else(|| "    ".into(), Into::into),
       load_dotenv: !matches.get_flag(arg::NO_DOTENV),
+      locations: matches.get_flag(arg::LOCATIONS),
       no_aliases: matches.get_flag(arg::NO_ALIASES),
       no_dependencies: matches.get_flag(arg::NO_DEPS),
       search_config,
+      search_limit: SearchLimit {
+        depth: matches.get_one::<usize>(arg::SEARCH_DEPTH).copied(),
+        project_root: matches.get_flag(arg::STOP_AT_PROJECT_ROOT),
+      },
       shell: matches.get_one::<String>(arg::SHELL).map(Into::into),
       shell_args,
       shell_command: matches.get_flag(arg::SHELL_COMMAND),
+      silence_stderr: matches.get_flag(arg::SILENCE_STDERR),
+      silence_stdout: matches.get_flag(arg::SILENCE_STDOUT),
+      stdin: matches.get_flag(arg::STDIN),
       subcommand,
+      timings: matches.get_flag(arg::TIMINGS),
+      trace_evaluation: matches.get_flag(arg::TRACE_EVALUATION),
+      transcript: matches.get_one::<PathBuf>(arg::TRANSCRIPT).map(Into::into),
       unsorted: matches.get_flag(arg::UNSORTED),
       unstable,
       verbosity,
+      warn_as_error: matches.get_flag(arg::WARN_AS_ERROR),
       yes: matches.get_flag(arg::YES),
     })
   }
@@ -710,6 +1209,7 @@ impl Config {
   }
 
   pub(crate) fn run(self, loader: &Loader) -> Result<(), Error> {
+    #[cfg(feature = "native")]
     if let Err(error) = InterruptHandler::install(self.verbosity) {
       warn!("Failed to set CTRL-C handler: {error}");
     }
@@ -730,17 +1230,28 @@ mod tests {
     {
       name: $name:ident,
       args: [$($arg:expr),*],
+      $(check: $check:expr,)?
       $(color: $color:expr,)?
+      $(dependency_arguments: $dependency_arguments:expr,)?
       $(dry_run: $dry_run:expr,)?
       $(dump_format: $dump_format:expr,)?
+      $(exit_codes: $exit_codes:expr,)?
       $(highlight: $highlight:expr,)?
+      $(jobs: $jobs:expr,)?
+      $(json: $json:expr,)?
       $(no_dependencies: $no_dependencies:expr,)?
       $(search_config: $search_config:expr,)?
+      $(search_limit: $search_limit:expr,)?
       $(shell: $shell:expr,)?
       $(shell_args: $shell_args:expr,)?
+      $(silence_stderr: $silence_stderr:expr,)?
+      $(silence_stdout: $silence_stdout:expr,)?
       $(subcommand: $subcommand:expr,)?
+      $(trace_evaluation: $trace_evaluation:expr,)?
+      $(transcript: $transcript:expr,)?
       $(unsorted: $unsorted:expr,)?
       $(verbosity: $verbosity:expr,)?
+      $(warn_as_error: $warn_as_error:expr,)?
     } => {
       #[test]
       fn $name() {
@@ -750,17 +1261,28 @@ mod tests {
         ];
 
         let want = Config {
+          $(check: $check,)?
           $(color: $color,)?
+          $(dependency_arguments: $dependency_arguments,)?
           $(dry_run: $dry_run,)?
           $(dump_format: $dump_format,)?
+          $(exit_codes: $exit_codes,)?
           $(highlight: $highlight,)?
+          $(jobs: $jobs,)?
+          $(json: $json,)?
           $(no_dependencies: $no_dependencies,)?
           $(search_config: $search_config,)?
+          $(search_limit: $search_limit,)?
           $(shell: $shell,)?
           $(shell_args: $shell_args,)?
+          $(silence_stderr: $silence_stderr,)?
+          $(silence_stdout: $silence_stdout,)?
           $(subcommand: $subcommand,)?
+          $(trace_evaluation: $trace_evaluation,)?
+          $(transcript: $transcript,)?
           $(unsorted: $unsorted,)?
           $(verbosity: $verbosity,)?
+          $(warn_as_error: $warn_as_error,)?
           ..testing::config(&[])
         };
 
@@ -955,6 +1477,53 @@ mod tests {
     highlight: false,
   }
 
+  test! {
+    name: dependency_arguments_default,
+    args: [],
+    dependency_arguments: BTreeMap::new(),
+  }
+
+  test! {
+    name: dependency_arguments,
+    args: [
+      "--dependency-argument",
+      "build::target=release",
+      "--dependency-argument",
+      "build::flags=-O=2",
+      "--dependency-argument",
+      "foo::test::filter="
+    ],
+    dependency_arguments: [
+      ("build".to_owned(), map! { "flags": "-O=2", "target": "release" }),
+      ("foo::test".to_owned(), map! { "filter": "" }),
+    ]
+    .into_iter()
+    .collect(),
+  }
+
+  test! {
+    name: jobs_default,
+    args: [],
+    jobs: num_cpus::get(),
+  }
+
+  test! {
+    name: jobs_long,
+    args: ["--jobs", "3"],
+    jobs: 3,
+  }
+
+  test! {
+    name: jobs_short,
+    args: ["-j", "3"],
+    jobs: 3,
+  }
+
+  error! {
+    name: jobs_zero,
+    args: ["--jobs", "0"],
+  }
+
   test! {
     name: no_deps,
     args: ["--no-deps"],
@@ -967,6 +1536,51 @@ mod tests {
     no_dependencies: true,
   }
 
+  test! {
+    name: trace_evaluation_default,
+    args: [],
+    trace_evaluation: false,
+  }
+
+  test! {
+    name: trace_evaluation,
+    args: ["--trace-evaluation"],
+    trace_evaluation: true,
+  }
+
+  test! {
+    name: transcript_default,
+    args: [],
+    transcript: None,
+  }
+
+  test! {
+    name: transcript,
+    args: ["--transcript", "run.json"],
+    transcript: Some(PathBuf::from("run.json")),
+  }
+
+  test! {
+    name: silence_default,
+    args: [],
+    silence_stderr: false,
+    silence_stdout: false,
+  }
+
+  test! {
+    name: silence_stderr,
+    args: ["--silence-stderr"],
+    silence_stderr: true,
+    silence_stdout: false,
+  }
+
+  test! {
+    name: silence_stdout,
+    args: ["--silence-stdout"],
+    silence_stderr: false,
+    silence_stdout: true,
+  }
+
   test! {
     name: unsorted_default,
     args: [],
@@ -1014,6 +1628,7 @@ mod tests {
     subcommand: Subcommand::Run {
       arguments: Vec::new(),
       overrides: map!(),
+      passthrough: Vec::new(),
     },
   }
 
@@ -1023,6 +1638,27 @@ mod tests {
     subcommand: Subcommand::Run {
       arguments: Vec::new(),
       overrides: map!{"foo": "bar"},
+      passthrough: Vec::new(),
+    },
+  }
+
+  test! {
+    name: passthrough,
+    args: ["build", "--", "--release", "foo=bar"],
+    subcommand: Subcommand::Run {
+      arguments: vec![String::from("build")],
+      overrides: map!{},
+      passthrough: vec![String::from("--release"), String::from("foo=bar")],
+    },
+  }
+
+  test! {
+    name: passthrough_flags_and_double_dash,
+    args: ["build", "--", "--list", "--", "-v"],
+    subcommand: Subcommand::Run {
+      arguments: vec![String::from("build")],
+      overrides: map!{},
+      passthrough: vec![String::from("--list"), String::from("--"), String::from("-v")],
     },
   }
 
@@ -1032,6 +1668,7 @@ mod tests {
     subcommand: Subcommand::Run {
       arguments: Vec::new(),
       overrides: map!{"foo": ""},
+      passthrough: Vec::new(),
     },
   }
 
@@ -1041,6 +1678,7 @@ mod tests {
     subcommand: Subcommand::Run {
       arguments: Vec::new(),
       overrides: map!{"foo": "bar", "bar": "baz"},
+      passthrough: Vec::new(),
     },
   }
 
@@ -1050,6 +1688,7 @@ mod tests {
     subcommand: Subcommand::Run {
       arguments: Vec::new(),
       overrides: map!{"foo": "baz"},
+      passthrough: Vec::new(),
     },
   }
 
@@ -1108,12 +1747,25 @@ mod tests {
     verbosity: Verbosity::Grandiloquent,
   }
 
+  test! {
+    name: warn_as_error_default,
+    args: [],
+    warn_as_error: false,
+  }
+
+  test! {
+    name: warn_as_error,
+    args: ["--warn-as-error"],
+    warn_as_error: true,
+  }
+
   test! {
     name: subcommand_default,
     args: [],
     subcommand: Subcommand::Run {
       arguments: Vec::new(),
       overrides: map!{},
+      passthrough: Vec::new(),
     },
   }
 
@@ -1132,11 +1784,21 @@ mod tests {
     args: ["--list", "--dump"],
   }
 
+  error! {
+    name: subcommand_conflict_dump_schema,
+    args: ["--list", "--dump-schema"],
+  }
+
   error! {
     name: subcommand_conflict_fmt,
     args: ["--list", "--fmt"],
   }
 
+  error! {
+    name: subcommand_conflict_groups,
+    args: ["--list", "--groups"],
+  }
+
   error! {
     name: subcommand_conflict_init,
     args: ["--list", "--init"],
@@ -1162,6 +1824,26 @@ mod tests {
     args: ["--list", "--variables"],
   }
 
+  test! {
+    name: subcommand_choose,
+    args: ["--choose"],
+    subcommand: Subcommand::Choose {
+      chooser: None,
+      overrides: map!{},
+      patterns: Vec::new(),
+    },
+  }
+
+  test! {
+    name: subcommand_choose_patterns,
+    args: ["--choose", "a=b", "test-*", "build"],
+    subcommand: Subcommand::Choose {
+      chooser: None,
+      overrides: map!{"a": "b"},
+      patterns: vec![String::from("test-*"), String::from("build")],
+    },
+  }
+
   error! {
     name: subcommand_conflict_choose,
     args: ["--list", "--choose"],
@@ -1187,7 +1869,82 @@ mod tests {
   test! {
     name: subcommand_dump,
     args: ["--dump"],
-    subcommand: Subcommand::Dump,
+    subcommand: Subcommand::Dump {
+      recipe: None,
+      variable: None,
+    },
+  }
+
+  test! {
+    name: subcommand_dump_recipe,
+    args: ["--dump", "--recipe", "build"],
+    subcommand: Subcommand::Dump {
+      recipe: Some(String::from("build")),
+      variable: None,
+    },
+  }
+
+  test! {
+    name: subcommand_dump_variable,
+    args: ["--dump", "--variable", "version"],
+    subcommand: Subcommand::Dump {
+      recipe: None,
+      variable: Some(String::from("version")),
+    },
+  }
+
+  error! {
+    name: dump_recipe_lossless,
+    args: ["--dump", "--recipe", "build", "--dump-format", "json-lossless"],
+    error: ConfigError::DumpItemLossless,
+  }
+
+  error! {
+    name: dump_recipe_without_dump,
+    args: ["--recipe", "build"],
+  }
+
+  error! {
+    name: dump_recipe_and_variable,
+    args: ["--dump", "--recipe", "build", "--variable", "version"],
+  }
+
+  test! {
+    name: subcommand_check,
+    args: ["--check"],
+    check: true,
+    subcommand: Subcommand::Check,
+  }
+
+  error! {
+    name: subcommand_check_arguments,
+    args: ["--check", "foo"],
+    error: ConfigError::SubcommandArguments { subcommand, arguments },
+    check: {
+      assert_eq!(subcommand, arg::CHECK);
+      assert_eq!(arguments, &["foo"]);
+    },
+  }
+
+  error! {
+    name: subcommand_check_overrides,
+    args: ["--check", "foo=bar"],
+    error: ConfigError::SubcommandOverrides { subcommand, overrides },
+    check: {
+      assert_eq!(subcommand, arg::CHECK);
+      assert_eq!(overrides, map!{"foo": "bar"});
+    },
+  }
+
+  error! {
+    name: subcommand_conflict_check,
+    args: ["--check", "--list"],
+  }
+
+  test! {
+    name: subcommand_dump_schema,
+    args: ["--dump-schema"],
+    subcommand: Subcommand::DumpSchema,
   }
 
   test! {
@@ -1196,17 +1953,56 @@ mod tests {
     dump_format: DumpFormat::Json,
   }
 
+  test! {
+    name: exit_codes_default,
+    args: [],
+    exit_codes: ExitCodes::Passthrough,
+  }
+
+  test! {
+    name: exit_codes_normalized,
+    args: ["--exit-codes", "normalized"],
+    exit_codes: ExitCodes::Normalized,
+  }
+
+  test! {
+    name: exit_codes_categorized,
+    args: ["--exit-codes", "categorized"],
+    exit_codes: ExitCodes::Categorized,
+  }
+
+  error! {
+    name: exit_codes_invalid,
+    args: ["--exit-codes", "foo"],
+  }
+
   test! {
     name: subcommand_edit,
     args: ["--edit"],
-    subcommand: Subcommand::Edit,
+    subcommand: Subcommand::Edit { recipe: None },
+  }
+
+  test! {
+    name: subcommand_edit_recipe,
+    args: ["--edit", "build"],
+    subcommand: Subcommand::Edit {
+      recipe: Some(String::from("build")),
+    },
+  }
+
+  test! {
+    name: subcommand_groups,
+    args: ["--groups"],
+    subcommand: Subcommand::Groups,
   }
 
   test! {
     name: subcommand_evaluate,
     args: ["--evaluate"],
     subcommand: Subcommand::Evaluate {
+      arguments: Vec::new(),
       overrides: map!{},
+      recipe: None,
       variable: None,
     },
   }
@@ -1215,7 +2011,9 @@ mod tests {
     name: subcommand_evaluate_overrides,
     args: ["--evaluate", "x=y"],
     subcommand: Subcommand::Evaluate {
+      arguments: Vec::new(),
       overrides: map!{"x": "y"},
+      recipe: None,
       variable: None,
     },
   }
@@ -1224,11 +2022,35 @@ mod tests {
     name: subcommand_evaluate_overrides_with_argument,
     args: ["--evaluate", "x=y", "foo"],
     subcommand: Subcommand::Evaluate {
+      arguments: Vec::new(),
       overrides: map!{"x": "y"},
+      recipe: None,
       variable: Some("foo".to_owned()),
     },
   }
 
+  test! {
+    name: subcommand_evaluate_recipe,
+    args: ["--evaluate", "x=y", "foo", "bar"],
+    subcommand: Subcommand::Evaluate {
+      arguments: Vec::new(),
+      overrides: map!{"x": "y"},
+      recipe: Some("foo".to_owned()),
+      variable: Some("bar".to_owned()),
+    },
+  }
+
+  test! {
+    name: subcommand_evaluate_recipe_with_arguments,
+    args: ["--evaluate", "foo", "bar", "baz", "qux"],
+    subcommand: Subcommand::Evaluate {
+      arguments: vec!["baz".to_owned(), "qux".to_owned()],
+      overrides: map!{},
+      recipe: Some("foo".to_owned()),
+      variable: Some("bar".to_owned()),
+    },
+  }
+
   test! {
     name: subcommand_list_long,
     args: ["--list"],
@@ -1258,18 +2080,79 @@ mod tests {
     args: ["--show"],
   }
 
+  test! {
+    name: subcommand_usage,
+    args: ["--usage", "build"],
+    subcommand: Subcommand::Usage { name: String::from("build") },
+  }
+
+  test! {
+    name: subcommand_usage_recipe_help,
+    args: ["build", "--help"],
+    subcommand: Subcommand::Usage { name: String::from("build") },
+  }
+
+  error! {
+    name: subcommand_usage_no_arg,
+    args: ["--usage"],
+  }
+
+  test! {
+    name: subcommand_find,
+    args: ["--find", "cargo"],
+    subcommand: Subcommand::Find { pattern: String::from("cargo") },
+  }
+
+  error! {
+    name: subcommand_find_no_arg,
+    args: ["--find"],
+  }
+
   test! {
     name: subcommand_summary,
     args: ["--summary"],
     subcommand: Subcommand::Summary,
   }
 
+  test! {
+    name: subcommand_settings,
+    args: ["--settings"],
+    subcommand: Subcommand::Settings,
+  }
+
+  test! {
+    name: subcommand_list_json,
+    args: ["--list", "--json"],
+    json: true,
+    subcommand: Subcommand::List,
+  }
+
+  test! {
+    name: subcommand_settings_json,
+    args: ["--settings", "--json"],
+    json: true,
+    subcommand: Subcommand::Settings,
+  }
+
+  test! {
+    name: subcommand_summary_json,
+    args: ["--summary", "--json"],
+    json: true,
+    subcommand: Subcommand::Summary,
+  }
+
+  error! {
+    name: json_without_summary,
+    args: ["--json"],
+  }
+
   test! {
     name: arguments,
     args: ["foo", "bar"],
     subcommand: Subcommand::Run {
       arguments: vec![String::from("foo"), String::from("bar")],
       overrides: map!{},
+      passthrough: Vec::new(),
     },
   }
 
@@ -1279,6 +2162,7 @@ mod tests {
     subcommand: Subcommand::Run {
       arguments: vec!["=foo".to_owned()],
       overrides: map!{},
+      passthrough: Vec::new(),
     },
   }
 
@@ -1288,6 +2172,7 @@ mod tests {
     subcommand: Subcommand::Run {
       arguments: Vec::new(),
       overrides: map!{"foo": "bar", "bar": "baz"},
+      passthrough: Vec::new(),
     },
   }
 
@@ -1297,6 +2182,7 @@ mod tests {
     subcommand: Subcommand::Run {
       arguments: Vec::new(),
       overrides: map!{"foo": "", "bar": ""},
+      passthrough: Vec::new(),
     },
   }
 
@@ -1306,6 +2192,7 @@ mod tests {
     subcommand: Subcommand::Run {
       arguments: Vec::new(),
       overrides: map!{"foo": "bar", "bar": "baz"},
+      passthrough: Vec::new(),
     },
   }
 
@@ -1392,6 +2279,30 @@ mod tests {
     },
   }
 
+  test! {
+    name: search_limit_default,
+    args: [],
+    search_limit: SearchLimit::default(),
+  }
+
+  test! {
+    name: search_limit_depth,
+    args: ["--search-depth", "2"],
+    search_limit: SearchLimit {
+      depth: Some(2),
+      project_root: false,
+    },
+  }
+
+  test! {
+    name: search_limit_project_root,
+    args: ["--stop-at-project-root"],
+    search_limit: SearchLimit {
+      depth: None,
+      project_root: true,
+    },
+  }
+
   test! {
     name: search_directory_parent,
     args: ["../"],
@@ -1406,7 +2317,11 @@ mod tests {
     search_config: SearchConfig::FromSearchDirectory {
       search_directory: PathBuf::from(".."),
     },
-    subcommand: Subcommand::Run { arguments: vec!["build".to_owned()], overrides: BTreeMap::new() },
+    subcommand: Subcommand::Run {
+      arguments: vec!["build".to_owned()],
+      overrides: BTreeMap::new(),
+      passthrough: Vec::new(),
+    },
   }
 
   test! {
@@ -1431,7 +2346,11 @@ mod tests {
     search_config: SearchConfig::FromSearchDirectory {
       search_directory: PathBuf::from("foo"),
     },
-    subcommand: Subcommand::Run { arguments: vec!["build".to_owned()], overrides: BTreeMap::new() },
+    subcommand: Subcommand::Run {
+      arguments: vec!["build".to_owned()],
+      overrides: BTreeMap::new(),
+      passthrough: Vec::new(),
+    },
   }
 
   error! {
@@ -1490,13 +2409,23 @@ mod tests {
     },
   }
 
+  error! {
+    name: dump_schema_arguments,
+    args: ["--dump-schema", "bar"],
+    error: ConfigError::SubcommandArguments { subcommand, arguments },
+    check: {
+      assert_eq!(subcommand, cmd::DUMP_SCHEMA);
+      assert_eq!(arguments, &["bar"]);
+    },
+  }
+
   error! {
     name: edit_arguments,
-    args: ["--edit", "bar"],
+    args: ["--edit", "bar", "baz"],
     error: ConfigError::SubcommandArguments { subcommand, arguments },
     check: {
       assert_eq!(subcommand, cmd::EDIT);
-      assert_eq!(arguments, &["bar"]);
+      assert_eq!(arguments, &["baz"]);
     },
   }
 
@@ -5,16 +5,35 @@ use super::*;
 pub(crate) enum ConfigError {
   #[snafu(display("Failed to get current directory: {}", source))]
   CurrentDir { source: io::Error },
+  #[snafu(display(
+    "Dependency argument `{}` is not of the form `RECIPE::PARAMETER=VALUE`",
+    argument
+  ))]
+  DependencyArgument { argument: String },
+  #[snafu(display(
+    "`--recipe` and `--variable` may not be used with `--dump-format json-lossless`"
+  ))]
+  DumpItemLossless,
   #[snafu(display(
     "Internal config error, this may indicate a bug in just: {} \
      consider filing an issue: https://github.com/casey/just/issues/new",
     message
   ))]
   Internal { message: String },
+  #[snafu(display("Failed to read overrides file `{}`: {}", path.display(), source))]
+  OverridesFileIo { path: PathBuf, source: io::Error },
+  #[snafu(display("Invalid overrides file `{}`: {}", path.display(), message))]
+  OverridesFileInvalid { path: PathBuf, message: String },
   #[snafu(display(
     "Path-prefixed recipes may not be used with `--working-directory` or `--justfile`."
   ))]
   SearchDirConflict,
+  #[snafu(display(
+    "`${}` is not a JSON array of strings: `{}`",
+    config::SHELL_ARGS_ENV,
+    value
+  ))]
+  ShellArgsEnv { value: String },
   #[snafu(display(
     "`--{}` used with unexpected {}: {}",
     subcommand.to_lowercase(),
@@ -3,13 +3,15 @@ use super::*;
 #[derive(PartialEq, Debug, Serialize)]
 pub(crate) struct Dependency<'src> {
   pub(crate) arguments: Vec<Expression<'src>>,
+  /// Only run the dependency if `condition` is true
+  pub(crate) condition: Option<Condition<'src>>,
   #[serde(serialize_with = "keyed::serialize")]
-  pub(crate) recipe: Rc<Recipe<'src>>,
+  pub(crate) recipe: Arc<Recipe<'src>>,
 }
 
 impl<'src> Display for Dependency<'src> {
   fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-    if self.arguments.is_empty() {
+    if self.arguments.is_empty() && self.condition.is_none() {
       write!(f, "{}", self.recipe.name())
     } else {
       write!(f, "({}", self.recipe.name())?;
@@ -18,6 +20,10 @@ impl<'src> Display for Dependency<'src> {
         write!(f, " {argument}")?;
       }
 
+      if let Some(condition) = &self.condition {
+        write!(f, " if {condition}")?;
+      }
+
       write!(f, ")")
     }
   }
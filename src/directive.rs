@@ -0,0 +1,126 @@
+use super::*;
+
+/// A recipe line beginning with `~error`, `~open`, `~sleep`, or `~warn`,
+/// which is handled by `just` instead of being passed to the shell
+#[derive(Debug, PartialEq)]
+pub(crate) enum Directive<'line> {
+  Error(&'line str),
+  Open(&'line str),
+  Sleep(&'line str),
+  Warn(&'line str),
+}
+
+impl<'line> Directive<'line> {
+  pub(crate) fn parse(line: &'line str) -> Option<Self> {
+    let (name, message) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+
+    let message = message.trim();
+
+    match name {
+      "~error" => Some(Self::Error(message)),
+      "~open" => Some(Self::Open(message)),
+      "~sleep" => Some(Self::Sleep(message)),
+      "~warn" => Some(Self::Warn(message)),
+      _ => None,
+    }
+  }
+
+  /// Parse a `~sleep` duration, given in possibly fractional seconds
+  pub(crate) fn sleep_duration(seconds: &str) -> Option<Duration> {
+    seconds
+      .parse::<f64>()
+      .ok()
+      .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+      .map(Duration::from_secs_f64)
+  }
+
+  /// The program used to open files and URLs with the platform's default
+  /// handler
+  pub(crate) fn opener() -> &'static str {
+    if cfg!(windows) {
+      "rundll32"
+    } else if cfg!(target_os = "macos") {
+      "open"
+    } else {
+      "xdg-open"
+    }
+  }
+
+  /// Command which opens `target` with the platform's default handler. On
+  /// Windows, `FileProtocolHandler` receives the rest of the command line
+  /// as-is, so `target` is passed raw and never interpreted by `cmd`.
+  pub(crate) fn open_command(target: &str) -> Command {
+    let mut command = Command::new(Self::opener());
+
+    #[cfg(windows)]
+    {
+      use std::os::windows::process::CommandExt;
+      command.arg("url.dll,FileProtocolHandler");
+      command.raw_arg(target);
+    }
+
+    #[cfg(not(windows))]
+    command.arg(target);
+
+    command
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse() {
+    #[track_caller]
+    fn case(line: &str, expected: Option<Directive>) {
+      assert_eq!(Directive::parse(line), expected);
+    }
+
+    case("~error", Some(Directive::Error("")));
+    case("~error bad things", Some(Directive::Error("bad things")));
+    case("~warn  careful ", Some(Directive::Warn("careful")));
+    case("~warn\tcareful", Some(Directive::Warn("careful")));
+    case(
+      "~open docs/index.html",
+      Some(Directive::Open("docs/index.html")),
+    );
+    case("~sleep 0.5", Some(Directive::Sleep("0.5")));
+    case("~warning careful", None);
+    case("echo ~warn", None);
+    case("~/bin/warn", None);
+  }
+
+  #[test]
+  fn open_command() {
+    let command = Directive::open_command("https://example.com/?a=1&b=2");
+
+    assert_eq!(command.get_program(), Directive::opener());
+
+    let args = command.get_args().collect::<Vec<&OsStr>>();
+
+    if cfg!(windows) {
+      assert_eq!(
+        args,
+        [
+          "url.dll,FileProtocolHandler",
+          "https://example.com/?a=1&b=2"
+        ]
+      );
+    } else {
+      assert_eq!(args, ["https://example.com/?a=1&b=2"]);
+    }
+  }
+
+  #[test]
+  fn sleep_duration() {
+    assert_eq!(Directive::sleep_duration("1"), Some(Duration::from_secs(1)));
+    assert_eq!(
+      Directive::sleep_duration("0.25"),
+      Some(Duration::from_millis(250))
+    );
+    assert_eq!(Directive::sleep_duration("-1"), None);
+    assert_eq!(Directive::sleep_duration("inf"), None);
+    assert_eq!(Directive::sleep_duration("1s"), None);
+  }
+}
@@ -0,0 +1,13 @@
+use super::*;
+
+/// What to do when a dotenv file is found in a parent of the justfile
+/// directory, set with `set dotenv-outside-project`
+#[derive(
+  Clone, Copy, Debug, Display, EnumString, IntoStaticStr, PartialEq, Serialize, VariantNames,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum DotenvOutsideProject {
+  Warn,
+  Error,
+}
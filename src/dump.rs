@@ -0,0 +1,1356 @@
+//! Owned data model of a compiled justfile.
+//!
+//! Justfiles can be compiled into these types with `compile`, which takes
+//! justfile source, or `load`, which reads a justfile and its imports from
+//! disk. Both are also available at the crate root, as `just::compile` and
+//! `just::load`:
+//!
+//! ```ignore
+//! let justfile = just::compile("foo:\n  echo foo")?;
+//! assert!(justfile.recipes.contains_key("foo"));
+//! ```
+//!
+//! The types in this module also serialize to, and deserialize from, exactly
+//! the JSON produced by `just --dump --dump-format json`, so a dumped justfile
+//! can be read back into structured types. The JSON Schema printed by
+//! `just --dump-schema` is generated from them with `schema`:
+//!
+//! ```ignore
+//! let justfile: just::dump::Justfile = serde_json::from_str(&json)?;
+//! ```
+
+use {
+  crate::{
+    color::Color, color_display::ColorDisplay, compiler::Compiler, error::Error as RunError,
+    loader::Loader,
+  },
+  schemars::{
+    gen::{SchemaGenerator, SchemaSettings},
+    schema::{RootSchema, Schema},
+    JsonSchema,
+  },
+  serde::{
+    de::{self, IgnoredAny, SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+  },
+  serde_json::json,
+  std::{
+    collections::{BTreeMap, BTreeSet},
+    error,
+    fmt::{self, Display, Formatter},
+    path::{Path, PathBuf},
+  },
+};
+
+mod full {
+  pub(crate) use crate::{
+    alias::Alias, assignment::Assignment, attribute::Attribute, condition::Condition,
+    conditional_operator::ConditionalOperator, dependency::Dependency, expression::Expression,
+    fragment::Fragment, justfile::Justfile, line::Line, parameter::Parameter,
+    parameter_kind::ParameterKind, recipe::Recipe, settings::Settings, shell::Shell, thunk::Thunk,
+    warning::Warning,
+  };
+}
+
+/// Compile justfile source `src`. Imports and modules are not loaded.
+///
+/// # Errors
+///
+/// Returns an error if `src` is not a valid justfile.
+pub fn compile(src: &str) -> Result<Justfile, Error> {
+  match Compiler::compile_src(Path::new("justfile"), src) {
+    Ok(justfile) => Ok(Justfile::new(&justfile)),
+    Err(compile_error) => Err(Error::new(&RunError::Compile { compile_error })),
+  }
+}
+
+/// Read and compile the justfile at `path`, along with its imports.
+///
+/// # Errors
+///
+/// Returns an error if the justfile or one of its imports cannot be read, or
+/// is not a valid justfile.
+pub fn load(path: &Path) -> Result<Justfile, Error> {
+  let loader = Loader::new();
+
+  match Compiler::compile(false, &loader, path) {
+    Ok(compilation) => Ok(Justfile::new(&compilation.justfile)),
+    Err(error) => Err(Error::new(&error)),
+  }
+}
+
+/// Generate the JSON Schema describing the output of
+/// `just --dump --dump-format json`, which is printed by `just --dump-schema`
+#[must_use]
+pub fn schema() -> RootSchema {
+  let mut settings = SchemaSettings::draft2019_09();
+  settings.meta_schema = Some("https://json-schema.org/draft/2020-12/schema".into());
+
+  let mut schema = settings.into_generator().into_root_schema_for::<Justfile>();
+
+  let metadata = schema.schema.metadata();
+  metadata.id = Some("https://just.systems/schema.json".into());
+  metadata.title = Some("justfile".into());
+  metadata.description = Some("The output of `just --dump --dump-format json`".into());
+
+  schema
+}
+
+/// Convert `value`, a schema written as JSON, for types whose serialization
+/// is implemented by hand
+fn from_json(value: serde_json::Value) -> Schema {
+  serde_json::from_value(value).unwrap()
+}
+
+/// An error encountered while compiling a justfile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+  /// Error message
+  pub message: String,
+  /// Path of the file in which the error occurred, if known
+  pub path: Option<PathBuf>,
+  /// One-based line on which the error occurred, if known
+  pub line: Option<usize>,
+  /// One-based column at which the error occurred, if known
+  pub column: Option<usize>,
+}
+
+impl Error {
+  pub(crate) fn new(error: &RunError) -> Self {
+    let context = error.context();
+
+    let message = if let RunError::Compile { compile_error } = error {
+      compile_error.to_string()
+    } else {
+      let rendered = error.color_display(Color::never()).to_string();
+      let rendered = rendered.strip_prefix("error: ").unwrap_or(&rendered);
+      match context {
+        Some(token) => rendered
+          .split_once(&format!("\n{}", token.color_display(Color::never())))
+          .map_or(rendered, |(message, _context)| message)
+          .to_owned(),
+        None => rendered.to_owned(),
+      }
+    };
+
+    Self {
+      message,
+      path: context.map(|token| token.path.to_owned()),
+      line: context.map(|token| token.line + 1),
+      column: context.map(|token| token.column + 1),
+    }
+  }
+}
+
+impl Display for Error {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    if let (Some(path), Some(line), Some(column)) = (&self.path, self.line, self.column) {
+      write!(f, "{}:{line}:{column}: ", path.display())?;
+    }
+    write!(f, "{}", self.message)
+  }
+}
+
+impl error::Error for Error {}
+
+/// A compiled justfile
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Justfile {
+  /// Aliases, by name
+  pub aliases: BTreeMap<String, Alias>,
+  /// Variable assignments, by name
+  pub assignments: BTreeMap<String, Assignment>,
+  /// Name of the default recipe
+  pub first: Option<String>,
+  /// Submodules, by name
+  pub modules: BTreeMap<String, Justfile>,
+  /// Recipes, by name
+  pub recipes: BTreeMap<String, Recipe>,
+  pub settings: Settings,
+  pub warnings: Vec<Warning>,
+}
+
+impl Justfile {
+  /// Convert `justfile`, including the location of each recipe in it and its
+  /// submodules
+  pub(crate) fn with_locations(justfile: &full::Justfile) -> Self {
+    let mut dumped = Self::new(justfile);
+    dumped.add_locations(justfile);
+    dumped
+  }
+
+  fn add_locations(&mut self, justfile: &full::Justfile) {
+    for (name, recipe) in &mut self.recipes {
+      recipe.location = justfile
+        .recipes
+        .get(name.as_str())
+        .map(|recipe| recipe.location());
+    }
+
+    for (name, module) in &mut self.modules {
+      if let Some(full) = justfile.modules.get(name) {
+        module.add_locations(full);
+      }
+    }
+  }
+
+  fn new(justfile: &full::Justfile) -> Self {
+    Self {
+      aliases: justfile
+        .aliases
+        .iter()
+        .map(|(name, alias)| ((*name).to_owned(), Alias::new(alias)))
+        .collect(),
+      assignments: justfile
+        .assignments
+        .iter()
+        .map(|(name, assignment)| ((*name).to_owned(), Assignment::new(assignment)))
+        .collect(),
+      first: justfile
+        .default
+        .as_ref()
+        .map(|recipe| recipe.name().to_owned()),
+      modules: justfile
+        .modules
+        .iter()
+        .map(|(name, module)| (name.clone(), Self::new(module)))
+        .collect(),
+      recipes: justfile
+        .recipes
+        .iter()
+        .map(|(name, recipe)| ((*name).to_owned(), Recipe::new(recipe)))
+        .collect(),
+      settings: Settings::new(&justfile.settings),
+      warnings: justfile.warnings.iter().map(Warning::new).collect(),
+    }
+  }
+}
+
+/// An alias, e.g. `alias b := build`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Alias {
+  pub attributes: BTreeSet<Attribute>,
+  pub name: String,
+  /// Name of the aliased recipe
+  pub target: String,
+}
+
+impl Alias {
+  fn new(alias: &full::Alias) -> Self {
+    Self {
+      attributes: alias.attributes.iter().map(Attribute::new).collect(),
+      name: alias.name.lexeme().to_owned(),
+      target: alias.target.name().to_owned(),
+    }
+  }
+}
+
+/// A variable assignment, e.g. `version := '1.0.0'`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Assignment {
+  /// Values the variable may be overridden with, or any value if empty
+  pub choices: Vec<String>,
+  /// Module depth at which the assignment was defined
+  pub depth: u32,
+  /// Doc comment preceding the assignment
+  pub doc: Option<String>,
+  /// Whether the variable is exported as an environment variable
+  pub export: bool,
+  pub name: String,
+  /// Whether the value is masked in output
+  pub secret: bool,
+  pub value: Expression,
+}
+
+impl Assignment {
+  fn new(assignment: &full::Assignment) -> Self {
+    Self {
+      choices: assignment
+        .choices
+        .iter()
+        .map(|choice| choice.cooked.clone())
+        .collect(),
+      depth: assignment.depth,
+      doc: assignment.doc.map(str::to_owned),
+      export: assignment.export,
+      name: assignment.name.lexeme().to_owned(),
+      secret: assignment.secret,
+      value: Expression::new(&assignment.value),
+    }
+  }
+}
+
+/// A recipe or alias attribute, e.g. `[private]`
+#[derive(
+  Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Attribute {
+  /// `[allow(CODE, ...)]`, suppressing warnings with the given codes
+  Allow(Vec<String>),
+  /// `[arch(ARCH, ...)]`, enabling the recipe on the given architectures
+  Arch(Vec<String>),
+  /// `[body-file(COMMAND)]`, running COMMAND with the recipe body written to
+  /// a file
+  BodyFile(String),
+  /// `[choices(VALUE, ...)]`, restricting overrides of a variable to the
+  /// given values
+  Choices(Vec<String>),
+  /// `[confirm]`, with an optional custom prompt
+  Confirm(Option<String>),
+  /// `[dotenv(PATH)]`, loading a dotenv file for the recipe
+  Dotenv(String),
+  /// `[forward-arguments]`, passing same-named parameters to dependencies
+  ForwardArguments,
+  Freebsd,
+  /// `[group(NAME)]`, listing the recipe under NAME
+  Group(String),
+  Linux,
+  Macos,
+  NoCd,
+  NoExitMessage,
+  NoList,
+  Openbsd,
+  /// `[parallel]`, running the recipe's prior dependencies concurrently
+  Parallel,
+  Private,
+  NoQuiet,
+  /// `[quiet]`, running the recipe as if `--quiet` were passed
+  Quiet,
+  /// `[secret(PARAMETER, ...)]`, masking the given parameters, or all
+  /// parameters if none are given. Values derived from masked values, such as
+  /// `uppercase(PARAMETER)`, are not masked.
+  Secret(Vec<String>),
+  /// `[tempdir(PATH)]`, creating the recipe's temporary directory in PATH
+  Tempdir(String),
+  Unix,
+  Verbatim,
+  /// `[verbose]`, running the recipe as if `--verbose` were passed
+  Verbose,
+  Windows,
+  Wsl,
+}
+
+impl Attribute {
+  pub(crate) fn new(attribute: &full::Attribute) -> Self {
+    match attribute {
+      full::Attribute::Allow(codes) => {
+        Self::Allow(codes.iter().map(|code| code.cooked.clone()).collect())
+      }
+      full::Attribute::Arch(architectures) => Self::Arch(
+        architectures
+          .iter()
+          .map(|architecture| architecture.cooked.clone())
+          .collect(),
+      ),
+      full::Attribute::BodyFile(command) => Self::BodyFile(
+        command
+          .as_ref()
+          .map_or_else(String::new, |command| command.cooked.clone()),
+      ),
+      full::Attribute::Choices(choices) => {
+        Self::Choices(choices.iter().map(|choice| choice.cooked.clone()).collect())
+      }
+      full::Attribute::Confirm(prompt) => {
+        Self::Confirm(prompt.as_ref().map(|prompt| prompt.cooked.clone()))
+      }
+      full::Attribute::Dotenv(path) => Self::Dotenv(
+        path
+          .as_ref()
+          .map_or_else(String::new, |path| path.cooked.clone()),
+      ),
+      full::Attribute::ForwardArguments => Self::ForwardArguments,
+      full::Attribute::Freebsd => Self::Freebsd,
+      full::Attribute::Group(name) => Self::Group(
+        name
+          .as_ref()
+          .map_or_else(String::new, |name| name.cooked.clone()),
+      ),
+      full::Attribute::Linux => Self::Linux,
+      full::Attribute::Macos => Self::Macos,
+      full::Attribute::NoCd => Self::NoCd,
+      full::Attribute::NoExitMessage => Self::NoExitMessage,
+      full::Attribute::NoList => Self::NoList,
+      full::Attribute::Openbsd => Self::Openbsd,
+      full::Attribute::Parallel => Self::Parallel,
+      full::Attribute::Private => Self::Private,
+      full::Attribute::NoQuiet => Self::NoQuiet,
+      full::Attribute::Quiet => Self::Quiet,
+      full::Attribute::Secret(parameters) => Self::Secret(
+        parameters
+          .iter()
+          .map(|parameter| parameter.cooked.clone())
+          .collect(),
+      ),
+      full::Attribute::Tempdir(path) => Self::Tempdir(
+        path
+          .as_ref()
+          .map_or_else(String::new, |path| path.cooked.clone()),
+      ),
+      full::Attribute::Unix => Self::Unix,
+      full::Attribute::Verbatim => Self::Verbatim,
+      full::Attribute::Verbose => Self::Verbose,
+      full::Attribute::Windows => Self::Windows,
+      full::Attribute::Wsl => Self::Wsl,
+    }
+  }
+}
+
+/// A recipe, e.g. `build target: clean`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Recipe {
+  pub attributes: BTreeSet<Attribute>,
+  pub body: Vec<Line>,
+  pub dependencies: Vec<Dependency>,
+  /// Documentation comment
+  pub doc: Option<String>,
+  /// Path and one-based line number of the recipe, e.g. `justfile:3`,
+  /// included when dumping with `--locations`
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub location: Option<String>,
+  pub name: String,
+  /// Path to the recipe from the root justfile, e.g. `foo::bar`
+  pub namepath: String,
+  pub parameters: Vec<Parameter>,
+  /// Number of dependencies that run before the recipe
+  pub priors: usize,
+  pub private: bool,
+  pub quiet: bool,
+  pub shebang: bool,
+}
+
+impl Recipe {
+  /// Convert `recipe`, including its location
+  pub(crate) fn with_location(recipe: &full::Recipe) -> Self {
+    Self {
+      location: Some(recipe.location()),
+      ..Self::new(recipe)
+    }
+  }
+
+  fn new(recipe: &full::Recipe) -> Self {
+    Self {
+      attributes: recipe.attributes.iter().map(Attribute::new).collect(),
+      body: recipe.body.iter().map(Line::new).collect(),
+      dependencies: recipe.dependencies.iter().map(Dependency::new).collect(),
+      doc: recipe.doc.map(str::to_owned),
+      location: None,
+      name: recipe.name.lexeme().to_owned(),
+      namepath: recipe.namepath.to_string(),
+      parameters: recipe.parameters.iter().map(Parameter::new).collect(),
+      priors: recipe.priors,
+      private: recipe.private,
+      quiet: recipe.quiet,
+      shebang: recipe.shebang,
+    }
+  }
+}
+
+/// A recipe dependency, e.g. `(build 'release')`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Dependency {
+  pub arguments: Vec<Expression>,
+  /// Only run the dependency if this condition is true
+  pub condition: Option<Condition>,
+  /// Name of the recipe depended upon
+  pub recipe: String,
+}
+
+impl Dependency {
+  fn new(dependency: &full::Dependency) -> Self {
+    Self {
+      arguments: dependency.arguments.iter().map(Expression::new).collect(),
+      condition: dependency.condition.as_ref().map(Condition::new),
+      recipe: dependency.recipe.name().to_owned(),
+    }
+  }
+}
+
+/// A recipe parameter, e.g. `$target='debug'`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Parameter {
+  pub default: Option<Expression>,
+  /// Whether the parameter is exported as an environment variable
+  pub export: bool,
+  /// Name of the exported environment variable, if not `name`
+  pub export_name: Option<String>,
+  pub kind: ParameterKind,
+  pub name: String,
+  /// Regular expression which arguments must match
+  pub pattern: Option<String>,
+  /// Whether arguments are masked in output
+  pub secret: bool,
+}
+
+impl Parameter {
+  fn new(parameter: &full::Parameter) -> Self {
+    Self {
+      default: parameter.default.as_ref().map(Expression::new),
+      export: parameter.export,
+      export_name: parameter
+        .export_name
+        .map(|export_name| export_name.lexeme().to_owned()),
+      kind: ParameterKind::new(parameter.kind),
+      name: parameter.name.lexeme().to_owned(),
+      pattern: parameter
+        .pattern
+        .as_ref()
+        .map(|pattern| pattern.cooked.clone()),
+      secret: parameter.secret,
+    }
+  }
+}
+
+/// Parameters can either be…
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ParameterKind {
+  /// …singular, accepting a single argument
+  Singular,
+  /// …variadic, accepting one or more arguments
+  Plus,
+  /// …variadic, accepting zero or more arguments
+  Star,
+  /// …passthrough, accepting the arguments after `--` verbatim
+  Passthrough,
+}
+
+impl ParameterKind {
+  fn new(parameter_kind: full::ParameterKind) -> Self {
+    match parameter_kind {
+      full::ParameterKind::Singular => Self::Singular,
+      full::ParameterKind::Plus => Self::Plus,
+      full::ParameterKind::Star => Self::Star,
+      full::ParameterKind::Passthrough => Self::Passthrough,
+    }
+  }
+}
+
+/// A single line in a recipe body
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(transparent)]
+pub struct Line {
+  pub fragments: Vec<Fragment>,
+}
+
+impl Line {
+  fn new(line: &full::Line) -> Self {
+    Self {
+      fragments: line.fragments.iter().map(Fragment::new).collect(),
+    }
+  }
+}
+
+/// A line fragment consisting either of…
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fragment {
+  /// …raw text…
+  Text { text: String },
+  /// …an interpolation containing `expression`.
+  Interpolation { expression: Expression },
+}
+
+impl Fragment {
+  fn new(fragment: &full::Fragment) -> Self {
+    match fragment {
+      full::Fragment::Text { token } => Self::Text {
+        text: token.lexeme().to_owned(),
+      },
+      full::Fragment::Interpolation { expression } => Self::Interpolation {
+        expression: Expression::new(expression),
+      },
+    }
+  }
+}
+
+impl Serialize for Fragment {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match self {
+      Self::Text { text } => serializer.serialize_str(text),
+      Self::Interpolation { expression } => {
+        let mut seq = serializer.serialize_seq(Some(1))?;
+        seq.serialize_element(expression)?;
+        seq.end()
+      }
+    }
+  }
+}
+
+impl JsonSchema for Fragment {
+  fn schema_name() -> String {
+    "Fragment".into()
+  }
+
+  fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+    from_json(json!({
+      "description": "A line fragment, either text or an interpolation",
+      "oneOf": [
+        { "description": "Text", "type": "string" },
+        {
+          "description": "Interpolation",
+          "type": "array",
+          "prefixItems": [gen.subschema_for::<Expression>()],
+          "items": false,
+          "minItems": 1,
+        },
+      ],
+    }))
+  }
+}
+
+impl<'de> Deserialize<'de> for Fragment {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+      Text(String),
+      Interpolation((Expression,)),
+    }
+
+    Ok(match Repr::deserialize(deserializer)? {
+      Repr::Text(text) => Self::Text { text },
+      Repr::Interpolation((expression,)) => Self::Interpolation { expression },
+    })
+  }
+}
+
+/// An expression. Parenthesized groups are replaced by their contents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expression {
+  /// `assert(condition, error)`
+  Assert {
+    condition: Condition,
+    error: Box<Expression>,
+  },
+  /// `` `command` ``
+  Backtick { command: String },
+  /// `name(arguments)`
+  Call {
+    name: String,
+    arguments: Vec<Expression>,
+  },
+  /// `lhs + rhs`
+  Concatenation {
+    lhs: Box<Expression>,
+    rhs: Box<Expression>,
+  },
+  /// `if condition { then } else { otherwise }`
+  Conditional {
+    condition: Condition,
+    then: Box<Expression>,
+    otherwise: Box<Expression>,
+  },
+  /// `lhs / rhs`, or `/ rhs` if `lhs` is `None`
+  Join {
+    lhs: Option<Box<Expression>>,
+    rhs: Box<Expression>,
+  },
+  /// A string literal, with escape sequences processed
+  String { text: String },
+  /// A variable or parameter reference
+  Variable { name: String },
+}
+
+impl Expression {
+  const TAGS: &'static [&'static str] = &[
+    "assert",
+    "call",
+    "concatenate",
+    "evaluate",
+    "if",
+    "join",
+    "variable",
+  ];
+
+  fn new(expression: &full::Expression) -> Self {
+    use full::Expression::*;
+    match expression {
+      Assert { condition, error } => Self::Assert {
+        condition: Condition::new(condition),
+        error: Self::new(error).into(),
+      },
+      Backtick { contents, .. } => Self::Backtick {
+        command: contents.clone(),
+      },
+      Call { thunk, .. } => {
+        let (name, arguments) = match thunk {
+          full::Thunk::Nullary { name, .. } => (name, Vec::new()),
+          full::Thunk::Unary { name, arg, .. } => (name, vec![Self::new(arg)]),
+          full::Thunk::UnaryOpt {
+            name,
+            args: (a, opt_b),
+            ..
+          } => (
+            name,
+            std::iter::once(a.as_ref())
+              .chain(opt_b.as_ref())
+              .map(Self::new)
+              .collect(),
+          ),
+          full::Thunk::UnaryPlus {
+            name,
+            args: (a, rest),
+            ..
+          } => (
+            name,
+            std::iter::once(a.as_ref())
+              .chain(rest)
+              .map(Self::new)
+              .collect(),
+          ),
+          full::Thunk::Binary { name, args, .. } => {
+            (name, args.iter().map(|arg| Self::new(arg)).collect())
+          }
+          full::Thunk::BinaryPlus {
+            name,
+            args: (args, rest),
+            ..
+          } => (
+            name,
+            args
+              .iter()
+              .map(|arg| Self::new(arg))
+              .chain(rest.iter().map(Self::new))
+              .collect(),
+          ),
+          full::Thunk::Ternary { name, args, .. } => {
+            (name, args.iter().map(|arg| Self::new(arg)).collect())
+          }
+          full::Thunk::Quaternary { name, args, .. } => {
+            (name, args.iter().map(|arg| Self::new(arg)).collect())
+          }
+        };
+
+        Self::Call {
+          name: name.lexeme().to_owned(),
+          arguments,
+        }
+      }
+      Concatenation { lhs, rhs } => Self::Concatenation {
+        lhs: Self::new(lhs).into(),
+        rhs: Self::new(rhs).into(),
+      },
+      Conditional {
+        condition,
+        then,
+        otherwise,
+      } => Self::Conditional {
+        condition: Condition::new(condition),
+        then: Self::new(then).into(),
+        otherwise: Self::new(otherwise).into(),
+      },
+      Group { contents } => Self::new(contents),
+      Join { lhs, rhs } => Self::Join {
+        lhs: lhs.as_ref().map(|lhs| Self::new(lhs).into()),
+        rhs: Self::new(rhs).into(),
+      },
+      StringLiteral { string_literal } => Self::String {
+        text: string_literal.cooked.clone(),
+      },
+      Variable { name, .. } => Self::Variable {
+        name: name.lexeme().to_owned(),
+      },
+    }
+  }
+}
+
+impl Serialize for Expression {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    match self {
+      Self::Assert { condition, error } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("assert")?;
+        seq.serialize_element(condition)?;
+        seq.serialize_element(error)?;
+        seq.end()
+      }
+      Self::Backtick { command } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("evaluate")?;
+        seq.serialize_element(command)?;
+        seq.end()
+      }
+      Self::Call { name, arguments } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("call")?;
+        seq.serialize_element(name)?;
+        for argument in arguments {
+          seq.serialize_element(argument)?;
+        }
+        seq.end()
+      }
+      Self::Concatenation { lhs, rhs } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("concatenate")?;
+        seq.serialize_element(lhs)?;
+        seq.serialize_element(rhs)?;
+        seq.end()
+      }
+      Self::Conditional {
+        condition,
+        then,
+        otherwise,
+      } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("if")?;
+        seq.serialize_element(condition)?;
+        seq.serialize_element(then)?;
+        seq.serialize_element(otherwise)?;
+        seq.end()
+      }
+      Self::Join { lhs, rhs } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("join")?;
+        seq.serialize_element(lhs)?;
+        seq.serialize_element(rhs)?;
+        seq.end()
+      }
+      Self::String { text } => serializer.serialize_str(text),
+      Self::Variable { name } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("variable")?;
+        seq.serialize_element(name)?;
+        seq.end()
+      }
+    }
+  }
+}
+
+impl JsonSchema for Expression {
+  fn schema_name() -> String {
+    "Expression".into()
+  }
+
+  fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+    let condition = gen.subschema_for::<Condition>();
+    let expression = gen.subschema_for::<Self>();
+
+    let tagged = |description: &str, tag: &str, elements: &[&Schema]| {
+      json!({
+        "description": description,
+        "type": "array",
+        "prefixItems": std::iter::once(json!({ "const": tag }))
+          .chain(elements.iter().map(|element| json!(element)))
+          .collect::<Vec<serde_json::Value>>(),
+        "items": false,
+        "minItems": elements.len() + 1,
+      })
+    };
+
+    let mut call = tagged(
+      "Function call",
+      "call",
+      &[&from_json(json!({ "type": "string" }))],
+    );
+    call["items"] = json!(expression);
+
+    from_json(json!({
+      "description": "An expression",
+      "oneOf": [
+        { "description": "String literal", "type": "string" },
+        tagged("`assert(condition, error)`", "assert", &[&condition, &expression]),
+        call,
+        tagged("`lhs + rhs`", "concatenate", &[&expression, &expression]),
+        tagged("Backtick", "evaluate", &[&from_json(json!({ "type": "string" }))]),
+        tagged(
+          "`if condition { then } else { otherwise }`",
+          "if",
+          &[&condition, &expression, &expression],
+        ),
+        tagged(
+          "`lhs / rhs`, with `lhs` null for `/ rhs`",
+          "join",
+          &[&gen.subschema_for::<Option<Self>>(), &expression],
+        ),
+        tagged("Variable reference", "variable", &[&from_json(json!({ "type": "string" }))]),
+      ],
+    }))
+  }
+}
+
+impl<'de> Deserialize<'de> for Expression {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    struct ExpressionVisitor;
+
+    fn element<'de, A, T>(seq: &mut A, index: usize) -> Result<T, A::Error>
+    where
+      A: SeqAccess<'de>,
+      T: Deserialize<'de>,
+    {
+      seq
+        .next_element()?
+        .ok_or_else(|| de::Error::invalid_length(index, &"a complete expression"))
+    }
+
+    impl<'de> Visitor<'de> for ExpressionVisitor {
+      type Value = Expression;
+
+      fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a string or an array beginning with an expression tag")
+      }
+
+      fn visit_str<E>(self, text: &str) -> Result<Expression, E>
+      where
+        E: de::Error,
+      {
+        Ok(Expression::String { text: text.into() })
+      }
+
+      fn visit_seq<A>(self, mut seq: A) -> Result<Expression, A::Error>
+      where
+        A: SeqAccess<'de>,
+      {
+        let tag: String = element(&mut seq, 0)?;
+
+        let expression = match tag.as_str() {
+          "assert" => Expression::Assert {
+            condition: element(&mut seq, 1)?,
+            error: Box::new(element(&mut seq, 2)?),
+          },
+          "call" => {
+            let name = element(&mut seq, 1)?;
+            let mut arguments = Vec::new();
+            while let Some(argument) = seq.next_element()? {
+              arguments.push(argument);
+            }
+            Expression::Call { name, arguments }
+          }
+          "concatenate" => Expression::Concatenation {
+            lhs: Box::new(element(&mut seq, 1)?),
+            rhs: Box::new(element(&mut seq, 2)?),
+          },
+          "evaluate" => Expression::Backtick {
+            command: element(&mut seq, 1)?,
+          },
+          "if" => Expression::Conditional {
+            condition: element(&mut seq, 1)?,
+            then: Box::new(element(&mut seq, 2)?),
+            otherwise: Box::new(element(&mut seq, 3)?),
+          },
+          "join" => Expression::Join {
+            lhs: element::<A, Option<Expression>>(&mut seq, 1)?.map(Box::new),
+            rhs: Box::new(element(&mut seq, 2)?),
+          },
+          "variable" => Expression::Variable {
+            name: element(&mut seq, 1)?,
+          },
+          _ => return Err(de::Error::unknown_variant(&tag, Expression::TAGS)),
+        };
+
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+          return Err(de::Error::custom(format!(
+            "too many elements in `{tag}` expression"
+          )));
+        }
+
+        Ok(expression)
+      }
+    }
+
+    deserializer.deserialize_any(ExpressionVisitor)
+  }
+}
+
+/// A condition, e.g. `os() == 'linux'`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+  pub lhs: Box<Expression>,
+  pub rhs: Box<Expression>,
+  pub operator: ConditionalOperator,
+}
+
+impl Condition {
+  fn new(condition: &full::Condition) -> Self {
+    Self {
+      lhs: Expression::new(&condition.lhs).into(),
+      rhs: Expression::new(&condition.rhs).into(),
+      operator: ConditionalOperator::new(condition.operator),
+    }
+  }
+}
+
+impl Serialize for Condition {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    (&self.operator, &self.lhs, &self.rhs).serialize(serializer)
+  }
+}
+
+impl JsonSchema for Condition {
+  fn schema_name() -> String {
+    "Condition".into()
+  }
+
+  fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+    let expression = gen.subschema_for::<Expression>();
+
+    from_json(json!({
+      "description": "A condition, as `[operator, lhs, rhs]`",
+      "type": "array",
+      "prefixItems": [gen.subschema_for::<ConditionalOperator>(), expression, expression],
+      "items": false,
+      "minItems": 3,
+    }))
+  }
+}
+
+impl<'de> Deserialize<'de> for Condition {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let (operator, lhs, rhs) = Deserialize::deserialize(deserializer)?;
+    Ok(Self { lhs, rhs, operator })
+  }
+}
+
+/// A conditional operator
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ConditionalOperator {
+  /// `==`
+  #[serde(rename = "==")]
+  Equality,
+  /// `!=`
+  #[serde(rename = "!=")]
+  Inequality,
+  /// `=~`
+  #[serde(rename = "=~")]
+  RegexMatch,
+}
+
+impl ConditionalOperator {
+  fn new(operator: full::ConditionalOperator) -> Self {
+    match operator {
+      full::ConditionalOperator::Equality => Self::Equality,
+      full::ConditionalOperator::Inequality => Self::Inequality,
+      full::ConditionalOperator::RegexMatch => Self::RegexMatch,
+    }
+  }
+}
+
+/// Settings, e.g. `set export`
+#[derive(
+  Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct Settings {
+  /// Codes of warnings suppressed with `set allow`
+  pub allow: Vec<String>,
+  pub allow_duplicate_recipes: bool,
+  pub allow_duplicate_variables: bool,
+  /// Whether to prompt for missing recipe arguments, `error` or `prompt`
+  pub allow_missing_recipe_args: Option<String>,
+  /// Command used to select recipes with `--choose`
+  pub chooser: Option<String>,
+  /// Name of the recipe run when no recipe is given
+  pub default: Option<String>,
+  pub dotenv_filename: Option<String>,
+  pub dotenv_load: Option<bool>,
+  /// Whether to `warn` about or `error` on loading a dotenv file from outside
+  /// the justfile directory
+  pub dotenv_outside_project: Option<String>,
+  pub dotenv_override: bool,
+  /// Paths of dotenv files, loaded in order. Serialized as `null` if empty, a
+  /// string if there is one path, and an array otherwise.
+  #[serde(
+    serialize_with = "crate::settings::serialize_dotenv_path",
+    deserialize_with = "deserialize_dotenv_path"
+  )]
+  #[schemars(schema_with = "dotenv_path_schema")]
+  pub dotenv_path: Vec<PathBuf>,
+  /// Patterns of environment variables passed to commands, or `None` to pass
+  /// all
+  pub env_allow: Option<Vec<String>>,
+  /// Patterns of environment variables not passed to commands
+  pub env_deny: Vec<String>,
+  pub export: bool,
+  pub fallback: bool,
+  /// Maximum number of parent directories searched for a justfile to fall
+  /// back to
+  pub fallback_depth: Option<usize>,
+  pub ignore_comments: bool,
+  /// Whether `--list` orders recipes `alpha`betically or in `source` order
+  pub list_order: Option<String>,
+  pub normalize_recipe_names: bool,
+  /// Whether prior dependencies of recipes run concurrently
+  pub parallel: bool,
+  pub positional_arguments: bool,
+  pub prefix_match: bool,
+  pub quiet: bool,
+  pub shell: Option<Shell>,
+  /// Arguments appended to the shell's arguments
+  pub shell_args: Vec<String>,
+  /// Directory temporary directories are created in, either a string or the
+  /// source of an expression evaluated when a recipe needs a temporary
+  /// directory
+  pub tempdir: Option<String>,
+  /// Patterns matching names of variables whose values are redacted from
+  /// `--trace-evaluation` output
+  pub trace_redact: Vec<String>,
+  pub windows_powershell: bool,
+  pub windows_shell: Option<Shell>,
+}
+
+impl Settings {
+  pub(crate) fn new(settings: &full::Settings) -> Self {
+    Self {
+      allow: settings.allow.clone(),
+      allow_duplicate_recipes: settings.allow_duplicate_recipes,
+      allow_duplicate_variables: settings.allow_duplicate_variables,
+      allow_missing_recipe_args: settings
+        .allow_missing_recipe_args
+        .map(|value| value.to_string()),
+      chooser: settings.chooser.clone(),
+      default: settings.default.clone(),
+      dotenv_filename: settings.dotenv_filename.clone(),
+      dotenv_load: settings.dotenv_load,
+      dotenv_outside_project: settings
+        .dotenv_outside_project
+        .map(|value| value.to_string()),
+      dotenv_override: settings.dotenv_override,
+      dotenv_path: settings.dotenv_path.clone(),
+      env_allow: settings.env_allow.clone(),
+      env_deny: settings.env_deny.clone(),
+      export: settings.export,
+      fallback: settings.fallback,
+      fallback_depth: settings.fallback_depth,
+      ignore_comments: settings.ignore_comments,
+      list_order: settings.list_order.map(|value| value.to_string()),
+      normalize_recipe_names: settings.normalize_recipe_names,
+      parallel: settings.parallel,
+      positional_arguments: settings.positional_arguments,
+      prefix_match: settings.prefix_match,
+      quiet: settings.quiet,
+      shell: settings.shell.as_ref().map(Shell::new),
+      shell_args: settings.shell_args.clone(),
+      tempdir: settings.tempdir_source(),
+      trace_redact: settings.trace_redact.clone(),
+      windows_powershell: settings.windows_powershell,
+      windows_shell: settings.windows_shell.as_ref().map(Shell::new),
+    }
+  }
+}
+
+fn dotenv_path_schema(_: &mut SchemaGenerator) -> Schema {
+  from_json(json!({
+    "oneOf": [
+      { "type": ["string", "null"] },
+      { "type": "array", "items": { "type": "string" } },
+    ],
+  }))
+}
+
+fn deserialize_dotenv_path<'de, D: Deserializer<'de>>(
+  deserializer: D,
+) -> Result<Vec<PathBuf>, D::Error> {
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum DotenvPath {
+    One(PathBuf),
+    Many(Vec<PathBuf>),
+  }
+
+  Ok(match Option::<DotenvPath>::deserialize(deserializer)? {
+    None => Vec::new(),
+    Some(DotenvPath::One(path)) => vec![path],
+    Some(DotenvPath::Many(paths)) => paths,
+  })
+}
+
+/// A shell and its arguments, e.g. `['bash', '-c']`
+#[derive(
+  Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct Shell {
+  pub arguments: Vec<String>,
+  pub command: String,
+}
+
+impl Shell {
+  fn new(shell: &full::Shell) -> Self {
+    Self {
+      arguments: shell
+        .arguments
+        .iter()
+        .map(|argument| argument.cooked.clone())
+        .collect(),
+      command: shell.command.cooked.clone(),
+    }
+  }
+}
+
+/// A non-fatal warning encountered during compilation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Warning {
+  /// Code which may be passed to `set allow` or `[allow(...)]`
+  pub code: String,
+  pub message: String,
+}
+
+impl Warning {
+  fn new(warning: &full::Warning) -> Self {
+    Self {
+      code: warning.code().name().into(),
+      message: warning.color_display(Color::never()).to_string(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    crate::{testing, unindent},
+  };
+
+  #[track_caller]
+  fn case(src: &str) {
+    let src = unindent(src);
+    let justfile = testing::compile(&src);
+    let dumped = serde_json::to_value(&justfile).unwrap();
+    let deserialized = serde_json::from_value::<Justfile>(dumped.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&deserialized).unwrap(), dumped);
+    assert_eq!(Justfile::new(&justfile), deserialized);
+  }
+
+  #[test]
+  fn empty() {
+    case("");
+  }
+
+  #[test]
+  fn settings() {
+    case(
+      "
+        set allow-duplicate-recipes
+        set dotenv-filename := 'foo'
+        set dotenv-load
+        set dotenv-path := 'bar'
+        set export
+        set shell := ['bash', '-c']
+        set tempdir := '/tmp'
+        set windows-shell := ['powershell.exe', '-c']
+      ",
+    );
+  }
+
+  #[test]
+  fn expressions() {
+    case(
+      "
+        a := 'a' + `echo b`
+        b := if a == 'a' { 'x' } else if a =~ '.*' { / 'y' } else { a / 'z' }
+        c := assert(a != 'b', 'error')
+        d := env_var_or_default('FOO', 'bar') + uuid() + join('a', 'b', 'c')
+        export e := (a)
+      ",
+    );
+  }
+
+  #[test]
+  fn recipes() {
+    case(
+      "
+        alias f := foo
+
+        # a recipe
+        [confirm('really?')]
+        [no-cd]
+        [private]
+        foo x $y='y' +z='z': (bar x) bar
+          echo {{x}} {{ y + z }}
+
+        [linux]
+        @bar *args:
+          #!/bin/sh
+          echo {{args}}
+      ",
+    );
+  }
+
+  #[test]
+  fn compile_source() {
+    let justfile = compile("set export\n\nfoo x='y':\n  echo {{x}}\n").unwrap();
+    assert_eq!(justfile.first.as_deref(), Some("foo"));
+    assert!(justfile.settings.export);
+    assert_eq!(
+      justfile.recipes["foo"].parameters,
+      vec![Parameter {
+        default: Some(Expression::String { text: "y".into() }),
+        export: false,
+        export_name: None,
+        kind: ParameterKind::Singular,
+        name: "x".into(),
+        pattern: None,
+        secret: false,
+      }],
+    );
+  }
+
+  #[test]
+  fn compile_error() {
+    assert_eq!(
+      compile("foo:\nfoo:\n").unwrap_err(),
+      Error {
+        message: "Recipe `foo` first defined on line 1 is redefined on line 2".into(),
+        path: Some("justfile".into()),
+        line: Some(2),
+        column: Some(1),
+      },
+    );
+  }
+
+  #[test]
+  fn load_missing_file() {
+    let tempdir = testing::tempdir();
+    let error = load(&tempdir.path().join("justfile")).unwrap_err();
+    assert!(error.message.starts_with("Failed to read justfile at"));
+    assert_eq!(error.path, None);
+  }
+
+  #[test]
+  fn unknown_fields_are_rejected() {
+    let mut dumped = serde_json::to_value(testing::compile("foo:")).unwrap();
+    dumped["recipes"]["foo"]["bogus"] = true.into();
+    assert!(serde_json::from_value::<Justfile>(dumped).is_err());
+  }
+
+  #[test]
+  fn malformed_expressions_are_rejected() {
+    for json in [
+      r#"["bogus"]"#,
+      r#"["variable"]"#,
+      r#"["variable", "a", "b"]"#,
+      r#"["concatenate", "a"]"#,
+      "1",
+    ] {
+      assert!(
+        serde_json::from_str::<Expression>(json).is_err(),
+        "{json} should not deserialize"
+      );
+    }
+  }
+}
@@ -1,5 +1,6 @@
 #[derive(Debug, PartialEq)]
 pub(crate) enum DumpFormat {
   Json,
+  JsonLossless,
   Just,
 }
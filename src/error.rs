@@ -6,6 +6,14 @@ pub(crate) enum Error<'src> {
     module: Name<'src>,
     found: Vec<String>,
   },
+  AmbiguousRecipeName {
+    name: String,
+    candidates: Vec<&'src str>,
+  },
+  AmbiguousRecipePrefix {
+    prefix: String,
+    candidates: Vec<&'src str>,
+  },
   ArgumentCountMismatch {
     recipe: &'src str,
     parameters: Vec<Parameter<'src>>,
@@ -13,6 +21,11 @@ pub(crate) enum Error<'src> {
     min: usize,
     max: usize,
   },
+  ArgumentPattern {
+    parameter: Name<'src>,
+    argument: String,
+    pattern: String,
+  },
   Assert {
     message: String,
   },
@@ -72,9 +85,17 @@ pub(crate) enum Error<'src> {
     recipe: &'src str,
     min_arguments: usize,
   },
+  Directive {
+    recipe: &'src str,
+    line_number: usize,
+    message: String,
+  },
   Dotenv {
     dotenv_error: dotenvy::Error,
   },
+  DotenvOutsideProject {
+    path: PathBuf,
+  },
   DumpJson {
     serde_json_error: serde_json::Error,
   },
@@ -92,9 +113,13 @@ pub(crate) enum Error<'src> {
   },
   FormatCheckFoundDiff,
   FunctionCall {
+    call: Token<'src>,
     function: Name<'src>,
     message: String,
   },
+  GetArgument {
+    io_error: io::Error,
+  },
   GetConfirmation {
     io_error: io::Error,
   },
@@ -105,6 +130,11 @@ pub(crate) enum Error<'src> {
   Internal {
     message: String,
   },
+  InvalidOverride {
+    variable: Name<'src>,
+    value: String,
+    choices: Vec<String>,
+  },
   Io {
     recipe: &'src str,
     io_error: io::Error,
@@ -121,10 +151,21 @@ pub(crate) enum Error<'src> {
   },
   NoChoosableRecipes,
   NoDefaultRecipe,
+  NoMatchingChoosableRecipes {
+    patterns: Vec<String>,
+  },
   NoRecipes,
   NotConfirmed {
     recipe: &'src str,
   },
+  Open {
+    recipe: &'src str,
+    opener: &'static str,
+    io_error: io::Error,
+  },
+  ParallelDependency {
+    recipe: &'src str,
+  },
   RegexCompile {
     source: regex::Error,
   },
@@ -152,6 +193,10 @@ pub(crate) enum Error<'src> {
   TempfileIo {
     io_error: io::Error,
   },
+  TranscriptWrite {
+    path: PathBuf,
+    io_error: io::Error,
+  },
   Unknown {
     recipe: &'src str,
     line_number: Option<usize>,
@@ -159,6 +204,10 @@ pub(crate) enum Error<'src> {
   UnknownOverrides {
     overrides: Vec<String>,
   },
+  UnknownParameter {
+    recipe: String,
+    parameter: String,
+  },
   UnknownRecipes {
     recipes: Vec<String>,
     suggestion: Option<Suggestion<'src>>,
@@ -166,6 +215,9 @@ pub(crate) enum Error<'src> {
   Unstable {
     message: String,
   },
+  Warning {
+    warning: Warning,
+  },
   WriteJustfile {
     justfile: PathBuf,
     io_error: io::Error,
@@ -185,14 +237,35 @@ impl<'src> Error<'src> {
     }
   }
 
-  fn context(&self) -> Option<Token<'src>> {
+  pub(crate) fn command_failed(&self) -> bool {
+    matches!(
+      self,
+      Self::Assert { .. }
+        | Self::Backtick {
+          output_error: OutputError::Code(_) | OutputError::Signal(_) | OutputError::Unknown,
+          ..
+        }
+        | Self::ChooserStatus { .. }
+        | Self::Code { .. }
+        | Self::CommandStatus { .. }
+        | Self::Directive { .. }
+        | Self::EditorStatus { .. }
+        | Self::FormatCheckFoundDiff
+        | Self::Signal { .. }
+        | Self::Unknown { .. }
+    )
+  }
+
+  pub(crate) fn context(&self) -> Option<Token<'src>> {
     match self {
       Self::AmbiguousModuleFile { module, .. } | Self::MissingModuleFile { module, .. } => {
         Some(module.token)
       }
+      Self::ArgumentPattern { parameter, .. } => Some(parameter.token),
       Self::Backtick { token, .. } => Some(*token),
       Self::Compile { compile_error } => Some(compile_error.context()),
-      Self::FunctionCall { function, .. } => Some(function.token),
+      Self::FunctionCall { call, .. } => Some(*call),
+      Self::InvalidOverride { variable, .. } => Some(variable.token),
       Self::MissingImportFile { path } => Some(*path),
       _ => None,
     }
@@ -253,6 +326,16 @@ impl<'src> ColorDisplay for Error<'src> {
           "Found multiple source files for module `{module}`: {}",
           List::and_ticked(found),
         )?,
+      AmbiguousRecipeName { name, candidates } =>
+        write!(f,
+          "Recipe name `{name}` is ambiguous, could be {}",
+          List::or_ticked(candidates),
+        )?,
+      AmbiguousRecipePrefix { prefix, candidates } =>
+        write!(f,
+          "Recipe prefix `{prefix}` is ambiguous, could be {}",
+          List::or_ticked(candidates),
+        )?,
       ArgumentCountMismatch { recipe, found, min, max, .. } => {
         let count = Count("argument", *found);
         if min == max {
@@ -265,6 +348,9 @@ impl<'src> ColorDisplay for Error<'src> {
           write!(f, "Recipe `{recipe}` got {found} {count} but takes at most {max}")?;
         }
       }
+      ArgumentPattern { parameter, argument, pattern } => {
+        write!(f, "Argument `{argument}` for parameter `{parameter}` does not match pattern `{pattern}`")?;
+      }
       Assert { message }=> {
         write!(f, "Assert failed: {message}")?;
       }
@@ -334,9 +420,19 @@ impl<'src> ColorDisplay for Error<'src> {
         let count = Count("argument", *min_arguments);
         write!(f, "Recipe `{recipe}` cannot be used as default recipe since it requires at least {min_arguments} {count}.")?;
       }
+      Directive { recipe, line_number, message } => {
+        write!(f, "Recipe `{recipe}` failed on line {line_number}: {message}")?;
+      }
       Dotenv { dotenv_error } => {
         write!(f, "Failed to load environment file: {dotenv_error}")?;
       }
+      DotenvOutsideProject { path } => {
+        write!(
+          f,
+          "Refusing to load environment file `{}` from outside the justfile directory",
+          path.display(),
+        )?;
+      }
       DumpJson { serde_json_error } => {
         write!(f, "Failed to dump JSON to stdout: {serde_json_error}")?;
       }
@@ -357,10 +453,15 @@ impl<'src> ColorDisplay for Error<'src> {
       FormatCheckFoundDiff => {
         write!(f, "Formatted justfile differs from original.")?;
       }
-      FunctionCall { function, message } => {
+      FunctionCall {
+        function, message, ..
+      } => {
         let function = function.lexeme();
         write!(f, "Call to function `{function}` failed: {message}")?;
       }
+      GetArgument { io_error } => {
+        write!(f, "Failed to read argument from stdin: {io_error}")?;
+      }
       GetConfirmation { io_error } => {
         write!(f, "Failed to read confirmation from stdin: {io_error}")?;
       }
@@ -374,6 +475,9 @@ impl<'src> ColorDisplay for Error<'src> {
         write!(f, "Internal runtime error, this may indicate a bug in just: {message} \
                    consider filing an issue: https://github.com/casey/just/issues/new")?;
       }
+      InvalidOverride { variable, value, choices } => {
+        write!(f, "Value `{value}` for variable `{variable}` must be {}", List::or_ticked(choices))?;
+      }
       Io { recipe, io_error } => {
         match io_error.kind() {
           io::ErrorKind::NotFound => write!(f, "Recipe `{recipe}` could not be run because just could not find the shell: {io_error}"),
@@ -389,10 +493,19 @@ impl<'src> ColorDisplay for Error<'src> {
       MissingModuleFile { module } => write!(f, "Could not find source file for module `{module}`.")?,
       NoChoosableRecipes => write!(f, "Justfile contains no choosable recipes.")?,
       NoDefaultRecipe => write!(f, "Justfile contains no default recipe.")?,
+      NoMatchingChoosableRecipes { patterns } => {
+        write!(f, "No choosable recipes match {}.", List::or_ticked(patterns))?;
+      }
       NoRecipes => write!(f, "Justfile contains no recipes.")?,
       NotConfirmed { recipe } => {
         write!(f, "Recipe `{recipe}` was not confirmed")?;
       }
+      Open { recipe, opener, io_error } => {
+        write!(f, "Recipe `{recipe}` could not open target because `{opener}` could not be run: {io_error}")?;
+      }
+      ParallelDependency { recipe } => {
+        write!(f, "Recipe `{recipe}` failed while running as a parallel dependency")?;
+      }
       RegexCompile { source } => write!(f, "{source}")?,
       Search { search_error } => Display::fmt(search_error, f)?,
       Shebang { recipe, command, argument, io_error} => {
@@ -419,6 +532,10 @@ impl<'src> ColorDisplay for Error<'src> {
       TempfileIo { io_error } => {
         write!(f, "Tempfile I/O error: {io_error}")?;
       }
+      TranscriptWrite { path, io_error } => {
+        let path = path.display();
+        write!(f, "Failed to write transcript to `{path}`: {io_error}")?;
+      }
       Unknown { recipe, line_number} => {
         if let Some(n) = line_number {
           write!(f, "Recipe `{recipe}` failed on line {n} for an unknown reason")?;
@@ -431,6 +548,9 @@ impl<'src> ColorDisplay for Error<'src> {
         let overrides = List::and_ticked(overrides);
         write!(f, "{count} {overrides} overridden on the command line but not present in justfile")?;
       }
+      UnknownParameter { recipe, parameter } => {
+        write!(f, "Recipe `{recipe}` does not have parameter `{parameter}`")?;
+      }
       UnknownRecipes { recipes, suggestion } => {
         let count = Count("recipe", recipes.len());
         let recipes = List::or_ticked(recipes);
@@ -442,6 +562,11 @@ impl<'src> ColorDisplay for Error<'src> {
       Unstable { message } => {
         write!(f, "{message} Invoke `just` with the `--unstable` flag to enable unstable features.")?;
       }
+      Warning { warning } => {
+        let code = warning.code().name();
+        let message = warning.message();
+        write!(f, "Warning `{code}` denied by `--warn-as-error`: {message}")?;
+      }
       WriteJustfile { justfile, io_error } => {
         let justfile = justfile.display();
         write!(f, "Failed to write justfile to `{justfile}`: {io_error}")?;
@@ -466,6 +591,22 @@ impl<'src> ColorDisplay for Error<'src> {
       write!(f, "{}", token.color_display(color.error()))?;
     }
 
+    if let Compile { compile_error } = self {
+      if let Some(related) = compile_error.foreign_related() {
+        writeln!(f)?;
+        write!(f, "{}", related.color_display(color.context()))?;
+      }
+
+      for edge in compile_error
+        .cycle
+        .iter()
+        .filter(|edge| **edge != compile_error.token)
+      {
+        writeln!(f)?;
+        write!(f, "{}", edge.color_display(color.context()))?;
+      }
+    }
+
     Ok(())
   }
 }
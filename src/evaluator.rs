@@ -4,6 +4,8 @@ pub(crate) struct Evaluator<'src: 'run, 'run> {
   assignments: Option<&'run Table<'src, Assignment<'src>>>,
   config: &'run Config,
   dotenv: &'run BTreeMap<String, String>,
+  modules: &'run [Name<'src>],
+  redacted: bool,
   scope: Scope<'src, 'run>,
   settings: &'run Settings<'run>,
   search: &'run Search,
@@ -23,6 +25,8 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       assignments: Some(assignments),
       config,
       dotenv,
+      modules: &[],
+      redacted: false,
       settings,
       search,
     };
@@ -38,8 +42,22 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     let name = assignment.name.lexeme();
 
     if !self.scope.bound(name) {
-      let value = self.evaluate_expression(&assignment.value)?;
-      self.scope.bind(assignment.export, assignment.name, value);
+      // Everything evaluated on the way to a redacted value is redacted too
+      let redacted = self.redacted;
+      self.redacted |= assignment.secret || self.settings.trace_redacted(name);
+      let value = self.evaluate_expression(&assignment.value);
+      self.redacted = redacted;
+      let value = value?;
+
+      if assignment.secret
+        && !value.is_empty()
+        && !Scope::maskable(&value)
+        && !self.settings.allows(WarningCode::ShortSecret)
+      {
+        Warning::ShortSecret { name: name.into() }.report(self.config, self.config.verbosity)?;
+      }
+
+      self.scope.bind(assignment, value);
     }
 
     Ok(self.scope.value(name).unwrap())
@@ -52,31 +70,40 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     match expression {
       Expression::Variable { name, .. } => {
         let variable = name.lexeme();
-        if let Some(value) = self.scope.value(variable) {
-          Ok(value.to_owned())
+        let value = if let Some(value) = self.scope.value(variable) {
+          value.to_owned()
         } else if let Some(assignment) = self
           .assignments
           .and_then(|assignments| assignments.get(variable))
         {
-          Ok(self.evaluate_assignment(assignment)?.to_owned())
+          self.evaluate_assignment(assignment)?.to_owned()
         } else {
-          Err(Error::Internal {
+          return Err(Error::Internal {
             message: format!("attempted to evaluate undefined variable `{variable}`"),
-          })
-        }
+          });
+        };
+        self.trace(
+          "variable",
+          variable,
+          &value,
+          self.scope.secret(variable) || self.settings.trace_redacted(variable),
+        );
+        Ok(value)
       }
-      Expression::Call { thunk } => {
+      Expression::Call { thunk, span } => {
         use Thunk::*;
 
         let context = FunctionContext {
+          config: self.config,
           dotenv: self.dotenv,
-          invocation_directory: &self.config.invocation_directory,
+          modules: self.modules,
           search: self.search,
         };
 
-        match thunk {
+        let value = match thunk {
           Nullary { name, function, .. } => {
             function(&context).map_err(|message| Error::FunctionCall {
+              call: *span,
               function: *name,
               message,
             })
@@ -88,6 +115,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             ..
           } => function(&context, &self.evaluate_expression(arg)?).map_err(|message| {
             Error::FunctionCall {
+              call: *span,
               function: *name,
               message,
             }
@@ -105,6 +133,26 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             };
 
             function(&context, &a, b.as_deref()).map_err(|message| Error::FunctionCall {
+              call: *span,
+              function: *name,
+              message,
+            })
+          }
+          UnaryPlus {
+            name,
+            function,
+            args: (a, rest),
+            ..
+          } => {
+            let a = self.evaluate_expression(a)?;
+
+            let mut rest_evaluated = Vec::new();
+            for arg in rest {
+              rest_evaluated.push(self.evaluate_expression(arg)?);
+            }
+
+            function(&context, &a, &rest_evaluated).map_err(|message| Error::FunctionCall {
+              call: *span,
               function: *name,
               message,
             })
@@ -120,6 +168,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             &self.evaluate_expression(b)?,
           )
           .map_err(|message| Error::FunctionCall {
+            call: *span,
             function: *name,
             message,
           }),
@@ -138,6 +187,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             }
 
             function(&context, &a, &b, &rest_evaluated).map_err(|message| Error::FunctionCall {
+              call: *span,
               function: *name,
               message,
             })
@@ -154,17 +204,39 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             &self.evaluate_expression(c)?,
           )
           .map_err(|message| Error::FunctionCall {
+            call: *span,
             function: *name,
             message,
           }),
-        }
+          Quaternary {
+            name,
+            function,
+            args: [a, b, c, d],
+            ..
+          } => function(
+            &context,
+            &self.evaluate_expression(a)?,
+            &self.evaluate_expression(b)?,
+            &self.evaluate_expression(c)?,
+            &self.evaluate_expression(d)?,
+          )
+          .map_err(|message| Error::FunctionCall {
+            call: *span,
+            function: *name,
+            message,
+          }),
+        }?;
+        self.trace("call", span.lexeme(), &value, false);
+        Ok(value)
       }
       Expression::StringLiteral { string_literal } => Ok(string_literal.cooked.clone()),
       Expression::Backtick { contents, token } => {
         if self.config.dry_run {
           Ok(format!("`{contents}`"))
         } else {
-          Ok(self.run_backtick(contents, token)?)
+          let value = self.run_backtick(contents, token)?;
+          self.trace("backtick", contents, &value, false);
+          Ok(value)
         }
       }
       Expression::Concatenation { lhs, rhs } => {
@@ -199,7 +271,10 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     }
   }
 
-  fn evaluate_condition(&mut self, condition: &Condition<'src>) -> RunResult<'src, bool> {
+  pub(crate) fn evaluate_condition(
+    &mut self,
+    condition: &Condition<'src>,
+  ) -> RunResult<'src, bool> {
     let lhs_value = self.evaluate_expression(&condition.lhs)?;
     let rhs_value = self.evaluate_expression(&condition.rhs)?;
     let condition = match condition.operator {
@@ -212,6 +287,28 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     Ok(condition)
   }
 
+  /// Print an evaluated variable, function call, or backtick and its value
+  /// if `--trace-evaluation` was passed
+  fn trace(&self, kind: &str, expression: &str, value: &str, redacted: bool) {
+    if !self.config.trace_evaluation {
+      return;
+    }
+
+    let color = self.config.color.stderr();
+
+    let value = if redacted || self.redacted {
+      "<redacted>".into()
+    } else {
+      format!("{:?}", self.scope.mask(value))
+    };
+
+    eprintln!(
+      "{} {kind} `{expression}` = {}",
+      color.context().paint("trace:"),
+      color.string().paint(&value),
+    );
+  }
+
   fn run_backtick(&self, raw: &str, token: &Token<'src>) -> RunResult<'src, String> {
     let mut cmd = self.settings.shell_command(self.config);
 
@@ -219,7 +316,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
 
     cmd.current_dir(&self.search.working_directory);
 
-    cmd.export(self.settings, self.dotenv, &self.scope);
+    cmd.export(self.config, self.settings, self.dotenv, &self.scope);
 
     cmd.stdin(Stdio::inherit());
 
@@ -265,8 +362,11 @@ impl<'src, 'run> Evaluator<'src, 'run> {
   pub(crate) fn evaluate_parameters(
     config: &'run Config,
     dotenv: &'run BTreeMap<String, String>,
+    modules: &'run [Name<'src>],
     parameters: &[Parameter<'src>],
     arguments: &[String],
+    passthrough: &[String],
+    overrides: Option<&BTreeMap<String, String>>,
     scope: &'run Scope<'src, 'run>,
     settings: &'run Settings,
     search: &'run Search,
@@ -278,6 +378,8 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       settings,
       dotenv,
       config,
+      modules,
+      redacted: false,
     };
 
     let mut scope = scope.child();
@@ -286,12 +388,35 @@ impl<'src, 'run> Evaluator<'src, 'run> {
 
     let mut rest = arguments;
     for parameter in parameters {
-      let value = if rest.is_empty() {
+      let value = if let Some(value) =
+        overrides.and_then(|overrides| overrides.get(parameter.name.lexeme()))
+      {
+        // Values from `--dependency-argument` replace the argument, if any,
+        // that would otherwise be bound to the parameter
+        parameter.check_argument(value)?;
+        positional.push(value.clone());
+        if parameter.kind.is_variadic() {
+          rest = &[];
+        } else if parameter.kind == ParameterKind::Singular && !rest.is_empty() {
+          rest = &rest[1..];
+        }
+        value.clone()
+      } else if parameter.kind == ParameterKind::Passthrough && !passthrough.is_empty() {
+        for value in passthrough {
+          parameter.check_argument(value)?;
+        }
+        positional.extend(passthrough.iter().cloned());
+        passthrough.join(" ")
+      } else if rest.is_empty() || parameter.kind == ParameterKind::Passthrough {
         if let Some(ref default) = parameter.default {
+          evaluator.redacted = parameter.secret;
           let value = evaluator.evaluate_expression(default)?;
           positional.push(value.clone());
           value
-        } else if parameter.kind == ParameterKind::Star {
+        } else if matches!(
+          parameter.kind,
+          ParameterKind::Star | ParameterKind::Passthrough
+        ) {
           String::new()
         } else {
           return Err(Error::Internal {
@@ -300,6 +425,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         }
       } else if parameter.kind.is_variadic() {
         for value in rest {
+          parameter.check_argument(value)?;
           positional.push(value.clone());
         }
         let value = rest.to_vec().join(" ");
@@ -307,11 +433,12 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         value
       } else {
         let value = rest[0].clone();
+        parameter.check_argument(&value)?;
         positional.push(value.clone());
         rest = &rest[1..];
         value
       };
-      scope.bind(parameter.export, parameter.name, value);
+      scope.bind_parameter(parameter, value);
     }
 
     Ok((scope, positional))
@@ -320,6 +447,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
   pub(crate) fn recipe_evaluator(
     config: &'run Config,
     dotenv: &'run BTreeMap<String, String>,
+    modules: &'run [Name<'src>],
     scope: &'run Scope<'src, 'run>,
     settings: &'run Settings,
     search: &'run Search,
@@ -331,6 +459,8 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       settings,
       dotenv,
       config,
+      modules,
+      redacted: false,
     }
   }
 }
@@ -0,0 +1,36 @@
+use std::{collections::BTreeMap, process::ExitStatus};
+
+/// Callbacks invoked while recipes run, for progress displays and telemetry.
+///
+/// Pass an implementation to `Runner::run_with`. All methods do nothing by
+/// default. Recipes are identified by their path from the root justfile, for
+/// example `foo` or `bar::baz`.
+///
+/// Lines are identified by their one-based line number in the justfile. The
+/// body of a shebang recipe is run as a single script, for which the line is
+/// `None` and the command is the script's shebang line.
+///
+/// Prior dependencies of recipes with the `[parallel]` attribute, or in
+/// justfiles with `set parallel`, run on separate threads, so callbacks for
+/// different recipes may be invoked concurrently.
+pub trait Events: Sync {
+  /// Recipe `recipe` is about to run, after its prior dependencies
+  fn recipe_started(&self, _recipe: &str) {}
+
+  /// Recipe `recipe` will run with `variables` set in its environment, from
+  /// dotenv files and exported variables and parameters, in addition to the
+  /// environment `just` was run with
+  fn recipe_environment(&self, _recipe: &str, _variables: &BTreeMap<String, String>) {}
+
+  /// `command`, on line `line` of `recipe`, is about to be spawned
+  fn line_spawned(&self, _recipe: &str, _line: Option<usize>, _command: &str) {}
+
+  /// Line `line` of `recipe` exited with `status`
+  fn line_finished(&self, _recipe: &str, _line: Option<usize>, _status: ExitStatus) {}
+
+  /// Recipe `recipe` finished running. `success` is false if it failed.
+  fn recipe_finished(&self, _recipe: &str, _success: bool) {}
+}
+
+/// Ignore all events
+impl Events for () {}
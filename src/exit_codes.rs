@@ -0,0 +1,26 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum ExitCodes {
+  Categorized,
+  Normalized,
+  Passthrough,
+}
+
+impl ExitCodes {
+  const JUST_FAILED: i32 = 2;
+
+  pub(crate) fn code(self, error: &Error) -> i32 {
+    match self {
+      Self::Categorized => {
+        if error.command_failed() {
+          EXIT_FAILURE
+        } else {
+          Self::JUST_FAILED
+        }
+      }
+      Self::Normalized => EXIT_FAILURE,
+      Self::Passthrough => error.code().unwrap_or(EXIT_FAILURE),
+    }
+  }
+}
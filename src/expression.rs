@@ -18,8 +18,11 @@ pub(crate) enum Expression<'src> {
     contents: String,
     token: Token<'src>,
   },
-  /// `name(arguments)`
-  Call { thunk: Thunk<'src> },
+  /// `name(arguments)`, with `span` covering the whole call
+  Call {
+    thunk: Thunk<'src>,
+    span: Token<'src>,
+  },
   /// `lhs + rhs`
   Concatenation {
     lhs: Box<Expression<'src>>,
@@ -68,7 +71,7 @@ impl<'src> Display for Expression<'src> {
       } => write!(f, "if {condition} {{ {then} }} else {{ {otherwise} }}"),
       Self::StringLiteral { string_literal } => write!(f, "{string_literal}"),
       Self::Variable { name } => write!(f, "{}", name.lexeme()),
-      Self::Call { thunk } => write!(f, "{thunk}"),
+      Self::Call { thunk, .. } => write!(f, "{thunk}"),
       Self::Group { contents } => write!(f, "({contents})"),
     }
   }
@@ -93,7 +96,7 @@ impl<'src> Serialize for Expression<'src> {
         seq.serialize_element(contents)?;
         seq.end()
       }
-      Self::Call { thunk } => thunk.serialize(serializer),
+      Self::Call { thunk, .. } => thunk.serialize(serializer),
       Self::Concatenation { lhs, rhs } => {
         let mut seq = serializer.serialize_seq(None)?;
         seq.serialize_element("concatenate")?;
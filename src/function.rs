@@ -12,14 +12,93 @@ pub(crate) enum Function {
   Nullary(fn(&FunctionContext) -> Result<String, String>),
   Unary(fn(&FunctionContext, &str) -> Result<String, String>),
   UnaryOpt(fn(&FunctionContext, &str, Option<&str>) -> Result<String, String>),
+  UnaryPlus(fn(&FunctionContext, &str, &[String]) -> Result<String, String>),
   Binary(fn(&FunctionContext, &str, &str) -> Result<String, String>),
   BinaryPlus(fn(&FunctionContext, &str, &str, &[String]) -> Result<String, String>),
   Ternary(fn(&FunctionContext, &str, &str, &str) -> Result<String, String>),
-}
+  Quaternary(fn(&FunctionContext, &str, &str, &str, &str) -> Result<String, String>),
+}
+
+pub(crate) const NAMES: &[&str] = &[
+  "absolute_path",
+  "absolute_path_invocation",
+  "arch",
+  "blake3",
+  "blake3_file",
+  "canonicalize",
+  "cache_directory",
+  "capitalize",
+  "clean",
+  "config_directory",
+  "config_local_directory",
+  "data_directory",
+  "data_local_directory",
+  "env",
+  "env_flag",
+  "env_var",
+  "env_var_or_default",
+  "error",
+  "executable_directory",
+  "extension",
+  "file_name",
+  "file_stem",
+  "home_directory",
+  "humanize_bytes",
+  "humanize_duration",
+  "invocation_directory",
+  "invocation_directory_native",
+  "is_dry_run",
+  "join",
+  "json_encode",
+  "json_object",
+  "just_executable",
+  "just_pid",
+  "justfile",
+  "justfile_directory",
+  "kebabcase",
+  "line",
+  "line_count",
+  "lowercamelcase",
+  "lowercase",
+  "lowercase_first",
+  "num_cpus",
+  "os",
+  "os_family",
+  "parent_directory",
+  "path_exists",
+  "quote",
+  "replace",
+  "replace_first",
+  "replace_n",
+  "replace_regex",
+  "run",
+  "semver_matches",
+  "sha256",
+  "sha256_file",
+  "shell_expand",
+  "shoutykebabcase",
+  "shoutysnakecase",
+  "snakecase",
+  "titlecase",
+  "trim",
+  "trim_end",
+  "trim_end_match",
+  "trim_end_matches",
+  "trim_start",
+  "trim_start_match",
+  "trim_start_matches",
+  "uppercamelcase",
+  "uppercase",
+  "uppercase_first",
+  "uuid",
+  "uuid_v5",
+  "without_extension",
+];
 
 pub(crate) fn get(name: &str) -> Option<Function> {
   let function = match name {
     "absolute_path" => Unary(absolute_path),
+    "absolute_path_invocation" => Unary(absolute_path_invocation),
     "arch" => Nullary(arch),
     "blake3" => Unary(blake3),
     "blake3_file" => Unary(blake3_file),
@@ -32,6 +111,7 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "data_directory" => Nullary(|_| dir("data", dirs::data_dir)),
     "data_local_directory" => Nullary(|_| dir("local data", dirs::data_local_dir)),
     "env" => UnaryOpt(env),
+    "env_flag" => Unary(env_flag),
     "env_var" => Unary(env_var),
     "env_var_or_default" => Binary(env_var_or_default),
     "error" => Unary(error),
@@ -40,16 +120,24 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "file_name" => Unary(file_name),
     "file_stem" => Unary(file_stem),
     "home_directory" => Nullary(|_| dir("home", dirs::home_dir)),
+    "humanize_bytes" => Unary(humanize_bytes),
+    "humanize_duration" => Unary(humanize_duration),
     "invocation_directory" => Nullary(invocation_directory),
     "invocation_directory_native" => Nullary(invocation_directory_native),
+    "is_dry_run" => Nullary(is_dry_run),
     "join" => BinaryPlus(join),
+    "json_encode" => Unary(json_encode),
+    "json_object" => BinaryPlus(json_object),
     "just_executable" => Nullary(just_executable),
     "just_pid" => Nullary(just_pid),
     "justfile" => Nullary(justfile),
     "justfile_directory" => Nullary(justfile_directory),
     "kebabcase" => Unary(kebabcase),
+    "line" => Binary(line),
+    "line_count" => Unary(line_count),
     "lowercamelcase" => Unary(lowercamelcase),
     "lowercase" => Unary(lowercase),
+    "lowercase_first" => Unary(lowercase_first),
     "num_cpus" => Nullary(num_cpus),
     "os" => Nullary(os),
     "os_family" => Nullary(os_family),
@@ -57,10 +145,14 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "path_exists" => Unary(path_exists),
     "quote" => Unary(quote),
     "replace" => Ternary(replace),
+    "replace_first" => Ternary(replace_first),
+    "replace_n" => Quaternary(replace_n),
     "replace_regex" => Ternary(replace_regex),
+    "run" => UnaryPlus(run),
     "semver_matches" => Binary(semver_matches),
     "sha256" => Unary(sha256),
     "sha256_file" => Unary(sha256_file),
+    "shell_expand" => Unary(shell_expand),
     "shoutykebabcase" => Unary(shoutykebabcase),
     "shoutysnakecase" => Unary(shoutysnakecase),
     "snakecase" => Unary(snakecase),
@@ -74,7 +166,9 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "trim_start_matches" => Binary(trim_start_matches),
     "uppercamelcase" => Unary(uppercamelcase),
     "uppercase" => Unary(uppercase),
+    "uppercase_first" => Unary(uppercase_first),
     "uuid" => Nullary(uuid),
+    "uuid_v5" => Binary(uuid_v5),
     "without_extension" => Unary(without_extension),
     _ => return None,
   };
@@ -87,9 +181,11 @@ impl Function {
       Nullary(_) => 0..0,
       Unary(_) => 1..1,
       UnaryOpt(_) => 1..2,
+      UnaryPlus(_) => 1..usize::MAX,
       Binary(_) => 2..2,
       BinaryPlus(_) => 2..usize::MAX,
       Ternary(_) => 3..3,
+      Quaternary(_) => 4..4,
     }
   }
 }
@@ -105,6 +201,17 @@ fn absolute_path(context: &FunctionContext, path: &str) -> Result<String, String
   }
 }
 
+fn absolute_path_invocation(context: &FunctionContext, path: &str) -> Result<String, String> {
+  let abs_path_unchecked = context.config.invocation_directory.join(path).lexiclean();
+  match abs_path_unchecked.to_str() {
+    Some(absolute_path) => Ok(absolute_path.to_owned()),
+    None => Err(format!(
+      "Invocation directory is not valid unicode: {}",
+      context.config.invocation_directory.display()
+    )),
+  }
+}
+
 fn arch(_context: &FunctionContext) -> Result<String, String> {
   Ok(target::arch().to_owned())
 }
@@ -113,6 +220,7 @@ fn blake3(_context: &FunctionContext, s: &str) -> Result<String, String> {
   Ok(blake3::hash(s.as_bytes()).to_string())
 }
 
+#[cfg(feature = "native")]
 fn blake3_file(context: &FunctionContext, path: &str) -> Result<String, String> {
   let path = context.search.working_directory.join(path);
   let mut hasher = blake3::Hasher::new();
@@ -122,6 +230,11 @@ fn blake3_file(context: &FunctionContext, path: &str) -> Result<String, String>
   Ok(hasher.finalize().to_string())
 }
 
+#[cfg(not(feature = "native"))]
+fn blake3_file(_context: &FunctionContext, _path: &str) -> Result<String, String> {
+  Err("`blake3_file()` requires the `native` feature".into())
+}
+
 fn canonicalize(_context: &FunctionContext, path: &str) -> Result<String, String> {
   let canonical =
     std::fs::canonicalize(path).map_err(|err| format!("I/O error canonicalizing path: {err}"))?;
@@ -147,7 +260,10 @@ fn capitalize(_context: &FunctionContext, s: &str) -> Result<String, String> {
 }
 
 fn clean(_context: &FunctionContext, path: &str) -> Result<String, String> {
-  Ok(Path::new(path).lexiclean().to_str().unwrap().to_owned())
+  let path = normalize_prefixed_separators(path);
+  Ok(normalize_prefixed_separators(
+    Path::new(&path).lexiclean().to_str().unwrap(),
+  ))
 }
 
 fn dir(name: &'static str, f: fn() -> Option<PathBuf>) -> Result<String, String> {
@@ -209,6 +325,18 @@ fn env(context: &FunctionContext, key: &str, default: Option<&str>) -> Result<St
   }
 }
 
+fn env_flag(context: &FunctionContext, key: &str) -> Result<String, String> {
+  let value = env_var_or_default(context, key, "")?;
+
+  match value.trim().to_lowercase().as_str() {
+    "1" | "true" | "yes" | "on" => Ok("true".into()),
+    "" | "0" | "false" | "no" | "off" => Ok("false".into()),
+    _ => Err(format!(
+      "environment variable `{key}` is not a boolean flag: `{value}`"
+    )),
+  }
+}
+
 fn error(_context: &FunctionContext, message: &str) -> Result<String, String> {
   Err(message.to_owned())
 }
@@ -234,38 +362,136 @@ fn file_stem(_context: &FunctionContext, path: &str) -> Result<String, String> {
     .ok_or_else(|| format!("Could not extract file stem from `{path}`"))
 }
 
+fn humanize_bytes(_context: &FunctionContext, n: &str) -> Result<String, String> {
+  const UNITS: &[&str] = &["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+  let bytes = n
+    .parse::<u64>()
+    .map_err(|err| format!("invalid byte count `{n}`: {err}"))?;
+
+  if bytes < 1024 {
+    return Ok(format!("{bytes} B"));
+  }
+
+  #[allow(clippy::cast_precision_loss)]
+  let mut value = bytes as f64 / 1024.0;
+  let mut unit = 0;
+
+  while value >= 1024.0 && unit < UNITS.len() - 1 {
+    value /= 1024.0;
+    unit += 1;
+  }
+
+  Ok(format!("{value:.1} {}", UNITS[unit]))
+}
+
+fn humanize_duration(_context: &FunctionContext, seconds: &str) -> Result<String, String> {
+  let seconds = seconds
+    .parse::<f64>()
+    .ok()
+    .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+    .ok_or_else(|| format!("invalid duration `{seconds}`: expected non-negative seconds"))?;
+
+  if seconds < 1.0 {
+    return Ok(format!("{}ms", (seconds * 1000.0).round()));
+  }
+
+  if seconds < 60.0 {
+    let formatted = format!("{seconds:.2}");
+    return Ok(format!(
+      "{}s",
+      formatted.trim_end_matches('0').trim_end_matches('.')
+    ));
+  }
+
+  #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+  let mut remaining = seconds.round() as u64;
+
+  let mut parts = Vec::new();
+
+  for (unit, size) in [("d", 86_400), ("h", 3_600), ("m", 60), ("s", 1)] {
+    let count = remaining / size;
+    remaining %= size;
+    if count > 0 {
+      parts.push(format!("{count}{unit}"));
+    }
+  }
+
+  Ok(parts.join(" "))
+}
+
 fn invocation_directory(context: &FunctionContext) -> Result<String, String> {
   Platform::convert_native_path(
     &context.search.working_directory,
-    context.invocation_directory,
+    &context.config.invocation_directory,
   )
   .map_err(|e| format!("Error getting shell path: {e}"))
 }
 
 fn invocation_directory_native(context: &FunctionContext) -> Result<String, String> {
   context
+    .config
     .invocation_directory
     .to_str()
     .map(str::to_owned)
     .ok_or_else(|| {
       format!(
         "Invocation directory is not valid unicode: {}",
-        context.invocation_directory.display()
+        context.config.invocation_directory.display()
       )
     })
 }
 
+fn is_dry_run(context: &FunctionContext) -> Result<String, String> {
+  Ok(context.config.dry_run.to_string())
+}
+
 fn join(
   _context: &FunctionContext,
   base: &str,
   with: &str,
   and: &[String],
 ) -> Result<String, String> {
-  let mut result = Utf8Path::new(base).join(with);
+  let mut result = Utf8Path::new(&normalize_prefixed_separators(base)).join(with);
   for arg in and {
     result.push(arg);
   }
-  Ok(result.to_string())
+  Ok(normalize_prefixed_separators(result.as_str()))
+}
+
+fn json_encode(_context: &FunctionContext, s: &str) -> Result<String, String> {
+  serde_json::to_string(s).map_err(|err| format!("failed to encode JSON: {err}"))
+}
+
+fn json_object(
+  context: &FunctionContext,
+  key: &str,
+  value: &str,
+  rest: &[String],
+) -> Result<String, String> {
+  if rest.len() % 2 != 0 {
+    return Err(format!(
+      "key `{}` has no value, keys and values must be given in pairs",
+      rest[rest.len() - 1],
+    ));
+  }
+
+  let mut object = String::from("{");
+
+  object += &json_encode(context, key)?;
+  object += ":";
+  object += &json_encode(context, value)?;
+
+  for pair in rest.chunks(2) {
+    object += ",";
+    object += &json_encode(context, &pair[0])?;
+    object += ":";
+    object += &json_encode(context, &pair[1])?;
+  }
+
+  object += "}";
+
+  Ok(object)
 }
 
 fn just_executable(_context: &FunctionContext) -> Result<String, String> {
@@ -321,6 +547,35 @@ fn kebabcase(_context: &FunctionContext, s: &str) -> Result<String, String> {
   Ok(s.to_kebab_case())
 }
 
+fn line(_context: &FunctionContext, s: &str, n: &str) -> Result<String, String> {
+  let index = n
+    .parse::<isize>()
+    .map_err(|err| format!("invalid line number `{n}`: {err}"))?;
+
+  let lines = s.lines().collect::<Vec<&str>>();
+
+  let line = match index {
+    0 => None,
+    1.. => lines.get(index.unsigned_abs() - 1),
+    _ => lines
+      .len()
+      .checked_sub(index.unsigned_abs())
+      .and_then(|i| lines.get(i)),
+  };
+
+  line.map(|line| (*line).to_owned()).ok_or_else(|| {
+    format!(
+      "line {n} out of range, string has {} {}",
+      lines.len(),
+      Count("line", lines.len())
+    )
+  })
+}
+
+fn line_count(_context: &FunctionContext, s: &str) -> Result<String, String> {
+  Ok(s.lines().count().to_string())
+}
+
 fn lowercamelcase(_context: &FunctionContext, s: &str) -> Result<String, String> {
   Ok(s.to_lower_camel_case())
 }
@@ -329,6 +584,16 @@ fn lowercase(_context: &FunctionContext, s: &str) -> Result<String, String> {
   Ok(s.to_lowercase())
 }
 
+fn lowercase_first(_context: &FunctionContext, s: &str) -> Result<String, String> {
+  let mut chars = s.chars();
+  Ok(
+    chars
+      .next()
+      .map(|first| first.to_lowercase().chain(chars).collect())
+      .unwrap_or_default(),
+  )
+}
+
 fn num_cpus(_context: &FunctionContext) -> Result<String, String> {
   let num = num_cpus::get();
   Ok(num.to_string())
@@ -342,8 +607,21 @@ fn os_family(_context: &FunctionContext) -> Result<String, String> {
   Ok(target::family().to_owned())
 }
 
+/// On Windows, `/` is not a separator in verbatim paths, which start with
+/// `\\?\`, and mixing separators in UNC paths, which start with
+/// `\\server\share`, produces paths that many programs reject, so normalize
+/// `/` to `\` in both.
+fn normalize_prefixed_separators(path: &str) -> String {
+  if cfg!(windows) && path.starts_with(r"\\") {
+    path.replace('/', "\\")
+  } else {
+    path.to_owned()
+  }
+}
+
 fn parent_directory(_context: &FunctionContext, path: &str) -> Result<String, String> {
-  Utf8Path::new(path)
+  let path = normalize_prefixed_separators(path);
+  Utf8Path::new(&path)
     .parent()
     .map(Utf8Path::to_string)
     .ok_or_else(|| format!("Could not extract parent directory from `{path}`"))
@@ -368,6 +646,28 @@ fn replace(_context: &FunctionContext, s: &str, from: &str, to: &str) -> Result<
   Ok(s.replace(from, to))
 }
 
+fn replace_first(
+  _context: &FunctionContext,
+  s: &str,
+  from: &str,
+  to: &str,
+) -> Result<String, String> {
+  Ok(s.replacen(from, to, 1))
+}
+
+fn replace_n(
+  _context: &FunctionContext,
+  s: &str,
+  from: &str,
+  to: &str,
+  count: &str,
+) -> Result<String, String> {
+  let count = count
+    .parse::<usize>()
+    .map_err(|err| format!("invalid count `{count}`: {err}"))?;
+  Ok(s.replacen(from, to, count))
+}
+
 fn replace_regex(
   _context: &FunctionContext,
   s: &str,
@@ -382,6 +682,74 @@ fn replace_regex(
   )
 }
 
+fn run(context: &FunctionContext, recipe: &str, arguments: &[String]) -> Result<String, String> {
+  let just = env::current_exe().map_err(|e| format!("Error getting current executable: {e}"))?;
+
+  let config = context.config;
+
+  let mut command = Command::new(just);
+
+  command
+    .arg("--justfile")
+    .arg(&context.search.justfile)
+    .arg("--working-directory")
+    .arg(&context.search.working_directory)
+    .arg("--color")
+    .arg(config.color.argument());
+
+  if let Some(dotenv_filename) = &config.dotenv_filename {
+    command.arg("--dotenv-filename").arg(dotenv_filename);
+  }
+
+  if let Some(dotenv_path) = &config.dotenv_path {
+    command.arg("--dotenv-path").arg(dotenv_path);
+  }
+
+  if !config.load_dotenv {
+    command.arg("--no-dotenv");
+  }
+
+  if config.dry_run {
+    command.arg("--dry-run");
+  }
+
+  if config.unstable {
+    command.arg("--unstable");
+  }
+
+  if config.yes {
+    command.arg("--yes");
+  }
+
+  if let Subcommand::Run { overrides, .. } = &config.subcommand {
+    for (name, value) in overrides {
+      command.arg("--set").arg(name).arg(value);
+    }
+  }
+
+  let path = context
+    .modules
+    .iter()
+    .map(|name| name.lexeme())
+    .chain(iter::once(recipe))
+    .collect::<Vec<&str>>()
+    .join("::");
+
+  command.export_shell(config);
+
+  command
+    .arg(&path)
+    .args(arguments)
+    .stdin(Stdio::inherit())
+    .stderr(if config.verbosity.quiet() {
+      Stdio::null()
+    } else {
+      Stdio::inherit()
+    });
+
+  output(command).map_err(|output_error| format!("Recipe `{path}` failed: {output_error}"))
+}
+
 fn sha256(_context: &FunctionContext, s: &str) -> Result<String, String> {
   use sha2::{Digest, Sha256};
   let mut hasher = Sha256::new();
@@ -402,6 +770,57 @@ fn sha256_file(context: &FunctionContext, path: &str) -> Result<String, String>
   Ok(format!("{hash:x}"))
 }
 
+/// Expand a leading `~` to the home directory, and `$NAME` and `${NAME}` to
+/// the value of environment variable `NAME`, looked up like `env_var()`
+fn shell_expand(context: &FunctionContext, s: &str) -> Result<String, String> {
+  let mut expanded = String::new();
+
+  let rest = match s.strip_prefix('~') {
+    Some(rest)
+      if rest.is_empty() || rest.starts_with('/') || rest.starts_with(path::MAIN_SEPARATOR) =>
+    {
+      let home = dirs::home_dir().ok_or_else(|| "home directory not found".to_owned())?;
+      expanded += home
+        .to_str()
+        .ok_or_else(|| format!("home directory is not valid unicode: {}", home.display()))?;
+      rest
+    }
+    _ => s,
+  };
+
+  let mut chars = rest.chars().peekable();
+
+  while let Some(c) = chars.next() {
+    if c != '$' {
+      expanded.push(c);
+      continue;
+    }
+
+    let braced = chars.next_if_eq(&'{').is_some();
+
+    let mut name = String::new();
+    while let Some(c) = chars.next_if(|&c| c == '_' || c.is_ascii_alphanumeric()) {
+      name.push(c);
+    }
+
+    if braced && chars.next_if_eq(&'}').is_none() {
+      return Err(format!("unterminated `${{{name}` in `{s}`"));
+    }
+
+    if name.is_empty() {
+      if braced {
+        return Err(format!("empty variable name `${{}}` in `{s}`"));
+      }
+      expanded.push('$');
+      continue;
+    }
+
+    expanded += &env_var(context, &name)?;
+  }
+
+  Ok(expanded)
+}
+
 fn shoutykebabcase(_context: &FunctionContext, s: &str) -> Result<String, String> {
   Ok(s.to_shouty_kebab_case())
 }
@@ -454,16 +873,58 @@ fn uppercase(_context: &FunctionContext, s: &str) -> Result<String, String> {
   Ok(s.to_uppercase())
 }
 
+fn uppercase_first(_context: &FunctionContext, s: &str) -> Result<String, String> {
+  let mut chars = s.chars();
+  Ok(
+    chars
+      .next()
+      .map(|first| first.to_uppercase().chain(chars).collect())
+      .unwrap_or_default(),
+  )
+}
+
+#[cfg(feature = "native")]
 fn uuid(_context: &FunctionContext) -> Result<String, String> {
   Ok(uuid::Uuid::new_v4().to_string())
 }
 
+#[cfg(not(feature = "native"))]
+fn uuid(_context: &FunctionContext) -> Result<String, String> {
+  Err("`uuid()` requires the `native` feature".into())
+}
+
+#[cfg(feature = "native")]
+fn uuid_v5(_context: &FunctionContext, namespace: &str, name: &str) -> Result<String, String> {
+  use uuid::Uuid;
+
+  let namespace = match namespace {
+    "dns" => Uuid::NAMESPACE_DNS,
+    "oid" => Uuid::NAMESPACE_OID,
+    "url" => Uuid::NAMESPACE_URL,
+    "x500" => Uuid::NAMESPACE_X500,
+    _ => Uuid::parse_str(namespace).map_err(|err| {
+      format!(
+        "invalid namespace `{namespace}`, expected `dns`, `oid`, `url`, `x500`, or a UUID: {err}"
+      )
+    })?,
+  };
+
+  Ok(Uuid::new_v5(&namespace, name.as_bytes()).to_string())
+}
+
+#[cfg(not(feature = "native"))]
+fn uuid_v5(_context: &FunctionContext, _namespace: &str, _name: &str) -> Result<String, String> {
+  Err("`uuid_v5()` requires the `native` feature".into())
+}
+
 fn without_extension(_context: &FunctionContext, path: &str) -> Result<String, String> {
-  let parent = Utf8Path::new(path)
+  let path = normalize_prefixed_separators(path);
+
+  let parent = Utf8Path::new(&path)
     .parent()
     .ok_or_else(|| format!("Could not extract parent from `{path}`"))?;
 
-  let file_stem = Utf8Path::new(path)
+  let file_stem = Utf8Path::new(&path)
     .file_stem()
     .ok_or_else(|| format!("Could not extract file stem from `{path}`"))?;
 
@@ -494,6 +955,13 @@ fn semver_matches(
 mod tests {
   use super::*;
 
+  #[test]
+  fn names() {
+    for name in NAMES {
+      assert!(get(name).is_some(), "unknown function `{name}`");
+    }
+  }
+
   #[test]
   fn dir_not_found() {
     assert_eq!(dir("foo", || None).unwrap_err(), "foo directory not found");
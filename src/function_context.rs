@@ -1,7 +1,8 @@
 use super::*;
 
 pub(crate) struct FunctionContext<'run> {
+  pub(crate) config: &'run Config,
   pub(crate) dotenv: &'run BTreeMap<String, String>,
-  pub(crate) invocation_directory: &'run Path,
+  pub(crate) modules: &'run [Name<'run>],
   pub(crate) search: &'run Search,
 }
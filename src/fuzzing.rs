@@ -1,5 +1,90 @@
 use super::*;
 
-pub fn compile(text: &str) {
-  let _ = testing::compile(text);
+/// Lex, parse, and analyze `src`
+pub fn compile(src: &str) {
+  let _ = Compiler::compile_src(Path::new("justfile"), src);
+}
+
+/// Compile `src` and, if it compiles, evaluate its assignments, parameter
+/// defaults, dependency arguments and conditions, and recipe lines, with recipe parameters
+/// bound to empty strings. The fuzzing crate depends on just without the
+/// `native` feature, so backticks, `shell()`, and `run()` fail instead of
+/// spawning processes.
+pub fn evaluate(src: &str) {
+  let justfile = match Compiler::compile_src(Path::new("justfile"), src) {
+    Ok(justfile) => justfile,
+    Err(_) => return,
+  };
+
+  let config = match Config::app()
+    .try_get_matches_from(["just", "--quiet"])
+    .ok()
+    .and_then(|matches| Config::from_matches(&matches).ok())
+  {
+    Some(config) => config,
+    None => return,
+  };
+
+  let search = Search {
+    justfile: config.invocation_directory.join("justfile"),
+    working_directory: config.invocation_directory.clone(),
+  };
+
+  let dotenv = BTreeMap::new();
+
+  let root = Scope::new();
+
+  let scope = match Evaluator::evaluate_assignments(
+    &justfile.assignments,
+    &config,
+    &dotenv,
+    root.child(),
+    &justfile.settings,
+    &search,
+  ) {
+    Ok(scope) => scope,
+    Err(_) => return,
+  };
+
+  for recipe in justfile.recipes.values() {
+    let mut parameters = scope.child();
+
+    for parameter in &recipe.parameters {
+      parameters.bind_parameter(parameter, String::new());
+    }
+
+    let mut evaluator = Evaluator::recipe_evaluator(
+      &config,
+      &dotenv,
+      recipe.namepath.modules(),
+      &parameters,
+      &justfile.settings,
+      &search,
+    );
+
+    for parameter in &recipe.parameters {
+      if let Some(default) = &parameter.default {
+        let _ = evaluator.evaluate_expression(default);
+      }
+    }
+
+    for dependency in &recipe.dependencies {
+      for argument in &dependency.arguments {
+        let _ = evaluator.evaluate_expression(argument);
+      }
+
+      if let Some(condition) = &dependency.condition {
+        let _ = evaluator.evaluate_condition(condition);
+      }
+    }
+
+    for line in &recipe.body {
+      let _ = evaluator.evaluate_line(line, false);
+    }
+  }
+}
+
+/// Unindent `text`
+pub fn unindent(text: &str) {
+  let _ = crate::unindent(text);
 }
@@ -7,6 +7,7 @@ pub(crate) struct InterruptHandler {
 }
 
 impl InterruptHandler {
+  #[cfg(feature = "native")]
   pub(crate) fn install(verbosity: Verbosity) -> Result<(), ctrlc::Error> {
     let mut instance = Self::instance();
     instance.verbosity = verbosity;
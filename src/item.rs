@@ -20,6 +20,7 @@ pub(crate) enum Item<'src> {
   },
   Recipe(UnresolvedRecipe<'src>),
   Set(Set<'src>),
+  TrailingComment(&'src str),
 }
 
 impl<'src> Display for Item<'src> {
@@ -27,7 +28,7 @@ impl<'src> Display for Item<'src> {
     match self {
       Self::Alias(alias) => write!(f, "{alias}"),
       Self::Assignment(assignment) => write!(f, "{assignment}"),
-      Self::Comment(comment) => write!(f, "{comment}"),
+      Self::Comment(comment) | Self::TrailingComment(comment) => write!(f, "{comment}"),
       Self::Import {
         relative, optional, ..
       } => {
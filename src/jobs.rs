@@ -0,0 +1,38 @@
+use super::*;
+
+/// Limits the number of recipe bodies which may run at the same time, as set
+/// by `--jobs`. Only recipe bodies hold a job while they run, so a recipe
+/// waiting on its dependencies never keeps them from starting.
+pub(crate) struct Jobs {
+  available: Mutex<usize>,
+  released: Condvar,
+}
+
+impl Jobs {
+  pub(crate) fn new(jobs: usize) -> Self {
+    Self {
+      available: Mutex::new(jobs),
+      released: Condvar::new(),
+    }
+  }
+
+  /// Run `f` once a job is available, blocking until then
+  pub(crate) fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+    {
+      let mut available = self.available.lock().unwrap();
+
+      while *available == 0 {
+        available = self.released.wait(available).unwrap();
+      }
+
+      *available -= 1;
+    }
+
+    let result = f();
+
+    *self.available.lock().unwrap() += 1;
+    self.released.notify_one();
+
+    result
+  }
+}
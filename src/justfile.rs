@@ -2,7 +2,8 @@ use {super::*, serde::Serialize};
 
 #[derive(Debug)]
 struct Invocation<'src: 'run, 'run> {
-  arguments: Vec<&'run str>,
+  arguments: Vec<String>,
+  passthrough: Vec<&'run str>,
   recipe: &'run Recipe<'src>,
   settings: &'run Settings<'src>,
   scope: &'run Scope<'src, 'run>,
@@ -13,29 +14,27 @@ pub(crate) struct Justfile<'src> {
   pub(crate) aliases: Table<'src, Alias<'src>>,
   pub(crate) assignments: Table<'src, Assignment<'src>>,
   #[serde(rename = "first", serialize_with = "keyed::serialize_option")]
-  pub(crate) default: Option<Rc<Recipe<'src>>>,
+  pub(crate) default: Option<Arc<Recipe<'src>>>,
   #[serde(skip)]
   pub(crate) loaded: Vec<PathBuf>,
   pub(crate) modules: BTreeMap<String, Justfile<'src>>,
-  pub(crate) recipes: Table<'src, Rc<Recipe<'src>>>,
+  pub(crate) recipes: Table<'src, Arc<Recipe<'src>>>,
   pub(crate) settings: Settings<'src>,
   pub(crate) warnings: Vec<Warning>,
 }
 
 impl<'src> Justfile<'src> {
   pub(crate) fn suggest_recipe(&self, input: &str) -> Option<Suggestion<'src>> {
+    let input = self.settings.recipe_name_key(input);
+    let distance = |name: &str| edit_distance(&self.settings.recipe_name_key(name), &input);
+
     let mut suggestions = self
       .recipes
       .keys()
-      .map(|name| {
-        (
-          edit_distance(name, input),
-          Suggestion { name, target: None },
-        )
-      })
+      .map(|name| (distance(name), Suggestion { name, target: None }))
       .chain(self.aliases.iter().map(|(name, alias)| {
         (
-          edit_distance(name, input),
+          distance(name),
           Suggestion {
             name,
             target: Some(alias.target.name.lexeme()),
@@ -88,7 +87,24 @@ impl<'src> Justfile<'src> {
 
     for (name, value) in overrides {
       if let Some(assignment) = self.assignments.get(name) {
-        scope.bind(assignment.export, assignment.name, value.clone());
+        if !assignment.choices.is_empty()
+          && !assignment
+            .choices
+            .iter()
+            .any(|choice| &choice.cooked == value)
+        {
+          return Err(Error::InvalidOverride {
+            variable: assignment.name,
+            value: value.clone(),
+            choices: assignment
+              .choices
+              .iter()
+              .map(|choice| choice.cooked.clone())
+              .collect(),
+          });
+        }
+
+        scope.bind(assignment, value.clone());
       } else {
         unknown_overrides.push(name.clone());
       }
@@ -116,6 +132,7 @@ impl<'src> Justfile<'src> {
     search: &Search,
     overrides: &BTreeMap<String, String>,
     arguments: &[String],
+    events: &dyn Events,
   ) -> RunResult<'src> {
     let unknown_overrides = overrides
       .keys()
@@ -129,8 +146,37 @@ impl<'src> Justfile<'src> {
       });
     }
 
+    for (path, parameters) in &config.dependency_arguments {
+      let (modules, name) = path.rsplit_once("::").unwrap_or(("", path.as_str()));
+
+      let Some(recipe) = modules
+        .split("::")
+        .filter(|module| !module.is_empty())
+        .try_fold(self, |justfile, module| justfile.modules.get(module))
+        .and_then(|justfile| justfile.recipes.get(name))
+      else {
+        return Err(Error::UnknownRecipes {
+          recipes: vec![path.clone()],
+          suggestion: None,
+        });
+      };
+
+      for parameter in parameters.keys() {
+        if !recipe
+          .parameters
+          .iter()
+          .any(|candidate| candidate.name.lexeme() == parameter)
+        {
+          return Err(Error::UnknownParameter {
+            recipe: path.clone(),
+            parameter: parameter.clone(),
+          });
+        }
+      }
+    }
+
     let dotenv = if config.load_dotenv {
-      load_dotenv(config, &self.settings, &search.working_directory)?
+      load_dotenv(config, &self.settings, search)?
     } else {
       BTreeMap::new()
     };
@@ -157,15 +203,15 @@ impl<'src> Justfile<'src> {
 
         let scope = scope.child();
 
-        command.export(&self.settings, &dotenv, &scope);
+        command.export(config, &self.settings, &dotenv, &scope);
 
-        let status = InterruptHandler::guard(|| command.status()).map_err(|io_error| {
-          Error::CommandInvoke {
+        let status = command
+          .spawn_status()
+          .map_err(|io_error| Error::CommandInvoke {
             binary: binary.clone(),
             arguments: arguments.clone(),
             io_error,
-          }
-        })?;
+          })?;
 
         if !status.success() {
           return Err(Error::CommandStatus {
@@ -177,7 +223,51 @@ impl<'src> Justfile<'src> {
 
         return Ok(());
       }
-      Subcommand::Evaluate { variable, .. } => {
+      Subcommand::Evaluate {
+        arguments,
+        recipe,
+        variable,
+        ..
+      } => {
+        let recipe_scope;
+
+        let scope = if let Some(name) = recipe {
+          let Some(recipe) = self.get_recipe(name) else {
+            return Err(Error::UnknownRecipes {
+              recipes: vec![name.clone()],
+              suggestion: self.suggest_recipe(name),
+            });
+          };
+
+          if !recipe.argument_range().contains(&arguments.len()) {
+            return Err(Error::ArgumentCountMismatch {
+              recipe: recipe.name(),
+              parameters: recipe.parameters.clone(),
+              found: arguments.len(),
+              min: recipe.min_arguments(),
+              max: recipe.max_arguments(),
+            });
+          }
+
+          recipe_scope = Evaluator::evaluate_parameters(
+            config,
+            &dotenv,
+            recipe.namepath.modules(),
+            &recipe.parameters,
+            arguments,
+            &[],
+            None,
+            &scope,
+            &self.settings,
+            search,
+          )?
+          .0;
+
+          &recipe_scope
+        } else {
+          &scope
+        };
+
         if let Some(variable) = variable {
           if let Some(value) = scope.value(variable) {
             print!("{value}");
@@ -199,7 +289,7 @@ impl<'src> Justfile<'src> {
               "{0:1$} := \"{2}\"",
               binding.name.lexeme(),
               width,
-              binding.value
+              scope.mask(&binding.value),
             );
           }
         }
@@ -209,10 +299,21 @@ impl<'src> Justfile<'src> {
       _ => {}
     }
 
-    let mut remaining: Vec<&str> = if !arguments.is_empty() {
-      arguments.iter().map(String::as_str).collect()
+    let passthrough = match &config.subcommand {
+      Subcommand::Run { passthrough, .. } => passthrough.as_slice(),
+      _ => &[],
+    };
+
+    let mut remaining: Vec<&str> = if !arguments.is_empty() || !passthrough.is_empty() {
+      arguments
+        .iter()
+        .chain(passthrough)
+        .map(String::as_str)
+        .collect()
     } else if let Some(recipe) = &self.default {
-      recipe.check_can_be_default_recipe()?;
+      if !self.prompts_for_arguments() {
+        recipe.check_can_be_default_recipe()?;
+      }
       vec![recipe.name()]
     } else if self.recipes.is_empty() {
       return Err(Error::NoRecipes);
@@ -225,6 +326,9 @@ impl<'src> Justfile<'src> {
     let mut scopes = BTreeMap::new();
     let arena: Arena<Scope> = Arena::new();
 
+    // number of trailing values in `remaining` that were given after `--`
+    let mut passthrough = passthrough.len();
+
     while let Some(first) = remaining.first().copied() {
       if first.contains("::")
         && !(first.starts_with(':') || first.ends_with(':') || first.contains(":::"))
@@ -239,6 +343,8 @@ impl<'src> Justfile<'src> {
 
       let rest = &remaining[1..];
 
+      passthrough = passthrough.min(rest.len());
+
       if let Some((invocation, consumed)) = self.invocation(
         0,
         &mut Vec::new(),
@@ -250,6 +356,7 @@ impl<'src> Justfile<'src> {
         &scope,
         first,
         rest,
+        passthrough,
       )? {
         remaining = rest[consumed..].to_vec();
         invocations.push(invocation);
@@ -271,25 +378,30 @@ impl<'src> Justfile<'src> {
       });
     }
 
-    let mut ran = Ran::default();
+    let jobs = Jobs::new(config.jobs);
+    let ran = Ran::default();
     for invocation in invocations {
       let context = RecipeContext {
+        buffer: None,
         settings: invocation.settings,
         config,
+        events,
+        jobs: &jobs,
         scope: invocation.scope,
         search,
       };
 
       Self::run_recipe(
+        &invocation.arguments,
         &invocation
-          .arguments
+          .passthrough
           .iter()
           .copied()
           .map(str::to_string)
           .collect::<Vec<String>>(),
         &context,
         &dotenv,
-        &mut ran,
+        &ran,
         invocation.recipe,
         search,
       )?;
@@ -306,10 +418,78 @@ impl<'src> Justfile<'src> {
     self
       .recipes
       .get(name)
-      .map(Rc::as_ref)
+      .map(Arc::as_ref)
       .or_else(|| self.aliases.get(name).map(|alias| alias.target.as_ref()))
   }
 
+  /// Find a recipe by name, or, if `prefix-match` is set, by unambiguous
+  /// prefix of a public recipe or alias name
+  fn find_recipe(&self, name: &str) -> RunResult<'src, Option<&Recipe<'src>>> {
+    if let Some(recipe) = self.get_recipe(name) {
+      return Ok(Some(recipe));
+    }
+
+    let key = self.settings.recipe_name_key(name);
+
+    if self.settings.normalize_recipe_names {
+      let candidates = self.recipe_candidates(false, |candidate| candidate == key);
+
+      if candidates.len() > 1 {
+        return Err(Error::AmbiguousRecipeName {
+          name: name.into(),
+          candidates: candidates.into_keys().collect(),
+        });
+      }
+
+      if let Some(recipe) = candidates.into_values().next() {
+        return Ok(Some(recipe));
+      }
+    }
+
+    if !self.settings.prefix_match || key.is_empty() {
+      return Ok(None);
+    }
+
+    let candidates = self.recipe_candidates(true, |candidate| candidate.starts_with(&key));
+
+    if candidates.len() > 1 {
+      return Err(Error::AmbiguousRecipePrefix {
+        prefix: name.into(),
+        candidates: candidates.into_keys().collect(),
+      });
+    }
+
+    Ok(candidates.into_values().next())
+  }
+
+  /// Recipes with a name or alias whose key satisfies `predicate`, keyed by
+  /// recipe name
+  fn recipe_candidates(
+    &self,
+    public_only: bool,
+    predicate: impl Fn(&str) -> bool,
+  ) -> BTreeMap<&'src str, &Recipe<'src>> {
+    let mut candidates = BTreeMap::new();
+
+    for recipe in self.recipes.values() {
+      if (recipe.is_public() || !public_only)
+        && predicate(&self.settings.recipe_name_key(recipe.name()))
+      {
+        candidates.insert(recipe.name(), recipe.as_ref());
+      }
+    }
+
+    for alias in self.aliases.values() {
+      if (!alias.is_private() || !public_only)
+        && predicate(&self.settings.recipe_name_key(alias.name.lexeme()))
+      {
+        candidates.insert(alias.target.name(), alias.target.as_ref());
+      }
+    }
+
+    candidates
+  }
+
   fn invocation<'run>(
     &'run self,
     depth: usize,
@@ -322,6 +502,7 @@ impl<'src> Justfile<'src> {
     parent: &'run Scope<'src, 'run>,
     first: &'run str,
     rest: &[&'run str],
+    passthrough: usize,
   ) -> RunResult<'src, Option<(Invocation<'src, 'run>, usize)>> {
     if let Some(module) = self.modules.get(first) {
       path.push(first);
@@ -337,12 +518,18 @@ impl<'src> Justfile<'src> {
 
       if rest.is_empty() {
         if let Some(recipe) = &module.default {
-          recipe.check_can_be_default_recipe()?;
+          let mut arguments = Vec::new();
+          if module.prompts_for_arguments() {
+            recipe.prompt_for_arguments(&mut arguments)?;
+          } else {
+            recipe.check_can_be_default_recipe()?;
+          }
           return Ok(Some((
             Invocation {
               settings: &module.settings,
               recipe,
-              arguments: Vec::new(),
+              arguments,
+              passthrough: Vec::new(),
               scope,
             },
             depth,
@@ -361,13 +548,15 @@ impl<'src> Justfile<'src> {
           scope,
           rest[0],
           &rest[1..],
+          passthrough.min(rest.len() - 1),
         )
       }
-    } else if let Some(recipe) = self.get_recipe(first) {
+    } else if let Some(recipe) = self.find_recipe(first)? {
       if recipe.parameters.is_empty() {
         Ok(Some((
           Invocation {
             arguments: Vec::new(),
+            passthrough: Vec::new(),
             recipe,
             scope: parent,
             settings: &self.settings,
@@ -375,25 +564,44 @@ impl<'src> Justfile<'src> {
           depth,
         )))
       } else {
-        let argument_range = recipe.argument_range();
+        let (rest, after) = if recipe.takes_passthrough() {
+          rest.split_at(rest.len() - passthrough)
+        } else {
+          (rest, [].as_slice())
+        };
         let argument_count = cmp::min(rest.len(), recipe.max_arguments());
-        if !argument_range.range_contains(&argument_count) {
+        let mut arguments = rest[..argument_count]
+          .iter()
+          .copied()
+          .map(str::to_string)
+          .collect::<Vec<String>>();
+        if argument_count < recipe.min_arguments() && self.prompts_for_arguments() {
+          recipe.prompt_for_arguments(&mut arguments)?;
+        }
+        if !recipe.argument_range().range_contains(&arguments.len()) {
           return Err(Error::ArgumentCountMismatch {
             recipe: recipe.name(),
             parameters: recipe.parameters.clone(),
-            found: rest.len(),
+            found: arguments.len(),
             min: recipe.min_arguments(),
             max: recipe.max_arguments(),
           });
         }
+        // arguments after `--` only go to the recipe immediately before it
+        let passthrough = if argument_count == rest.len() {
+          after
+        } else {
+          &[]
+        };
         Ok(Some((
           Invocation {
-            arguments: rest[..argument_count].to_vec(),
+            arguments,
+            passthrough: passthrough.to_vec(),
             recipe,
             scope: parent,
             settings: &self.settings,
           },
-          depth + argument_count,
+          depth + argument_count + passthrough.len(),
         )))
       }
     } else {
@@ -401,18 +609,41 @@ impl<'src> Justfile<'src> {
     }
   }
 
+  /// Whether missing arguments should be read interactively from stdin
+  fn prompts_for_arguments(&self) -> bool {
+    self.settings.prompt_for_missing_arguments() && atty::is(atty::Stream::Stdin)
+  }
+
   fn run_recipe(
     arguments: &[String],
+    passthrough: &[String],
     context: &RecipeContext<'src, '_>,
     dotenv: &BTreeMap<String, String>,
-    ran: &mut Ran<'src>,
+    ran: &Ran<'src>,
     recipe: &Recipe<'src>,
     search: &Search,
   ) -> RunResult<'src> {
-    if ran.has_run(&recipe.namepath, arguments) {
+    if !ran.claim(recipe, arguments)? {
       return Ok(());
     }
 
+    let result =
+      Self::run_claimed_recipe(arguments, passthrough, context, dotenv, ran, recipe, search);
+
+    ran.finish(recipe, arguments, result.is_ok());
+
+    result
+  }
+
+  fn run_claimed_recipe(
+    arguments: &[String],
+    passthrough: &[String],
+    context: &RecipeContext<'src, '_>,
+    dotenv: &BTreeMap<String, String>,
+    ran: &Ran<'src>,
+    recipe: &Recipe<'src>,
+    search: &Search,
+  ) -> RunResult<'src> {
     if !context.config.yes && !recipe.confirm()? {
       return Err(Error::NotConfirmed {
         recipe: recipe.name(),
@@ -422,8 +653,14 @@ impl<'src> Justfile<'src> {
     let (outer, positional) = Evaluator::evaluate_parameters(
       context.config,
       dotenv,
+      recipe.namepath.modules(),
       &recipe.parameters,
       arguments,
+      passthrough,
+      context
+        .config
+        .dependency_arguments
+        .get(&recipe.namepath.to_string()),
       context.scope,
       context.settings,
       search,
@@ -431,49 +668,147 @@ impl<'src> Justfile<'src> {
 
     let scope = outer.child();
 
-    let mut evaluator =
-      Evaluator::recipe_evaluator(context.config, dotenv, &scope, context.settings, search);
+    let mut evaluator = Evaluator::recipe_evaluator(
+      context.config,
+      dotenv,
+      recipe.namepath.modules(),
+      &scope,
+      context.settings,
+      search,
+    );
 
     if !context.config.no_dependencies {
-      for Dependency { recipe, arguments } in recipe.dependencies.iter().take(recipe.priors) {
-        let arguments = arguments
-          .iter()
-          .map(|argument| evaluator.evaluate_expression(argument))
-          .collect::<RunResult<Vec<String>>>()?;
+      let priors = recipe
+        .dependencies
+        .iter()
+        .take(recipe.priors)
+        .filter_map(|dependency| Self::evaluate_dependency(&mut evaluator, dependency).transpose());
 
-        Self::run_recipe(&arguments, context, dotenv, ran, recipe, search)?;
+      if recipe.parallel(context.settings) {
+        let priors = priors.collect::<RunResult<Vec<(&Recipe, Vec<String>)>>>()?;
+        Self::run_parallel(&priors, context, dotenv, ran, search)?;
+      } else {
+        for prior in priors {
+          let (recipe, arguments) = prior?;
+          Self::run_recipe(&arguments, &[], context, dotenv, ran, recipe, search)?;
+        }
       }
     }
 
-    recipe.run(context, dotenv, scope.child(), search, &positional)?;
+    context
+      .jobs
+      .run(|| recipe.run(context, dotenv, scope.child(), search, &positional))?;
 
     if !context.config.no_dependencies {
-      let mut ran = Ran::default();
+      let ran = Ran::default();
 
-      for Dependency { recipe, arguments } in recipe.dependencies.iter().skip(recipe.priors) {
-        let mut evaluated = Vec::new();
-
-        for argument in arguments {
-          evaluated.push(evaluator.evaluate_expression(argument)?);
+      for dependency in recipe.dependencies.iter().skip(recipe.priors) {
+        if let Some((recipe, arguments)) = Self::evaluate_dependency(&mut evaluator, dependency)? {
+          Self::run_recipe(&arguments, &[], context, dotenv, &ran, recipe, search)?;
         }
-
-        Self::run_recipe(&evaluated, context, dotenv, &mut ran, recipe, search)?;
       }
     }
 
-    ran.ran(&recipe.namepath, arguments.to_vec());
     Ok(())
   }
 
+  /// Evaluate the condition and arguments of `dependency`, returning `None` if
+  /// its condition is false
+  fn evaluate_dependency<'a>(
+    evaluator: &mut Evaluator<'src, '_>,
+    Dependency {
+      recipe,
+      arguments,
+      condition,
+    }: &'a Dependency<'src>,
+  ) -> RunResult<'src, Option<(&'a Recipe<'src>, Vec<String>)>> {
+    if let Some(condition) = condition {
+      if !evaluator.evaluate_condition(condition)? {
+        return Ok(None);
+      }
+    }
+
+    let arguments = arguments
+      .iter()
+      .map(|argument| evaluator.evaluate_expression(argument))
+      .collect::<RunResult<Vec<String>>>()?;
+
+    Ok(Some((recipe, arguments)))
+  }
+
+  /// Run `dependencies` concurrently, each on its own thread, with output
+  /// buffered until it finishes. If more than one fails, the first error is
+  /// returned, preferring errors from the dependency that actually failed
+  /// over those from siblings which were waiting for it.
+  fn run_parallel(
+    dependencies: &[(&Recipe<'src>, Vec<String>)],
+    context: &RecipeContext<'src, '_>,
+    dotenv: &BTreeMap<String, String>,
+    ran: &Ran<'src>,
+    search: &Search,
+  ) -> RunResult<'src> {
+    let results = thread::scope(|scope| {
+      let handles = dependencies
+        .iter()
+        .map(|(recipe, arguments)| {
+          scope.spawn(move || {
+            let buffer = OutputBuffer::default();
+
+            let result = Self::run_recipe(
+              arguments,
+              &[],
+              &RecipeContext {
+                buffer: Some(&buffer),
+                ..*context
+              },
+              dotenv,
+              ran,
+              recipe,
+              search,
+            );
+
+            let flushed = buffer.flush(context.buffer);
+
+            result.and(flushed.map_err(|io_error| Error::StdoutIo { io_error }))
+          })
+        })
+        .collect::<Vec<thread::ScopedJoinHandle<RunResult<'src>>>>();
+
+      handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Vec<RunResult<'src>>>()
+    });
+
+    let (waiting, failed) = results
+      .into_iter()
+      .filter_map(Result::err)
+      .partition::<Vec<Error>, _>(|error| matches!(error, Error::ParallelDependency { .. }));
+
+    match failed.into_iter().chain(waiting).next() {
+      Some(error) => Err(error),
+      None => Ok(()),
+    }
+  }
+
   pub(crate) fn public_recipes(&self, source_order: bool) -> Vec<&Recipe<'src, Dependency>> {
+    self
+      .recipes_sorted(source_order)
+      .into_iter()
+      .filter(|recipe| recipe.is_public())
+      .collect()
+  }
+
+  /// All recipes, public and private, in alphabetical order, or in source
+  /// order if `source_order` is true or `set list-order := 'source'`
+  pub(crate) fn recipes_sorted(&self, source_order: bool) -> Vec<&Recipe<'src, Dependency>> {
     let mut recipes = self
       .recipes
       .values()
       .map(AsRef::as_ref)
-      .filter(|recipe| recipe.is_public())
       .collect::<Vec<&Recipe<Dependency>>>();
 
-    if source_order {
+    if source_order || self.settings.list_source_order() {
       recipes.sort_by_key(|recipe| {
         (
           self
@@ -932,7 +1267,7 @@ foo := \"xx\"
 
 goodbye := \"y\"
 
-hello a b c: x y z
+hello a b c: x y z #hello
     #! blah
     #blarg
     {{ foo + bar }}abc{{ goodbye + \"x\" }}xyz
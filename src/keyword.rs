@@ -4,25 +4,41 @@ use super::*;
 #[strum(serialize_all = "kebab_case")]
 pub(crate) enum Keyword {
   Alias,
+  Allow,
   AllowDuplicateRecipes,
   AllowDuplicateVariables,
+  AllowMissingRecipeArgs,
+  As,
   Assert,
+  Chooser,
+  Default,
   DotenvFilename,
   DotenvLoad,
+  DotenvOutsideProject,
+  DotenvOverride,
   DotenvPath,
   Else,
+  EnvAllow,
+  EnvDeny,
   Export,
   Fallback,
+  FallbackDepth,
   False,
   If,
   IgnoreComments,
   Import,
+  ListOrder,
   Mod,
+  NormalizeRecipeNames,
+  Parallel,
   PositionalArguments,
+  PrefixMatch,
   Quiet,
   Set,
   Shell,
+  ShellArgs,
   Tempdir,
+  TraceRedact,
   True,
   WindowsPowershell,
   WindowsShell,
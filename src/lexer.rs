@@ -25,6 +25,8 @@ pub(crate) struct Lexer<'src> {
   recipe_body: bool,
   /// Next indent will start a recipe body
   recipe_body_pending: bool,
+  /// Inside body of recipe with `[verbatim]` attribute
+  recipe_body_verbatim: bool,
   /// Source text
   src: &'src str,
   /// Tokens
@@ -63,6 +65,7 @@ impl<'src> Lexer<'src> {
       token_start: start,
       token_end: start,
       recipe_body_pending: false,
+      recipe_body_verbatim: false,
       recipe_body: false,
       interpolation_stack: Vec::new(),
       open_delimiters: Vec::new(),
@@ -239,6 +242,8 @@ impl<'src> Lexer<'src> {
         };
         kind.delimiter().len()
       }
+      // highlight the opening `/*`
+      UnterminatedBlockComment => 2,
       // highlight the full token
       _ => self.lexeme().len(),
     };
@@ -457,7 +462,7 @@ impl<'src> Lexer<'src> {
           self.advance()?;
         }
 
-        if self.open_delimiters() {
+        if self.line_continues() {
           self.token(Whitespace);
         } else {
           let indentation = self.lexeme();
@@ -465,6 +470,7 @@ impl<'src> Lexer<'src> {
           self.token(Indent);
           if self.recipe_body_pending {
             self.recipe_body = true;
+            self.recipe_body_verbatim = self.verbatim_recipe();
           }
         }
 
@@ -487,6 +493,8 @@ impl<'src> Lexer<'src> {
       '*' => self.lex_single(Asterisk),
       '+' => self.lex_single(Plus),
       ',' => self.lex_single(Comma),
+      '-' if self.rest_starts_with("--") => self.lex_double(MinusMinus),
+      '/' if self.rest_starts_with("/*") => self.lex_block_comment(),
       '/' => self.lex_single(Slash),
       ':' => self.lex_colon(),
       '=' => self.lex_choices('=', &[('=', EqualsEquals), ('~', EqualsTilde)], Equals),
@@ -525,7 +533,7 @@ impl<'src> Lexer<'src> {
       }
       // Emit interpolation end token
       self.lex_double(InterpolationEnd)
-    } else if self.at_eol_or_eof() {
+    } else if self.at_eof() || (self.at_eol() && !self.line_continues()) {
       // Return unterminated interpolation error that highlights the opening
       // {{
       Err(Self::unterminated_interpolation_error(interpolation_start))
@@ -547,7 +555,7 @@ impl<'src> Lexer<'src> {
     use Terminator::*;
 
     let terminator = loop {
-      if self.rest_starts_with("{{{{") {
+      if !self.recipe_body_verbatim && self.rest_starts_with("{{{{") {
         self.skip(4)?;
         continue;
       }
@@ -560,7 +568,7 @@ impl<'src> Lexer<'src> {
         break NewlineCarriageReturn;
       }
 
-      if self.rest_starts_with("{{") {
+      if !self.recipe_body_verbatim && self.rest_starts_with("{{") {
         break Interpolation;
       }
 
@@ -595,9 +603,42 @@ impl<'src> Lexer<'src> {
     self.token(Dedent);
     self.indentation.pop();
     self.recipe_body_pending = false;
+    self.recipe_body_verbatim = false;
     self.recipe_body = false;
   }
 
+  /// Whether the recipe whose body is starting has a `[verbatim]` attribute.
+  /// The lexer doesn't know about recipes, so look backwards past blank lines
+  /// and the recipe's header line, through the attribute and comment lines
+  /// preceding it, for attribute lines mentioning `verbatim`. The parser
+  /// checks that it agrees.
+  fn verbatim_recipe(&self) -> bool {
+    self
+      .tokens
+      .split(|token| token.kind == Eol)
+      .rev()
+      .map(|line| {
+        line
+          .iter()
+          .filter(|token| !matches!(token.kind, Whitespace | Dedent | Indent))
+          .collect::<Vec<&Token>>()
+      })
+      .skip_while(Vec::is_empty)
+      .skip(1)
+      .take_while(|line| {
+        line
+          .first()
+          .map_or(false, |token| matches!(token.kind, BracketL | Comment))
+      })
+      .any(|line| {
+        line.windows(2).any(|pair| {
+          matches!(pair[0].kind, BracketL | Comma)
+            && pair[1].kind == Identifier
+            && pair[1].lexeme() == "verbatim"
+        })
+      })
+  }
+
   /// Lex a single-character token
   fn lex_single(&mut self, kind: TokenKind) -> CompileResult<'src> {
     self.advance()?;
@@ -683,6 +724,28 @@ impl<'src> Lexer<'src> {
     !self.open_delimiters.is_empty()
   }
 
+  /// Return true if the last token is a binary operator, in which case the
+  /// expression continues on the next line
+  fn trailing_operator(&self) -> bool {
+    self
+      .tokens
+      .iter()
+      .rev()
+      .find(|token| token.kind != Whitespace)
+      .map_or(false, |token| {
+        matches!(
+          token.kind,
+          BangEquals | EqualsEquals | EqualsTilde | Plus | Slash
+        )
+      })
+  }
+
+  /// Return true if the current line continues on to the next, because of
+  /// unclosed delimiters or a trailing operator
+  fn line_continues(&self) -> bool {
+    self.open_delimiters() || self.trailing_operator()
+  }
+
   /// Lex a two-character digraph
   fn lex_digraph(&mut self, left: char, right: char, token: TokenKind) -> CompileResult<'src> {
     self.presume(left)?;
@@ -755,9 +818,9 @@ impl<'src> Lexer<'src> {
       self.presume('\n')?;
     }
 
-    // Emit an eol if there are no open delimiters, otherwise emit a whitespace
+    // Emit an eol if the line doesn't continue, otherwise emit a whitespace
     // token.
-    if self.open_delimiters() {
+    if self.line_continues() {
       self.token(Whitespace);
     } else {
       self.token(Eol);
@@ -791,7 +854,37 @@ impl<'src> Lexer<'src> {
       self.advance()?;
     }
 
-    self.token(Comment);
+    self.comment_token();
+
+    Ok(())
+  }
+
+  /// Emit a comment token. Comments on lines which continue on to the next,
+  /// inside an expression, are emitted as whitespace, so that the expression
+  /// is parsed as if they weren't there.
+  fn comment_token(&mut self) {
+    if self.line_continues() {
+      self.token(Whitespace);
+    } else {
+      self.token(Comment);
+    }
+  }
+
+  /// Lex block comment: /\*.*?\*/, which may span multiple lines
+  fn lex_block_comment(&mut self) -> CompileResult<'src> {
+    self.presume_str("/*")?;
+
+    while !self.rest_starts_with("*/") {
+      if self.at_eof() {
+        return Err(self.error(UnterminatedBlockComment));
+      }
+
+      self.advance()?;
+    }
+
+    self.presume_str("*/")?;
+
+    self.comment_token();
 
     Ok(())
   }
@@ -969,6 +1062,7 @@ mod tests {
       Indent => "  ",
       InterpolationEnd => "}}",
       InterpolationStart => "{{",
+      MinusMinus => "--",
       ParenL => "(",
       ParenR => ")",
       Plus => "+",
@@ -1025,6 +1119,8 @@ mod tests {
             path: "justfile".as_ref(),
           },
           kind: kind.into(),
+          related: None,
+          cycle: Vec::new(),
         };
         assert_eq!(have, want);
       }
@@ -1043,6 +1139,141 @@ mod tests {
     tokens: (Comment:"# hello"),
   }
 
+  test! {
+    name:   block_comment,
+    text:   "/* hello */",
+    tokens: (Comment:"/* hello */"),
+  }
+
+  test! {
+    name:   block_comment_multi_line,
+    text:   "/*\nfoo:\n  echo foo\n*/\nbar:",
+    tokens: (
+      Comment:"/*\nfoo:\n  echo foo\n*/",
+      Eol,
+      Identifier:"bar",
+      Colon,
+    ),
+  }
+
+  test! {
+    name:   comment_in_delimiters,
+    text:   "a := ( # c\n'b')",
+    tokens: (
+      Identifier:"a",
+      Whitespace,
+      ColonEquals,
+      Whitespace,
+      ParenL,
+      Whitespace,
+      Whitespace:"# c",
+      Whitespace:"\n",
+      StringToken:"'b'",
+      ParenR,
+    ),
+  }
+
+  test! {
+    name:   comment_after_trailing_operator,
+    text:   "a := 'b' + /* c */ # d\n'e'",
+    tokens: (
+      Identifier:"a",
+      Whitespace,
+      ColonEquals,
+      Whitespace,
+      StringToken:"'b'",
+      Whitespace,
+      Plus,
+      Whitespace,
+      Whitespace:"/* c */",
+      Whitespace,
+      Whitespace:"# d",
+      Whitespace:"\n",
+      StringToken:"'e'",
+    ),
+  }
+
+  test! {
+    name:   block_comment_trailing,
+    text:   "a := 'b' /* c */",
+    tokens: (
+      Identifier:"a",
+      Whitespace,
+      ColonEquals,
+      Whitespace,
+      StringToken:"'b'",
+      Whitespace,
+      Comment:"/* c */",
+    ),
+  }
+
+  test! {
+    name:   trailing_operator_continuation,
+    text:   "a := 'b' +\n  'c'",
+    tokens: (
+      Identifier:"a",
+      Whitespace,
+      ColonEquals,
+      Whitespace,
+      StringToken:"'b'",
+      Whitespace,
+      Plus,
+      Whitespace:"\n",
+      Whitespace:"  ",
+      StringToken:"'c'",
+    ),
+  }
+
+  test! {
+    name:   passthrough_parameter,
+    text:   "foo --args:",
+    tokens: (
+      Identifier:"foo",
+      Whitespace,
+      MinusMinus,
+      Identifier:"args",
+      Colon,
+    ),
+  }
+
+  test! {
+    name:   interpolation_parentheses_continuation,
+    text:   "foo:\n  {{ (\n  'a') }}",
+    tokens: (
+      Identifier:"foo",
+      Colon,
+      Eol,
+      Indent,
+      InterpolationStart,
+      Whitespace,
+      ParenL,
+      Whitespace:"\n",
+      Whitespace:"  ",
+      StringToken:"'a'",
+      ParenR,
+      Whitespace,
+      InterpolationEnd,
+      Dedent,
+    ),
+  }
+
+  test! {
+    name: block_comment_in_recipe_body,
+    text: "
+      foo:
+        ls /*
+    ",
+    tokens: (
+      Identifier:"foo",
+      Colon,
+      Eol,
+      Indent,
+      Text:"ls /*",
+      Eol,
+      Dedent,
+    )
+  }
+
   test! {
     name:   backtick,
     text:   "`echo`",
@@ -1661,6 +1892,74 @@ mod tests {
     ),
   }
 
+  test! {
+    name: tokenize_verbatim_recipe,
+    text: "
+      [private, verbatim]
+      foo:
+       {{hello}} {{{{
+      bar:
+       {{hello}}
+    ",
+    tokens: (
+      BracketL,
+      Identifier:"private",
+      Comma,
+      Whitespace,
+      Identifier:"verbatim",
+      BracketR,
+      Eol,
+      Identifier:"foo",
+      Colon,
+      Eol,
+      Indent:" ",
+      Text:"{{hello}} {{{{",
+      Eol,
+      Dedent,
+      Identifier:"bar",
+      Colon,
+      Eol,
+      Indent:" ",
+      InterpolationStart,
+      Identifier:"hello",
+      InterpolationEnd,
+      Eol,
+      Dedent
+    ),
+  }
+
+  test! {
+    name: tokenize_verbatim_recipe_with_comments_between_attributes,
+    text: "
+      [verbatim] # no interpolation
+      # between
+      [private]
+      foo:
+       {{hello}}
+    ",
+    tokens: (
+      BracketL,
+      Identifier:"verbatim",
+      BracketR,
+      Whitespace,
+      Comment:"# no interpolation",
+      Eol,
+      Comment:"# between",
+      Eol,
+      BracketL,
+      Identifier:"private",
+      BracketR,
+      Eol,
+      Identifier:"foo",
+      Colon,
+      Eol,
+      Indent:" ",
+      Text:"{{hello}}",
+      Eol,
+      Dedent
+    ),
+  }
+
   test! {
     name: tokenize_recipe_complex_interpolation_expression,
     text: "foo: #lol\n {{a + b + \"z\" + blarg}}",
@@ -2132,6 +2431,16 @@ mod tests {
     kind:   UnterminatedInterpolation,
   }
 
+  error! {
+    name:   unterminated_block_comment,
+    input:  "/* hello\n",
+    offset: 0,
+    line:   0,
+    column: 0,
+    width:  2,
+    kind:   UnterminatedBlockComment,
+  }
+
   error! {
     name:   unterminated_backtick,
     input:  "`echo",
@@ -15,46 +15,125 @@
 
 pub(crate) use {
   crate::{
-    alias::Alias, analyzer::Analyzer, assignment::Assignment,
-    assignment_resolver::AssignmentResolver, ast::Ast, attribute::Attribute, binding::Binding,
-    color::Color, color_display::ColorDisplay, command_ext::CommandExt, compilation::Compilation,
-    compile_error::CompileError, compile_error_kind::CompileErrorKind, compiler::Compiler,
-    condition::Condition, conditional_operator::ConditionalOperator, config::Config,
-    config_error::ConfigError, count::Count, delimiter::Delimiter, dependency::Dependency,
-    dump_format::DumpFormat, enclosure::Enclosure, error::Error, evaluator::Evaluator,
-    expression::Expression, fragment::Fragment, function::Function,
-    function_context::FunctionContext, interrupt_guard::InterruptGuard,
-    interrupt_handler::InterruptHandler, item::Item, justfile::Justfile, keyed::Keyed,
-    keyword::Keyword, lexer::Lexer, line::Line, list::List, load_dotenv::load_dotenv,
-    loader::Loader, name::Name, namepath::Namepath, ordinal::Ordinal, output::output,
-    output_error::OutputError, parameter::Parameter, parameter_kind::ParameterKind, parser::Parser,
-    platform::Platform, platform_interface::PlatformInterface, position::Position,
-    positional::Positional, ran::Ran, range_ext::RangeExt, recipe::Recipe,
-    recipe_context::RecipeContext, recipe_resolver::RecipeResolver, scope::Scope, search::Search,
-    search_config::SearchConfig, search_error::SearchError, set::Set, setting::Setting,
-    settings::Settings, shebang::Shebang, shell::Shell, show_whitespace::ShowWhitespace,
-    source::Source, string_kind::StringKind, string_literal::StringLiteral, subcommand::Subcommand,
-    suggestion::Suggestion, table::Table, thunk::Thunk, token::Token, token_kind::TokenKind,
-    unresolved_dependency::UnresolvedDependency, unresolved_recipe::UnresolvedRecipe,
-    use_color::UseColor, variables::Variables, verbosity::Verbosity, warning::Warning,
+    alias::Alias,
+    allow_missing_recipe_args::AllowMissingRecipeArgs,
+    analyzer::Analyzer,
+    assignment::Assignment,
+    assignment_resolver::AssignmentResolver,
+    ast::Ast,
+    attribute::Attribute,
+    attribute_line::AttributeLine,
+    attributes::Attributes,
+    binding::Binding,
+    color::Color,
+    color_display::ColorDisplay,
+    command_ext::{masked_exported_variables, CommandExt},
+    compilation::Compilation,
+    compile_error::CompileError,
+    compile_error_kind::CompileErrorKind,
+    compiler::Compiler,
+    condition::Condition,
+    conditional_operator::ConditionalOperator,
+    config::Config,
+    config_error::ConfigError,
+    count::Count,
+    delimiter::Delimiter,
+    dependency::Dependency,
+    directive::Directive,
+    dotenv_outside_project::DotenvOutsideProject,
+    dump_format::DumpFormat,
+    enclosure::Enclosure,
+    error::Error,
+    evaluator::Evaluator,
+    exit_codes::ExitCodes,
+    expression::Expression,
+    fragment::Fragment,
+    function::Function,
+    function_context::FunctionContext,
+    interrupt_guard::InterruptGuard,
+    interrupt_handler::InterruptHandler,
+    item::Item,
+    jobs::Jobs,
+    justfile::Justfile,
+    keyed::Keyed,
+    keyword::Keyword,
+    lexer::Lexer,
+    line::Line,
+    list::List,
+    list_entry::ListEntry,
+    list_order::ListOrder,
+    load_dotenv::{load_dotenv, load_recipe_dotenv},
+    loader::Loader,
+    lossless::Lossless,
+    lossless_entry::LosslessEntry,
+    name::Name,
+    namepath::Namepath,
+    ordinal::Ordinal,
+    output::output,
+    output_buffer::OutputBuffer,
+    output_error::OutputError,
+    parameter::Parameter,
+    parameter_kind::ParameterKind,
+    parser::Parser,
+    platform::Platform,
+    platform_interface::PlatformInterface,
+    position::Position,
+    positional::Positional,
+    ran::Ran,
+    range_ext::RangeExt,
+    recipe::Recipe,
+    recipe_context::RecipeContext,
+    recipe_resolver::RecipeResolver,
+    scope::Scope,
+    search::Search,
+    search_config::SearchConfig,
+    search_error::SearchError,
+    search_limit::SearchLimit,
+    set::Set,
+    setting::Setting,
+    settings::Settings,
+    shebang::Shebang,
+    shell::Shell,
+    show_whitespace::ShowWhitespace,
+    source::Source,
+    string_kind::StringKind,
+    string_literal::StringLiteral,
+    subcommand::Subcommand,
+    suggestion::Suggestion,
+    table::Table,
+    thunk::Thunk,
+    timings::Timings,
+    token::Token,
+    token_kind::TokenKind,
+    transcript::Transcript,
+    unresolved_dependency::UnresolvedDependency,
+    unresolved_recipe::UnresolvedRecipe,
+    use_color::UseColor,
+    variables::Variables,
+    verbosity::Verbosity,
+    warning::Warning,
+    warning_code::WarningCode,
+    wildcard::wildcard_regex,
   },
   std::{
     cmp,
     collections::{BTreeMap, BTreeSet, HashMap},
     env,
-    ffi::OsString,
+    ffi::{OsStr, OsString},
     fmt::{self, Debug, Display, Formatter},
     fs,
-    io::{self, Write},
+    io::{self, BufRead, Write},
     iter::{self, FromIterator},
     mem,
     ops::Deref,
     ops::{Index, Range, RangeInclusive},
     path::{self, Path, PathBuf},
     process::{self, Command, ExitStatus, Stdio},
-    rc::Rc,
-    str::{self, Chars},
-    sync::{Mutex, MutexGuard},
+    slice,
+    str::{self, Chars, FromStr},
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    thread,
+    time::{Duration, Instant},
     vec,
   },
   {
@@ -62,15 +141,14 @@ pub(crate) use {
     derivative::Derivative,
     edit_distance::edit_distance,
     lexiclean::Lexiclean,
-    libc::EXIT_FAILURE,
-    log::{info, warn},
+    log::info,
     regex::Regex,
     serde::{
       ser::{SerializeMap, SerializeSeq},
       Serialize, Serializer,
     },
     snafu::{ResultExt, Snafu},
-    strum::{Display, EnumString, IntoStaticStr},
+    strum::{Display, EnumString, IntoStaticStr, VariantNames},
     typed_arena::Arena,
     unicode_width::{UnicodeWidthChar, UnicodeWidthStr},
   },
@@ -79,7 +157,18 @@ pub(crate) use {
 #[cfg(test)]
 pub(crate) use crate::{node::Node, tree::Tree};
 
-pub use crate::run::run;
+#[cfg(feature = "native")]
+pub(crate) use {libc::EXIT_FAILURE, log::warn};
+
+#[cfg(not(feature = "native"))]
+pub(crate) const EXIT_FAILURE: i32 = 1;
+
+pub use crate::{
+  dump::{compile, load},
+  events::Events,
+  run::run,
+  runner::Runner,
+};
 
 // Used in integration tests.
 #[doc(hidden)]
@@ -104,6 +193,10 @@ pub mod node;
 #[cfg(fuzzing)]
 pub mod fuzzing;
 
+pub mod dump;
+
+pub mod runner;
+
 // Used by Janus, https://github.com/casey/janus, a tool
 // that analyses all public justfiles on GitHub to avoid
 // breaking changes.
@@ -111,11 +204,14 @@ pub mod fuzzing;
 pub mod summary;
 
 mod alias;
+mod allow_missing_recipe_args;
 mod analyzer;
 mod assignment;
 mod assignment_resolver;
 mod ast;
 mod attribute;
+mod attribute_line;
+mod attributes;
 mod binding;
 mod color;
 mod color_display;
@@ -132,10 +228,14 @@ mod config_error;
 mod count;
 mod delimiter;
 mod dependency;
+mod directive;
+mod dotenv_outside_project;
 mod dump_format;
 mod enclosure;
 mod error;
 mod evaluator;
+mod events;
+mod exit_codes;
 mod expression;
 mod fragment;
 mod function;
@@ -143,18 +243,24 @@ mod function_context;
 mod interrupt_guard;
 mod interrupt_handler;
 mod item;
+mod jobs;
 mod justfile;
 mod keyed;
 mod keyword;
 mod lexer;
 mod line;
 mod list;
+mod list_entry;
+mod list_order;
 mod load_dotenv;
 mod loader;
+mod lossless;
+mod lossless_entry;
 mod name;
 mod namepath;
 mod ordinal;
 mod output;
+mod output_buffer;
 mod output_error;
 mod parameter;
 mod parameter_kind;
@@ -173,6 +279,7 @@ mod scope;
 mod search;
 mod search_config;
 mod search_error;
+mod search_limit;
 mod set;
 mod setting;
 mod settings;
@@ -186,8 +293,10 @@ mod subcommand;
 mod suggestion;
 mod table;
 mod thunk;
+mod timings;
 mod token;
 mod token_kind;
+mod transcript;
 mod unindent;
 mod unresolved_dependency;
 mod unresolved_recipe;
@@ -195,3 +304,5 @@ mod use_color;
 mod variables;
 mod verbosity;
 mod warning;
+mod warning_code;
+mod wildcard;
@@ -41,6 +41,27 @@ impl<'src> Line<'src> {
     )
   }
 
+  /// Text of a line in the body of a `[verbatim]` recipe, which is lexed
+  /// without interpolations, and in which `{{{{` is not an escape
+  pub(crate) fn verbatim(&self, continued: bool) -> String {
+    let text = self
+      .fragments
+      .iter()
+      .map(|fragment| match fragment {
+        Fragment::Text { token } => token.lexeme(),
+        Fragment::Interpolation { .. } => {
+          unreachable!("the parser rejects verbatim recipes with interpolations")
+        }
+      })
+      .collect::<String>();
+
+    if continued {
+      text.trim_start().into()
+    } else {
+      text
+    }
+  }
+
   pub(crate) fn is_infallible(&self) -> bool {
     matches!(
       self.fragments.first(),
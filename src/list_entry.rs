@@ -0,0 +1,18 @@
+use super::*;
+
+/// A line of `--list` output, for a recipe or one of its aliases
+pub(crate) struct ListEntry<'a> {
+  pub(crate) doc: Option<String>,
+  pub(crate) file_path: &'a Path,
+  pub(crate) groups: BTreeSet<String>,
+  pub(crate) name: String,
+  pub(crate) parameters: String,
+  pub(crate) parameters_width: usize,
+}
+
+impl<'a> ListEntry<'a> {
+  /// Display width of the name and parameters, used to align doc comments
+  pub(crate) fn width(&self) -> usize {
+    UnicodeWidthStr::width(self.name.as_str()) + self.parameters_width
+  }
+}
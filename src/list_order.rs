@@ -0,0 +1,12 @@
+use super::*;
+
+/// The order in which recipes are listed, set with `set list-order`
+#[derive(
+  Clone, Copy, Debug, Display, EnumString, IntoStaticStr, PartialEq, Serialize, VariantNames,
+)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum ListOrder {
+  Alpha,
+  Source,
+}
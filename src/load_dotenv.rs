@@ -5,25 +5,35 @@ const DEFAULT_DOTENV_FILENAME: &str = ".env";
 pub(crate) fn load_dotenv(
   config: &Config,
   settings: &Settings,
-  working_directory: &Path,
+  search: &Search,
 ) -> RunResult<'static, BTreeMap<String, String>> {
+  let working_directory: &Path = &search.working_directory;
+
   let dotenv_filename = config
     .dotenv_filename
     .as_ref()
     .or(settings.dotenv_filename.as_ref());
 
-  let dotenv_path = config
+  let dotenv_paths = config
     .dotenv_path
     .as_ref()
-    .or(settings.dotenv_path.as_ref());
+    .map_or(settings.dotenv_path.as_slice(), slice::from_ref);
 
-  if !settings.dotenv_load.unwrap_or_default() && dotenv_filename.is_none() && dotenv_path.is_none()
+  if !settings.dotenv_load.unwrap_or_default()
+    && !settings.dotenv_override
+    && dotenv_filename.is_none()
+    && dotenv_paths.is_empty()
   {
     return Ok(BTreeMap::new());
   }
 
-  if let Some(path) = dotenv_path {
-    return load_from_file(&working_directory.join(path));
+  if !dotenv_paths.is_empty() {
+    let paths = dotenv_paths
+      .iter()
+      .map(|path| working_directory.join(path))
+      .collect::<Vec<PathBuf>>();
+
+    return load_from_files(&paths, settings.dotenv_override);
   }
 
   let filename = dotenv_filename.map_or(DEFAULT_DOTENV_FILENAME, |s| s.as_str());
@@ -31,19 +41,70 @@ pub(crate) fn load_dotenv(
   for directory in working_directory.ancestors() {
     let path = directory.join(filename);
     if path.is_file() {
-      return load_from_file(&path);
+      let justfile_directory = search.justfile.parent().unwrap_or(working_directory);
+      if directory != working_directory && !directory.starts_with(justfile_directory) {
+        check_outside_project(config, settings, path.clone())?;
+      }
+      return load_from_files(&[path], settings.dotenv_override);
     }
   }
 
   Ok(BTreeMap::new())
 }
 
-fn load_from_file(path: &Path) -> RunResult<'static, BTreeMap<String, String>> {
-  let iter = dotenvy::from_path_iter(path)?;
+/// Warn about or refuse a dotenv file found in a parent of the justfile
+/// directory, such as `$HOME`, according to the `dotenv-outside-project`
+/// setting
+fn check_outside_project(
+  config: &Config,
+  settings: &Settings,
+  path: PathBuf,
+) -> RunResult<'static, ()> {
+  match settings.dotenv_outside_project {
+    Some(DotenvOutsideProject::Error) => return Err(Error::DotenvOutsideProject { path }),
+    Some(DotenvOutsideProject::Warn) | None => {
+      if !settings.allows(WarningCode::DotenvOutsideProject) {
+        Warning::DotenvOutsideProject { path }.report(config, config.verbosity)?;
+      }
+    }
+  }
+
+  Ok(())
+}
+
+/// Load the dotenv file at `path`, given by a recipe's `[dotenv(PATH)]`
+/// attribute, on top of the justfile's dotenv variables
+pub(crate) fn load_recipe_dotenv(
+  settings: &Settings,
+  working_directory: &Path,
+  path: &str,
+  dotenv: &BTreeMap<String, String>,
+) -> RunResult<'static, BTreeMap<String, String>> {
+  let mut dotenv = dotenv.clone();
+  dotenv.extend(load_from_files(
+    &[working_directory.join(path)],
+    settings.dotenv_override,
+  )?);
+  Ok(dotenv)
+}
+
+fn load_from_files(
+  paths: &[PathBuf],
+  dotenv_override: bool,
+) -> RunResult<'static, BTreeMap<String, String>> {
+  // Files are parsed as one, so that `${VAR}` in a later file can refer to a
+  // key from an earlier file, and later keys override earlier ones
+  let mut contents = Vec::new();
+  for path in paths {
+    contents.extend(fs::read(path).map_err(dotenvy::Error::Io)?);
+    contents.push(b'\n');
+  }
+
   let mut dotenv = BTreeMap::new();
-  for result in iter {
+  for result in dotenvy::from_read_iter(contents.as_slice()) {
     let (key, value) = result?;
-    if env::var_os(&key).is_none() {
+    // Unless overriding, variables already in the environment take precedence
+    if dotenv_override || env::var_os(&key).is_none() {
       dotenv.insert(key, value);
     }
   }
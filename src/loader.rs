@@ -1,4 +1,7 @@
-use super::*;
+use {super::*, std::io::Read};
+
+/// Path used in error messages for justfiles read from standard input
+const STDIN_PATH: &str = "<stdin>";
 
 pub(crate) struct Loader {
   srcs: Arena<String>,
@@ -13,22 +16,74 @@ impl Loader {
     }
   }
 
-  pub(crate) fn load<'src>(
+  /// Read the justfiles at `paths` concurrently. Results are returned in
+  /// the same order as `paths`, as the path of each justfile relative to
+  /// `root` and its source.
+  pub(crate) fn load_all<'src>(
     &'src self,
     root: &Path,
-    path: &Path,
-  ) -> RunResult<(&'src Path, &'src str)> {
-    let src = fs::read_to_string(path).map_err(|io_error| Error::Load {
-      path: path.to_owned(),
-      io_error,
-    })?;
-
-    let relative = if let Ok(path) = path.strip_prefix(root.parent().unwrap()) {
-      path
+    paths: &[&Path],
+  ) -> Vec<RunResult<'src, (&'src Path, &'src str)>> {
+    let srcs = if paths.len() > 1 {
+      thread::scope(|scope| {
+        paths
+          .iter()
+          .map(|path| scope.spawn(move || Self::read(path)))
+          .collect::<Vec<_>>()
+          .into_iter()
+          .map(|handle| handle.join().unwrap())
+          .collect::<Vec<io::Result<String>>>()
+      })
     } else {
-      path
+      paths.iter().map(|path| Self::read(path)).collect()
     };
 
-    Ok((self.paths.alloc(relative.into()), self.srcs.alloc(src)))
+    paths
+      .iter()
+      .zip(srcs)
+      .map(|(path, src)| {
+        let src = src.map_err(|io_error| Error::Load {
+          path: path.to_path_buf(),
+          io_error,
+        })?;
+
+        let relative = if let Ok(path) = path.strip_prefix(root.parent().unwrap()) {
+          path
+        } else {
+          path
+        };
+
+        Ok((
+          &**self.paths.alloc(relative.into()),
+          &**self.srcs.alloc(src),
+        ))
+      })
+      .collect()
+  }
+
+  /// Read a justfile from standard input, for formatting unsaved buffers
+  pub(crate) fn load_stdin(&self) -> RunResult<(&Path, &str)> {
+    let path = Path::new(STDIN_PATH);
+
+    let mut src = String::new();
+
+    io::stdin()
+      .read_to_string(&mut src)
+      .map_err(|io_error| Error::Load {
+        path: path.into(),
+        io_error,
+      })?;
+
+    Ok((path, &**self.srcs.alloc(src)))
+  }
+
+  #[cfg(feature = "native")]
+  fn read(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+  }
+
+  #[cfg(not(feature = "native"))]
+  fn read(_path: &Path) -> io::Result<String> {
+    Err(Platform::unsupported("loading justfiles"))
   }
 }
@@ -0,0 +1,72 @@
+use super::*;
+
+/// A lossless representation of a justfile, produced by `--dump-format
+/// json-lossless`. Every byte of the source belongs to exactly one entry, so
+/// concatenating the text of each entry reconstructs the original file.
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct Lossless<'src> {
+  pub(crate) entries: Vec<LosslessEntry<'src>>,
+}
+
+impl<'src> Lossless<'src> {
+  pub(crate) fn new(src: &'src str, ast: &Ast<'src>) -> Self {
+    let mut entries = Vec::new();
+    let mut offset = 0;
+
+    for (item, span) in ast.items.iter().zip(&ast.spans) {
+      if offset < span.start {
+        entries.push(LosslessEntry::whitespace(src, offset..span.start));
+      }
+
+      entries.push(LosslessEntry::item(src, item, span.clone()));
+
+      offset = span.end;
+    }
+
+    if offset < src.len() {
+      entries.push(LosslessEntry::whitespace(src, offset..src.len()));
+    }
+
+    Self { entries }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn lossless(src: &str) -> String {
+    let tokens = Lexer::test_lex(src).unwrap();
+    let ast = Parser::parse(
+      &PathBuf::new(),
+      &Namepath::default(),
+      0,
+      &tokens,
+      &PathBuf::new(),
+    )
+    .unwrap();
+
+    Lossless::new(src, &ast)
+      .entries
+      .iter()
+      .map(|entry| entry.text)
+      .collect()
+  }
+
+  #[test]
+  fn reconstructs_source() {
+    for src in [
+      "",
+      "\n\n",
+      "\u{feff}foo:\n",
+      "# comment\n\nfoo := 'bar' # trailing\n\n\n# doc\n[private]\nbaz: # header\n  echo baz\n\n",
+      "set shell := ['bash', '-c'] # shell\nimport? 'foo' # import\nmod bar # module\nalias b := baz\n",
+      "x := (\n  'a' +\n  'b'\n)\nfoo:\n  #!/bin/sh\n\n  echo {{x}}\n",
+      "@foo:\n\techo foo",
+      "[no-cd] # no cd\n# between\n[private]\nfoo:\n",
+      "x := ( # open\n  'a' + # a\n  /* b */ 'b'\n) # close\n",
+    ] {
+      assert_eq!(lossless(src), src);
+    }
+  }
+}
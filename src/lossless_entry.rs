@@ -0,0 +1,43 @@
+use super::*;
+
+/// An entry in a `Lossless` dump: either an item, or the whitespace between
+/// items.
+#[derive(Debug, PartialEq, Serialize)]
+pub(crate) struct LosslessEntry<'src> {
+  pub(crate) end: usize,
+  pub(crate) kind: &'static str,
+  pub(crate) name: Option<&'src str>,
+  pub(crate) start: usize,
+  pub(crate) text: &'src str,
+}
+
+impl<'src> LosslessEntry<'src> {
+  pub(crate) fn item(src: &'src str, item: &Item<'src>, span: Range<usize>) -> Self {
+    let (kind, name) = match item {
+      Item::Alias(alias) => ("alias", Some(alias.name.lexeme())),
+      Item::Assignment(assignment) => ("assignment", Some(assignment.name.lexeme())),
+      Item::Comment(_) => ("comment", None),
+      Item::Import { .. } => ("import", None),
+      Item::Module { name, .. } => ("module", Some(name.lexeme())),
+      Item::Recipe(recipe) => ("recipe", Some(recipe.name.lexeme())),
+      Item::Set(set) => ("set", Some(set.name.lexeme())),
+      Item::TrailingComment(_) => ("trailing-comment", None),
+    };
+
+    Self::new(src, kind, name, span)
+  }
+
+  pub(crate) fn whitespace(src: &'src str, span: Range<usize>) -> Self {
+    Self::new(src, "whitespace", None, span)
+  }
+
+  fn new(src: &'src str, kind: &'static str, name: Option<&'src str>, span: Range<usize>) -> Self {
+    Self {
+      kind,
+      name,
+      start: span.start,
+      end: span.end,
+      text: &src[span],
+    }
+  }
+}
@@ -7,22 +7,30 @@ impl<'src> Namepath<'src> {
   pub(crate) fn join(&self, name: Name<'src>) -> Self {
     Self(self.0.iter().copied().chain(iter::once(name)).collect())
   }
-}
 
-impl<'str> Serialize for Namepath<'str> {
-  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-  where
-    S: Serializer,
-  {
-    let mut path = String::new();
+  /// Names of the modules containing the item this path refers to
+  pub(crate) fn modules(&self) -> &[Name<'src>] {
+    &self.0[..self.0.len().saturating_sub(1)]
+  }
+}
 
+impl<'src> Display for Namepath<'src> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     for (i, name) in self.0.iter().enumerate() {
       if i > 0 {
-        path.push_str("::");
+        write!(f, "::")?;
       }
-      path.push_str(name.lexeme());
+      write!(f, "{name}")?;
     }
+    Ok(())
+  }
+}
 
-    serializer.serialize_str(&path)
+impl<'str> Serialize for Namepath<'str> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
   }
 }
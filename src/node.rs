@@ -54,6 +54,10 @@ impl<'src> Node<'src> for Item<'src> {
       }
       Self::Recipe(recipe) => recipe.tree(),
       Self::Set(set) => set.tree(),
+      Self::TrailingComment(comment) => Tree::atom("comment")
+        .push("#")
+        .push("trailing")
+        .push(["\"", comment, "\""].concat()),
     }
   }
 }
@@ -68,15 +72,28 @@ impl<'src> Node<'src> for Alias<'src, Name<'src>> {
 
 impl<'src> Node<'src> for Assignment<'src> {
   fn tree(&self) -> Tree<'src> {
+    let mut tree = Tree::atom("assignment");
+
+    if !self.choices.is_empty() {
+      tree.push_mut("#");
+      tree.push_mut("choices");
+    }
+
+    if self.secret {
+      tree.push_mut("#");
+      tree.push_mut("secret");
+    }
+
     if self.export {
-      Tree::atom("assignment")
-        .push("#")
-        .push(Keyword::Export.lexeme())
-    } else {
-      Tree::atom("assignment")
+      tree.push_mut("#");
+      tree.push_mut(Keyword::Export.lexeme());
     }
-    .push(self.name.lexeme())
-    .push(self.value.tree())
+
+    if let Some(doc) = self.doc {
+      tree.push_mut(Tree::string(doc));
+    }
+
+    tree.push(self.name.lexeme()).push(self.value.tree())
   }
 }
 
@@ -105,7 +122,7 @@ impl<'src> Node<'src> for Expression<'src> {
         tree.push_mut(otherwise.tree());
         tree
       }
-      Self::Call { thunk } => {
+      Self::Call { thunk, .. } => {
         use Thunk::*;
 
         let mut tree = Tree::atom("call");
@@ -125,6 +142,17 @@ impl<'src> Node<'src> for Expression<'src> {
               tree.push_mut(b.tree());
             }
           }
+          UnaryPlus {
+            name,
+            args: (a, rest),
+            ..
+          } => {
+            tree.push_mut(name.lexeme());
+            tree.push_mut(a.tree());
+            for arg in rest {
+              tree.push_mut(arg.tree());
+            }
+          }
           Binary {
             name, args: [a, b], ..
           } => {
@@ -154,6 +182,17 @@ impl<'src> Node<'src> for Expression<'src> {
             tree.push_mut(b.tree());
             tree.push_mut(c.tree());
           }
+          Quaternary {
+            name,
+            args: [a, b, c, d],
+            ..
+          } => {
+            tree.push_mut(name.lexeme());
+            tree.push_mut(a.tree());
+            tree.push_mut(b.tree());
+            tree.push_mut(c.tree());
+            tree.push_mut(d.tree());
+          }
         }
 
         tree
@@ -213,6 +252,13 @@ impl<'src> Node<'src> for UnresolvedRecipe<'src> {
           d.push_mut(argument.tree());
         }
 
+        if let Some(Condition { lhs, rhs, operator }) = &dependency.condition {
+          d.push_mut(Keyword::If.lexeme());
+          d.push_mut(lhs.tree());
+          d.push_mut(operator.to_string());
+          d.push_mut(rhs.tree());
+        }
+
         if i < self.priors {
           dependencies.push_mut(d);
         } else {
@@ -229,6 +275,10 @@ impl<'src> Node<'src> for UnresolvedRecipe<'src> {
       }
     }
 
+    if let Some(comment) = self.comment {
+      t.push_mut(comment.tree());
+    }
+
     if !self.body.is_empty() {
       t.push_mut(Tree::atom("body").extend(self.body.iter().map(Node::tree)));
     }
@@ -241,6 +291,18 @@ impl<'src> Node<'src> for Parameter<'src> {
   fn tree(&self) -> Tree<'src> {
     let mut children = vec![Tree::atom(self.name.lexeme())];
 
+    if let Some(export_name) = self.export_name {
+      children.push(Tree::atom(Keyword::As.lexeme()));
+      children.push(Tree::atom(export_name.lexeme()));
+    }
+
+    if let Some(pattern) = &self.pattern {
+      children.push(Tree::list([
+        Tree::atom("pattern"),
+        Tree::string(&pattern.cooked),
+      ]));
+    }
+
     if let Some(default) = &self.default {
       children.push(default.tree());
     }
@@ -273,14 +335,30 @@ impl<'src> Node<'src> for Set<'src> {
       Setting::AllowDuplicateRecipes(value)
       | Setting::AllowDuplicateVariables(value)
       | Setting::DotenvLoad(value)
+      | Setting::DotenvOverride(value)
       | Setting::Export(value)
       | Setting::Fallback(value)
+      | Setting::NormalizeRecipeNames(value)
+      | Setting::Parallel(value)
       | Setting::PositionalArguments(value)
+      | Setting::PrefixMatch(value)
       | Setting::Quiet(value)
       | Setting::WindowsPowerShell(value)
       | Setting::IgnoreComments(value) => {
         set.push_mut(value.to_string());
       }
+      Setting::AllowMissingRecipeArgs(value) => {
+        set.push_mut(Tree::string(value.to_string()));
+      }
+      Setting::DotenvOutsideProject(value) => {
+        set.push_mut(Tree::string(value.to_string()));
+      }
+      Setting::ListOrder(value) => {
+        set.push_mut(Tree::string(value.to_string()));
+      }
+      Setting::FallbackDepth(depth) => {
+        set.push_mut(Tree::string(depth.to_string()));
+      }
       Setting::Shell(Shell { command, arguments })
       | Setting::WindowsShell(Shell { command, arguments }) => {
         set.push_mut(Tree::string(&command.cooked));
@@ -288,9 +366,22 @@ impl<'src> Node<'src> for Set<'src> {
           set.push_mut(Tree::string(&argument.cooked));
         }
       }
-      Setting::DotenvFilename(value) | Setting::DotenvPath(value) | Setting::Tempdir(value) => {
+      Setting::Chooser(value) | Setting::Default(value) | Setting::DotenvFilename(value) => {
         set.push_mut(Tree::string(value));
       }
+      Setting::Tempdir(expression) => {
+        set.push_mut(expression.tree());
+      }
+      Setting::Allow(values)
+      | Setting::DotenvPath(values)
+      | Setting::EnvAllow(values)
+      | Setting::EnvDeny(values)
+      | Setting::ShellArgs(values)
+      | Setting::TraceRedact(values) => {
+        for value in values {
+          set.push_mut(Tree::string(value));
+        }
+      }
     }
 
     set
@@ -2,7 +2,7 @@ use super::*;
 
 /// Run a command and return the data it wrote to stdout as a string
 pub(crate) fn output(mut command: Command) -> Result<String, OutputError> {
-  match command.output() {
+  match command.spawn_output() {
     Ok(output) => {
       if let Some(code) = output.status.code() {
         if code != 0 {
@@ -0,0 +1,42 @@
+use super::*;
+
+/// Output of a prior dependency run in parallel with its siblings, collected
+/// so that it can be written all at once when the dependency finishes, instead
+/// of being interleaved with the output of the others
+#[derive(Default)]
+pub(crate) struct OutputBuffer {
+  stderr: Mutex<Vec<u8>>,
+  stdout: Mutex<Vec<u8>>,
+}
+
+impl OutputBuffer {
+  /// Append `line` to buffered stderr
+  pub(crate) fn echo(&self, line: impl Display) {
+    writeln!(self.stderr.lock().unwrap(), "{line}").ok();
+  }
+
+  /// Run `command` to completion, appending its output to the buffer
+  pub(crate) fn spawn(&self, command: &mut Command) -> io::Result<ExitStatus> {
+    let output = InterruptHandler::guard(|| command.spawn_output())?;
+    self.stdout.lock().unwrap().extend(output.stdout);
+    self.stderr.lock().unwrap().extend(output.stderr);
+    Ok(output.status)
+  }
+
+  /// Write buffered output to `parent`, if this dependency was itself run in
+  /// parallel, or to stdout and stderr otherwise
+  pub(crate) fn flush(self, parent: Option<&OutputBuffer>) -> io::Result<()> {
+    let stdout = self.stdout.into_inner().unwrap();
+    let stderr = self.stderr.into_inner().unwrap();
+
+    if let Some(parent) = parent {
+      parent.stdout.lock().unwrap().extend(stdout);
+      parent.stderr.lock().unwrap().extend(stderr);
+    } else {
+      io::stdout().write_all(&stdout)?;
+      io::stderr().write_all(&stderr)?;
+    }
+
+    Ok(())
+  }
+}
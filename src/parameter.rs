@@ -7,10 +7,37 @@ pub(crate) struct Parameter<'src> {
   pub(crate) default: Option<Expression<'src>>,
   /// Export parameter as environment variable
   pub(crate) export: bool,
+  /// Name of exported environment variable, if not the parameter name
+  pub(crate) export_name: Option<Name<'src>>,
   /// The kind of parameter
   pub(crate) kind: ParameterKind,
   /// The parameter name
   pub(crate) name: Name<'src>,
+  /// An optional regular expression which arguments must match
+  pub(crate) pattern: Option<StringLiteral<'src>>,
+  /// Mask argument in echoed commands and other output, set by the recipe's
+  /// `[secret]` attribute
+  pub(crate) secret: bool,
+}
+
+impl<'src> Parameter<'src> {
+  pub(crate) fn check_argument(&self, argument: &str) -> RunResult<'src, ()> {
+    let Some(pattern) = &self.pattern else {
+      return Ok(());
+    };
+
+    let regex = Regex::new(&pattern.cooked).map_err(|source| Error::RegexCompile { source })?;
+
+    if !regex.is_match(argument) {
+      return Err(Error::ArgumentPattern {
+        parameter: self.name,
+        argument: argument.into(),
+        pattern: pattern.cooked.clone(),
+      });
+    }
+
+    Ok(())
+  }
 }
 
 impl<'src> ColorDisplay for Parameter<'src> {
@@ -22,6 +49,12 @@ impl<'src> ColorDisplay for Parameter<'src> {
       write!(f, "$")?;
     }
     write!(f, "{}", color.parameter().paint(self.name.lexeme()))?;
+    if let Some(export_name) = self.export_name {
+      write!(f, " {} {}", Keyword::As.lexeme(), export_name.lexeme())?;
+    }
+    if let Some(ref pattern) = self.pattern {
+      write!(f, "=~{}", color.string().paint(&pattern.to_string()))?;
+    }
     if let Some(ref default) = self.default {
       write!(f, "={}", color.string().paint(&default.to_string()))?;
     }
@@ -10,6 +10,8 @@ pub(crate) enum ParameterKind {
   Plus,
   /// …variadic, accepting zero or more arguments
   Star,
+  /// …passthrough, accepting the arguments after `--` verbatim
+  Passthrough,
 }
 
 impl ParameterKind {
@@ -18,10 +20,11 @@ impl ParameterKind {
       Self::Singular => None,
       Self::Plus => Some("+"),
       Self::Star => Some("*"),
+      Self::Passthrough => Some("--"),
     }
   }
 
   pub(crate) fn is_variadic(self) -> bool {
-    self != Self::Singular
+    matches!(self, Self::Plus | Self::Star)
   }
 }
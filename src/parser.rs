@@ -149,15 +149,25 @@ impl<'run, 'src> Parser<'run, 'src> {
     }
   }
 
-  /// Return an unexpected token error if the next token is not an EOL
-  fn expect_eol(&mut self) -> CompileResult<'src> {
-    self.accept(Comment)?;
+  /// Return an unexpected token error if the next token is not an EOL,
+  /// returning the preceding comment, if any
+  fn expect_eol(&mut self) -> CompileResult<'src, Option<&'src str>> {
+    let comment = self.accept_comment()?;
 
-    if self.next_is(Eof) {
-      return Ok(());
+    if !self.next_is(Eof) {
+      self.expect(Eol)?;
     }
 
-    self.expect(Eol).map(|_| ())
+    Ok(comment)
+  }
+
+  /// Accept a comment, returning its contents without trailing whitespace
+  fn accept_comment(&mut self) -> CompileResult<'src, Option<&'src str>> {
+    Ok(
+      self
+        .accept(Comment)?
+        .map(|comment| comment.lexeme().trim_end()),
+    )
   }
 
   fn expect_keyword(&mut self, expected: Keyword) -> CompileResult<'src> {
@@ -269,6 +279,7 @@ impl<'run, 'src> Parser<'run, 'src> {
     if let Some(recipe) = self.accept_name()? {
       Ok(Some(UnresolvedDependency {
         arguments: Vec::new(),
+        condition: None,
         recipe,
       }))
     } else if self.accepted(ParenL)? {
@@ -276,11 +287,31 @@ impl<'run, 'src> Parser<'run, 'src> {
 
       let mut arguments = Vec::new();
 
+      let mut condition = None;
+
       while !self.accepted(ParenR)? {
-        arguments.push(self.parse_expression()?);
+        if self.accepted_keyword(Keyword::If)? {
+          // `if` either begins a conditional expression argument, or, if the
+          // condition isn't followed by `{`, the dependency's condition
+          let parsed = self.parse_condition()?;
+
+          if self.next_is(BraceL) {
+            arguments.push(self.parse_conditional_branches(parsed)?);
+          } else {
+            condition = Some(parsed);
+            self.expect(ParenR)?;
+            break;
+          }
+        } else {
+          arguments.push(self.parse_expression()?);
+        }
       }
 
-      Ok(Some(UnresolvedDependency { recipe, arguments }))
+      Ok(Some(UnresolvedDependency {
+        recipe,
+        arguments,
+        condition,
+      }))
     } else {
       Ok(None)
     }
@@ -295,12 +326,18 @@ impl<'run, 'src> Parser<'run, 'src> {
   fn parse_ast(mut self) -> CompileResult<'src, Ast<'src>> {
     fn pop_doc_comment<'src>(
       items: &mut Vec<Item<'src>>,
+      spans: &mut Vec<Range<usize>>,
+      start: &mut usize,
       eol_since_last_comment: bool,
     ) -> Option<&'src str> {
       if !eol_since_last_comment {
         if let Some(Item::Comment(contents)) = items.last() {
-          let doc = Some(contents[1..].trim_start());
+          // block comments are never doc comments
+          let doc = Some(contents.strip_prefix('#')?.trim_start());
           items.pop();
+          if let Some(span) = spans.pop() {
+            *start = span.start;
+          }
           return doc;
         }
       }
@@ -310,6 +347,10 @@ impl<'run, 'src> Parser<'run, 'src> {
 
     let mut items = Vec::new();
 
+    let mut spans = Vec::new();
+
+    let mut verbatim = BTreeMap::new();
+
     let mut eol_since_last_comment = false;
 
     self.accept(ByteOrderMark)?;
@@ -317,8 +358,12 @@ impl<'run, 'src> Parser<'run, 'src> {
     loop {
       let next = self.next()?;
 
-      if let Some(comment) = self.accept(Comment)? {
-        items.push(Item::Comment(comment.lexeme().trim_end()));
+      let first = self.next_token;
+
+      let mut start = next.offset;
+
+      if let Some(comment) = self.accept_comment()? {
+        items.push(Item::Comment(comment));
         self.expect_eol()?;
         eol_since_last_comment = false;
       } else if self.accepted(Eol)? {
@@ -328,11 +373,18 @@ impl<'run, 'src> Parser<'run, 'src> {
       } else if self.next_is(Identifier) {
         match Keyword::from_lexeme(next.lexeme()) {
           Some(Keyword::Alias) if self.next_are(&[Identifier, Identifier, ColonEquals]) => {
-            items.push(Item::Alias(self.parse_alias(BTreeSet::new())?));
+            items.push(Item::Alias(self.parse_alias(Attributes::default())?));
+            items.extend(self.expect_eol()?.map(Item::TrailingComment));
           }
           Some(Keyword::Export) if self.next_are(&[Identifier, Identifier, ColonEquals]) => {
             self.presume_keyword(Keyword::Export)?;
-            items.push(Item::Assignment(self.parse_assignment(true)?));
+            let doc = pop_doc_comment(&mut items, &mut spans, &mut start, eol_since_last_comment);
+            items.push(Item::Assignment(self.parse_assignment(
+              true,
+              doc,
+              Attributes::default(),
+            )?));
+            items.extend(self.expect_eol()?.map(Item::TrailingComment));
           }
           Some(Keyword::Import)
             if self.next_are(&[Identifier, StringToken])
@@ -347,11 +399,13 @@ impl<'run, 'src> Parser<'run, 'src> {
               path,
               relative,
             });
+            items.extend(self.accept_comment()?.map(Item::TrailingComment));
           }
           Some(Keyword::Mod)
             if self.next_are(&[Identifier, Identifier, StringToken])
               || self.next_are(&[Identifier, Identifier, Eof])
               || self.next_are(&[Identifier, Identifier, Eol])
+              || self.next_are(&[Identifier, Identifier, Comment])
               || self.next_are(&[Identifier, QuestionMark]) =>
           {
             self.presume_keyword(Keyword::Mod)?;
@@ -372,6 +426,7 @@ impl<'run, 'src> Parser<'run, 'src> {
               optional,
               relative,
             });
+            items.extend(self.accept_comment()?.map(Item::TrailingComment));
           }
           Some(Keyword::Set)
             if self.next_are(&[Identifier, Identifier, ColonEquals])
@@ -381,48 +436,87 @@ impl<'run, 'src> Parser<'run, 'src> {
               || self.next_are(&[Identifier, Identifier, Eol]) =>
           {
             items.push(Item::Set(self.parse_set()?));
+            items.extend(self.accept_comment()?.map(Item::TrailingComment));
           }
           _ => {
             if self.next_are(&[Identifier, ColonEquals]) {
-              items.push(Item::Assignment(self.parse_assignment(false)?));
+              let doc = pop_doc_comment(&mut items, &mut spans, &mut start, eol_since_last_comment);
+              items.push(Item::Assignment(self.parse_assignment(
+                false,
+                doc,
+                Attributes::default(),
+              )?));
+              items.extend(self.expect_eol()?.map(Item::TrailingComment));
             } else {
-              let doc = pop_doc_comment(&mut items, eol_since_last_comment);
+              let doc = pop_doc_comment(&mut items, &mut spans, &mut start, eol_since_last_comment);
               items.push(Item::Recipe(self.parse_recipe(
                 doc,
                 false,
-                BTreeSet::new(),
+                Attributes::default(),
               )?));
             }
           }
         }
       } else if self.accepted(At)? {
-        let doc = pop_doc_comment(&mut items, eol_since_last_comment);
+        let doc = pop_doc_comment(&mut items, &mut spans, &mut start, eol_since_last_comment);
         items.push(Item::Recipe(self.parse_recipe(
           doc,
           true,
-          BTreeSet::new(),
+          Attributes::default(),
         )?));
       } else if let Some(attributes) = self.parse_attributes()? {
         let next_keyword = Keyword::from_lexeme(self.next()?.lexeme());
         match next_keyword {
           Some(Keyword::Alias) if self.next_are(&[Identifier, Identifier, ColonEquals]) => {
             items.push(Item::Alias(self.parse_alias(attributes)?));
+            items.extend(self.expect_eol()?.map(Item::TrailingComment));
+          }
+          Some(Keyword::Export) if self.next_are(&[Identifier, Identifier, ColonEquals]) => {
+            self.presume_keyword(Keyword::Export)?;
+            let doc = pop_doc_comment(&mut items, &mut spans, &mut start, eol_since_last_comment);
+            items.push(Item::Assignment(
+              self.parse_assignment(true, doc, attributes)?,
+            ));
+            items.extend(self.expect_eol()?.map(Item::TrailingComment));
+          }
+          _ if self.next_are(&[Identifier, ColonEquals]) => {
+            let doc = pop_doc_comment(&mut items, &mut spans, &mut start, eol_since_last_comment);
+            items.push(Item::Assignment(
+              self.parse_assignment(false, doc, attributes)?,
+            ));
+            items.extend(self.expect_eol()?.map(Item::TrailingComment));
           }
           _ => {
             let quiet = self.accepted(At)?;
-            let doc = pop_doc_comment(&mut items, eol_since_last_comment);
+            let doc = pop_doc_comment(&mut items, &mut spans, &mut start, eol_since_last_comment);
             items.push(Item::Recipe(self.parse_recipe(doc, quiet, attributes)?));
           }
         }
       } else {
         return Err(self.unexpected_token()?);
       }
+
+      let index = spans.len();
+
+      self.record_spans(&items, &mut spans, start, first);
+
+      // comments inside expressions are lexed as whitespace
+      if let Some(span) = spans.get(index) {
+        if self.tokens[first..self.next_token]
+          .iter()
+          .any(|token| token.kind == Whitespace && token.lexeme().starts_with(['#', '/']))
+        {
+          verbatim.insert(index, &next.src[span.clone()]);
+        }
+      }
     }
 
     if self.next_token == self.tokens.len() {
       Ok(Ast {
         warnings: Vec::new(),
         items,
+        spans,
+        verbatim,
       })
     } else {
       Err(self.internal_error(format!(
@@ -432,33 +526,94 @@ impl<'run, 'src> Parser<'run, 'src> {
     }
   }
 
+  /// Record the source spans of items pushed since `spans` was last updated.
+  /// `start` is the offset at which the items began, and `first` is the index
+  /// of the first token consumed while parsing them.
+  fn record_spans(
+    &self,
+    items: &[Item<'src>],
+    spans: &mut Vec<Range<usize>>,
+    start: usize,
+    first: usize,
+  ) {
+    let consumed = self.tokens[first..self.next_token]
+      .iter()
+      .filter(|token| !matches!(token.kind, Whitespace | Eol | Dedent | Eof));
+
+    let trailing = match items.last() {
+      Some(Item::TrailingComment(_)) if items.len() > spans.len() + 1 => consumed
+        .clone()
+        .rev()
+        .find(|token| token.kind == Comment)
+        .map(|comment| comment.offset..comment.offset + comment.length),
+      _ => None,
+    };
+
+    if spans.len() < items.len() {
+      let end = consumed
+        .rev()
+        .find(|token| trailing.is_none() || token.kind != Comment)
+        .map_or(start, |token| token.offset + token.length);
+
+      spans.push(start..end);
+    }
+
+    spans.extend(trailing);
+  }
+
   /// Parse an alias, e.g `alias name := target`
   fn parse_alias(
     &mut self,
-    attributes: BTreeSet<Attribute<'src>>,
+    attributes: Attributes<'src>,
   ) -> CompileResult<'src, Alias<'src, Name<'src>>> {
     self.presume_keyword(Keyword::Alias)?;
     let name = self.parse_name()?;
     self.presume_any(&[Equals, ColonEquals])?;
     let target = self.parse_name()?;
-    self.expect_eol()?;
     Ok(Alias {
-      attributes,
+      attribute_lines: attributes.lines,
+      attributes: attributes.set,
       name,
       target,
     })
   }
 
   /// Parse an assignment, e.g. `foo := bar`
-  fn parse_assignment(&mut self, export: bool) -> CompileResult<'src, Assignment<'src>> {
+  fn parse_assignment(
+    &mut self,
+    export: bool,
+    doc: Option<&'src str>,
+    attributes: Attributes<'src>,
+  ) -> CompileResult<'src, Assignment<'src>> {
     let name = self.parse_name()?;
+
+    let mut choices = Vec::new();
+    let mut secret = false;
+
+    for attribute in attributes.set {
+      match attribute {
+        Attribute::Choices(arguments) => choices = arguments,
+        Attribute::Secret(arguments) if arguments.is_empty() => secret = true,
+        attribute => {
+          return Err(name.error(CompileErrorKind::AssignmentInvalidAttribute {
+            assignment: name.lexeme(),
+            attribute,
+          }))
+        }
+      }
+    }
+
     self.presume_any(&[Equals, ColonEquals])?;
     let value = self.parse_expression()?;
-    self.expect_eol()?;
     Ok(Assignment {
+      attribute_lines: attributes.lines,
+      choices,
       depth: self.submodule_depth,
+      doc,
       export,
+      export_name: None,
       name,
+      secret,
       value,
     })
   }
@@ -505,7 +660,14 @@ impl<'run, 'src> Parser<'run, 'src> {
   /// Parse a conditional, e.g. `if a == b { "foo" } else { "bar" }`
   fn parse_conditional(&mut self) -> CompileResult<'src, Expression<'src>> {
     let condition = self.parse_condition()?;
+    self.parse_conditional_branches(condition)
+  }
 
+  /// Parse the branches of a conditional whose condition has been parsed
+  fn parse_conditional_branches(
+    &mut self,
+    condition: Condition<'src>,
+  ) -> CompileResult<'src, Expression<'src>> {
     self.expect(BraceL)?;
 
     let then = self.parse_expression()?;
@@ -582,9 +744,10 @@ impl<'run, 'src> Parser<'run, 'src> {
         let name = self.parse_name()?;
 
         if self.next_is(ParenL) {
-          let arguments = self.parse_sequence()?;
+          let (arguments, close) = self.parse_sequence()?;
           Ok(Expression::Call {
             thunk: Thunk::resolve(name, arguments)?,
+            span: name.token.through(close),
           })
         } else {
           Ok(Expression::Variable { name })
@@ -663,7 +826,9 @@ impl<'run, 'src> Parser<'run, 'src> {
   }
 
   /// Parse sequence of comma-separated expressions
-  fn parse_sequence(&mut self) -> CompileResult<'src, Vec<Expression<'src>>> {
+  /// Parse a parenthesized, comma-separated sequence of expressions,
+  /// returning them and the closing parenthesis
+  fn parse_sequence(&mut self) -> CompileResult<'src, (Vec<Expression<'src>>, Token<'src>)> {
     self.presume(ParenL)?;
 
     let mut elements = Vec::new();
@@ -676,9 +841,9 @@ impl<'run, 'src> Parser<'run, 'src> {
       }
     }
 
-    self.expect(ParenR)?;
+    let close = self.expect(ParenR)?;
 
-    Ok(elements)
+    Ok((elements, close))
   }
 
   /// Parse a recipe
@@ -686,7 +851,7 @@ impl<'run, 'src> Parser<'run, 'src> {
     &mut self,
     doc: Option<&'src str>,
     quiet: bool,
-    attributes: BTreeSet<Attribute<'src>>,
+    attributes: Attributes<'src>,
   ) -> CompileResult<'src, UnresolvedRecipe<'src>> {
     let name = self.parse_name()?;
 
@@ -700,11 +865,15 @@ impl<'run, 'src> Parser<'run, 'src> {
       ParameterKind::Plus
     } else if self.accepted(Asterisk)? {
       ParameterKind::Star
+    } else if self.accepted(MinusMinus)? {
+      ParameterKind::Passthrough
     } else {
       ParameterKind::Singular
     };
 
-    let variadic = if kind.is_variadic() {
+    let variadic = if kind == ParameterKind::Singular {
+      None
+    } else {
       let variadic = self.parse_parameter(kind)?;
 
       self.forbid(Identifier, |token| {
@@ -714,8 +883,6 @@ impl<'run, 'src> Parser<'run, 'src> {
       })?;
 
       Some(variadic)
-    } else {
-      None
     };
 
     self.expect(Colon)?;
@@ -742,21 +909,62 @@ impl<'run, 'src> Parser<'run, 'src> {
       dependencies.append(&mut subsequents);
     }
 
-    self.expect_eol()?;
+    let comment = self.expect_eol()?;
 
     let body = self.parse_body()?;
 
+    // Whether a recipe body is lexed with interpolations is decided by the
+    // lexer, before attributes are parsed, so check that it got it right
+    let verbatim = attributes.set.contains(&Attribute::Verbatim);
+
+    if body
+      .iter()
+      .flat_map(|line| &line.fragments)
+      .any(|fragment| match fragment {
+        Fragment::Interpolation { .. } => verbatim,
+        Fragment::Text { token } => !verbatim && token.lexeme().replace("{{{{", "").contains("{{"),
+      })
+    {
+      return Err(name.error(CompileErrorKind::Internal {
+        message: format!(
+          "Lexer and parser disagree about whether recipe `{}` is verbatim",
+          name.lexeme()
+        ),
+      }));
+    }
+
+    let mut parameters = positional
+      .into_iter()
+      .chain(variadic)
+      .collect::<Vec<Parameter>>();
+
+    for attribute in &attributes.set {
+      if let Attribute::Secret(names) = attribute {
+        for parameter in &mut parameters {
+          if names.is_empty()
+            || names
+              .iter()
+              .any(|name| name.cooked == parameter.name.lexeme())
+          {
+            parameter.secret = true;
+          }
+        }
+      }
+    }
+
     Ok(Recipe {
       shebang: body.first().map_or(false, Line::is_shebang),
-      attributes,
+      attribute_lines: attributes.lines,
+      attributes: attributes.set,
       body,
+      comment,
       dependencies,
       depth: self.submodule_depth,
       doc,
       file_path: self.file_path.into(),
       name,
       namepath: self.module_namepath.join(name),
-      parameters: positional.into_iter().chain(variadic).collect(),
+      parameters,
       priors,
       private: name.lexeme().starts_with('_'),
       quiet,
@@ -770,6 +978,29 @@ impl<'run, 'src> Parser<'run, 'src> {
 
     let name = self.parse_name()?;
 
+    let export_name = if export
+      && self.next_are(&[Identifier, Identifier])
+      && self.next()?.lexeme() == Keyword::As.lexeme()
+    {
+      self.presume_keyword(Keyword::As)?;
+      Some(self.parse_name()?)
+    } else {
+      None
+    };
+
+    let pattern = if self.accepted(EqualsTilde)? {
+      let (token, pattern) = self.parse_string_literal_token()?;
+      if let Err(source) = Regex::new(&pattern.cooked) {
+        return Err(token.error(CompileErrorKind::InvalidParameterPattern {
+          parameter: name.lexeme(),
+          source,
+        }));
+      }
+      Some(pattern)
+    } else {
+      None
+    };
+
     let default = if self.accepted(Equals)? {
       Some(self.parse_value()?)
     } else {
@@ -779,8 +1010,11 @@ impl<'run, 'src> Parser<'run, 'src> {
     Ok(Parameter {
       default,
       export,
+      export_name,
       kind,
       name,
+      pattern,
+      secret: false,
     })
   }
 
@@ -854,6 +1088,7 @@ impl<'run, 'src> Parser<'run, 'src> {
     let Some(keyword) = Keyword::from_lexeme(lexeme) else {
       return Err(name.error(CompileErrorKind::UnknownSetting {
         setting: name.lexeme(),
+        suggestion: Setting::suggest(name.lexeme()),
       }));
     };
 
@@ -865,10 +1100,14 @@ impl<'run, 'src> Parser<'run, 'src> {
         Some(Setting::AllowDuplicateVariables(self.parse_set_bool()?))
       }
       Keyword::DotenvLoad => Some(Setting::DotenvLoad(self.parse_set_bool()?)),
+      Keyword::DotenvOverride => Some(Setting::DotenvOverride(self.parse_set_bool()?)),
       Keyword::Export => Some(Setting::Export(self.parse_set_bool()?)),
       Keyword::Fallback => Some(Setting::Fallback(self.parse_set_bool()?)),
       Keyword::IgnoreComments => Some(Setting::IgnoreComments(self.parse_set_bool()?)),
+      Keyword::NormalizeRecipeNames => Some(Setting::NormalizeRecipeNames(self.parse_set_bool()?)),
+      Keyword::Parallel => Some(Setting::Parallel(self.parse_set_bool()?)),
       Keyword::PositionalArguments => Some(Setting::PositionalArguments(self.parse_set_bool()?)),
+      Keyword::PrefixMatch => Some(Setting::PrefixMatch(self.parse_set_bool()?)),
       Keyword::Quiet => Some(Setting::Quiet(self.parse_set_bool()?)),
       Keyword::WindowsPowershell => Some(Setting::WindowsPowerShell(self.parse_set_bool()?)),
       _ => None,
@@ -881,10 +1120,25 @@ impl<'run, 'src> Parser<'run, 'src> {
     self.expect(ColonEquals)?;
 
     let set_value = match keyword {
+      Keyword::Allow => Some(Setting::Allow(self.parse_warning_codes()?)),
+      Keyword::AllowMissingRecipeArgs => Some(Setting::AllowMissingRecipeArgs(
+        self.parse_setting_choice(name)?,
+      )),
+      Keyword::Chooser => Some(Setting::Chooser(self.parse_string_literal()?.cooked)),
+      Keyword::Default => Some(Setting::Default(self.parse_string_literal()?.cooked)),
       Keyword::DotenvFilename => Some(Setting::DotenvFilename(self.parse_string_literal()?.cooked)),
-      Keyword::DotenvPath => Some(Setting::DotenvPath(self.parse_string_literal()?.cooked)),
+      Keyword::DotenvOutsideProject => Some(Setting::DotenvOutsideProject(
+        self.parse_setting_choice(name)?,
+      )),
+      Keyword::DotenvPath => Some(Setting::DotenvPath(self.parse_dotenv_path()?)),
+      Keyword::EnvAllow => Some(Setting::EnvAllow(self.parse_string_list()?)),
+      Keyword::ListOrder => Some(Setting::ListOrder(self.parse_setting_choice(name)?)),
+      Keyword::FallbackDepth => Some(Setting::FallbackDepth(self.parse_setting_integer(name)?)),
+      Keyword::EnvDeny => Some(Setting::EnvDeny(self.parse_string_list()?)),
       Keyword::Shell => Some(Setting::Shell(self.parse_shell()?)),
-      Keyword::Tempdir => Some(Setting::Tempdir(self.parse_string_literal()?.cooked)),
+      Keyword::ShellArgs => Some(Setting::ShellArgs(self.parse_string_list()?)),
+      Keyword::Tempdir => Some(Setting::Tempdir(self.parse_expression()?)),
+      Keyword::TraceRedact => Some(Setting::TraceRedact(self.parse_string_list()?)),
       Keyword::WindowsShell => Some(Setting::WindowsShell(self.parse_shell()?)),
       _ => None,
     };
@@ -895,9 +1149,110 @@ impl<'run, 'src> Parser<'run, 'src> {
 
     Err(name.error(CompileErrorKind::UnknownSetting {
       setting: name.lexeme(),
+      suggestion: Setting::suggest(name.lexeme()),
     }))
   }
 
+  /// Parse a setting value that must be a string naming one of the variants
+  /// of `T`
+  fn parse_setting_choice<T: FromStr + VariantNames>(
+    &mut self,
+    name: Name<'src>,
+  ) -> CompileResult<'src, T> {
+    let (token, value) = self.parse_string_literal_token()?;
+
+    value.cooked.parse().map_err(|_| {
+      token.error(CompileErrorKind::InvalidSettingValue {
+        setting: name.lexeme(),
+        value: value.cooked,
+        choices: T::VARIANTS,
+      })
+    })
+  }
+
+  /// Parse a setting value that must be a string containing a non-negative
+  /// integer
+  fn parse_setting_integer(&mut self, name: Name<'src>) -> CompileResult<'src, usize> {
+    let (token, value) = self.parse_string_literal_token()?;
+
+    value.cooked.parse().map_err(|_| {
+      token.error(CompileErrorKind::InvalidSettingInteger {
+        setting: name.lexeme(),
+        value: value.cooked,
+      })
+    })
+  }
+
+  /// Parse a dotenv-path setting value, either a single path or a list of
+  /// paths
+  fn parse_dotenv_path(&mut self) -> CompileResult<'src, Vec<String>> {
+    if !self.accepted(BracketL)? {
+      return Ok(vec![self.parse_string_literal()?.cooked]);
+    }
+
+    let mut paths = vec![self.parse_string_literal()?.cooked];
+
+    while self.accepted(Comma)? {
+      if self.next_is(BracketR) {
+        break;
+      }
+
+      paths.push(self.parse_string_literal()?.cooked);
+    }
+
+    self.expect(BracketR)?;
+
+    Ok(paths)
+  }
+
+  /// Parse a possibly empty list of strings, e.g. `['AWS_*', 'HOME']`
+  fn parse_string_list(&mut self) -> CompileResult<'src, Vec<String>> {
+    self.expect(BracketL)?;
+
+    let mut list = Vec::new();
+
+    while !self.next_is(BracketR) {
+      list.push(self.parse_string_literal()?.cooked);
+
+      if !self.accepted(Comma)? {
+        break;
+      }
+    }
+
+    self.expect(BracketR)?;
+
+    Ok(list)
+  }
+
+  /// Parse a possibly empty list of warning codes, e.g.
+  /// `['dotenv-outside-project']`
+  fn parse_warning_codes(&mut self) -> CompileResult<'src, Vec<String>> {
+    self.expect(BracketL)?;
+
+    let mut codes = Vec::new();
+
+    while !self.next_is(BracketR) {
+      let (token, code) = self.parse_string_literal_token()?;
+
+      if code.cooked.parse::<WarningCode>().is_err() {
+        return Err(token.error(CompileErrorKind::UnknownWarningCode {
+          suggestion: WarningCode::suggest(&code.cooked),
+          code: code.cooked,
+        }));
+      }
+
+      codes.push(code.cooked);
+
+      if !self.accepted(Comma)? {
+        break;
+      }
+    }
+
+    self.expect(BracketR)?;
+
+    Ok(codes)
+  }
+
   /// Parse a shell setting value
   fn parse_shell(&mut self) -> CompileResult<'src, Shell<'src>> {
     self.expect(BracketL)?;
@@ -921,32 +1276,46 @@ impl<'run, 'src> Parser<'run, 'src> {
     Ok(Shell { arguments, command })
   }
 
-  /// Parse recipe attributes
-  fn parse_attributes(&mut self) -> CompileResult<'src, Option<BTreeSet<Attribute<'src>>>> {
-    let mut attributes = BTreeMap::new();
+  /// Parse attributes, along with comments on and between attribute lines,
+  /// which are kept in place so that formatting doesn't move or remove them
+  fn parse_attributes(&mut self) -> CompileResult<'src, Option<Attributes<'src>>> {
+    let mut attributes = BTreeMap::<Attribute, usize>::new();
+    let mut lines = Vec::new();
 
     while self.accepted(BracketL)? {
+      let mut line = Vec::new();
+
       loop {
         let name = self.parse_name()?;
-        let attribute = Attribute::from_name(name).ok_or_else(|| {
-          name.error(CompileErrorKind::UnknownAttribute {
-            attribute: name.lexeme(),
-          })
-        })?;
-        if let Some(line) = attributes.get(&attribute) {
+
+        let mut arguments = Vec::new();
+
+        if self.accepted(ParenL)? {
+          while !self.next_is(ParenR) {
+            arguments.push(self.parse_string_literal()?);
+
+            if !self.accepted(Comma)? {
+              break;
+            }
+          }
+
+          self.expect(ParenR)?;
+        }
+
+        let attribute = Attribute::new(name, arguments)?;
+
+        if let Some(line) = attributes
+          .iter()
+          .find(|(first, _line)| first.name() == attribute.name() && !attribute.repeatable())
+          .map(|(_attribute, line)| line)
+        {
           return Err(name.error(CompileErrorKind::DuplicateAttribute {
             attribute: name.lexeme(),
             first: *line,
           }));
         }
 
-        let attribute = if self.accepted(ParenL)? {
-          let argument = self.parse_string_literal()?;
-          self.expect(ParenR)?;
-          attribute.with_argument(name, argument)?
-        } else {
-          attribute
-        };
+        line.push(attribute.clone());
 
         attributes.insert(attribute, name.line);
 
@@ -955,13 +1324,28 @@ impl<'run, 'src> Parser<'run, 'src> {
         }
       }
       self.expect(BracketR)?;
-      self.expect_eol()?;
+
+      lines.push(AttributeLine {
+        attributes: line,
+        comment: self.expect_eol()?,
+      });
+
+      while let Some(comment) = self.accept_comment()? {
+        lines.push(AttributeLine {
+          attributes: Vec::new(),
+          comment: Some(comment),
+        });
+        self.expect_eol()?;
+      }
     }
 
     if attributes.is_empty() {
       Ok(None)
     } else {
-      Ok(Some(attributes.into_keys().collect()))
+      Ok(Some(Attributes {
+        lines,
+        set: attributes.into_keys().collect(),
+      }))
     }
   }
 }
@@ -1056,6 +1440,8 @@ mod tests {
             path: "justfile".as_ref(),
           },
           kind: kind.into(),
+          related: None,
+          cycle: Vec::new(),
         };
         assert_eq!(have, want);
       }
@@ -1134,6 +1520,24 @@ mod tests {
     tree: (justfile (assignment #export x "hello")),
   }
 
+  test! {
+    name: secret,
+    text: "[secret]\nx := 'hello'",
+    tree: (justfile (assignment #secret x "hello")),
+  }
+
+  test! {
+    name: choices,
+    text: "[choices('a', 'b')]\nx := 'a'",
+    tree: (justfile (assignment #choices x "a")),
+  }
+
+  test! {
+    name: secret_export,
+    text: "[secret]\nexport x := 'hello'",
+    tree: (justfile (assignment #secret #export x "hello")),
+  }
+
   test! {
     name: export_equals,
     text: r#"export x := "hello""#,
@@ -1232,6 +1636,18 @@ mod tests {
     tree: (justfile (recipe foo (params (bar)))),
   }
 
+  test! {
+    name: recipe_parameter_export_as,
+    text: "foo $bar as BAR='baz':",
+    tree: (justfile (recipe foo (params (bar as BAR "baz")))),
+  }
+
+  test! {
+    name: recipe_parameter_export_followed_by_as,
+    text: "foo $bar as:",
+    tree: (justfile (recipe foo (params (bar) (as)))),
+  }
+
   test! {
     name: recipe_parameter_multiple,
     text: "foo bar baz:",
@@ -1244,6 +1660,12 @@ mod tests {
     tree: (justfile (recipe foo (params (bar "baz")))),
   }
 
+  test! {
+    name: recipe_parameter_pattern,
+    text: r"foo bar=~'^v\d+$' baz=~'x'='xyz':",
+    tree: (justfile (recipe foo (params (bar (pattern "^v\\d+$")) (baz (pattern "x") "xyz")))),
+  }
+
   test! {
     name: recipe_default_multiple,
     text: r#"foo bar="baz" bob="biz":"#,
@@ -1298,6 +1720,24 @@ mod tests {
     tree: (justfile (recipe foo (deps bar))),
   }
 
+  test! {
+    name: recipe_dependency_condition,
+    text: "foo: (bar if 'a' == 'b')",
+    tree: (justfile (recipe foo (deps (bar if "a" == "b")))),
+  }
+
+  test! {
+    name: recipe_dependency_argument_and_condition,
+    text: "foo: (bar 'baz' if 'a' != 'b')",
+    tree: (justfile (recipe foo (deps (bar "baz" if "a" != "b")))),
+  }
+
+  test! {
+    name: recipe_dependency_conditional_argument,
+    text: "foo: (bar if 'a' == 'b' { 'c' } else { 'd' })",
+    tree: (justfile (recipe foo (deps (bar (if "a" == "b" "c" "d"))))),
+  }
+
   test! {
     name: recipe_dependency_argument_string,
     text: "foo: (bar 'baz')",
@@ -1355,31 +1795,46 @@ mod tests {
   test! {
     name: comment_after_alias,
     text: "alias x := y # foo",
-    tree: (justfile (alias x y)),
+    tree: (justfile (alias x y) (comment #trailing "# foo")),
   }
 
   test! {
     name: comment_assignment,
     text: "x := y # foo",
-    tree: (justfile (assignment x y)),
+    tree: (justfile (assignment x y) (comment #trailing "# foo")),
   }
 
   test! {
     name: comment_export,
     text: "export x := y # foo",
-    tree: (justfile (assignment #export x y)),
+    tree: (justfile (assignment #export x y) (comment #trailing "# foo")),
   }
 
   test! {
     name: comment_recipe,
     text: "foo: # bar",
-    tree: (justfile (recipe foo)),
+    tree: (justfile (recipe foo (comment "# bar"))),
   }
 
   test! {
     name: comment_recipe_dependencies,
     text: "foo: bar # baz",
-    tree: (justfile (recipe foo (deps bar))),
+    tree: (justfile (recipe foo (deps bar) (comment "# baz"))),
+  }
+
+  test! {
+    name: comment_after_set,
+    text: "set quiet # foo",
+    tree: (justfile (set quiet true) (comment #trailing "# foo")),
+  }
+
+  test! {
+    name: comment_after_set_is_not_doc_comment,
+    text: "
+      set quiet # foo
+      bar:
+    ",
+    tree: (justfile (set quiet true) (comment #trailing "# foo") (recipe bar)),
   }
 
   test! {
@@ -1418,7 +1873,7 @@ mod tests {
       x := y
       bar:
     ",
-    tree: (justfile (comment "# foo") (assignment x y) (recipe bar)),
+    tree: (justfile (assignment "foo" x y) (recipe bar)),
   }
 
   test! {
@@ -1615,6 +2070,7 @@ mod tests {
     ",
     tree: (justfile
       (alias f foo)
+      (comment # trailing "#comment")
       (recipe foo (body ("echo a")))
     ),
   }
@@ -1628,6 +2084,7 @@ mod tests {
     ",
     tree: (justfile
       (assignment f foo)
+      (comment # trailing "#comment")
       (recipe foo (body ("echo a")))
     ),
   }
@@ -1659,6 +2116,7 @@ mod tests {
       (recipe hello
         (params (a) (b) (c))
         (deps x y z)
+        (comment "#hello")
         (body
           ("#! blah")
           ("#blarg")
@@ -1924,6 +2382,12 @@ mod tests {
     tree: (justfile (set dotenv_load true)),
   }
 
+  test! {
+    name: set_allow,
+    text: "set allow := ['dotenv-outside-project', 'warn-directive']",
+    tree: (justfile (set allow "dotenv-outside-project" "warn-directive")),
+  }
+
   test! {
     name: set_allow_duplicate_recipes_implicit,
     text: "set allow-duplicate-recipes",
@@ -1942,6 +2406,72 @@ mod tests {
     tree: (justfile (set dotenv_load true)),
   }
 
+  test! {
+    name: set_dotenv_path_list,
+    text: "set dotenv-path := ['a', 'b',]",
+    tree: (justfile (set dotenv_path "a" "b")),
+  }
+
+  test! {
+    name: set_allow_missing_recipe_args,
+    text: "set allow-missing-recipe-args := 'prompt'",
+    tree: (justfile (set allow_missing_recipe_args "prompt")),
+  }
+
+  test! {
+    name: set_chooser,
+    text: "set chooser := 'fzf --height 40%'",
+    tree: (justfile (set chooser "fzf --height 40%")),
+  }
+
+  test! {
+    name: set_default,
+    text: "set default := 'build'",
+    tree: (justfile (set default "build")),
+  }
+
+  test! {
+    name: set_dotenv_outside_project,
+    text: "set dotenv-outside-project := 'error'",
+    tree: (justfile (set dotenv_outside_project "error")),
+  }
+
+  test! {
+    name: set_dotenv_override,
+    text: "set dotenv-override",
+    tree: (justfile (set dotenv_override true)),
+  }
+
+  test! {
+    name: set_env_allow_empty,
+    text: "set env-allow := []",
+    tree: (justfile (set env_allow)),
+  }
+
+  test! {
+    name: set_trace_redact,
+    text: "set trace-redact := ['*TOKEN*', 'password']",
+    tree: (justfile (set trace_redact "*TOKEN*" "password")),
+  }
+
+  test! {
+    name: set_env_deny,
+    text: "set env-deny := ['AWS_*', 'GITHUB_TOKEN',]",
+    tree: (justfile (set env_deny "AWS_*" "GITHUB_TOKEN")),
+  }
+
+  test! {
+    name: set_fallback_depth,
+    text: "set fallback-depth := '2'",
+    tree: (justfile (set fallback_depth "2")),
+  }
+
+  test! {
+    name: set_list_order,
+    text: "set list-order := 'source'",
+    tree: (justfile (set list_order "source")),
+  }
+
   test! {
     name: set_dotenv_load_false,
     text: "set dotenv-load := false",
@@ -1978,6 +2508,24 @@ mod tests {
     tree: (justfile (set quiet false)),
   }
 
+  test! {
+    name: set_normalize_recipe_names_true,
+    text: "set normalize-recipe-names := true",
+    tree: (justfile (set normalize_recipe_names true)),
+  }
+
+  test! {
+    name: set_prefix_match_implicit,
+    text: "set prefix-match",
+    tree: (justfile (set prefix_match true)),
+  }
+
+  test! {
+    name: set_parallel_implicit,
+    text: "set parallel",
+    tree: (justfile (set parallel true)),
+  }
+
   test! {
     name: set_positional_arguments_false,
     text: "set positional-arguments := false",
@@ -2020,6 +2568,12 @@ mod tests {
     tree: (justfile (set shell "bash" "-cu" "-l")),
   }
 
+  test! {
+    name: set_shell_args,
+    text: "set shell-args := ['-o', 'pipefail']",
+    tree: (justfile (set shell_args "-o" "pipefail")),
+  }
+
   test! {
     name: set_windows_powershell_implicit,
     text: "set windows-powershell",
@@ -2156,7 +2710,7 @@ mod tests {
     column: 5,
     width:  1,
     kind:   UnexpectedToken{
-      expected: vec![Asterisk, Colon, Dollar, Equals, Identifier, Plus],
+      expected: vec![Asterisk, Colon, Dollar, Equals, EqualsTilde, Identifier, MinusMinus, Plus],
       found:    Eol
     },
   }
@@ -2291,7 +2845,7 @@ mod tests {
     column: 8,
     width:  0,
     kind:   UnexpectedToken {
-      expected: vec![Asterisk, Colon, Dollar, Equals, Identifier, Plus],
+      expected: vec![Asterisk, Colon, Dollar, Equals, EqualsTilde, Identifier, MinusMinus, Plus],
       found:    Eof
     },
   }
@@ -2416,7 +2970,52 @@ mod tests {
     line:   0,
     column: 1,
     width:  7,
-    kind:   UnknownAttribute { attribute: "unknown" },
+    kind:   UnknownAttribute { attribute: "unknown", suggestion: None },
+  }
+
+  error! {
+    name:   attribute_too_many_arguments,
+    input:  "[confirm('a', 'b')]\nsome_recipe:",
+    offset: 1,
+    line:   0,
+    column: 1,
+    width:  7,
+    kind:   AttributeArgumentCountMismatch {
+      attribute: "confirm",
+      found: 2,
+      min: 0,
+      max: 1,
+    },
+  }
+
+  error! {
+    name:   attribute_unexpected_argument,
+    input:  "[private('a')]\nsome_recipe:",
+    offset: 1,
+    line:   0,
+    column: 1,
+    width:  7,
+    kind:   UnexpectedAttributeArgument { attribute: Attribute::Private },
+  }
+
+  error! {
+    name:   attribute_missing_argument,
+    input:  "[dotenv()]\nsome_recipe:",
+    offset: 1,
+    line:   0,
+    column: 1,
+    width:  6,
+    kind:   MissingAttributeArgument { attribute: Attribute::Dotenv(None) },
+  }
+
+  error! {
+    name:   attribute_duplicate_with_different_arguments,
+    input:  "[confirm]\n[confirm('sure?')]\nsome_recipe:",
+    offset: 11,
+    line:   1,
+    column: 1,
+    width:  7,
+    kind:   DuplicateAttribute { attribute: "confirm", first: 0 },
   }
 
   error! {
@@ -2428,6 +3027,33 @@ mod tests {
     width:  5,
     kind:   UnknownSetting {
       setting: "shall",
+      suggestion: Some(Suggestion { name: "shell", target: None }),
+    },
+  }
+
+  error! {
+    name:   assignment_invalid_attribute,
+    input:  "[private]\nx := 'a'",
+    offset: 10,
+    line:   1,
+    column: 0,
+    width:  1,
+    kind:   AssignmentInvalidAttribute {
+      assignment: "x",
+      attribute: Attribute::Private,
+    },
+  }
+
+  error! {
+    name:   set_allow_unknown_code,
+    input:  "set allow := ['warn-directiv']",
+    offset: 14,
+    line:   0,
+    column: 14,
+    width:  15,
+    kind:   UnknownWarningCode {
+      code: "warn-directiv".into(),
+      suggestion: Some(Suggestion { name: "warn-directive", target: None }),
     },
   }
 
@@ -2440,6 +3066,7 @@ mod tests {
     width:  5,
     kind:   UnknownSetting {
       setting: "shall",
+      suggestion: Some(Suggestion { name: "shell", target: None }),
     },
   }
 
@@ -2450,7 +3077,10 @@ mod tests {
     line:   0,
     column: 5,
     width:  3,
-    kind:   UnknownFunction{function: "foo"},
+    kind:   UnknownFunction {
+      function: "foo",
+      suggestion: Some(Suggestion { name: "os", target: None }),
+    },
   }
 
   error! {
@@ -2460,7 +3090,7 @@ mod tests {
     line:   1,
     column: 8,
     width:  3,
-    kind:   UnknownFunction{function: "bar"},
+    kind:   UnknownFunction { function: "bar", suggestion: None },
   }
 
   error! {
@@ -2470,7 +3100,7 @@ mod tests {
     line:   0,
     column: 4,
     width:  3,
-    kind:   UnknownFunction{function: "baz"},
+    kind:   UnknownFunction { function: "baz", suggestion: None },
   }
 
   error! {
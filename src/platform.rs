@@ -2,7 +2,7 @@ use super::*;
 
 pub(crate) struct Platform;
 
-#[cfg(unix)]
+#[cfg(all(feature = "native", unix))]
 impl PlatformInterface for Platform {
   fn make_shebang_command(
     path: &Path,
@@ -46,7 +46,7 @@ impl PlatformInterface for Platform {
   }
 }
 
-#[cfg(windows)]
+#[cfg(all(feature = "native", windows))]
 impl PlatformInterface for Platform {
   fn make_shebang_command(
     path: &Path,
@@ -113,3 +113,41 @@ impl PlatformInterface for Platform {
     }
   }
 }
+
+#[cfg(not(feature = "native"))]
+impl Platform {
+  pub(crate) fn unsupported(operation: &str) -> io::Error {
+    io::Error::new(
+      io::ErrorKind::Unsupported,
+      format!("{operation} requires the `native` feature"),
+    )
+  }
+}
+
+#[cfg(not(feature = "native"))]
+impl PlatformInterface for Platform {
+  fn make_shebang_command(
+    _path: &Path,
+    _working_directory: Option<&Path>,
+    _shebang: Shebang,
+  ) -> Result<Command, OutputError> {
+    Err(OutputError::Io(Self::unsupported(
+      "running shebang recipes",
+    )))
+  }
+
+  fn set_execute_permission(_path: &Path) -> Result<(), io::Error> {
+    Err(Self::unsupported("setting file permissions"))
+  }
+
+  fn signal_from_exit_status(_exit_status: ExitStatus) -> Option<i32> {
+    None
+  }
+
+  fn convert_native_path(_working_directory: &Path, path: &Path) -> Result<String, String> {
+    path
+      .to_str()
+      .map(str::to_string)
+      .ok_or_else(|| String::from("Error getting current directory: unicode decode error"))
+  }
+}
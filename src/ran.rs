@@ -1,18 +1,70 @@
 use super::*;
 
+#[derive(Clone, Copy, PartialEq)]
+enum State {
+  Failed,
+  Finished,
+  Running,
+}
+
+/// Recipes which have been run, keyed by recipe and arguments. Recipes are
+/// claimed before they run, so that if the same recipe is a dependency of
+/// parallel siblings, it only runs once, and the others wait for it to finish.
 #[derive(Default)]
-pub(crate) struct Ran<'src>(BTreeMap<Namepath<'src>, BTreeSet<Vec<String>>>);
+pub(crate) struct Ran<'src> {
+  changed: Condvar,
+  recipes: Mutex<BTreeMap<Namepath<'src>, BTreeMap<Vec<String>, State>>>,
+}
 
 impl<'src> Ran<'src> {
-  pub(crate) fn has_run(&self, recipe: &Namepath<'src>, arguments: &[String]) -> bool {
-    self
-      .0
-      .get(recipe)
-      .map(|ran| ran.contains(arguments))
-      .unwrap_or_default()
+  /// Claim `recipe` with `arguments`, returning `true` if the caller should
+  /// run it, and `false` if it has already run. Blocks until `recipe` has
+  /// finished if it is being run by another thread.
+  pub(crate) fn claim(&self, recipe: &Recipe<'src>, arguments: &[String]) -> RunResult<'src, bool> {
+    let mut recipes = self.recipes.lock().unwrap();
+
+    loop {
+      match recipes
+        .get(&recipe.namepath)
+        .and_then(|ran| ran.get(arguments))
+      {
+        None => break,
+        Some(State::Finished) => return Ok(false),
+        Some(State::Failed) => {
+          return Err(Error::ParallelDependency {
+            recipe: recipe.name(),
+          })
+        }
+        Some(State::Running) => recipes = self.changed.wait(recipes).unwrap(),
+      }
+    }
+
+    recipes
+      .entry(recipe.namepath.clone())
+      .or_default()
+      .insert(arguments.to_vec(), State::Running);
+
+    Ok(true)
   }
 
-  pub(crate) fn ran(&mut self, recipe: &Namepath<'src>, arguments: Vec<String>) {
-    self.0.entry(recipe.clone()).or_default().insert(arguments);
+  /// Record that `recipe` with `arguments` has finished, waking any threads
+  /// waiting for it
+  pub(crate) fn finish(&self, recipe: &Recipe<'src>, arguments: &[String], success: bool) {
+    self
+      .recipes
+      .lock()
+      .unwrap()
+      .entry(recipe.namepath.clone())
+      .or_default()
+      .insert(
+        arguments.to_vec(),
+        if success {
+          State::Finished
+        } else {
+          State::Failed
+        },
+      );
+
+    self.changed.notify_all();
   }
 }
@@ -19,8 +19,12 @@ fn error_from_signal(recipe: &str, line_number: Option<usize>, exit_status: Exit
 /// A recipe, e.g. `foo: bar baz`
 #[derive(PartialEq, Debug, Clone, Serialize)]
 pub(crate) struct Recipe<'src, D = Dependency<'src>> {
+  #[serde(skip)]
+  pub(crate) attribute_lines: Vec<AttributeLine<'src>>,
   pub(crate) attributes: BTreeSet<Attribute<'src>>,
   pub(crate) body: Vec<Line<'src>>,
+  #[serde(skip)]
+  pub(crate) comment: Option<&'src str>,
   pub(crate) dependencies: Vec<D>,
   #[serde(skip)]
   pub(crate) depth: u32,
@@ -47,7 +51,9 @@ impl<'src, D> Recipe<'src, D> {
     self
       .parameters
       .iter()
-      .filter(|p| p.default.is_none() && p.kind != ParameterKind::Star)
+      .filter(|p| {
+        p.default.is_none() && p.kind != ParameterKind::Star && p.kind != ParameterKind::Passthrough
+      })
       .count()
   }
 
@@ -55,10 +61,21 @@ impl<'src, D> Recipe<'src, D> {
     if self.parameters.iter().any(|p| p.kind.is_variadic()) {
       usize::MAX - 1
     } else {
-      self.parameters.len()
+      self
+        .parameters
+        .iter()
+        .filter(|p| p.kind != ParameterKind::Passthrough)
+        .count()
     }
   }
 
+  pub(crate) fn takes_passthrough(&self) -> bool {
+    self
+      .parameters
+      .iter()
+      .any(|p| p.kind == ParameterKind::Passthrough)
+  }
+
   pub(crate) fn name(&self) -> &'src str {
     self.name.lexeme()
   }
@@ -67,6 +84,11 @@ impl<'src, D> Recipe<'src, D> {
     self.name.line
   }
 
+  /// Path and one-based line number of the recipe, e.g. `justfile:3`
+  pub(crate) fn location(&self) -> String {
+    format!("{}:{}", self.name.path.display(), self.line_number() + 1)
+  }
+
   pub(crate) fn confirm(&self) -> RunResult<'src, bool> {
     for attribute in &self.attributes {
       if let Attribute::Confirm(prompt) = attribute {
@@ -86,6 +108,64 @@ impl<'src, D> Recipe<'src, D> {
     Ok(true)
   }
 
+  /// Read missing arguments from stdin, one parameter at a time, stopping at
+  /// the first empty entry for a parameter with a default
+  pub(crate) fn prompt_for_arguments(&self, arguments: &mut Vec<String>) -> RunResult<'src, ()> {
+    self.read_arguments(&mut io::stdin().lock(), &mut io::stderr(), arguments)
+  }
+
+  fn read_arguments(
+    &self,
+    input: &mut impl BufRead,
+    prompt: &mut impl Write,
+    arguments: &mut Vec<String>,
+  ) -> RunResult<'src, ()> {
+    for parameter in self.parameters.iter().skip(arguments.len()) {
+      if parameter.kind == ParameterKind::Passthrough {
+        break;
+      }
+
+      if let Some(default) = &parameter.default {
+        write!(prompt, "{} [{default}]: ", parameter.name)
+      } else {
+        write!(prompt, "{}: ", parameter.name)
+      }
+      .and_then(|()| prompt.flush())
+      .map_err(|io_error| Error::GetArgument { io_error })?;
+
+      let mut line = String::new();
+      let read = input
+        .read_line(&mut line)
+        .map_err(|io_error| Error::GetArgument { io_error })?;
+
+      if read == 0 {
+        if parameter.default.is_some() {
+          break;
+        }
+
+        return Err(Error::GetArgument {
+          io_error: io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+              "end of input before value for parameter `{}`",
+              parameter.name
+            ),
+          ),
+        });
+      }
+
+      let line = line.trim_end_matches(['\r', '\n']);
+
+      if line.is_empty() && parameter.default.is_some() {
+        break;
+      }
+
+      arguments.push(line.into());
+    }
+
+    Ok(())
+  }
+
   pub(crate) fn check_can_be_default_recipe(&self) -> RunResult<'src, ()> {
     let min_arguments = self.min_arguments();
     if min_arguments > 0 {
@@ -102,6 +182,36 @@ impl<'src, D> Recipe<'src, D> {
     !self.private && !self.attributes.contains(&Attribute::Private)
   }
 
+  /// Whether the recipe is shown by `--list` and offered by `--choose`
+  pub(crate) fn is_listed(&self) -> bool {
+    self.is_public() && !self.attributes.contains(&Attribute::NoList)
+  }
+
+  /// Groups given by `[group(NAME)]` attributes, under which the recipe is
+  /// listed
+  pub(crate) fn groups(&self) -> BTreeSet<String> {
+    self
+      .attributes
+      .iter()
+      .filter_map(|attribute| match attribute {
+        Attribute::Group(Some(group)) => Some(group.cooked.clone()),
+        _ => None,
+      })
+      .collect()
+  }
+
+  /// True if `pattern` occurs in the recipe's name, doc comment, or body
+  pub(crate) fn contains(&self, pattern: &str) -> bool {
+    self.name().contains(pattern)
+      || self.doc.map_or(false, |doc| doc.contains(pattern))
+      || self.body.iter().any(|line| {
+        line.fragments.iter().any(|fragment| match fragment {
+          Fragment::Text { token } => token.lexeme().contains(pattern),
+          Fragment::Interpolation { expression } => expression.to_string().contains(pattern),
+        })
+      })
+  }
+
   pub(crate) fn change_directory(&self) -> bool {
     !self.attributes.contains(&Attribute::NoCd)
   }
@@ -110,14 +220,81 @@ impl<'src, D> Recipe<'src, D> {
     let windows = self.attributes.contains(&Attribute::Windows);
     let linux = self.attributes.contains(&Attribute::Linux);
     let macos = self.attributes.contains(&Attribute::Macos);
+    let freebsd = self.attributes.contains(&Attribute::Freebsd);
+    let openbsd = self.attributes.contains(&Attribute::Openbsd);
+    let wsl = self.attributes.contains(&Attribute::Wsl);
     let unix = self.attributes.contains(&Attribute::Unix);
 
-    (!windows && !linux && !macos && !unix)
+    let os = (!windows && !linux && !macos && !freebsd && !openbsd && !wsl && !unix)
       || (cfg!(target_os = "windows") && windows)
       || (cfg!(target_os = "linux") && (linux || unix))
       || (cfg!(target_os = "macos") && (macos || unix))
+      || (cfg!(target_os = "freebsd") && (freebsd || unix))
+      || (cfg!(target_os = "openbsd") && (openbsd || unix))
+      || (cfg!(target_os = "linux") && wsl && Self::wsl())
       || (cfg!(windows) && windows)
-      || (cfg!(unix) && unix)
+      || (cfg!(unix) && unix);
+
+    let arch = self.attributes.iter().all(|attribute| match attribute {
+      Attribute::Arch(architectures) => architectures
+        .iter()
+        .any(|architecture| architecture.cooked == env::consts::ARCH),
+      _ => true,
+    });
+
+    os && arch
+  }
+
+  /// How specifically an enabled recipe's configuration attributes match the
+  /// current platform, used to choose between recipes with the same name.
+  /// `[wsl]` beats operating systems, which beat `[unix]`, which beats no
+  /// operating system attribute. Ties are broken by the presence of `[arch]`.
+  pub(crate) fn specificity(&self) -> (u8, bool) {
+    let os =
+      if cfg!(target_os = "linux") && self.attributes.contains(&Attribute::Wsl) && Self::wsl() {
+        3
+      } else if self.attributes.iter().any(|attribute| match attribute {
+        Attribute::Freebsd => cfg!(target_os = "freebsd"),
+        Attribute::Linux => cfg!(target_os = "linux"),
+        Attribute::Macos => cfg!(target_os = "macos"),
+        Attribute::Openbsd => cfg!(target_os = "openbsd"),
+        Attribute::Windows => cfg!(windows),
+        _ => false,
+      }) {
+        2
+      } else {
+        u8::from(cfg!(unix) && self.attributes.contains(&Attribute::Unix))
+      };
+
+    let arch = self
+      .attributes
+      .iter()
+      .any(|attribute| matches!(attribute, Attribute::Arch(_)));
+
+    (os, arch)
+  }
+
+  /// Whether we're running under the Windows Subsystem for Linux, detected
+  /// by the kernel release, which contains `microsoft` under WSL 1 and 2
+  fn wsl() -> bool {
+    fs::read_to_string("/proc/sys/kernel/osrelease").map_or(false, |release| {
+      release.to_lowercase().contains("microsoft")
+    })
+  }
+
+  fn verbatim(&self) -> bool {
+    self.attributes.contains(&Attribute::Verbatim)
+  }
+
+  /// Remove trailing whitespace from the end of a line, unless doing so
+  /// would turn a trailing backslash into a line continuation
+  fn trim_trailing_whitespace(text: &str) -> &str {
+    let trimmed = text.trim_end_matches([' ', '\t']);
+    if trimmed.ends_with('\\') {
+      text
+    } else {
+      trimmed
+    }
   }
 
   fn print_exit_message(&self) -> bool {
@@ -136,10 +313,58 @@ impl<'src, D> Recipe<'src, D> {
     }
   }
 
+  /// Whether prior dependencies should be run concurrently, due to
+  /// `[parallel]` or `set parallel`
+  pub(crate) fn parallel(&self, settings: &Settings) -> bool {
+    settings.parallel || self.attributes.contains(&Attribute::Parallel)
+  }
+
   fn no_quiet(&self) -> bool {
     self.attributes.contains(&Attribute::NoQuiet)
   }
 
+  /// Verbosity with which to run the recipe, taking `[quiet]` and `[verbose]`
+  /// into account
+  fn verbosity(&self, config: &Config) -> Verbosity {
+    if self.attributes.contains(&Attribute::Quiet) {
+      Verbosity::Quiet
+    } else if self.attributes.contains(&Attribute::Verbose) && !config.verbosity.loquacious() {
+      Verbosity::Loquacious
+    } else {
+      config.verbosity
+    }
+  }
+
+  /// Whether warnings with `code` are suppressed by `[allow(...)]` or the
+  /// `allow` setting
+  fn allows(&self, settings: &Settings, code: WarningCode) -> bool {
+    settings.allows(code)
+      || self.attributes.iter().any(|attribute| match attribute {
+        Attribute::Allow(codes) => codes.iter().any(|allowed| allowed.cooked == code.name()),
+        _ => false,
+      })
+  }
+
+  fn body_file_command(&self) -> Option<&str> {
+    self
+      .attributes
+      .iter()
+      .find_map(|attribute| match attribute {
+        Attribute::BodyFile(Some(command)) => Some(command.cooked.as_str()),
+        _ => None,
+      })
+  }
+
+  fn dotenv_path(&self) -> Option<&str> {
+    self
+      .attributes
+      .iter()
+      .find_map(|attribute| match attribute {
+        Attribute::Dotenv(Some(path)) => Some(path.cooked.as_str()),
+        _ => None,
+      })
+  }
+
   pub(crate) fn run<'run>(
     &self,
     context: &RecipeContext<'src, 'run>,
@@ -150,24 +375,65 @@ impl<'src, D> Recipe<'src, D> {
   ) -> RunResult<'src, ()> {
     let config = &context.config;
 
-    if config.verbosity.loquacious() {
+    if self.verbosity(config).loquacious() {
       let color = config.color.stderr().banner();
-      eprintln!(
+      context.echo(format_args!(
         "{}===> Running recipe `{}`...{}",
         color.prefix(),
         self.name,
         color.suffix()
-      );
+      ));
     }
 
-    let evaluator =
-      Evaluator::recipe_evaluator(context.config, dotenv, &scope, context.settings, search);
+    let recipe_dotenv;
+    let dotenv = match self.dotenv_path() {
+      Some(path) if config.load_dotenv => {
+        recipe_dotenv =
+          load_recipe_dotenv(context.settings, &search.working_directory, path, dotenv)?;
+        &recipe_dotenv
+      }
+      _ => dotenv,
+    };
+
+    if !self.allows(context.settings, WarningCode::ShortSecret) {
+      for parameter in &self.parameters {
+        let name = parameter.name.lexeme();
+        match scope.value(name) {
+          Some(value) if parameter.secret && !value.is_empty() && !Scope::maskable(value) => {
+            Warning::ShortSecret { name: name.into() }.report(config, config.verbosity)?;
+          }
+          _ => {}
+        }
+      }
+    }
+
+    let evaluator = Evaluator::recipe_evaluator(
+      context.config,
+      dotenv,
+      self.namepath.modules(),
+      &scope,
+      context.settings,
+      search,
+    );
+
+    let namepath = self.namepath.to_string();
+
+    context.events.recipe_started(&namepath);
 
-    if self.shebang {
-      self.run_shebang(context, dotenv, &scope, positional, config, evaluator)
+    context.events.recipe_environment(
+      &namepath,
+      &masked_exported_variables(context.settings, dotenv, &scope),
+    );
+
+    let result = if self.shebang || self.body_file_command().is_some() {
+      self.run_script(context, dotenv, &scope, positional, config, evaluator)
     } else {
       self.run_linewise(context, dotenv, &scope, positional, config, evaluator)
-    }
+    };
+
+    context.events.recipe_finished(&namepath, result.is_ok());
+
+    result
   }
 
   fn run_linewise<'run>(
@@ -179,6 +445,7 @@ impl<'src, D> Recipe<'src, D> {
     config: &Config,
     mut evaluator: Evaluator<'src, 'run>,
   ) -> RunResult<'src, ()> {
+    let verbosity = self.verbosity(config);
     let mut lines = self.body.iter().peekable();
     let mut line_number = self.line_number() + 1;
     loop {
@@ -200,7 +467,11 @@ impl<'src, D> Recipe<'src, D> {
         let line = lines.next().unwrap();
         line_number += 1;
         if !comment_line {
-          evaluated += &evaluator.evaluate_line(line, continued)?;
+          evaluated += &if self.verbatim() {
+            line.verbatim(continued)
+          } else {
+            evaluator.evaluate_line(line, continued)?
+          };
         }
         if line.is_continuation() && !comment_line {
           continued = true;
@@ -224,46 +495,126 @@ impl<'src, D> Recipe<'src, D> {
         continue;
       }
 
+      let directive = Directive::parse(command);
+
+      let mut sleep = None;
+
+      match directive {
+        Some(Directive::Error(message)) => {
+          let error = Error::Directive {
+            recipe: self.name(),
+            line_number,
+            message: message.into(),
+          };
+
+          if !infallible_line {
+            return Err(error);
+          }
+
+          if verbosity.loud() {
+            eprintln!("{}", error.color_display(config.color.stderr()));
+          }
+
+          continue;
+        }
+        Some(Directive::Warn(message)) => {
+          if !self.allows(context.settings, WarningCode::WarnDirective) {
+            Warning::WarnDirective {
+              message: message.into(),
+            }
+            .report(config, verbosity)?;
+          }
+
+          continue;
+        }
+        Some(Directive::Sleep(seconds)) => {
+          let Some(duration) = Directive::sleep_duration(seconds) else {
+            let error = Error::Directive {
+              recipe: self.name(),
+              line_number,
+              message: format!("invalid `~sleep` duration `{seconds}`, expected seconds"),
+            };
+
+            if !infallible_line {
+              return Err(error);
+            }
+
+            if verbosity.loud() {
+              eprintln!("{}", error.color_display(config.color.stderr()));
+            }
+
+            continue;
+          };
+
+          sleep = Some(duration);
+        }
+        Some(Directive::Open(_)) | None => {}
+      }
+
       if config.dry_run
-        || config.verbosity.loquacious()
+        || verbosity.loquacious()
         || !((quiet_line ^ self.quiet)
           || (context.settings.quiet && !self.no_quiet())
-          || config.verbosity.quiet())
+          || verbosity.quiet())
       {
         let color = if config.highlight {
           config.color.command(config.command_color)
         } else {
           config.color
         };
-        eprintln!("{}", color.stderr().paint(command));
+        context.echo(color.stderr().paint(&scope.mask(command)));
       }
 
       if config.dry_run {
         continue;
       }
 
-      let mut cmd = context.settings.shell_command(config);
+      if let Some(duration) = sleep {
+        thread::sleep(duration);
+        continue;
+      }
+
+      let mut cmd = if let Some(Directive::Open(target)) = directive {
+        Directive::open_command(target)
+      } else {
+        let mut cmd = context.settings.shell_command(config);
+
+        cmd.arg(command);
+
+        if context.settings.positional_arguments {
+          cmd.arg(self.name.lexeme());
+          cmd.args(positional);
+        }
+
+        cmd
+      };
 
       if let Some(working_directory) = self.working_directory(context.search) {
         cmd.current_dir(working_directory);
       }
 
-      cmd.arg(command);
-
-      if context.settings.positional_arguments {
-        cmd.arg(self.name.lexeme());
-        cmd.args(positional);
+      if verbosity.quiet() || config.silence_stderr {
+        cmd.stderr(Stdio::null());
       }
 
-      if config.verbosity.quiet() {
-        cmd.stderr(Stdio::null());
+      if verbosity.quiet() || config.silence_stdout {
         cmd.stdout(Stdio::null());
       }
 
-      cmd.export(context.settings, dotenv, scope);
+      cmd.export(config, context.settings, dotenv, scope);
+
+      let namepath = self.namepath.to_string();
 
-      match InterruptHandler::guard(|| cmd.status()) {
+      context
+        .events
+        .line_spawned(&namepath, Some(line_number), &scope.mask(command));
+
+      match context.spawn(&mut cmd) {
         Ok(exit_status) => {
+          context
+            .events
+            .line_finished(&namepath, Some(line_number), exit_status);
+
           if let Some(code) = exit_status.code() {
             if code != 0 && !infallible_line {
               return Err(Error::Code {
@@ -282,16 +633,77 @@ impl<'src, D> Recipe<'src, D> {
           }
         }
         Err(io_error) => {
-          return Err(Error::Io {
-            recipe: self.name(),
-            io_error,
+          return Err(if let Some(Directive::Open(_)) = directive {
+            Error::Open {
+              recipe: self.name(),
+              opener: Directive::opener(),
+              io_error,
+            }
+          } else {
+            Error::Io {
+              recipe: self.name(),
+              io_error,
+            }
           });
         }
       };
     }
   }
 
-  pub(crate) fn run_shebang<'run>(
+  /// Path given by the `[tempdir(PATH)]` attribute
+  fn tempdir_path(&self) -> Option<&str> {
+    self
+      .attributes
+      .iter()
+      .find_map(|attribute| match attribute {
+        Attribute::Tempdir(Some(path)) => Some(path.cooked.as_str()),
+        _ => None,
+      })
+  }
+
+  /// Create a temporary directory in the directory given by the
+  /// `[tempdir(PATH)]` attribute, the `tempdir` setting, or the system
+  /// temporary directory, in that order of precedence
+  fn tempdir(
+    &self,
+    context: &RecipeContext<'src, '_>,
+    dotenv: &BTreeMap<String, String>,
+  ) -> RunResult<'src, tempfile::TempDir> {
+    let tempdir = if let Some(path) = self.tempdir_path() {
+      Some(path.to_owned())
+    } else if let Some(expression) = &context.settings.tempdir {
+      Some(
+        Evaluator::recipe_evaluator(
+          context.config,
+          dotenv,
+          self.namepath.modules(),
+          context.scope,
+          context.settings,
+          context.search,
+        )
+        .evaluate_expression(expression)?,
+      )
+    } else {
+      None
+    };
+
+    let mut tempdir_builder = tempfile::Builder::new();
+    tempdir_builder.prefix("just-");
+    match tempdir {
+      Some(tempdir) => tempdir_builder.tempdir_in(context.search.working_directory.join(tempdir)),
+      None => tempdir_builder.tempdir(),
+    }
+    .map_err(|error| Error::TempdirIo {
+      recipe: self.name(),
+      io_error: error,
+    })
+  }
+
+  /// Write the evaluated body to a file and run it, either directly,
+  /// according to its shebang line, or, for `[body-file(COMMAND)]` recipes,
+  /// by running `COMMAND` with the shell, with the path to the file, which
+  /// has no shebang line, in the `JUST_BODY_FILE` environment variable
+  fn run_script<'run>(
     &self,
     context: &RecipeContext<'src, 'run>,
     dotenv: &BTreeMap<String, String>,
@@ -302,18 +714,21 @@ impl<'src, D> Recipe<'src, D> {
   ) -> RunResult<'src, ()> {
     let mut evaluated_lines = Vec::new();
     for line in &self.body {
-      evaluated_lines.push(evaluator.evaluate_line(line, false)?);
+      evaluated_lines.push(if self.verbatim() {
+        line.verbatim(false)
+      } else {
+        evaluator.evaluate_line(line, false)?
+      });
     }
 
-    if config.verbosity.loud() && (config.dry_run || self.quiet) {
+    if self.verbosity(config).loud() && (config.dry_run || self.quiet) {
       for line in &evaluated_lines {
-        eprintln!(
-          "{}",
+        context.echo(
           config
             .color
             .command(config.command_color)
             .stderr()
-            .paint(line)
+            .paint(&scope.mask(line)),
         );
       }
     }
@@ -322,26 +737,26 @@ impl<'src, D> Recipe<'src, D> {
       return Ok(());
     }
 
-    let shebang_line = evaluated_lines.first().ok_or_else(|| Error::Internal {
-      message: "evaluated_lines was empty".to_owned(),
-    })?;
+    let body_file_command = self.body_file_command();
 
-    let shebang = Shebang::new(shebang_line).ok_or_else(|| Error::Internal {
-      message: format!("bad shebang line: {shebang_line}"),
-    })?;
+    let shebang = if body_file_command.is_some() {
+      None
+    } else {
+      let shebang_line = evaluated_lines.first().ok_or_else(|| Error::Internal {
+        message: "evaluated_lines was empty".to_owned(),
+      })?;
 
-    let mut tempdir_builder = tempfile::Builder::new();
-    tempdir_builder.prefix("just-");
-    let tempdir = match &context.settings.tempdir {
-      Some(tempdir) => tempdir_builder.tempdir_in(context.search.working_directory.join(tempdir)),
-      None => tempdir_builder.tempdir(),
-    }
-    .map_err(|error| Error::TempdirIo {
-      recipe: self.name(),
-      io_error: error,
-    })?;
+      Some(Shebang::new(shebang_line).ok_or_else(|| Error::Internal {
+        message: format!("bad shebang line: {shebang_line}"),
+      })?)
+    };
+
+    let tempdir = self.tempdir(context, dotenv)?;
     let mut path = tempdir.path().to_path_buf();
-    path.push(shebang.script_filename(self.name()));
+    path.push(shebang.map_or_else(
+      || self.name().to_owned(),
+      |shebang| shebang.script_filename(self.name()),
+    ));
 
     {
       let mut f = fs::File::create(&path).map_err(|error| Error::TempdirIo {
@@ -350,25 +765,32 @@ impl<'src, D> Recipe<'src, D> {
       })?;
       let mut text = String::new();
 
-      if shebang.include_shebang_line() {
-        text += &evaluated_lines[0];
-      } else {
-        text += "\n";
-      }
+      if let Some(shebang) = shebang {
+        if shebang.include_shebang_line() {
+          text += &evaluated_lines[0];
+        } else {
+          text += "\n";
+        }
 
-      text += "\n";
-      // add blank lines so that lines in the generated script have the same line
-      // number as the corresponding lines in the justfile
-      for _ in 1..(self.line_number() + 2) {
-        text += "\n";
-      }
-      for line in &evaluated_lines[1..] {
-        text += line;
         text += "\n";
+        // add blank lines so that lines in the generated script have the same line
+        // number as the corresponding lines in the justfile
+        for _ in 1..(self.line_number() + 2) {
+          text += "\n";
+        }
+        for line in &evaluated_lines[1..] {
+          text += line;
+          text += "\n";
+        }
+      } else {
+        for line in &evaluated_lines {
+          text += line;
+          text += "\n";
+        }
       }
 
       if config.verbosity.grandiloquent() {
-        eprintln!("{}", config.color.doc().stderr().paint(&text));
+        context.echo(config.color.doc().stderr().paint(&scope.mask(&text)));
       }
 
       f.write_all(text.as_bytes())
@@ -378,48 +800,98 @@ impl<'src, D> Recipe<'src, D> {
         })?;
     }
 
-    // make script executable
-    Platform::set_execute_permission(&path).map_err(|error| Error::TempdirIo {
-      recipe: self.name(),
-      io_error: error,
-    })?;
+    let mut command = if let Some(body_file_command) = body_file_command {
+      let mut command = context.settings.shell_command(config);
 
-    // create command to run script
-    let mut command =
-      Platform::make_shebang_command(&path, self.working_directory(context.search), shebang)
-        .map_err(|output_error| Error::Cygpath {
-          recipe: self.name(),
-          output_error,
-        })?;
+      command.arg(body_file_command);
+
+      if context.settings.positional_arguments {
+        command.arg(self.name.lexeme());
+        command.args(positional);
+      }
+
+      if let Some(working_directory) = self.working_directory(context.search) {
+        command.current_dir(working_directory);
+      }
+
+      command.env(config::BODY_FILE_ENV, &path);
 
-    if context.settings.positional_arguments {
-      command.args(positional);
+      command
+    } else {
+      // make script executable
+      Platform::set_execute_permission(&path).map_err(|error| Error::TempdirIo {
+        recipe: self.name(),
+        io_error: error,
+      })?;
+
+      // create command to run script
+      let mut command = Platform::make_shebang_command(
+        &path,
+        self.working_directory(context.search),
+        shebang.unwrap(),
+      )
+      .map_err(|output_error| Error::Cygpath {
+        recipe: self.name(),
+        output_error,
+      })?;
+
+      if context.settings.positional_arguments {
+        command.args(positional);
+      }
+
+      command
+    };
+
+    if config.silence_stderr {
+      command.stderr(Stdio::null());
     }
 
-    command.export(context.settings, dotenv, scope);
+    if config.silence_stdout {
+      command.stdout(Stdio::null());
+    }
+
+    command.export(config, context.settings, dotenv, scope);
+
+    let namepath = self.namepath.to_string();
+
+    context.events.line_spawned(
+      &namepath,
+      None,
+      &scope.mask(body_file_command.unwrap_or(&evaluated_lines[0])),
+    );
 
     // run it!
-    match InterruptHandler::guard(|| command.status()) {
-      Ok(exit_status) => exit_status.code().map_or_else(
-        || Err(error_from_signal(self.name(), None, exit_status)),
-        |code| {
-          if code == 0 {
-            Ok(())
-          } else {
-            Err(Error::Code {
-              recipe: self.name(),
-              line_number: None,
-              code,
-              print_message: self.print_exit_message(),
-            })
-          }
+    match context.spawn(&mut command) {
+      Ok(exit_status) => {
+        context.events.line_finished(&namepath, None, exit_status);
+
+        exit_status.code().map_or_else(
+          || Err(error_from_signal(self.name(), None, exit_status)),
+          |code| {
+            if code == 0 {
+              Ok(())
+            } else {
+              Err(Error::Code {
+                recipe: self.name(),
+                line_number: None,
+                code,
+                print_message: self.print_exit_message(),
+              })
+            }
+          },
+        )
+      }
+      Err(io_error) => Err(match shebang {
+        Some(shebang) => Error::Shebang {
+          recipe: self.name(),
+          command: shebang.interpreter.to_owned(),
+          argument: shebang.argument.map(String::from),
+          io_error,
+        },
+        None => Error::Io {
+          recipe: self.name(),
+          io_error,
         },
-      ),
-      Err(io_error) => Err(Error::Shebang {
-        recipe: self.name(),
-        command: shebang.interpreter.to_owned(),
-        argument: shebang.argument.map(String::from),
-        io_error,
       }),
     }
   }
@@ -431,8 +903,8 @@ impl<'src, D: Display> ColorDisplay for Recipe<'src, D> {
       writeln!(f, "# {doc}")?;
     }
 
-    for attribute in &self.attributes {
-      writeln!(f, "[{attribute}]")?;
+    for line in &self.attribute_lines {
+      writeln!(f, "{line}")?;
     }
 
     if self.quiet {
@@ -454,7 +926,24 @@ impl<'src, D: Display> ColorDisplay for Recipe<'src, D> {
       write!(f, " {dependency}")?;
     }
 
-    for (i, line) in self.body.iter().enumerate() {
+    if let Some(comment) = self.comment {
+      write!(f, " {comment}")?;
+    }
+
+    // Runs of blank lines in linewise recipes are collapsed, since they have
+    // no effect. Shebang recipe bodies are passed to the interpreter, and are
+    // left untouched.
+    let body = self
+      .body
+      .iter()
+      .enumerate()
+      .filter(|(i, line)| {
+        self.shebang || *i == 0 || !line.is_empty() || !self.body[i - 1].is_empty()
+      })
+      .map(|(_, line)| line)
+      .collect::<Vec<&Line>>();
+
+    for (i, line) in body.iter().enumerate() {
       if i == 0 {
         writeln!(f)?;
       }
@@ -463,11 +952,14 @@ impl<'src, D: Display> ColorDisplay for Recipe<'src, D> {
           write!(f, "    ")?;
         }
         match fragment {
+          Fragment::Text { token } if !self.shebang && j + 1 == line.fragments.len() => {
+            write!(f, "{}", Self::trim_trailing_whitespace(token.lexeme()))?;
+          }
           Fragment::Text { token } => write!(f, "{}", token.lexeme())?,
           Fragment::Interpolation { expression, .. } => write!(f, "{{{{ {expression} }}}}")?,
         }
       }
-      if i + 1 < self.body.len() {
+      if i + 1 < body.len() {
         writeln!(f)?;
       }
     }
@@ -480,3 +972,46 @@ impl<'src, D> Keyed<'src> for Recipe<'src, D> {
     self.name.lexeme()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn read_arguments() {
+    #[track_caller]
+    fn case(src: &str, input: &str, prompt: &str, expected: &[&str]) {
+      let justfile = testing::compile(src);
+      let recipe = justfile.recipes.get("foo").unwrap();
+
+      let mut output = Vec::new();
+      let mut arguments = Vec::new();
+      recipe
+        .read_arguments(&mut input.as_bytes(), &mut output, &mut arguments)
+        .unwrap();
+
+      assert_eq!(String::from_utf8(output).unwrap(), prompt);
+      assert_eq!(arguments, expected);
+    }
+
+    case("foo a b:", "x\ny\n", "a: b: ", &["x", "y"]);
+    case("foo a b:", "x\r\n\n", "a: b: ", &["x", ""]);
+    case("foo a b='B':", "x\ny\n", "a: b ['B']: ", &["x", "y"]);
+    case("foo a b='B':", "x\n\n", "a: b ['B']: ", &["x"]);
+    case("foo a b='B':", "x\n", "a: b ['B']: ", &["x"]);
+    case("foo a b='B' c='C':", "x\n\nz\n", "a: b ['B']: ", &["x"]);
+  }
+
+  #[test]
+  fn read_arguments_end_of_input() {
+    let justfile = testing::compile("foo a b:");
+    let recipe = justfile.recipes.get("foo").unwrap();
+
+    let mut arguments = Vec::new();
+
+    assert_matches!(
+      recipe.read_arguments(&mut "x\n".as_bytes(), &mut Vec::new(), &mut arguments),
+      Err(Error::GetArgument { io_error }) if io_error.kind() == io::ErrorKind::UnexpectedEof
+    );
+  }
+}
@@ -1,8 +1,31 @@
 use super::*;
 
 pub(crate) struct RecipeContext<'src: 'run, 'run> {
+  pub(crate) buffer: Option<&'run OutputBuffer>,
   pub(crate) config: &'run Config,
+  pub(crate) events: &'run dyn Events,
+  pub(crate) jobs: &'run Jobs,
   pub(crate) scope: &'run Scope<'src, 'run>,
   pub(crate) search: &'run Search,
   pub(crate) settings: &'run Settings<'src>,
 }
+
+impl<'src: 'run, 'run> RecipeContext<'src, 'run> {
+  /// Write `line` to stderr, or to the output buffer if the recipe is being
+  /// run as a parallel dependency
+  pub(crate) fn echo(&self, line: impl Display) {
+    match self.buffer {
+      Some(buffer) => buffer.echo(line),
+      None => eprintln!("{line}"),
+    }
+  }
+
+  /// Run `command` to completion, buffering its output if the recipe is being
+  /// run as a parallel dependency
+  pub(crate) fn spawn(&self, command: &mut Command) -> io::Result<ExitStatus> {
+    match self.buffer {
+      Some(buffer) => buffer.spawn(command),
+      None => command.spawn_status(),
+    }
+  }
+}
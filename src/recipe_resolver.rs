@@ -2,19 +2,23 @@ use {super::*, CompileErrorKind::*};
 
 pub(crate) struct RecipeResolver<'src: 'run, 'run> {
   unresolved_recipes: Table<'src, UnresolvedRecipe<'src>>,
-  resolved_recipes: Table<'src, Rc<Recipe<'src>>>,
+  resolved_recipes: Table<'src, Arc<Recipe<'src>>>,
   assignments: &'run Table<'src, Assignment<'src>>,
+  /// Dependency tokens followed to reach each recipe on the stack after the
+  /// first
+  edges: Vec<Token<'src>>,
 }
 
 impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
   pub(crate) fn resolve_recipes(
     unresolved_recipes: Table<'src, UnresolvedRecipe<'src>>,
     assignments: &'run Table<'src, Assignment<'src>>,
-  ) -> CompileResult<'src, Table<'src, Rc<Recipe<'src>>>> {
+  ) -> CompileResult<'src, Table<'src, Arc<Recipe<'src>>>> {
     let mut resolver = Self {
       resolved_recipes: Table::new(),
       unresolved_recipes,
       assignments,
+      edges: Vec::new(),
     };
 
     while let Some(unresolved) = resolver.unresolved_recipes.pop() {
@@ -36,6 +40,12 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
             resolver.resolve_variable(&variable, &recipe.parameters)?;
           }
         }
+
+        if let Some(condition) = &dependency.condition {
+          for variable in condition.lhs.variables().chain(condition.rhs.variables()) {
+            resolver.resolve_variable(&variable, &recipe.parameters)?;
+          }
+        }
       }
 
       for line in &recipe.body {
@@ -72,36 +82,45 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
     &mut self,
     stack: &mut Vec<&'src str>,
     recipe: UnresolvedRecipe<'src>,
-  ) -> CompileResult<'src, Rc<Recipe<'src>>> {
+  ) -> CompileResult<'src, Arc<Recipe<'src>>> {
     if let Some(resolved) = self.resolved_recipes.get(recipe.name()) {
-      return Ok(Rc::clone(resolved));
+      return Ok(Arc::clone(resolved));
     }
 
     stack.push(recipe.name());
 
-    let mut dependencies: Vec<Rc<Recipe>> = Vec::new();
+    let mut dependencies: Vec<Arc<Recipe>> = Vec::new();
     for dependency in &recipe.dependencies {
       let name = dependency.recipe.lexeme();
 
       if let Some(resolved) = self.resolved_recipes.get(name) {
         // dependency already resolved
-        dependencies.push(Rc::clone(resolved));
-      } else if stack.contains(&name) {
-        let first = stack[0];
-        stack.push(first);
+        dependencies.push(Arc::clone(resolved));
+      } else if let Some(start) = stack.iter().position(|recipe| *recipe == name) {
         return Err(
-          dependency.recipe.error(CircularRecipeDependency {
-            recipe: recipe.name(),
-            circle: stack
-              .iter()
-              .skip_while(|name| **name != dependency.recipe.lexeme())
-              .copied()
-              .collect(),
-          }),
+          dependency
+            .recipe
+            .error(CircularRecipeDependency {
+              recipe: recipe.name(),
+              circle: stack[start..]
+                .iter()
+                .copied()
+                .chain(iter::once(name))
+                .collect(),
+            })
+            .with_cycle(
+              self.edges[start..]
+                .iter()
+                .copied()
+                .chain(iter::once(dependency.recipe.token))
+                .collect(),
+            ),
         );
       } else if let Some(unresolved) = self.unresolved_recipes.remove(name) {
         // resolve unresolved dependency
+        self.edges.push(dependency.recipe.token);
         dependencies.push(self.resolve_recipe(stack, unresolved)?);
+        self.edges.pop();
       } else {
         // dependency is unknown
         return Err(dependency.recipe.error(UnknownDependency {
@@ -113,8 +132,8 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
 
     stack.pop();
 
-    let resolved = Rc::new(recipe.resolve(dependencies)?);
-    self.resolved_recipes.insert(Rc::clone(&resolved));
+    let resolved = Arc::new(recipe.resolve(dependencies)?);
+    self.resolved_recipes.insert(Arc::clone(&resolved));
     Ok(resolved)
   }
 }
@@ -131,6 +150,18 @@ mod tests {
     column: 3,
     width:  1,
     kind:   CircularRecipeDependency{recipe: "b", circle: vec!["a", "b", "a"]},
+    cycle:  [(3, 0, 3, 1), (8, 1, 3, 1)],
+  }
+
+  analysis_error! {
+    name:   circular_recipe_dependency_with_lead_in,
+    input:  "a: b\nb: c\nc: b",
+    offset: 13,
+    line:   2,
+    column: 3,
+    width:  1,
+    kind:   CircularRecipeDependency{recipe: "c", circle: vec!["b", "c", "b"]},
+    cycle:  [(8, 1, 3, 1), (13, 2, 3, 1)],
   }
 
   analysis_error! {
@@ -141,6 +172,7 @@ mod tests {
     column: 3,
     width:  1,
     kind:   CircularRecipeDependency{recipe: "a", circle: vec!["a", "a"]},
+    cycle:  [(3, 0, 3, 1)],
   }
 
   analysis_error! {
@@ -20,10 +20,10 @@ pub fn run() -> Result<(), i32> {
 
   let config = Config::from_matches(&matches).map_err(Error::from);
 
-  let (color, verbosity) = config
+  let (color, exit_codes, verbosity) = config
     .as_ref()
-    .map(|config| (config.color, config.verbosity))
-    .unwrap_or((Color::auto(), Verbosity::default()));
+    .map(|config| (config.color, config.exit_codes, config.verbosity))
+    .unwrap_or((Color::auto(), ExitCodes::Passthrough, Verbosity::default()));
 
   let loader = Loader::new();
 
@@ -33,6 +33,6 @@ pub fn run() -> Result<(), i32> {
       if !verbosity.quiet() && error.print_message() {
         eprintln!("{}", error.color_display(color.stderr()));
       }
-      error.code().unwrap_or(EXIT_FAILURE)
+      exit_codes.code(&error)
     })
 }
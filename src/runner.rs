@@ -0,0 +1,578 @@
+//! Embeddable justfile runner.
+//!
+//! `Runner` runs recipes without going through the command line, so that
+//! tools embedding `just` do not need to construct `argv` for `just::run`:
+//!
+//! ```ignore
+//! just::Runner::new()
+//!   .justfile("project/justfile")
+//!   .set("target", "release")
+//!   .verbosity(just::runner::Verbosity::Quiet)
+//!   .recipes(["build", "test"])
+//!   .run()?;
+//! ```
+//!
+//! Recipes and their arguments are given in the same order as on the command
+//! line. If no recipes are given, the default recipe is run.
+
+use {
+  crate::{
+    color, compiler::Compiler, config::Config, dump, dump_format::DumpFormat,
+    error::Error as RunError, events::Events, exit_codes::ExitCodes, loader::Loader,
+    search::Search, search_config::SearchConfig, search_limit::SearchLimit, subcommand::Subcommand,
+    verbosity, EXIT_FAILURE,
+  },
+  std::{
+    collections::BTreeMap,
+    env, error,
+    fmt::{self, Display, Formatter},
+    path::{Path, PathBuf},
+  },
+};
+
+/// Where the justfile to run comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+  /// Search for a justfile, as `just` does when run without `--justfile`
+  Search,
+  /// Read the justfile at this path, along with its imports and modules
+  Path(PathBuf),
+  /// Compile this justfile source. Imports and modules are not loaded.
+  Text(String),
+}
+
+/// How much `just` prints while running recipes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Verbosity {
+  /// Suppress all output from `just` itself, like `--quiet`
+  Quiet,
+  /// Print recipe lines before running them
+  #[default]
+  Normal,
+  /// Also print informational messages, like `--verbose`
+  Verbose,
+  /// Print even more, like `--verbose --verbose`
+  VeryVerbose,
+}
+
+impl Verbosity {
+  fn verbosity(self) -> verbosity::Verbosity {
+    match self {
+      Self::Quiet => verbosity::Verbosity::Quiet,
+      Self::Normal => verbosity::Verbosity::Taciturn,
+      Self::Verbose => verbosity::Verbosity::Loquacious,
+      Self::VeryVerbose => verbosity::Verbosity::Grandiloquent,
+    }
+  }
+}
+
+/// When to print colored output
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Color {
+  /// Print colored output if the output stream is a terminal, unless
+  /// overridden by `NO_COLOR`, `FORCE_COLOR`, `CLICOLOR_FORCE`, or `CLICOLOR`
+  #[default]
+  Auto,
+  /// Always print colored output
+  Always,
+  /// Never print colored output
+  Never,
+}
+
+impl Color {
+  fn color(self) -> color::Color {
+    match self {
+      Self::Auto => color::Color::auto(),
+      Self::Always => color::Color::always(),
+      Self::Never => color::Color::never(),
+    }
+  }
+}
+
+/// Builder that runs recipes from a justfile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Runner {
+  arguments: Vec<String>,
+  color: Color,
+  dry_run: bool,
+  overrides: BTreeMap<String, String>,
+  source: Source,
+  verbosity: Verbosity,
+  working_directory: Option<PathBuf>,
+  yes: bool,
+}
+
+impl Default for Runner {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Runner {
+  /// Create a runner which searches for a justfile starting in the current
+  /// directory and runs the default recipe
+  #[must_use]
+  pub fn new() -> Self {
+    Self {
+      arguments: Vec::new(),
+      color: Color::default(),
+      dry_run: false,
+      overrides: BTreeMap::new(),
+      source: Source::Search,
+      verbosity: Verbosity::default(),
+      working_directory: None,
+      yes: false,
+    }
+  }
+
+  /// Run the justfile at `path`, like `--justfile`
+  #[must_use]
+  pub fn justfile(mut self, path: impl Into<PathBuf>) -> Self {
+    self.source = Source::Path(path.into());
+    self
+  }
+
+  /// Run justfile source `src`. Imports and modules are not loaded.
+  #[must_use]
+  pub fn source(mut self, src: impl Into<String>) -> Self {
+    self.source = Source::Text(src.into());
+    self
+  }
+
+  /// Run recipes in `path`, like `--working-directory`. If neither a justfile
+  /// nor source is given, instead search for a justfile starting in `path`
+  /// and run recipes in the directory containing it, like `just path/`.
+  #[must_use]
+  pub fn working_directory(mut self, path: impl Into<PathBuf>) -> Self {
+    self.working_directory = Some(path.into());
+    self
+  }
+
+  /// Override variable `name` with `value`, like `--set`
+  #[must_use]
+  pub fn set(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    self.overrides.insert(name.into(), value.into());
+    self
+  }
+
+  /// Set verbosity
+  #[must_use]
+  pub fn verbosity(mut self, verbosity: Verbosity) -> Self {
+    self.verbosity = verbosity;
+    self
+  }
+
+  /// Set when to print colored output
+  #[must_use]
+  pub fn color(mut self, color: Color) -> Self {
+    self.color = color;
+    self
+  }
+
+  /// Print recipe lines instead of running them, like `--dry-run`
+  #[must_use]
+  pub fn dry_run(mut self, dry_run: bool) -> Self {
+    self.dry_run = dry_run;
+    self
+  }
+
+  /// Automatically confirm recipes with `[confirm]`, like `--yes`
+  #[must_use]
+  pub fn yes(mut self, yes: bool) -> Self {
+    self.yes = yes;
+    self
+  }
+
+  /// Append a recipe name or recipe argument
+  #[must_use]
+  pub fn recipe(mut self, recipe: impl Into<String>) -> Self {
+    self.arguments.push(recipe.into());
+    self
+  }
+
+  /// Append recipe names and recipe arguments
+  #[must_use]
+  pub fn recipes<I, S>(mut self, recipes: I) -> Self
+  where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+  {
+    self.arguments.extend(recipes.into_iter().map(Into::into));
+    self
+  }
+
+  /// Run recipes. Output from recipes is not captured.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the justfile cannot be found or compiled, or if a
+  /// recipe cannot be run or fails.
+  pub fn run(&self) -> Result<(), Error> {
+    self.run_with(&())
+  }
+
+  /// Run recipes, calling `events` as recipes and their lines run
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the justfile cannot be found or compiled, or if a
+  /// recipe cannot be run or fails.
+  pub fn run_with(&self, events: &dyn Events) -> Result<(), Error> {
+    let invocation_directory = env::current_dir().map_err(|io_error| Error {
+      code: EXIT_FAILURE,
+      message: format!("Error getting current directory: {io_error}"),
+      path: None,
+      line: None,
+      column: None,
+    })?;
+
+    let config = self.config(invocation_directory);
+
+    let loader = Loader::new();
+
+    let result = match &self.source {
+      Source::Search | Source::Path(_) => {
+        Subcommand::run(&config, &loader, &self.arguments, &self.overrides, events)
+      }
+      Source::Text(src) => self.run_source(&config, src, events),
+    };
+
+    result.map_err(|error| Error::new(&error))
+  }
+
+  fn run_source<'src>(
+    &self,
+    config: &Config,
+    src: &'src str,
+    events: &dyn Events,
+  ) -> Result<(), RunError<'src>> {
+    let working_directory = config.invocation_directory.join(
+      self
+        .working_directory
+        .as_deref()
+        .unwrap_or_else(|| Path::new("")),
+    );
+
+    let search = Search {
+      justfile: working_directory.join("justfile"),
+      working_directory,
+    };
+
+    let justfile = Compiler::compile_src(Path::new("justfile"), src)?;
+
+    justfile.run(config, &search, &self.overrides, &self.arguments, events)
+  }
+
+  fn config(&self, invocation_directory: PathBuf) -> Config {
+    let search_config = match (&self.source, &self.working_directory) {
+      (Source::Path(justfile), Some(working_directory)) => {
+        SearchConfig::WithJustfileAndWorkingDirectory {
+          justfile: justfile.clone(),
+          working_directory: working_directory.clone(),
+        }
+      }
+      (Source::Path(justfile), None) => SearchConfig::WithJustfile {
+        justfile: justfile.clone(),
+      },
+      (_, Some(search_directory)) => SearchConfig::FromSearchDirectory {
+        search_directory: search_directory.clone(),
+      },
+      (_, None) => SearchConfig::FromInvocationDirectory,
+    };
+
+    Config {
+      align: false,
+      check: false,
+      color: self.color.color(),
+      command_color: None,
+      dependency_arguments: BTreeMap::new(),
+      dotenv_filename: None,
+      dotenv_path: None,
+      dry_run: self.dry_run,
+      dump_format: DumpFormat::Just,
+      exit_codes: ExitCodes::Passthrough,
+      highlight: true,
+      invocation_directory,
+      jobs: num_cpus::get(),
+      json: false,
+      list_flat: false,
+      list_heading: "Available recipes:\n".into(),
+      list_prefix: "    ".into(),
+      load_dotenv: true,
+      locations: false,
+      no_aliases: false,
+      no_dependencies: false,
+      search_config,
+      search_limit: SearchLimit::default(),
+      shell: None,
+      shell_args: None,
+      shell_command: false,
+      silence_stderr: false,
+      silence_stdout: false,
+      stdin: false,
+      subcommand: Subcommand::Run {
+        arguments: self.arguments.clone(),
+        overrides: self.overrides.clone(),
+        passthrough: Vec::new(),
+      },
+      timings: false,
+      trace_evaluation: false,
+      transcript: None,
+      unsorted: false,
+      unstable: false,
+      verbosity: self.verbosity.verbosity(),
+      warn_as_error: false,
+      yes: self.yes,
+    }
+  }
+}
+
+/// An error encountered while running recipes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+  /// Exit code `just` would exit with
+  pub code: i32,
+  /// Error message
+  pub message: String,
+  /// Path of the file in which the error occurred, if known
+  pub path: Option<PathBuf>,
+  /// One-based line on which the error occurred, if known
+  pub line: Option<usize>,
+  /// One-based column at which the error occurred, if known
+  pub column: Option<usize>,
+}
+
+impl Error {
+  fn new(error: &RunError) -> Self {
+    let dump::Error {
+      message,
+      path,
+      line,
+      column,
+    } = dump::Error::new(error);
+
+    Self {
+      code: error.code().unwrap_or(EXIT_FAILURE),
+      message,
+      path,
+      line,
+      column,
+    }
+  }
+}
+
+impl Display for Error {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    if let (Some(path), Some(line), Some(column)) = (&self.path, self.line, self.column) {
+      write!(f, "{}:{line}:{column}: ", path.display())?;
+    }
+    write!(f, "{}", self.message)
+  }
+}
+
+impl error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+  use {
+    super::*,
+    crate::testing,
+    std::{fs, process::ExitStatus, sync::Mutex},
+  };
+
+  #[derive(Default)]
+  struct Recorder(Mutex<Vec<String>>);
+
+  impl Events for Recorder {
+    fn recipe_started(&self, recipe: &str) {
+      self.0.lock().unwrap().push(format!("started {recipe}"));
+    }
+
+    fn line_spawned(&self, recipe: &str, line: Option<usize>, command: &str) {
+      self
+        .0
+        .lock()
+        .unwrap()
+        .push(format!("spawned {recipe} {line:?} {command}"));
+    }
+
+    fn line_finished(&self, recipe: &str, line: Option<usize>, status: ExitStatus) {
+      self
+        .0
+        .lock()
+        .unwrap()
+        .push(format!("finished {recipe} {line:?} {:?}", status.code()));
+    }
+
+    fn recipe_finished(&self, recipe: &str, success: bool) {
+      self
+        .0
+        .lock()
+        .unwrap()
+        .push(format!("finished {recipe} {success}"));
+    }
+  }
+
+  fn runner() -> Runner {
+    Runner::new()
+      .verbosity(Verbosity::Quiet)
+      .color(Color::Never)
+  }
+
+  #[test]
+  fn source() {
+    let tmp = testing::tempdir();
+
+    runner()
+      .source("foo x:\n  echo {{x}} > out")
+      .working_directory(tmp.path())
+      .recipes(["foo", "bar"])
+      .run()
+      .unwrap();
+
+    assert_eq!(fs::read_to_string(tmp.path().join("out")).unwrap(), "bar\n");
+  }
+
+  #[test]
+  fn justfile() {
+    let tmp = testing::tempdir();
+
+    fs::write(
+      tmp.path().join("justfile"),
+      "x := 'foo'\ndefault:\n  echo {{x}} > out",
+    )
+    .unwrap();
+
+    runner()
+      .justfile(tmp.path().join("justfile"))
+      .set("x", "bar")
+      .run()
+      .unwrap();
+
+    assert_eq!(fs::read_to_string(tmp.path().join("out")).unwrap(), "bar\n");
+  }
+
+  #[test]
+  fn search() {
+    let tmp = testing::tempdir();
+
+    fs::write(tmp.path().join("justfile"), "foo:\n  touch out").unwrap();
+
+    fs::create_dir(tmp.path().join("sub")).unwrap();
+
+    runner()
+      .working_directory(tmp.path().join("sub"))
+      .recipe("foo")
+      .run()
+      .unwrap();
+
+    assert!(tmp.path().join("out").is_file());
+  }
+
+  #[test]
+  fn dry_run() {
+    let tmp = testing::tempdir();
+
+    runner()
+      .source("foo:\n  touch out")
+      .working_directory(tmp.path())
+      .dry_run(true)
+      .run()
+      .unwrap();
+
+    assert!(!tmp.path().join("out").exists());
+  }
+
+  #[test]
+  fn recipe_failure() {
+    let tmp = testing::tempdir();
+
+    assert_eq!(
+      runner()
+        .source("foo:\n  exit 7")
+        .working_directory(tmp.path())
+        .run()
+        .unwrap_err(),
+      Error {
+        code: 7,
+        message: "Recipe `foo` failed on line 2 with exit code 7".into(),
+        path: None,
+        line: None,
+        column: None,
+      },
+    );
+  }
+
+  #[test]
+  fn compile_error() {
+    let error = runner().source("foo: bar").run().unwrap_err();
+    assert_eq!(error.code, EXIT_FAILURE);
+    assert_eq!(
+      error.to_string(),
+      "justfile:1:6: Recipe `foo` has unknown dependency `bar`"
+    );
+  }
+
+  #[test]
+  fn unknown_override() {
+    let error = runner().source("foo:").set("x", "y").run().unwrap_err();
+    assert_eq!(
+      error.message,
+      "Variable `x` overridden on the command line but not present in justfile"
+    );
+  }
+
+  #[test]
+  fn events() {
+    let tmp = testing::tempdir();
+
+    let recorder = Recorder::default();
+
+    let error = runner()
+      .source("foo: bar\n  exit 3\nbar:\n  true\n  -false")
+      .working_directory(tmp.path())
+      .run_with(&recorder)
+      .unwrap_err();
+
+    assert_eq!(error.code, 3);
+
+    assert_eq!(
+      recorder.0.into_inner().unwrap(),
+      [
+        "started bar",
+        "spawned bar Some(4) true",
+        "finished bar Some(4) Some(0)",
+        "spawned bar Some(5) false",
+        "finished bar Some(5) Some(1)",
+        "finished bar true",
+        "started foo",
+        "spawned foo Some(2) exit 3",
+        "finished foo Some(2) Some(3)",
+        "finished foo false",
+      ],
+    );
+  }
+
+  #[test]
+  fn shebang_events() {
+    let tmp = testing::tempdir();
+
+    let recorder = Recorder::default();
+
+    runner()
+      .source("foo:\n  #!/usr/bin/env sh\n  true")
+      .working_directory(tmp.path())
+      .run_with(&recorder)
+      .unwrap();
+
+    assert_eq!(
+      recorder.0.into_inner().unwrap(),
+      [
+        "started foo",
+        "spawned foo None #!/usr/bin/env sh",
+        "finished foo None Some(0)",
+        "finished foo true",
+      ],
+    );
+  }
+}
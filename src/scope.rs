@@ -7,6 +7,10 @@ pub(crate) struct Scope<'src: 'run, 'run> {
 }
 
 impl<'src, 'run> Scope<'src, 'run> {
+  /// Secret values shorter than this are not masked, since replacing every
+  /// occurrence of a short string would mangle unrelated output
+  pub(crate) const MIN_MASKED_LENGTH: usize = 4;
+
   pub(crate) fn child(&'run self) -> Self {
     Self {
       parent: Some(self),
@@ -21,11 +25,30 @@ impl<'src, 'run> Scope<'src, 'run> {
     }
   }
 
-  pub(crate) fn bind(&mut self, export: bool, name: Name<'src>, value: String) {
+  pub(crate) fn bind(&mut self, assignment: &Assignment<'src>, value: String) {
     self.bindings.insert(Binding {
+      attribute_lines: Vec::new(),
+      choices: Vec::new(),
       depth: 0,
-      export,
-      name,
+      doc: None,
+      export: assignment.export,
+      export_name: None,
+      name: assignment.name,
+      secret: assignment.secret,
+      value,
+    });
+  }
+
+  pub(crate) fn bind_parameter(&mut self, parameter: &Parameter<'src>, value: String) {
+    self.bindings.insert(Binding {
+      attribute_lines: Vec::new(),
+      choices: Vec::new(),
+      depth: 0,
+      doc: None,
+      export: parameter.export,
+      export_name: parameter.export_name,
+      name: parameter.name,
+      secret: parameter.secret,
       value,
     });
   }
@@ -42,6 +65,48 @@ impl<'src, 'run> Scope<'src, 'run> {
     }
   }
 
+  /// Whether the binding of `name` is secret
+  pub(crate) fn secret(&self, name: &str) -> bool {
+    if let Some(binding) = self.bindings.get(name) {
+      binding.secret
+    } else {
+      self.parent.map_or(false, |parent| parent.secret(name))
+    }
+  }
+
+  /// Whether the secret `value` is long enough to be masked by `mask`
+  pub(crate) fn maskable(value: &str) -> bool {
+    value.chars().count() >= Self::MIN_MASKED_LENGTH
+  }
+
+  /// Replace the values of secret bindings in this scope and its parents
+  /// which appear in `text` with `***`. Values which are not `maskable` are
+  /// left as is.
+  pub(crate) fn mask(&self, text: &str) -> String {
+    let mut secrets = Vec::new();
+
+    let mut scope = Some(self);
+    while let Some(current) = scope {
+      secrets.extend(
+        current
+          .bindings
+          .values()
+          .filter(|binding| binding.secret && Self::maskable(&binding.value))
+          .map(|binding| binding.value.as_str()),
+      );
+      scope = current.parent;
+    }
+
+    // Replace longer secrets first, in case one contains another
+    secrets.sort_by_key(|secret| cmp::Reverse(secret.len()));
+
+    let mut text = text.to_owned();
+    for secret in secrets {
+      text = text.replace(secret, "***");
+    }
+    text
+  }
+
   pub(crate) fn bindings(&self) -> impl Iterator<Item = &Binding<String>> {
     self.bindings.values()
   }
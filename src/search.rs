@@ -1,6 +1,7 @@
 use {super::*, std::path::Component};
 
 const DEFAULT_JUSTFILE_NAME: &str = JUSTFILE_NAMES[0];
+const JUSTIGNORE_NAME: &str = ".justignore";
 pub(crate) const JUSTFILE_NAMES: &[&str] = &["justfile", ".justfile"];
 const PROJECT_ROOT_CHILDREN: &[&str] = &[".bzr", ".git", ".hg", ".svn", "_darcs"];
 
@@ -12,14 +13,17 @@ pub(crate) struct Search {
 impl Search {
   pub(crate) fn find(
     search_config: &SearchConfig,
+    search_limit: SearchLimit,
     invocation_directory: &Path,
   ) -> SearchResult<Self> {
     match search_config {
-      SearchConfig::FromInvocationDirectory => Self::find_next(invocation_directory),
+      SearchConfig::FromInvocationDirectory => {
+        Self::find_next(search_limit.ancestors(invocation_directory)?)
+      }
       SearchConfig::FromSearchDirectory { search_directory } => {
         let search_directory = Self::clean(invocation_directory, search_directory);
 
-        let justfile = Self::justfile(&search_directory)?;
+        let justfile = Self::justfile(search_limit.ancestors(&search_directory)?)?;
 
         let working_directory = Self::working_directory_from_justfile(&justfile)?;
 
@@ -48,8 +52,11 @@ impl Search {
     }
   }
 
-  pub(crate) fn find_next(starting_dir: &Path) -> SearchResult<Self> {
-    let justfile = Self::justfile(starting_dir)?;
+  /// Search `directories`, in order, for a justfile
+  pub(crate) fn find_next<'a>(
+    directories: impl IntoIterator<Item = &'a Path>,
+  ) -> SearchResult<Self> {
+    let justfile = Self::justfile(directories)?;
 
     let working_directory = Self::working_directory_from_justfile(&justfile)?;
 
@@ -109,8 +116,14 @@ impl Search {
     }
   }
 
-  pub(crate) fn justfile(directory: &Path) -> SearchResult<PathBuf> {
-    for directory in directory.ancestors() {
+  pub(crate) fn justfile<'a>(
+    directories: impl IntoIterator<Item = &'a Path>,
+  ) -> SearchResult<PathBuf> {
+    for directory in directories {
+      if Self::ignored(directory)? {
+        continue;
+      }
+
       let mut candidates = BTreeSet::new();
 
       let entries = fs::read_dir(directory).map_err(|io_error| SearchError::Io {
@@ -141,6 +154,68 @@ impl Search {
     Err(SearchError::NotFound)
   }
 
+  /// Whether `directory` is excluded from the search by a pattern in a
+  /// `.justignore` file in one of its ancestors
+  fn ignored(directory: &Path) -> SearchResult<bool> {
+    for ancestor in directory.ancestors().skip(1) {
+      let patterns = match fs::read_to_string(ancestor.join(JUSTIGNORE_NAME)) {
+        Ok(patterns) => patterns,
+        Err(io_error) if io_error.kind() == io::ErrorKind::NotFound => continue,
+        Err(io_error) => {
+          return Err(SearchError::Io {
+            io_error,
+            directory: ancestor.to_owned(),
+          })
+        }
+      };
+
+      let components = directory
+        .strip_prefix(ancestor)
+        .unwrap()
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<String>>();
+
+      for pattern in patterns.lines().map(str::trim) {
+        if pattern.is_empty() || pattern.starts_with('#') {
+          continue;
+        }
+
+        if Self::ignore_pattern_matches(pattern, &components) {
+          return Ok(true);
+        }
+      }
+    }
+
+    Ok(false)
+  }
+
+  /// Whether `pattern` from a `.justignore` file matches the directory with
+  /// path `components` relative to the `.justignore` file. Patterns without
+  /// a `/` match a directory with a matching name at any depth, and patterns
+  /// with a `/` match a path relative to the `.justignore` file. In both
+  /// cases, directories inside a matching directory also match.
+  fn ignore_pattern_matches(pattern: &str, components: &[String]) -> bool {
+    let pattern = pattern.trim_end_matches('/');
+
+    if pattern.contains('/') {
+      let segments = pattern
+        .trim_start_matches('/')
+        .split('/')
+        .map(wildcard_regex)
+        .collect::<Vec<Regex>>();
+
+      segments.len() <= components.len()
+        && segments
+          .iter()
+          .zip(components)
+          .all(|(segment, component)| segment.is_match(component))
+    } else {
+      let regex = wildcard_regex(pattern);
+      components.iter().any(|component| regex.is_match(component))
+    }
+  }
+
   fn clean(invocation_directory: &Path, path: &Path) -> PathBuf {
     let path = invocation_directory.join(path);
 
@@ -161,25 +236,34 @@ impl Search {
 
   fn project_root(directory: &Path) -> SearchResult<PathBuf> {
     for directory in directory.ancestors() {
-      let entries = fs::read_dir(directory).map_err(|io_error| SearchError::Io {
+      if Self::is_project_root(directory)? {
+        return Ok(directory.to_owned());
+      }
+    }
+
+    Ok(directory.to_owned())
+  }
+
+  /// Whether `directory` contains a version control directory, like `.git`
+  pub(crate) fn is_project_root(directory: &Path) -> SearchResult<bool> {
+    let entries = fs::read_dir(directory).map_err(|io_error| SearchError::Io {
+      io_error,
+      directory: directory.to_owned(),
+    })?;
+
+    for entry in entries {
+      let entry = entry.map_err(|io_error| SearchError::Io {
         io_error,
         directory: directory.to_owned(),
       })?;
-
-      for entry in entries {
-        let entry = entry.map_err(|io_error| SearchError::Io {
-          io_error,
-          directory: directory.to_owned(),
-        })?;
-        for project_root_child in PROJECT_ROOT_CHILDREN.iter().copied() {
-          if entry.file_name() == project_root_child {
-            return Ok(directory.to_owned());
-          }
+      for project_root_child in PROJECT_ROOT_CHILDREN.iter().copied() {
+        if entry.file_name() == project_root_child {
+          return Ok(true);
         }
       }
     }
 
-    Ok(directory.to_owned())
+    Ok(false)
   }
 
   fn working_directory_from_justfile(justfile: &Path) -> SearchResult<PathBuf> {
@@ -202,7 +286,7 @@ mod tests {
   #[test]
   fn not_found() {
     let tmp = testing::tempdir();
-    match Search::justfile(tmp.path()) {
+    match Search::justfile(tmp.path().ancestors()) {
       Err(SearchError::NotFound) => {}
       _ => panic!("No justfile found error was expected"),
     }
@@ -222,7 +306,7 @@ mod tests {
     }
     fs::write(&path, "default:\n\techo ok").unwrap();
     path.pop();
-    match Search::justfile(path.as_path()) {
+    match Search::justfile(path.ancestors()) {
       Err(SearchError::MultipleCandidates { .. }) => {}
       _ => panic!("Multiple candidates error was expected"),
     }
@@ -235,7 +319,7 @@ mod tests {
     path.push(DEFAULT_JUSTFILE_NAME);
     fs::write(&path, "default:\n\techo ok").unwrap();
     path.pop();
-    if let Err(err) = Search::justfile(path.as_path()) {
+    if let Err(err) = Search::justfile(path.ancestors()) {
       panic!("No errors were expected: {err}");
     }
   }
@@ -258,7 +342,7 @@ mod tests {
     path.push(spongebob_case);
     fs::write(&path, "default:\n\techo ok").unwrap();
     path.pop();
-    if let Err(err) = Search::justfile(path.as_path()) {
+    if let Err(err) = Search::justfile(path.ancestors()) {
       panic!("No errors were expected: {err}");
     }
   }
@@ -274,7 +358,7 @@ mod tests {
     fs::create_dir(&path).expect("test justfile search: failed to create intermediary directory");
     path.push("b");
     fs::create_dir(&path).expect("test justfile search: failed to create intermediary directory");
-    if let Err(err) = Search::justfile(path.as_path()) {
+    if let Err(err) = Search::justfile(path.ancestors()) {
       panic!("No errors were expected: {err}");
     }
   }
@@ -293,7 +377,7 @@ mod tests {
     path.pop();
     path.push("b");
     fs::create_dir(&path).expect("test justfile search: failed to create intermediary directory");
-    match Search::justfile(path.as_path()) {
+    match Search::justfile(path.ancestors()) {
       Ok(found_path) => {
         path.pop();
         path.push(DEFAULT_JUSTFILE_NAME);
@@ -322,12 +406,120 @@ mod tests {
 
     let search_config = SearchConfig::FromInvocationDirectory;
 
-    let search = Search::find(&search_config, &sub).unwrap();
+    let search = Search::find(&search_config, SearchLimit::default(), &sub).unwrap();
 
     assert_eq!(search.justfile, justfile);
     assert_eq!(search.working_directory, sub);
   }
 
+  #[test]
+  fn depth_limit() {
+    let tmp = temptree! {
+      justfile: "",
+      a: {
+        b: {},
+      },
+    };
+
+    let b = tmp.path().join("a").join("b");
+
+    let limit = SearchLimit {
+      depth: Some(1),
+      ..SearchLimit::default()
+    };
+
+    match Search::justfile(limit.ancestors(&b).unwrap()) {
+      Err(SearchError::NotFound) => {}
+      _ => panic!("No justfile found error was expected"),
+    }
+
+    let limit = SearchLimit {
+      depth: Some(2),
+      ..SearchLimit::default()
+    };
+
+    assert_eq!(
+      Search::justfile(limit.ancestors(&b).unwrap()).unwrap(),
+      tmp.path().join("justfile"),
+    );
+  }
+
+  #[test]
+  fn project_root_limit() {
+    let tmp = temptree! {
+      justfile: "",
+      a: {
+        b: {},
+      },
+    };
+
+    fs::create_dir(tmp.path().join("a").join(".git")).unwrap();
+
+    let b = tmp.path().join("a").join("b");
+
+    let limit = SearchLimit {
+      project_root: true,
+      ..SearchLimit::default()
+    };
+
+    assert_eq!(
+      limit.ancestors(&b).unwrap(),
+      [b.as_path(), tmp.path().join("a").as_path()],
+    );
+
+    match Search::justfile(limit.ancestors(&b).unwrap()) {
+      Err(SearchError::NotFound) => {}
+      _ => panic!("No justfile found error was expected"),
+    }
+  }
+
+  #[test]
+  fn ignored_directory_is_skipped() {
+    let tmp = temptree! {
+      justfile: "",
+      ".justignore": "# dependencies\nnode_modules/\n",
+      node_modules: {
+        pkg: {
+          justfile: "",
+        },
+      },
+    };
+
+    let pkg = tmp.path().join("node_modules").join("pkg");
+
+    assert_eq!(
+      Search::justfile(pkg.ancestors()).unwrap(),
+      tmp.path().join("justfile"),
+    );
+  }
+
+  #[test]
+  fn ignore_pattern_matches() {
+    #[track_caller]
+    fn case(pattern: &str, path: &str, want: bool) {
+      let components = path.split('/').map(str::to_owned).collect::<Vec<String>>();
+      assert_eq!(
+        Search::ignore_pattern_matches(pattern, &components),
+        want,
+        "pattern `{pattern}` against `{path}`",
+      );
+    }
+
+    case("node_modules", "node_modules", true);
+    case("node_modules", "node_modules/pkg", true);
+    case("node_modules", "a/node_modules/pkg", true);
+    case("node_modules/", "node_modules", true);
+    case("node_modules", "modules", false);
+    case("build-*", "build-release", true);
+    case("build-*", "src", false);
+    case("b?ild", "build", true);
+    case("/target", "target", true);
+    case("/target", "a/target", false);
+    case("a/target", "a/target/debug", true);
+    case("a/target", "a", false);
+    case("a/*/out", "a/b/out", true);
+  }
+
   #[test]
   fn clean() {
     let cases = &[
@@ -0,0 +1,32 @@
+use super::*;
+
+/// Limits on how far `just` will climb when searching upward for a justfile
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub(crate) struct SearchLimit {
+  /// Maximum number of parent directories to search
+  pub(crate) depth: Option<usize>,
+  /// Don't search above the nearest project root
+  pub(crate) project_root: bool,
+}
+
+impl SearchLimit {
+  /// The directories to search, starting with `directory` and followed by
+  /// as many of its ancestors as the limits allow
+  pub(crate) fn ancestors<'a>(&self, directory: &'a Path) -> SearchResult<Vec<&'a Path>> {
+    let mut ancestors = Vec::new();
+
+    for ancestor in directory.ancestors().take(
+      self
+        .depth
+        .map_or(usize::MAX, |depth| depth.saturating_add(1)),
+    ) {
+      ancestors.push(ancestor);
+
+      if self.project_root && Search::is_project_root(ancestor)? {
+        break;
+      }
+    }
+
+    Ok(ancestors)
+  }
+}
@@ -1,39 +1,93 @@
 use super::*;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, VariantNames)]
+#[strum(serialize_all = "kebab-case")]
 pub(crate) enum Setting<'src> {
+  Allow(Vec<String>),
   AllowDuplicateRecipes(bool),
   AllowDuplicateVariables(bool),
+  AllowMissingRecipeArgs(AllowMissingRecipeArgs),
+  Chooser(String),
+  Default(String),
   DotenvFilename(String),
   DotenvLoad(bool),
-  DotenvPath(String),
+  DotenvOutsideProject(DotenvOutsideProject),
+  DotenvOverride(bool),
+  DotenvPath(Vec<String>),
+  EnvAllow(Vec<String>),
+  EnvDeny(Vec<String>),
   Export(bool),
   Fallback(bool),
+  FallbackDepth(usize),
   IgnoreComments(bool),
+  ListOrder(ListOrder),
+  NormalizeRecipeNames(bool),
+  Parallel(bool),
   PositionalArguments(bool),
+  PrefixMatch(bool),
   Quiet(bool),
   Shell(Shell<'src>),
-  Tempdir(String),
+  ShellArgs(Vec<String>),
+  Tempdir(Expression<'src>),
+  TraceRedact(Vec<String>),
+  #[strum(serialize = "windows-powershell")]
   WindowsPowerShell(bool),
   WindowsShell(Shell<'src>),
 }
 
+impl<'src> Setting<'src> {
+  pub(crate) fn suggest(name: &str) -> Option<Suggestion<'static>> {
+    Suggestion::closest(name, Self::VARIANTS.iter().copied())
+  }
+}
+
 impl<'src> Display for Setting<'src> {
   fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
     match self {
       Self::AllowDuplicateRecipes(value)
       | Self::AllowDuplicateVariables(value)
       | Self::DotenvLoad(value)
+      | Self::DotenvOverride(value)
       | Self::Export(value)
       | Self::Fallback(value)
       | Self::IgnoreComments(value)
+      | Self::NormalizeRecipeNames(value)
+      | Self::Parallel(value)
       | Self::PositionalArguments(value)
+      | Self::PrefixMatch(value)
       | Self::Quiet(value)
       | Self::WindowsPowerShell(value) => write!(f, "{value}"),
+      Self::AllowMissingRecipeArgs(value) => write!(f, "\"{value}\""),
+      Self::DotenvOutsideProject(value) => write!(f, "\"{value}\""),
+      Self::ListOrder(value) => write!(f, "\"{value}\""),
+      Self::FallbackDepth(depth) => write!(f, "\"{depth}\""),
       Self::Shell(shell) | Self::WindowsShell(shell) => write!(f, "{shell}"),
-      Self::DotenvFilename(value) | Self::DotenvPath(value) | Self::Tempdir(value) => {
+      Self::Chooser(value) | Self::Default(value) | Self::DotenvFilename(value) => {
         write!(f, "{value:?}")
       }
+      Self::Tempdir(expression) => write!(f, "{expression}"),
+      Self::DotenvPath(paths) => match paths.as_slice() {
+        [path] => write!(f, "{path:?}"),
+        paths => Self::fmt_list(f, paths),
+      },
+      Self::Allow(values)
+      | Self::EnvAllow(values)
+      | Self::EnvDeny(values)
+      | Self::ShellArgs(values)
+      | Self::TraceRedact(values) => Self::fmt_list(f, values),
+    }
+  }
+}
+
+impl<'src> Setting<'src> {
+  fn fmt_list(f: &mut Formatter, items: &[String]) -> Result<(), fmt::Error> {
+    write!(f, "[")?;
+    for (i, item) in items.iter().enumerate() {
+      if i > 0 {
+        write!(f, ", ")?;
+      }
+      write!(f, "{item:?}")?;
     }
+    write!(f, "]")
   }
 }
@@ -7,42 +7,96 @@ pub(crate) const WINDOWS_POWERSHELL_ARGS: &[&str] = &["-NoLogo", "-Command"];
 
 #[derive(Debug, PartialEq, Serialize, Default)]
 pub(crate) struct Settings<'src> {
+  pub(crate) allow: Vec<String>,
   pub(crate) allow_duplicate_recipes: bool,
   pub(crate) allow_duplicate_variables: bool,
+  pub(crate) allow_missing_recipe_args: Option<AllowMissingRecipeArgs>,
+  pub(crate) chooser: Option<String>,
+  pub(crate) default: Option<String>,
   pub(crate) dotenv_filename: Option<String>,
   pub(crate) dotenv_load: Option<bool>,
-  pub(crate) dotenv_path: Option<PathBuf>,
+  pub(crate) dotenv_outside_project: Option<DotenvOutsideProject>,
+  pub(crate) dotenv_override: bool,
+  #[serde(serialize_with = "serialize_dotenv_path")]
+  pub(crate) dotenv_path: Vec<PathBuf>,
+  pub(crate) env_allow: Option<Vec<String>>,
+  pub(crate) env_deny: Vec<String>,
   pub(crate) export: bool,
   pub(crate) fallback: bool,
+  pub(crate) fallback_depth: Option<usize>,
   pub(crate) ignore_comments: bool,
+  pub(crate) list_order: Option<ListOrder>,
+  pub(crate) normalize_recipe_names: bool,
+  pub(crate) parallel: bool,
   pub(crate) positional_arguments: bool,
+  pub(crate) prefix_match: bool,
   pub(crate) quiet: bool,
   pub(crate) shell: Option<Shell<'src>>,
-  pub(crate) tempdir: Option<String>,
+  pub(crate) shell_args: Vec<String>,
+  pub(crate) tempdir: Option<Expression<'src>>,
+  pub(crate) trace_redact: Vec<String>,
   pub(crate) windows_powershell: bool,
   pub(crate) windows_shell: Option<Shell<'src>>,
 }
 
+/// Serialize `dotenv_path` as `null` if unset, a string if set to a single
+/// path, and an array of strings if set to more than one
+#[allow(clippy::ptr_arg)]
+pub(crate) fn serialize_dotenv_path<S: Serializer>(
+  paths: &Vec<PathBuf>,
+  serializer: S,
+) -> Result<S::Ok, S::Error> {
+  match paths.as_slice() {
+    [] => serializer.serialize_none(),
+    [path] => path.serialize(serializer),
+    paths => paths.serialize(serializer),
+  }
+}
+
 impl<'src> Settings<'src> {
   pub(crate) fn from_setting_iter(iter: impl Iterator<Item = Setting<'src>>) -> Self {
     let mut settings = Self::default();
 
     for set in iter {
       match set {
+        Setting::Allow(allow) => {
+          settings.allow = allow;
+        }
         Setting::AllowDuplicateRecipes(allow_duplicate_recipes) => {
           settings.allow_duplicate_recipes = allow_duplicate_recipes;
         }
         Setting::AllowDuplicateVariables(allow_duplicate_variables) => {
           settings.allow_duplicate_variables = allow_duplicate_variables;
         }
+        Setting::AllowMissingRecipeArgs(allow_missing_recipe_args) => {
+          settings.allow_missing_recipe_args = Some(allow_missing_recipe_args);
+        }
+        Setting::Chooser(chooser) => {
+          settings.chooser = Some(chooser);
+        }
+        Setting::Default(default) => {
+          settings.default = Some(default);
+        }
         Setting::DotenvFilename(filename) => {
           settings.dotenv_filename = Some(filename);
         }
         Setting::DotenvLoad(dotenv_load) => {
           settings.dotenv_load = Some(dotenv_load);
         }
-        Setting::DotenvPath(path) => {
-          settings.dotenv_path = Some(PathBuf::from(path));
+        Setting::DotenvOutsideProject(dotenv_outside_project) => {
+          settings.dotenv_outside_project = Some(dotenv_outside_project);
+        }
+        Setting::DotenvOverride(dotenv_override) => {
+          settings.dotenv_override = dotenv_override;
+        }
+        Setting::DotenvPath(paths) => {
+          settings.dotenv_path = paths.into_iter().map(PathBuf::from).collect();
+        }
+        Setting::EnvAllow(env_allow) => {
+          settings.env_allow = Some(env_allow);
+        }
+        Setting::EnvDeny(env_deny) => {
+          settings.env_deny = env_deny;
         }
         Setting::Export(export) => {
           settings.export = export;
@@ -50,18 +104,36 @@ impl<'src> Settings<'src> {
         Setting::Fallback(fallback) => {
           settings.fallback = fallback;
         }
+        Setting::FallbackDepth(fallback_depth) => {
+          settings.fallback_depth = Some(fallback_depth);
+        }
         Setting::IgnoreComments(ignore_comments) => {
           settings.ignore_comments = ignore_comments;
         }
+        Setting::ListOrder(list_order) => {
+          settings.list_order = Some(list_order);
+        }
+        Setting::NormalizeRecipeNames(normalize_recipe_names) => {
+          settings.normalize_recipe_names = normalize_recipe_names;
+        }
+        Setting::Parallel(parallel) => {
+          settings.parallel = parallel;
+        }
         Setting::PositionalArguments(positional_arguments) => {
           settings.positional_arguments = positional_arguments;
         }
+        Setting::PrefixMatch(prefix_match) => {
+          settings.prefix_match = prefix_match;
+        }
         Setting::Quiet(quiet) => {
           settings.quiet = quiet;
         }
         Setting::Shell(shell) => {
           settings.shell = Some(shell);
         }
+        Setting::ShellArgs(shell_args) => {
+          settings.shell_args = shell_args;
+        }
         Setting::WindowsPowerShell(windows_powershell) => {
           settings.windows_powershell = windows_powershell;
         }
@@ -71,12 +143,25 @@ impl<'src> Settings<'src> {
         Setting::Tempdir(tempdir) => {
           settings.tempdir = Some(tempdir);
         }
+        Setting::TraceRedact(trace_redact) => {
+          settings.trace_redact = trace_redact;
+        }
       }
     }
 
     settings
   }
 
+  /// The `tempdir` setting as it appears in `--dump` and `--summary` output:
+  /// the value of a string literal, or the source of any other expression,
+  /// which is only evaluated when a recipe needs a temporary directory
+  pub(crate) fn tempdir_source(&self) -> Option<String> {
+    self.tempdir.as_ref().map(|tempdir| match tempdir {
+      Expression::StringLiteral { string_literal } => string_literal.cooked.clone(),
+      expression => expression.to_string(),
+    })
+  }
+
   pub(crate) fn shell_command(&self, config: &Config) -> Command {
     let (command, args) = self.shell(config);
 
@@ -87,13 +172,19 @@ impl<'src> Settings<'src> {
     cmd
   }
 
+  /// Shell binary and arguments used to run commands. Arguments from the
+  /// `shell-args` setting are appended unless arguments were given on the
+  /// command line.
   pub(crate) fn shell<'a>(&'a self, config: &'a Config) -> (&'a str, Vec<&'a str>) {
-    match (&config.shell, &config.shell_args) {
-      (Some(shell), Some(shell_args)) => (shell, shell_args.iter().map(String::as_ref).collect()),
-      (Some(shell), None) => (shell, DEFAULT_SHELL_ARGS.to_vec()),
+    let (command, mut arguments) = match (&config.shell, &config.shell_args) {
+      (Some(shell), Some(shell_args)) => (
+        shell.as_str(),
+        shell_args.iter().map(String::as_str).collect(),
+      ),
+      (Some(shell), None) => (shell.as_str(), DEFAULT_SHELL_ARGS.to_vec()),
       (None, Some(shell_args)) => (
         DEFAULT_SHELL,
-        shell_args.iter().map(String::as_ref).collect(),
+        shell_args.iter().map(String::as_str).collect(),
       ),
       (None, None) => {
         if let (true, Some(shell)) = (cfg!(windows), &self.windows_shell) {
@@ -120,14 +211,114 @@ impl<'src> Settings<'src> {
           (DEFAULT_SHELL, DEFAULT_SHELL_ARGS.to_vec())
         }
       }
+    };
+
+    if config.shell_args.is_none() {
+      arguments.extend(self.shell_args.iter().map(String::as_str));
+    }
+
+    (command, arguments)
+  }
+
+  /// Whether to prompt for missing recipe arguments instead of failing
+  pub(crate) fn prompt_for_missing_arguments(&self) -> bool {
+    self.allow_missing_recipe_args == Some(AllowMissingRecipeArgs::Prompt)
+  }
+
+  /// The number of parent directories to search for a justfile to fall back
+  /// to if a recipe isn't found, or `None` if fallback is disabled
+  pub(crate) fn fallback_depth(&self) -> Option<usize> {
+    self
+      .fallback
+      .then(|| self.fallback_depth.unwrap_or(usize::MAX))
+  }
+
+  /// Key used to compare recipe names given on the command line. If
+  /// `normalize-recipe-names` is set, case, `-`, and `_` are ignored, so
+  /// `buildAll`, `build_all`, and `build-all` all have the same key.
+  pub(crate) fn recipe_name_key(&self, name: &str) -> String {
+    if self.normalize_recipe_names {
+      name
+        .chars()
+        .filter(|c| !matches!(c, '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+    } else {
+      name.into()
     }
   }
+
+  /// Whether recipes are listed in the order they appear in the source
+  pub(crate) fn list_source_order(&self) -> bool {
+    self.list_order == Some(ListOrder::Source)
+  }
+
+  /// Whether warnings with `code` are suppressed by the `allow` setting
+  pub(crate) fn allows(&self, code: WarningCode) -> bool {
+    self.allow.iter().any(|allowed| allowed == code.name())
+  }
+
+  /// Whether `env-allow` and `env-deny` restrict the environment of commands
+  pub(crate) fn env_filtered(&self) -> bool {
+    self.env_allow.is_some() || !self.env_deny.is_empty()
+  }
+
+  /// Whether environment variable `name` may be passed to commands. Names
+  /// must match a pattern in `env-allow`, if set, and must not match any
+  /// pattern in `env-deny`.
+  pub(crate) fn env_allowed(&self, name: &str) -> bool {
+    let allowed = self.env_allow.as_ref().map_or(true, |allow| {
+      allow
+        .iter()
+        .any(|pattern| wildcard_regex(pattern).is_match(name))
+    });
+
+    allowed
+      && !self
+        .env_deny
+        .iter()
+        .any(|pattern| wildcard_regex(pattern).is_match(name))
+  }
+
+  /// Whether the value of variable `name` is hidden from
+  /// `--trace-evaluation` output by a pattern in `trace-redact`
+  pub(crate) fn trace_redacted(&self, name: &str) -> bool {
+    self
+      .trace_redact
+      .iter()
+      .any(|pattern| wildcard_regex(pattern).is_match(name))
+  }
 }
 
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn allows() {
+    let settings = Settings {
+      allow: vec!["warn-directive".into()],
+      ..Default::default()
+    };
+
+    assert!(settings.allows(WarningCode::WarnDirective));
+    assert!(!settings.allows(WarningCode::DotenvOutsideProject));
+  }
+
+  #[test]
+  fn env_allowed() {
+    let settings = Settings {
+      env_allow: Some(vec!["PATH".into(), "AWS_*".into()]),
+      env_deny: vec!["AWS_SECRET_*".into()],
+      ..Default::default()
+    };
+
+    assert!(settings.env_allowed("PATH"));
+    assert!(settings.env_allowed("AWS_REGION"));
+    assert!(!settings.env_allowed("AWS_SECRET_ACCESS_KEY"));
+    assert!(!settings.env_allowed("HOME"));
+  }
+
   #[test]
   fn default_shell() {
     let settings = Settings::default();
@@ -219,6 +410,37 @@ mod tests {
     assert_eq!(settings.shell(&config), ("asdf.exe", vec!["-nope"]));
   }
 
+  #[test]
+  fn shell_args_appended_to_default_shell() {
+    let settings = Settings {
+      shell_args: vec!["-e".to_string()],
+      ..Default::default()
+    };
+
+    let config = Config {
+      shell_command: false,
+      ..testing::config(&[])
+    };
+
+    assert_eq!(settings.shell(&config), ("sh", vec!["-cu", "-e"]));
+  }
+
+  #[test]
+  fn shell_args_not_appended_to_command_line_shell_args() {
+    let settings = Settings {
+      shell_args: vec!["-e".to_string()],
+      ..Default::default()
+    };
+
+    let config = Config {
+      shell_command: true,
+      shell_args: Some(vec!["-nice".to_string()]),
+      ..testing::config(&[])
+    };
+
+    assert_eq!(settings.shell(&config), ("sh", vec!["-nice"]));
+  }
+
   #[test]
   fn shell_present_but_not_shell_args() {
     let settings = Settings {
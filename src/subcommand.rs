@@ -1,18 +1,57 @@
 use {
   super::*,
   clap_mangen::Man,
-  std::io::{Read, Seek},
+  std::{
+    fmt::Write as _,
+    io::{Read, Seek},
+  },
   tempfile::tempfile,
 };
 
 const INIT_JUSTFILE: &str = "default:\n    echo 'Hello, world!'\n";
 
+/// Width of the widest `--list` line whose doc comment is aligned with others
+const LIST_MAX_WIDTH: usize = 50;
+
+/// A recipe in the output of `--list --json`
+#[derive(Serialize)]
+struct ListRecipe {
+  aliases: Vec<String>,
+  attributes: Vec<String>,
+  doc: Option<String>,
+  groups: BTreeSet<String>,
+  name: String,
+  parameters: Vec<ListParameter>,
+}
+
+/// A recipe parameter in the output of `--list --json`
+#[derive(Serialize)]
+struct ListParameter {
+  /// Source of the default value, masked if the parameter is secret
+  default: Option<String>,
+  export: bool,
+  kind: ParameterKind,
+  name: String,
+  pattern: Option<String>,
+}
+
+/// A recipe in the output of `--summary --json`
+#[derive(Serialize)]
+struct SummaryRecipe {
+  aliases: Vec<String>,
+  min_arguments: usize,
+  name: String,
+  private: bool,
+}
+
 #[derive(PartialEq, Clone, Debug)]
 pub(crate) enum Subcommand {
   Changelog,
+  Check,
   Choose {
     overrides: BTreeMap<String, String>,
     chooser: Option<String>,
+    patterns: Vec<String>,
   },
   Command {
     arguments: Vec<OsString>,
@@ -22,24 +61,41 @@ pub(crate) enum Subcommand {
   Completions {
     shell: clap_complete::Shell,
   },
-  Dump,
-  Edit,
+  Dump {
+    recipe: Option<String>,
+    variable: Option<String>,
+  },
+  DumpSchema,
+  Edit {
+    recipe: Option<String>,
+  },
   Evaluate {
+    arguments: Vec<String>,
     overrides: BTreeMap<String, String>,
+    recipe: Option<String>,
     variable: Option<String>,
   },
+  Find {
+    pattern: String,
+  },
   Format,
+  Groups,
   Init,
   List,
   Man,
   Run {
     arguments: Vec<String>,
     overrides: BTreeMap<String, String>,
+    passthrough: Vec<String>,
   },
+  Settings,
   Show {
     name: String,
   },
   Summary,
+  Usage {
+    name: String,
+  },
   Variables,
 }
 
@@ -57,19 +113,31 @@ impl Subcommand {
         return Ok(());
       }
       Completions { shell } => return Self::completions(*shell),
+      DumpSchema => return Self::dump_schema(),
       Init => return Self::init(config),
       Man => return Self::man(),
       Run {
         arguments,
         overrides,
-      } => return Self::run(config, loader, arguments, overrides),
+        ..
+      } => return Self::run_transcribed(config, loader, arguments, overrides),
       _ => {}
     }
 
-    let search = Search::find(&config.search_config, &config.invocation_directory)?;
+    if let Format = self {
+      if config.stdin {
+        return Self::format_stdin(config, loader);
+      }
+    }
+
+    let search = Search::find(
+      &config.search_config,
+      config.search_limit,
+      &config.invocation_directory,
+    )?;
 
-    if let Edit = self {
-      return Self::edit(&search);
+    if let Edit { recipe: None } = self {
+      return Self::edit(&search, None);
     }
 
     let compilation = Self::compile(config, loader, &search)?;
@@ -78,29 +146,111 @@ impl Subcommand {
     let src = compilation.root_src();
 
     match self {
-      Choose { overrides, chooser } => {
-        Self::choose(config, justfile, &search, overrides, chooser.as_deref())?;
+      Check => {}
+      Choose {
+        overrides,
+        chooser,
+        patterns,
+      } => {
+        Self::choose(
+          config,
+          justfile,
+          &search,
+          overrides,
+          chooser.as_deref(),
+          patterns,
+        )?;
       }
       Command { overrides, .. } | Evaluate { overrides, .. } => {
-        justfile.run(config, &search, overrides, &[])?;
+        justfile.run(config, &search, overrides, &[], &())?;
       }
-      Dump => Self::dump(config, ast, justfile)?,
+      Dump { recipe, variable } => Self::dump(
+        config,
+        src,
+        ast,
+        justfile,
+        recipe.as_deref(),
+        variable.as_deref(),
+      )?,
+      Edit { recipe: Some(name) } => Self::edit_recipe(&search, name, justfile)?,
+      Find { pattern } => Self::find(config, pattern, justfile),
       Format => Self::format(config, &search, src, ast)?,
-      List => Self::list(config, 0, justfile),
+      Groups => Self::groups(config, justfile),
+      List => Self::list(config, justfile)?,
+      Settings => Self::settings(config, justfile)?,
       Show { ref name } => Self::show(config, name, justfile)?,
-      Summary => Self::summary(config, justfile),
-      Variables => Self::variables(justfile),
-      Changelog | Completions { .. } | Edit | Init | Man | Run { .. } => unreachable!(),
+      Summary => Self::summary(config, justfile)?,
+      Usage { ref name } => Self::usage(config, name, justfile)?,
+      Variables => Self::variables(config, justfile),
+      Changelog
+      | Completions { .. }
+      | DumpSchema
+      | Edit { recipe: None }
+      | Init
+      | Man
+      | Run { .. } => {
+        unreachable!()
+      }
     }
 
     Ok(())
   }
 
-  fn run<'src>(
+  /// Run recipes, writing a transcript of the run if `--transcript` was
+  /// given
+  fn run_transcribed<'src>(
     config: &Config,
     loader: &'src Loader,
     arguments: &[String],
     overrides: &BTreeMap<String, String>,
+  ) -> Result<(), Error<'src>> {
+    let Some(path) = &config.transcript else {
+      return Self::run_timed(config, loader, arguments, overrides, &());
+    };
+
+    let transcript = Transcript::default();
+
+    let result = Self::run_timed(config, loader, arguments, overrides, &transcript);
+
+    let written = transcript.write(
+      &config.invocation_directory.join(path),
+      result.as_ref().err(),
+    );
+
+    result?;
+
+    written
+  }
+
+  /// Run recipes, printing how long each took if `--timings` was given
+  fn run_timed<'src>(
+    config: &Config,
+    loader: &'src Loader,
+    arguments: &[String],
+    overrides: &BTreeMap<String, String>,
+    events: &dyn Events,
+  ) -> Result<(), Error<'src>> {
+    if !config.timings {
+      return Self::run(config, loader, arguments, overrides, events);
+    }
+
+    let timings = Timings::new(events);
+
+    let result = Self::run(config, loader, arguments, overrides, &timings);
+
+    if !config.verbosity.quiet() {
+      eprint!("{}", timings.color_display(config.color.stderr()));
+    }
+
+    result
+  }
+
+  pub(crate) fn run<'src>(
+    config: &Config,
+    loader: &'src Loader,
+    arguments: &[String],
+    overrides: &BTreeMap<String, String>,
+    events: &dyn Events,
   ) -> Result<(), Error<'src>> {
     if matches!(
       config.search_config,
@@ -114,23 +264,25 @@ impl Subcommand {
         _ => unreachable!(),
       };
 
-      let mut path = starting_path.clone();
+      let directories = config.search_limit.ancestors(&starting_path)?;
+
+      let mut remaining = directories.as_slice();
 
       let mut unknown_recipes_errors = None;
 
       loop {
-        let search = match Search::find_next(&path) {
+        let search = match Search::find_next(remaining.iter().copied()) {
           Err(SearchError::NotFound) => match unknown_recipes_errors {
             Some(err) => return Err(err),
             None => return Err(SearchError::NotFound.into()),
           },
           Err(err) => return Err(err.into()),
           Ok(search) => {
-            if config.verbosity.loquacious() && path != starting_path {
+            if config.verbosity.loquacious() && remaining[0] != starting_path {
               eprintln!(
                 "Trying {}",
                 starting_path
-                  .strip_prefix(path)
+                  .strip_prefix(remaining[0])
                   .unwrap()
                   .components()
                   .map(|_| path::Component::ParentDir)
@@ -143,15 +295,19 @@ impl Subcommand {
           }
         };
 
-        match Self::run_inner(config, loader, arguments, overrides, &search) {
-          Err((err @ Error::UnknownRecipes { .. }, true)) => {
-            match search.justfile.parent().unwrap().parent() {
-              Some(parent) => {
-                unknown_recipes_errors.get_or_insert(err);
-                path = parent.into();
-              }
-              None => return Err(err),
-            }
+        match Self::run_inner(config, loader, arguments, overrides, &search, events) {
+          Err((err @ Error::UnknownRecipes { .. }, Some(fallback_depth))) => {
+            let directory = search.justfile.parent().unwrap();
+
+            let parents = directories
+              .iter()
+              .position(|ancestor| *ancestor == directory)
+              .map_or(directories.len(), |i| i + 1);
+
+            remaining = &directories[parents..];
+            remaining = &remaining[..remaining.len().min(fallback_depth)];
+
+            unknown_recipes_errors.get_or_insert(err);
           }
           result => return result.map_err(|(err, _fallback)| err),
         }
@@ -162,7 +318,12 @@ impl Subcommand {
         loader,
         arguments,
         overrides,
-        &Search::find(&config.search_config, &config.invocation_directory)?,
+        &Search::find(
+          &config.search_config,
+          config.search_limit,
+          &config.invocation_directory,
+        )?,
+        events,
       )
       .map_err(|(err, _fallback)| err)
     }
@@ -174,12 +335,13 @@ impl Subcommand {
     arguments: &[String],
     overrides: &BTreeMap<String, String>,
     search: &Search,
-  ) -> Result<(), (Error<'src>, bool)> {
-    let compilation = Self::compile(config, loader, search).map_err(|err| (err, false))?;
+    events: &dyn Events,
+  ) -> Result<(), (Error<'src>, Option<usize>)> {
+    let compilation = Self::compile(config, loader, search).map_err(|err| (err, None))?;
     let justfile = &compilation.justfile;
     justfile
-      .run(config, search, overrides, arguments)
-      .map_err(|err| (err, justfile.settings.fallback))
+      .run(config, search, overrides, arguments, events)
+      .map_err(|err| (err, justfile.settings.fallback_depth()))
   }
 
   fn compile<'src>(
@@ -189,9 +351,9 @@ impl Subcommand {
   ) -> Result<Compilation<'src>, Error<'src>> {
     let compilation = Compiler::compile(config.unstable, loader, &search.justfile)?;
 
-    if config.verbosity.loud() {
-      for warning in &compilation.justfile.warnings {
-        eprintln!("{}", warning.color_display(config.color.stderr()));
+    for warning in &compilation.justfile.warnings {
+      if !compilation.justfile.settings.allows(warning.code()) {
+        warning.clone().report(config, config.verbosity)?;
       }
     }
 
@@ -208,11 +370,12 @@ impl Subcommand {
     search: &Search,
     overrides: &BTreeMap<String, String>,
     chooser: Option<&str>,
+    patterns: &[String],
   ) -> Result<(), Error<'src>> {
     let recipes = justfile
       .public_recipes(config.unsorted)
       .iter()
-      .filter(|recipe| recipe.min_arguments() == 0)
+      .filter(|recipe| recipe.is_listed() && recipe.min_arguments() == 0)
       .copied()
       .collect::<Vec<&Recipe<Dependency>>>();
 
@@ -220,7 +383,31 @@ impl Subcommand {
       return Err(Error::NoChoosableRecipes);
     }
 
-    let chooser = chooser.map_or_else(|| config::chooser_default(&search.justfile), From::from);
+    let recipes = if patterns.is_empty() {
+      recipes
+    } else {
+      let regexes = patterns
+        .iter()
+        .map(|pattern| wildcard_regex(pattern))
+        .collect::<Vec<Regex>>();
+
+      let recipes = recipes
+        .into_iter()
+        .filter(|recipe| regexes.iter().any(|regex| regex.is_match(recipe.name())))
+        .collect::<Vec<&Recipe<Dependency>>>();
+
+      if recipes.is_empty() {
+        return Err(Error::NoMatchingChoosableRecipes {
+          patterns: patterns.to_vec(),
+        });
+      }
+
+      recipes
+    };
+
+    let chooser = chooser
+      .or(justfile.settings.chooser.as_deref())
+      .map_or_else(|| config::chooser_default(&search.justfile), From::from);
 
     let result = justfile
       .settings
@@ -276,7 +463,7 @@ impl Subcommand {
       .map(str::to_owned)
       .collect::<Vec<String>>();
 
-    justfile.run(config, search, overrides, &recipes)
+    justfile.run(config, search, overrides, &recipes, &())
   }
 
   fn completions(shell: clap_complete::Shell) -> RunResult<'static, ()> {
@@ -344,27 +531,133 @@ impl Subcommand {
     Ok(())
   }
 
-  fn dump(config: &Config, ast: &Ast, justfile: &Justfile) -> Result<(), Error<'static>> {
+  fn dump<'src>(
+    config: &Config,
+    src: &str,
+    ast: &Ast,
+    justfile: &Justfile<'src>,
+    recipe: Option<&str>,
+    variable: Option<&str>,
+  ) -> Result<(), Error<'src>> {
+    if let Some(name) = recipe {
+      let Some(recipe) = justfile.get_recipe(name) else {
+        return Err(Error::UnknownRecipes {
+          recipes: vec![name.to_owned()],
+          suggestion: justfile.suggest_recipe(name),
+        });
+      };
+
+      match config.dump_format {
+        DumpFormat::Json if config.locations => {
+          Self::dump_json(&dump::Recipe::with_location(recipe))?;
+        }
+        DumpFormat::Json => Self::dump_json(recipe)?,
+        DumpFormat::JsonLossless => unreachable!(),
+        DumpFormat::Just => {
+          if config.locations {
+            println!("# {}", recipe.location());
+          }
+          println!("{}", recipe.color_display(Color::never()));
+        }
+      }
+
+      return Ok(());
+    }
+
+    if let Some(name) = variable {
+      let Some(assignment) = justfile.assignments.get(name) else {
+        return Err(Error::EvalUnknownVariable {
+          suggestion: justfile.suggest_variable(name),
+          variable: name.to_owned(),
+        });
+      };
+
+      match config.dump_format {
+        DumpFormat::Json => Self::dump_json(assignment)?,
+        DumpFormat::JsonLossless => unreachable!(),
+        DumpFormat::Just => println!("{assignment}"),
+      }
+
+      return Ok(());
+    }
+
     match config.dump_format {
-      DumpFormat::Json => {
-        serde_json::to_writer(io::stdout(), justfile)
-          .map_err(|serde_json_error| Error::DumpJson { serde_json_error })?;
-        println!();
+      DumpFormat::Json if config.locations => {
+        Self::dump_json(&dump::Justfile::with_locations(justfile))?;
       }
-      DumpFormat::Just => print!("{ast}"),
+      DumpFormat::Json => Self::dump_json(justfile)?,
+      DumpFormat::JsonLossless => Self::dump_json(&Lossless::new(src, ast))?,
+      DumpFormat::Just => print!("{}", ast.format(false, config.locations)),
     }
+
+    Ok(())
+  }
+
+  fn dump_json(value: &impl Serialize) -> Result<(), Error<'static>> {
+    serde_json::to_writer(io::stdout(), value)
+      .map_err(|serde_json_error| Error::DumpJson { serde_json_error })?;
+    println!();
+    Ok(())
+  }
+
+  fn dump_schema() -> Result<(), Error<'static>> {
+    serde_json::to_writer_pretty(io::stdout(), &dump::schema())
+      .map_err(|serde_json_error| Error::DumpJson { serde_json_error })?;
+    println!();
+    Ok(())
+  }
+
+  fn edit_recipe<'src>(
+    search: &Search,
+    name: &str,
+    justfile: &Justfile<'src>,
+  ) -> Result<(), Error<'src>> {
+    let Some(recipe) = justfile.get_recipe(name) else {
+      return Err(Error::UnknownRecipes {
+        recipes: vec![name.to_owned()],
+        suggestion: justfile.suggest_recipe(name),
+      });
+    };
+
+    Self::edit(search, Some((&recipe.file_path, recipe.line_number() + 1)))?;
+
     Ok(())
   }
 
-  fn edit(search: &Search) -> Result<(), Error<'static>> {
+  /// Open the justfile in the user's editor, or, if `location` is given, the
+  /// file and one-based line number it contains
+  fn edit(search: &Search, location: Option<(&Path, usize)>) -> Result<(), Error<'static>> {
     let editor = env::var_os("VISUAL")
       .or_else(|| env::var_os("EDITOR"))
       .unwrap_or_else(|| "vim".into());
 
-    let error = Command::new(&editor)
-      .current_dir(&search.working_directory)
-      .arg(&search.justfile)
-      .status();
+    let mut command = Command::new(&editor);
+
+    command.current_dir(&search.working_directory);
+
+    match location {
+      Some((path, line)) => {
+        let name = Path::new(&editor)
+          .file_stem()
+          .and_then(OsStr::to_str)
+          .unwrap_or_default();
+
+        // Most editors, like vim, emacs, and nano, accept `+LINE PATH`, but
+        // some only accept `PATH:LINE`.
+        match name {
+          "code" | "code-insiders" | "codium" => command
+            .arg("--goto")
+            .arg(format!("{}:{line}", path.display())),
+          "hx" | "subl" | "zed" => command.arg(format!("{}:{line}", path.display())),
+          _ => command.arg(format!("+{line}")).arg(path),
+        };
+      }
+      None => {
+        command.arg(&search.justfile);
+      }
+    }
+
+    let error = command.status();
 
     let status = match error {
       Err(io_error) => return Err(Error::EditorInvoke { editor, io_error }),
@@ -381,34 +674,10 @@ impl Subcommand {
   fn format(config: &Config, search: &Search, src: &str, ast: &Ast) -> Result<(), Error<'static>> {
     config.require_unstable("The `--fmt` command is currently unstable.")?;
 
-    let formatted = ast.to_string();
+    let formatted = ast.format(config.align, false);
 
     if config.check {
-      return if formatted == src {
-        Ok(())
-      } else {
-        if !config.verbosity.quiet() {
-          use similar::{ChangeTag, TextDiff};
-
-          let diff = TextDiff::configure()
-            .algorithm(similar::Algorithm::Patience)
-            .diff_lines(src, &formatted);
-
-          for op in diff.ops() {
-            for change in diff.iter_changes(op) {
-              let (symbol, color) = match change.tag() {
-                ChangeTag::Delete => ("-", config.color.stdout().diff_deleted()),
-                ChangeTag::Equal => (" ", config.color.stdout()),
-                ChangeTag::Insert => ("+", config.color.stdout().diff_added()),
-              };
-
-              print!("{}{symbol}{change}{}", color.prefix(), color.suffix());
-            }
-          }
-        }
-
-        Err(Error::FormatCheckFoundDiff)
-      };
+      return Self::format_check(config, src, &formatted);
     }
 
     fs::write(&search.justfile, formatted).map_err(|io_error| Error::WriteJustfile {
@@ -423,6 +692,50 @@ impl Subcommand {
     Ok(())
   }
 
+  fn format_stdin<'src>(config: &Config, loader: &'src Loader) -> Result<(), Error<'src>> {
+    config.require_unstable("The `--fmt` command is currently unstable.")?;
+
+    let (path, src) = loader.load_stdin()?;
+
+    let formatted = Compiler::parse_src(path, src)?.format(config.align, false);
+
+    if config.check {
+      return Self::format_check(config, src, &formatted);
+    }
+
+    print!("{formatted}");
+
+    Ok(())
+  }
+
+  fn format_check(config: &Config, src: &str, formatted: &str) -> Result<(), Error<'static>> {
+    if formatted == src {
+      return Ok(());
+    }
+
+    if !config.verbosity.quiet() {
+      use similar::{ChangeTag, TextDiff};
+
+      let diff = TextDiff::configure()
+        .algorithm(similar::Algorithm::Patience)
+        .diff_lines(src, formatted);
+
+      for op in diff.ops() {
+        for change in diff.iter_changes(op) {
+          let (symbol, color) = match change.tag() {
+            ChangeTag::Delete => ("-", config.color.stdout().diff_deleted()),
+            ChangeTag::Equal => (" ", config.color.stdout()),
+            ChangeTag::Insert => ("+", config.color.stdout().diff_added()),
+          };
+
+          print!("{}{symbol}{change}{}", color.prefix(), color.suffix());
+        }
+      }
+    }
+
+    Err(Error::FormatCheckFoundDiff)
+  }
+
   fn init(config: &Config) -> Result<(), Error<'static>> {
     let search = Search::init(&config.search_config, &config.invocation_directory)?;
 
@@ -463,9 +776,176 @@ impl Subcommand {
     Ok(())
   }
 
-  fn list(config: &Config, level: usize, justfile: &Justfile) {
-    const MAX_WIDTH: usize = 50;
+  fn list(config: &Config, justfile: &Justfile) -> Result<(), Error<'static>> {
+    if config.json {
+      let mut recipes = Vec::new();
+      Self::list_json_recursive(config, &mut Vec::new(), &mut recipes, justfile);
+      return Self::dump_json(&recipes);
+    }
+
+    let mut stdout = io::BufWriter::new(io::stdout().lock());
+
+    let result = if config.list_flat {
+      Self::list_flat(config, justfile, &mut stdout)
+    } else {
+      Self::list_module(config, 0, justfile, &mut stdout)
+    };
+
+    result
+      .and_then(|()| stdout.flush())
+      .map_err(|io_error| Error::StdoutIo { io_error })
+  }
+
+  fn list_json_recursive<'a>(
+    config: &Config,
+    components: &mut Vec<&'a str>,
+    recipes: &mut Vec<ListRecipe>,
+    justfile: &'a Justfile,
+  ) {
+    let path = |name: &str| {
+      components
+        .iter()
+        .copied()
+        .chain(iter::once(name))
+        .collect::<Vec<&str>>()
+        .join("::")
+    };
+
+    for recipe in justfile
+      .public_recipes(config.unsorted)
+      .into_iter()
+      .filter(|recipe| recipe.is_listed())
+    {
+      recipes.push(ListRecipe {
+        aliases: justfile
+          .aliases
+          .values()
+          .filter(|alias| {
+            !config.no_aliases && !alias.is_private() && alias.target.name() == recipe.name()
+          })
+          .map(|alias| path(alias.name.lexeme()))
+          .collect(),
+        attributes: recipe.attributes.iter().map(ToString::to_string).collect(),
+        doc: recipe.doc.map(Into::into),
+        groups: recipe.groups(),
+        name: path(recipe.name()),
+        parameters: recipe
+          .parameters
+          .iter()
+          .map(|parameter| ListParameter {
+            default: parameter.default.as_ref().map(|default| {
+              if parameter.secret {
+                "***".into()
+              } else {
+                default.to_string()
+              }
+            }),
+            export: parameter.export,
+            kind: parameter.kind,
+            name: parameter.name.lexeme().into(),
+            pattern: parameter
+              .pattern
+              .as_ref()
+              .map(|pattern| pattern.cooked.clone()),
+          })
+          .collect(),
+      });
+    }
+
+    for (name, module) in &justfile.modules {
+      components.push(name);
+      Self::list_json_recursive(config, components, recipes, module);
+      components.pop();
+    }
+  }
+
+  fn groups(config: &Config, justfile: &Justfile) {
+    let mut groups = Vec::<String>::new();
+
+    for group in justfile
+      .public_recipes(config.unsorted)
+      .into_iter()
+      .filter(|recipe| recipe.is_listed())
+      .flat_map(Recipe::groups)
+    {
+      if !groups.contains(&group) {
+        groups.push(group);
+      }
+    }
+
+    if !config.unsorted {
+      groups.sort();
+    }
 
+    println!("Recipe groups:");
+
+    for group in groups {
+      println!("{}{group}", config.list_prefix);
+    }
+  }
+
+  fn list_module(
+    config: &Config,
+    level: usize,
+    justfile: &Justfile,
+    out: &mut impl Write,
+  ) -> io::Result<()> {
+    if level == 0 {
+      write!(out, "{}", config.list_heading)?;
+    }
+
+    let mut entries = Vec::new();
+    Self::list_entries(config, "", justfile, &mut entries);
+    Self::list_entries_write(config, &config.list_prefix.repeat(level + 1), &entries, out)?;
+
+    for (name, module) in &justfile.modules {
+      writeln!(out, "    {name}:")?;
+      Self::list_module(config, level + 1, module, out)?;
+    }
+
+    Ok(())
+  }
+
+  /// List recipes in `justfile` and its submodules with `module::recipe`
+  /// names, aligning doc comments across all modules
+  fn list_flat(config: &Config, justfile: &Justfile, out: &mut impl Write) -> io::Result<()> {
+    fn collect<'a>(
+      config: &Config,
+      components: &mut Vec<&'a str>,
+      justfile: &'a Justfile,
+      entries: &mut Vec<ListEntry<'a>>,
+    ) {
+      let path = components
+        .iter()
+        .fold(String::new(), |mut path, component| {
+          write!(path, "{component}::").expect("writing to a string cannot fail");
+          path
+        });
+
+      Subcommand::list_entries(config, &path, justfile, entries);
+
+      for (name, module) in &justfile.modules {
+        components.push(name);
+        collect(config, components, module, entries);
+        components.pop();
+      }
+    }
+
+    write!(out, "{}", config.list_heading)?;
+
+    let mut entries = Vec::new();
+    collect(config, &mut Vec::new(), justfile, &mut entries);
+    Self::list_entries_write(config, &config.list_prefix, &entries, out)
+  }
+
+  /// Append a list entry for each listed recipe in `justfile`, and each of
+  /// its aliases, with names prefixed by `path`
+  fn list_entries<'a>(
+    config: &Config,
+    path: &str,
+    justfile: &'a Justfile,
+    entries: &mut Vec<ListEntry<'a>>,
+  ) {
     // Construct a target to alias map.
     let mut recipe_aliases: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
     if !config.no_aliases {
@@ -474,86 +954,176 @@ impl Subcommand {
           continue;
         }
 
-        if recipe_aliases.contains_key(alias.target.name.lexeme()) {
-          let aliases = recipe_aliases.get_mut(alias.target.name.lexeme()).unwrap();
-          aliases.push(alias.name.lexeme());
-        } else {
-          recipe_aliases.insert(alias.target.name.lexeme(), vec![alias.name.lexeme()]);
-        }
+        recipe_aliases
+          .entry(alias.target.name.lexeme())
+          .or_default()
+          .push(alias.name.lexeme());
       }
     }
 
-    let mut line_widths: BTreeMap<&str, usize> = BTreeMap::new();
+    let color = config.color.stdout();
 
-    for (name, recipe) in &justfile.recipes {
-      if !recipe.is_public() {
-        continue;
-      }
+    // Render and measure the parameters of each recipe once, since they're
+    // shared by the recipe's aliases.
+    for recipe in justfile
+      .public_recipes(config.unsorted)
+      .into_iter()
+      .filter(|recipe| recipe.is_listed())
+    {
+      let location = config.locations.then(|| format!(" {}", recipe.location()));
+
+      let plain = recipe
+        .parameters
+        .iter()
+        .map(|parameter| format!(" {}", parameter.color_display(Color::never())))
+        .chain(location.clone())
+        .collect::<String>();
+
+      let parameters_width = UnicodeWidthStr::width(plain.as_str());
+
+      let parameters = if color.active() {
+        recipe
+          .parameters
+          .iter()
+          .map(|parameter| format!(" {}", parameter.color_display(color)))
+          .chain(location.map(|location| color.context().paint(&location).to_string()))
+          .collect::<String>()
+      } else {
+        plain
+      };
 
-      for name in iter::once(name).chain(recipe_aliases.get(name).unwrap_or(&Vec::new())) {
-        let mut line_width = UnicodeWidthStr::width(*name);
+      let groups = recipe.groups();
 
-        for parameter in &recipe.parameters {
-          line_width += UnicodeWidthStr::width(
-            format!(" {}", parameter.color_display(Color::never())).as_str(),
-          );
-        }
+      let aliases = recipe_aliases.get(recipe.name()).into_iter().flatten();
 
-        if line_width <= MAX_WIDTH {
-          line_widths.insert(name, line_width);
-        }
+      for (i, name) in iter::once(recipe.name())
+        .chain(aliases.copied())
+        .enumerate()
+      {
+        entries.push(ListEntry {
+          doc: match i {
+            0 => recipe.doc.map(Into::into),
+            _ => Some(format!("alias for `{}`", recipe.name)),
+          },
+          file_path: &recipe.file_path,
+          groups: groups.clone(),
+          name: format!("{path}{name}"),
+          parameters: parameters.clone(),
+          parameters_width,
+        });
       }
     }
+  }
 
-    let max_line_width = cmp::min(line_widths.values().copied().max().unwrap_or(0), MAX_WIDTH);
-    let doc_color = config.color.stdout().doc();
+  /// Write `entries`, with ungrouped entries first, followed by the entries
+  /// in each group under a `[group]` heading. Entries in more than one group
+  /// are written under each of them.
+  fn list_entries_write(
+    config: &Config,
+    prefix: &str,
+    entries: &[ListEntry],
+    out: &mut impl Write,
+  ) -> io::Result<()> {
+    let color = config.color.stdout();
+
+    // Find the widest line that will be padded before printing anything.
+    let max_line_width = entries
+      .iter()
+      .map(ListEntry::width)
+      .filter(|width| *width <= LIST_MAX_WIDTH)
+      .max()
+      .unwrap_or_default();
+
+    let mut groups = Vec::<&str>::new();
+    for group in entries.iter().flat_map(|entry| &entry.groups) {
+      if !groups.contains(&group.as_str()) {
+        groups.push(group);
+      }
+    }
 
-    if level == 0 {
-      print!("{}", config.list_heading);
+    if !config.unsorted {
+      groups.sort_unstable();
     }
 
-    for recipe in justfile.public_recipes(config.unsorted) {
-      let name = recipe.name();
+    let ungrouped = entries.iter().filter(|entry| entry.groups.is_empty());
 
-      for (i, name) in iter::once(&name)
-        .chain(recipe_aliases.get(name).unwrap_or(&Vec::new()))
-        .enumerate()
-      {
-        print!("{}{name}", config.list_prefix.repeat(level + 1));
-        for parameter in &recipe.parameters {
-          print!(" {}", parameter.color_display(config.color.stdout()));
-        }
+    let sections = iter::once((None, ungrouped.collect::<Vec<&ListEntry>>())).chain(
+      groups.into_iter().map(|group| {
+        (
+          Some(group),
+          entries
+            .iter()
+            .filter(|entry| entry.groups.contains(group))
+            .collect(),
+        )
+      }),
+    );
 
-        // Declaring this outside of the nested loops will probably be more efficient,
-        // but it creates all sorts of lifetime issues with variables inside the loops.
-        // If this is inlined like the docs say, it shouldn't make any difference.
-        let print_doc = |doc| {
-          print!(
-            " {:padding$}{} {}",
-            "",
-            doc_color.paint("#"),
-            doc_color.paint(doc),
-            padding = max_line_width
-              .saturating_sub(line_widths.get(name).copied().unwrap_or(max_line_width))
-          );
-        };
+    let mut first = true;
 
-        match (i, recipe.doc) {
-          (0, Some(doc)) => print_doc(doc),
-          (0, None) => (),
-          _ => {
-            let alias_doc = format!("alias for `{}`", recipe.name);
-            print_doc(&alias_doc);
-          }
+    for (group, entries) in sections {
+      if entries.is_empty() {
+        continue;
+      }
+
+      if let Some(group) = group {
+        if !first {
+          writeln!(out)?;
         }
-        println!();
+        writeln!(
+          out,
+          "{prefix}{}",
+          color.annotation().paint(&format!("[{group}]"))
+        )?;
       }
+
+      first = false;
+
+      Self::list_section_write(config, prefix, max_line_width, &entries, out)?;
     }
 
-    for (name, module) in &justfile.modules {
-      println!("    {name}:");
-      Self::list(config, level + 1, module);
+    Ok(())
+  }
+
+  fn list_section_write(
+    config: &Config,
+    prefix: &str,
+    max_line_width: usize,
+    entries: &[&ListEntry],
+    out: &mut impl Write,
+  ) -> io::Result<()> {
+    let color = config.color.stdout();
+    let doc_color = color.doc();
+
+    for entry in entries {
+      write!(
+        out,
+        "{prefix}{}{}",
+        color.hyperlink(entry.file_path, &entry.name),
+        entry.parameters,
+      )?;
+
+      if let Some(doc) = &entry.doc {
+        let line_width = entry.width();
+
+        write!(
+          out,
+          " {:padding$}{} {}",
+          "",
+          doc_color.paint("#"),
+          doc_color.paint(doc),
+          padding = if line_width <= LIST_MAX_WIDTH {
+            max_line_width - line_width
+          } else {
+            0
+          }
+        )?;
+      }
+
+      writeln!(out)?;
     }
+
+    Ok(())
   }
 
   fn show<'src>(config: &Config, name: &str, justfile: &Justfile<'src>) -> Result<(), Error<'src>> {
@@ -573,7 +1143,172 @@ impl Subcommand {
     }
   }
 
-  fn summary(config: &Config, justfile: &Justfile) {
+  fn usage<'src>(
+    config: &Config,
+    name: &str,
+    justfile: &Justfile<'src>,
+  ) -> Result<(), Error<'src>> {
+    let Some(recipe) = justfile.get_recipe(name) else {
+      return Err(Error::UnknownRecipes {
+        recipes: vec![name.to_owned()],
+        suggestion: justfile.suggest_recipe(name),
+      });
+    };
+
+    let color = config.color.stdout();
+
+    if let Some(doc) = recipe.doc {
+      println!("{}\n", color.doc().paint(doc));
+    }
+
+    let arguments = recipe
+      .parameters
+      .iter()
+      .map(|parameter| {
+        let name = parameter.name.lexeme();
+
+        let usage = match (parameter.kind, parameter.default.is_some()) {
+          (ParameterKind::Singular, false) => format!("<{name}>"),
+          (ParameterKind::Singular, true) => format!("[{name}]"),
+          (ParameterKind::Plus, false) => format!("<{name}>..."),
+          (ParameterKind::Plus, true) | (ParameterKind::Star, _) => format!("[{name}]..."),
+          (ParameterKind::Passthrough, _) => format!("-- [{name}]..."),
+        };
+
+        let mut details = Vec::new();
+
+        if let Some(pattern) = &parameter.pattern {
+          details.push(format!("pattern: {pattern}"));
+        }
+
+        if let Some(default) = &parameter.default {
+          if parameter.secret {
+            details.push("default: ***".to_owned());
+          } else {
+            details.push(format!("default: {default}"));
+          }
+        }
+
+        if parameter.export {
+          let export_name = parameter.export_name.unwrap_or(parameter.name);
+          details.push(format!("exported as `${}`", export_name.lexeme()));
+        }
+
+        (usage, details.join(", "))
+      })
+      .collect::<Vec<(String, String)>>();
+
+    print!("Usage: just {}", recipe.namepath);
+    for (usage, _details) in &arguments {
+      print!(" {}", color.parameter().paint(usage));
+    }
+    println!();
+
+    if !arguments.is_empty() {
+      let width = arguments
+        .iter()
+        .map(|(usage, _details)| UnicodeWidthStr::width(usage.as_str()))
+        .max()
+        .unwrap_or_default();
+
+      println!("\nArguments:");
+
+      for (usage, details) in &arguments {
+        print!("  {}", color.parameter().paint(usage));
+
+        if !details.is_empty() {
+          print!(
+            "{:padding$}  {details}",
+            "",
+            padding = width - UnicodeWidthStr::width(usage.as_str()),
+          );
+        }
+
+        println!();
+      }
+    }
+
+    if !recipe.attributes.is_empty() {
+      println!("\nAttributes:");
+
+      for attribute in &recipe.attributes {
+        println!("  [{attribute}]");
+      }
+    }
+
+    Ok(())
+  }
+
+  fn find(config: &Config, pattern: &str, justfile: &Justfile) {
+    let mut found = 0;
+    Self::find_recursive(config, pattern, &mut found, justfile);
+
+    if found == 0 && config.verbosity.loud() {
+      eprintln!("No recipes contain `{pattern}`.");
+    }
+  }
+
+  fn find_recursive(config: &Config, pattern: &str, found: &mut usize, justfile: &Justfile) {
+    for recipe in justfile.public_recipes(config.unsorted) {
+      if recipe.contains(pattern) {
+        println!("{}: {}", recipe.location(), recipe.namepath);
+        *found += 1;
+      }
+    }
+
+    for module in justfile.modules.values() {
+      Self::find_recursive(config, pattern, found, module);
+    }
+  }
+
+  fn settings(config: &Config, justfile: &Justfile) -> Result<(), Error<'static>> {
+    let (command, arguments) = justfile.settings.shell(config);
+
+    let mut settings = serde_json::to_value(&justfile.settings)
+      .map_err(|serde_json_error| Error::DumpJson { serde_json_error })?;
+
+    settings["shell"] = serde_json::json!({
+      "arguments": arguments,
+      "command": command,
+    });
+
+    if config.json {
+      return Self::dump_json(&settings);
+    }
+
+    let settings = settings.as_object().into_iter().flatten();
+
+    let width = settings
+      .clone()
+      .map(|(name, _)| name.len())
+      .max()
+      .unwrap_or(0);
+
+    for (name, value) in settings {
+      let value = if name == "shell" {
+        serde_json::to_string(
+          &iter::once(command)
+            .chain(arguments.iter().copied())
+            .collect::<Vec<&str>>(),
+        )
+        .map_err(|serde_json_error| Error::DumpJson { serde_json_error })?
+      } else {
+        value.to_string()
+      };
+
+      println!("{:width$} {value}", name.replace('_', "-"));
+    }
+
+    Ok(())
+  }
+
+  fn summary(config: &Config, justfile: &Justfile) -> Result<(), Error<'static>> {
+    if config.json {
+      let mut recipes = Vec::new();
+      Self::summary_json_recursive(config, &mut Vec::new(), &mut recipes, justfile);
+      return Self::dump_json(&recipes);
+    }
+
     let mut printed = 0;
     Self::summary_recursive(config, &mut Vec::new(), &mut printed, justfile);
     println!();
@@ -581,6 +1316,44 @@ impl Subcommand {
     if printed == 0 && config.verbosity.loud() {
       eprintln!("Justfile contains no recipes.");
     }
+
+    Ok(())
+  }
+
+  fn summary_json_recursive<'a>(
+    config: &Config,
+    components: &mut Vec<&'a str>,
+    recipes: &mut Vec<SummaryRecipe>,
+    justfile: &'a Justfile,
+  ) {
+    let path = |name: &str| {
+      components
+        .iter()
+        .copied()
+        .chain(iter::once(name))
+        .collect::<Vec<&str>>()
+        .join("::")
+    };
+
+    for recipe in justfile.recipes_sorted(config.unsorted) {
+      recipes.push(SummaryRecipe {
+        aliases: justfile
+          .aliases
+          .values()
+          .filter(|alias| alias.target.name() == recipe.name())
+          .map(|alias| path(alias.name.lexeme()))
+          .collect(),
+        min_arguments: recipe.min_arguments(),
+        name: path(recipe.name()),
+        private: !recipe.is_public(),
+      });
+    }
+
+    for (name, module) in &justfile.modules {
+      components.push(name);
+      Self::summary_json_recursive(config, components, recipes, module);
+      components.pop();
+    }
   }
 
   fn summary_recursive<'a>(
@@ -610,7 +1383,54 @@ impl Subcommand {
     }
   }
 
-  fn variables(justfile: &Justfile) {
+  fn variables(config: &Config, justfile: &Justfile) {
+    if config.verbosity.loquacious() {
+      let color = config.color.stdout();
+
+      let assignments = justfile
+        .assignments
+        .iter()
+        .map(|(name, assignment)| (*name, assignment.value.to_string(), assignment.doc))
+        .collect::<Vec<(&str, String, Option<&str>)>>();
+
+      let name_width = assignments
+        .iter()
+        .map(|(name, _value, _doc)| UnicodeWidthStr::width(*name))
+        .max()
+        .unwrap_or_default();
+
+      let value_width = assignments
+        .iter()
+        .filter(|(_name, _value, doc)| doc.is_some())
+        .map(|(_name, value, _doc)| UnicodeWidthStr::width(value.as_str()))
+        .max()
+        .unwrap_or_default();
+
+      for (name, value, doc) in &assignments {
+        print!(
+          "{}{:padding$} := {}",
+          color.parameter().paint(name),
+          "",
+          color.string().paint(value),
+          padding = name_width - UnicodeWidthStr::width(*name),
+        );
+
+        if let Some(doc) = doc {
+          print!(
+            "{:padding$} {} {}",
+            "",
+            color.doc().paint("#"),
+            color.doc().paint(doc),
+            padding = value_width - UnicodeWidthStr::width(value.as_str()),
+          );
+        }
+
+        println!();
+      }
+
+      return;
+    }
+
     for (i, (_, assignment)) in justfile.assignments.iter().enumerate() {
       if i > 0 {
         print!(" ");
@@ -6,6 +6,20 @@ pub(crate) struct Suggestion<'src> {
   pub(crate) target: Option<&'src str>,
 }
 
+impl<'src> Suggestion<'src> {
+  pub(crate) fn closest(
+    input: &str,
+    names: impl IntoIterator<Item = &'src str>,
+  ) -> Option<Suggestion<'src>> {
+    names
+      .into_iter()
+      .map(|name| (edit_distance(name, input), name))
+      .filter(|(distance, _name)| distance < &3)
+      .min_by_key(|(distance, _name)| *distance)
+      .map(|(_distance, name)| Suggestion { name, target: None })
+  }
+}
+
 impl<'src> Display for Suggestion<'src> {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     write!(f, "Did you mean `{}`", self.name)?;
@@ -19,12 +19,15 @@ use {
 
 mod full {
   pub(crate) use crate::{
-    assignment::Assignment, condition::Condition, conditional_operator::ConditionalOperator,
-    dependency::Dependency, expression::Expression, fragment::Fragment, justfile::Justfile,
-    line::Line, parameter::Parameter, parameter_kind::ParameterKind, recipe::Recipe, thunk::Thunk,
+    alias::Alias, assignment::Assignment, condition::Condition,
+    conditional_operator::ConditionalOperator, dependency::Dependency, expression::Expression,
+    fragment::Fragment, justfile::Justfile, line::Line, parameter::Parameter,
+    parameter_kind::ParameterKind, recipe::Recipe, thunk::Thunk,
   };
 }
 
+pub use crate::dump::{Attribute, Settings, Shell};
+
 pub fn summary(path: &Path) -> Result<Result<Summary, String>, io::Error> {
   let loader = Loader::new();
 
@@ -40,8 +43,11 @@ pub fn summary(path: &Path) -> Result<Result<Summary, String>, io::Error> {
 
 #[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
 pub struct Summary {
+  pub aliases: BTreeMap<String, Alias>,
   pub assignments: BTreeMap<String, Assignment>,
+  pub modules: BTreeMap<String, Summary>,
   pub recipes: BTreeMap<String, Recipe>,
+  pub settings: Settings,
 }
 
 impl Summary {
@@ -56,6 +62,17 @@ impl Summary {
     }
 
     Self {
+      aliases: justfile
+        .aliases
+        .iter()
+        .map(|(name, alias)| ((*name).to_owned(), Alias::new(alias)))
+        .collect(),
+      modules: justfile
+        .modules
+        .iter()
+        .map(|(name, module)| (name.clone(), Self::new(module)))
+        .collect(),
+      settings: Settings::new(&justfile.settings),
       recipes: justfile
         .recipes
         .iter()
@@ -75,10 +92,27 @@ impl Summary {
   }
 }
 
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
+pub struct Alias {
+  pub attributes: Vec<Attribute>,
+  pub target: String,
+}
+
+impl Alias {
+  fn new(alias: &full::Alias) -> Self {
+    Self {
+      attributes: alias.attributes.iter().map(Attribute::new).collect(),
+      target: alias.target.name().to_owned(),
+    }
+  }
+}
+
 #[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
 pub struct Recipe {
   pub aliases: Vec<String>,
+  pub attributes: Vec<Attribute>,
   pub dependencies: Vec<Dependency>,
+  pub doc: Option<String>,
   pub lines: Vec<Line>,
   pub private: bool,
   pub quiet: bool,
@@ -89,6 +123,8 @@ pub struct Recipe {
 impl Recipe {
   fn new(recipe: &full::Recipe, aliases: Vec<String>) -> Self {
     Self {
+      attributes: recipe.attributes.iter().map(Attribute::new).collect(),
+      doc: recipe.doc.map(str::to_owned),
       private: recipe.private,
       shebang: recipe.shebang,
       quiet: recipe.quiet,
@@ -105,14 +141,25 @@ pub struct Parameter {
   pub kind: ParameterKind,
   pub name: String,
   pub default: Option<Expression>,
+  pub export: bool,
+  pub export_name: Option<String>,
+  pub pattern: Option<String>,
 }
 
 impl Parameter {
   fn new(parameter: &full::Parameter) -> Self {
     Self {
+      export: parameter.export,
+      export_name: parameter
+        .export_name
+        .map(|export_name| export_name.lexeme().to_owned()),
       kind: ParameterKind::new(parameter.kind),
       name: parameter.name.lexeme().to_owned(),
       default: parameter.default.as_ref().map(Expression::new),
+      pattern: parameter
+        .pattern
+        .as_ref()
+        .map(|pattern| pattern.cooked.clone()),
     }
   }
 }
@@ -122,6 +169,7 @@ pub enum ParameterKind {
   Singular,
   Plus,
   Star,
+  Passthrough,
 }
 
 impl ParameterKind {
@@ -130,6 +178,7 @@ impl ParameterKind {
       full::ParameterKind::Singular => Self::Singular,
       full::ParameterKind::Plus => Self::Plus,
       full::ParameterKind::Star => Self::Star,
+      full::ParameterKind::Passthrough => Self::Passthrough,
     }
   }
 }
@@ -235,7 +284,7 @@ impl Expression {
       Backtick { contents, .. } => Self::Backtick {
         command: (*contents).clone(),
       },
-      Call { thunk } => match thunk {
+      Call { thunk, .. } => match thunk {
         full::Thunk::Nullary { name, .. } => Self::Call {
           name: name.lexeme().to_owned(),
           arguments: Vec::new(),
@@ -261,6 +310,20 @@ impl Expression {
             arguments,
           }
         }
+        full::Thunk::UnaryPlus {
+          name,
+          args: (a, rest),
+          ..
+        } => {
+          let mut arguments = vec![Self::new(a)];
+          for arg in rest {
+            arguments.push(Self::new(arg));
+          }
+          Self::Call {
+            name: name.lexeme().to_owned(),
+            arguments,
+          }
+        }
         full::Thunk::Binary {
           name, args: [a, b], ..
         } => Self::Call {
@@ -289,6 +352,14 @@ impl Expression {
           name: name.lexeme().to_owned(),
           arguments: vec![Self::new(a), Self::new(b), Self::new(c)],
         },
+        full::Thunk::Quaternary {
+          name,
+          args: [a, b, c, d],
+          ..
+        } => Self::Call {
+          name: name.lexeme().to_owned(),
+          arguments: vec![Self::new(a), Self::new(b), Self::new(c), Self::new(d)],
+        },
       },
       Concatenation { lhs, rhs } => Self::Concatenation {
         lhs: Self::new(lhs).into(),
@@ -322,9 +393,9 @@ impl Expression {
 
 #[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
 pub struct Condition {
-  lhs: Box<Expression>,
-  rhs: Box<Expression>,
-  operator: ConditionalOperator,
+  pub lhs: Box<Expression>,
+  pub rhs: Box<Expression>,
+  pub operator: ConditionalOperator,
 }
 
 #[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
@@ -348,6 +419,7 @@ impl ConditionalOperator {
 pub struct Dependency {
   pub recipe: String,
   pub arguments: Vec<Expression>,
+  pub condition: Option<Condition>,
 }
 
 impl Dependency {
@@ -355,6 +427,52 @@ impl Dependency {
     Self {
       recipe: dependency.recipe.name().to_owned(),
       arguments: dependency.arguments.iter().map(Expression::new).collect(),
+      condition: dependency
+        .condition
+        .as_ref()
+        .map(|full::Condition { lhs, rhs, operator }| Condition {
+          lhs: Box::new(Expression::new(lhs)),
+          rhs: Box::new(Expression::new(rhs)),
+          operator: ConditionalOperator::new(*operator),
+        }),
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use {super::*, crate::testing, std::fs};
+
+  #[test]
+  fn settings_aliases_and_attributes() {
+    let tmp = testing::tempdir();
+    let path = tmp.path().join("justfile");
+
+    fs::write(
+      &path,
+      "set export\nset shell := ['bash', '-c']\n\nalias b := build\n\n# build it\n[no-cd]\n[confirm('sure?')]\nbuild $target='release':\n",
+    )
+    .unwrap();
+
+    let summary = summary(&path).unwrap().unwrap();
+
+    assert!(summary.settings.export);
+    assert_eq!(
+      summary.settings.shell,
+      Some(Shell {
+        command: "bash".into(),
+        arguments: vec!["-c".into()],
+      }),
+    );
+
+    assert_eq!(summary.aliases["b"].target, "build");
+
+    let recipe = &summary.recipes["build"];
+    assert_eq!(recipe.doc.as_deref(), Some("build it"));
+    assert_eq!(
+      recipe.attributes,
+      [Attribute::Confirm(Some("sure?".into())), Attribute::NoCd,],
+    );
+    assert!(recipe.parameters[0].export);
+  }
+}
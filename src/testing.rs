@@ -41,10 +41,21 @@ macro_rules! analysis_error {
       column: $column:expr,
       width:  $width:expr,
       kind:   $kind:expr,
+      $(related: ($related_offset:expr, $related_line:expr, $related_column:expr, $related_width:expr),)?
+      $(cycle: [$(($cycle_offset:expr, $cycle_line:expr, $cycle_column:expr, $cycle_width:expr)),* $(,)?],)?
     ) => {
     #[test]
     fn $name() {
-      $crate::testing::analysis_error($input, $offset, $line, $column, $width, $kind);
+      $crate::testing::analysis_error(
+        $input,
+        $offset,
+        $line,
+        $column,
+        $width,
+        $kind,
+        None $(.or(Some(($related_offset, $related_line, $related_column, $related_width))))?,
+        &[$($(($cycle_offset, $cycle_line, $cycle_column, $cycle_width)),*)?],
+      );
     }
   };
 }
@@ -56,6 +67,8 @@ pub(crate) fn analysis_error(
   column: usize,
   length: usize,
   kind: CompileErrorKind,
+  related: Option<(usize, usize, usize, usize)>,
+  cycle: &[(usize, usize, usize, usize)],
 ) {
   let tokens = Lexer::test_lex(src).expect("Lexing failed in parse test...");
 
@@ -89,6 +102,32 @@ pub(crate) fn analysis_error(
           path: "justfile".as_ref(),
         },
         kind: kind.into(),
+        related: related.map(|(offset, line, column, length)| {
+          Box::new(Token {
+            kind: have
+              .related
+              .as_ref()
+              .map_or(TokenKind::Identifier, |related| related.kind),
+            src,
+            offset,
+            line,
+            column,
+            length,
+            path: "justfile".as_ref(),
+          })
+        }),
+        cycle: cycle
+          .iter()
+          .map(|&(offset, line, column, length)| Token {
+            kind: TokenKind::Identifier,
+            src,
+            offset,
+            line,
+            column,
+            length,
+            path: "justfile".as_ref(),
+          })
+          .collect(),
       };
       assert_eq!(have, want);
     }
@@ -108,13 +147,14 @@ macro_rules! run_error {
       let config = $crate::testing::config(&$args);
       let search = $crate::testing::search(&config);
 
-      if let Subcommand::Run{ overrides, arguments } = &config.subcommand {
+      if let Subcommand::Run{ overrides, arguments, .. } = &config.subcommand {
         match $crate::testing::compile(&$crate::unindent::unindent($src))
           .run(
             &config,
             &search,
             &overrides,
             &arguments,
+            &(),
           ).expect_err("Expected runtime error") {
             $error => $check
             other => {
@@ -20,6 +20,12 @@ pub(crate) enum Thunk<'src> {
     function: fn(&FunctionContext, &str, Option<&str>) -> Result<String, String>,
     args: (Box<Expression<'src>>, Box<Option<Expression<'src>>>),
   },
+  UnaryPlus {
+    name: Name<'src>,
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    function: fn(&FunctionContext, &str, &[String]) -> Result<String, String>,
+    args: (Box<Expression<'src>>, Vec<Expression<'src>>),
+  },
   Binary {
     name: Name<'src>,
     #[derivative(Debug = "ignore", PartialEq = "ignore")]
@@ -38,6 +44,12 @@ pub(crate) enum Thunk<'src> {
     function: fn(&FunctionContext, &str, &str, &str) -> Result<String, String>,
     args: [Box<Expression<'src>>; 3],
   },
+  Quaternary {
+    name: Name<'src>,
+    #[derivative(Debug = "ignore", PartialEq = "ignore")]
+    function: fn(&FunctionContext, &str, &str, &str, &str) -> Result<String, String>,
+    args: [Box<Expression<'src>>; 4],
+  },
 }
 
 impl<'src> Thunk<'src> {
@@ -46,9 +58,11 @@ impl<'src> Thunk<'src> {
       Self::Nullary { name, .. }
       | Self::Unary { name, .. }
       | Self::UnaryOpt { name, .. }
+      | Self::UnaryPlus { name, .. }
       | Self::Binary { name, .. }
       | Self::BinaryPlus { name, .. }
-      | Self::Ternary { name, .. } => name,
+      | Self::Ternary { name, .. }
+      | Self::Quaternary { name, .. } => name,
     }
   }
 
@@ -59,6 +73,7 @@ impl<'src> Thunk<'src> {
     function::get(name.lexeme()).map_or(
       Err(name.error(CompileErrorKind::UnknownFunction {
         function: name.lexeme(),
+        suggestion: Suggestion::closest(name.lexeme(), function::NAMES.iter().copied()),
       })),
       |function| match (function, arguments.len()) {
         (Function::Nullary(function), 0) => Ok(Thunk::Nullary { function, name }),
@@ -79,6 +94,15 @@ impl<'src> Thunk<'src> {
             name,
           })
         }
+        (Function::UnaryPlus(function), 1..=usize::MAX) => {
+          let rest = arguments.drain(1..).collect();
+          let a = arguments.pop().unwrap().into();
+          Ok(Thunk::UnaryPlus {
+            function,
+            args: (a, rest),
+            name,
+          })
+        }
         (Function::Binary(function), 2) => {
           let b = arguments.pop().unwrap().into();
           let a = arguments.pop().unwrap().into();
@@ -108,6 +132,17 @@ impl<'src> Thunk<'src> {
             name,
           })
         }
+        (Function::Quaternary(function), 4) => {
+          let d = arguments.pop().unwrap().into();
+          let c = arguments.pop().unwrap().into();
+          let b = arguments.pop().unwrap().into();
+          let a = arguments.pop().unwrap().into();
+          Ok(Thunk::Quaternary {
+            function,
+            args: [a, b, c, d],
+            name,
+          })
+        }
         (function, _) => Err(name.error(CompileErrorKind::FunctionArgumentCountMismatch {
           function: name.lexeme(),
           found: arguments.len(),
@@ -133,6 +168,17 @@ impl Display for Thunk<'_> {
           write!(f, "{}({a})", name.lexeme())
         }
       }
+      UnaryPlus {
+        name,
+        args: (a, rest),
+        ..
+      } => {
+        write!(f, "{}({a}", name.lexeme())?;
+        for arg in rest {
+          write!(f, ", {arg}")?;
+        }
+        write!(f, ")")
+      }
       Binary {
         name, args: [a, b], ..
       } => write!(f, "{}({a}, {b})", name.lexeme()),
@@ -152,6 +198,11 @@ impl Display for Thunk<'_> {
         args: [a, b, c],
         ..
       } => write!(f, "{}({a}, {b}, {c})", name.lexeme()),
+      Quaternary {
+        name,
+        args: [first, second, third, fourth],
+        ..
+      } => write!(f, "{}({first}, {second}, {third}, {fourth})", name.lexeme()),
     }
   }
 }
@@ -175,6 +226,14 @@ impl<'src> Serialize for Thunk<'src> {
           seq.serialize_element(b)?;
         }
       }
+      Self::UnaryPlus {
+        args: (a, rest), ..
+      } => {
+        seq.serialize_element(a)?;
+        for arg in rest {
+          seq.serialize_element(arg)?;
+        }
+      }
       Self::Binary { args, .. } => {
         for arg in args {
           seq.serialize_element(arg)?;
@@ -190,6 +249,11 @@ impl<'src> Serialize for Thunk<'src> {
           seq.serialize_element(arg)?;
         }
       }
+      Self::Quaternary { args, .. } => {
+        for arg in args {
+          seq.serialize_element(arg)?;
+        }
+      }
     }
     seq.end()
   }
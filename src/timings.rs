@@ -0,0 +1,107 @@
+use super::*;
+
+/// How long each recipe took to run, printed after the run when `--timings`
+/// is given. Events are forwarded to `events`, so that timings can be
+/// combined with a transcript.
+pub(crate) struct Timings<'run> {
+  events: &'run dyn Events,
+  recipes: Mutex<Vec<Timing>>,
+  started: Instant,
+}
+
+struct Timing {
+  duration: Option<Duration>,
+  recipe: String,
+  started: Instant,
+  success: bool,
+}
+
+impl<'run> Timings<'run> {
+  pub(crate) fn new(events: &'run dyn Events) -> Self {
+    Self {
+      events,
+      recipes: Mutex::new(Vec::new()),
+      started: Instant::now(),
+    }
+  }
+}
+
+impl Events for Timings<'_> {
+  fn recipe_started(&self, recipe: &str) {
+    self.recipes.lock().unwrap().push(Timing {
+      duration: None,
+      recipe: recipe.into(),
+      started: Instant::now(),
+      success: false,
+    });
+
+    self.events.recipe_started(recipe);
+  }
+
+  fn recipe_environment(&self, recipe: &str, variables: &BTreeMap<String, String>) {
+    self.events.recipe_environment(recipe, variables);
+  }
+
+  fn line_spawned(&self, recipe: &str, line: Option<usize>, command: &str) {
+    self.events.line_spawned(recipe, line, command);
+  }
+
+  fn line_finished(&self, recipe: &str, line: Option<usize>, status: ExitStatus) {
+    self.events.line_finished(recipe, line, status);
+  }
+
+  fn recipe_finished(&self, recipe: &str, success: bool) {
+    if let Some(timing) = self
+      .recipes
+      .lock()
+      .unwrap()
+      .iter_mut()
+      .rev()
+      .find(|timing| timing.recipe == recipe && timing.duration.is_none())
+    {
+      timing.duration = Some(timing.started.elapsed());
+      timing.success = success;
+    }
+
+    self.events.recipe_finished(recipe, success);
+  }
+}
+
+impl ColorDisplay for Timings<'_> {
+  fn fmt(&self, f: &mut Formatter, color: Color) -> fmt::Result {
+    let recipes = self.recipes.lock().unwrap();
+
+    let width = recipes
+      .iter()
+      .map(|timing| UnicodeWidthStr::width(timing.recipe.as_str()))
+      .chain(iter::once("total".len()))
+      .max()
+      .unwrap_or_default();
+
+    writeln!(f, "Timings:")?;
+
+    for timing in recipes.iter() {
+      write!(
+        f,
+        "    {}{:padding$} {:.3}s",
+        timing.recipe,
+        "",
+        timing.duration.unwrap_or_default().as_secs_f64(),
+        padding = width - UnicodeWidthStr::width(timing.recipe.as_str()),
+      )?;
+
+      if !timing.success {
+        write!(f, " {}", color.error().paint("(failed)"))?;
+      }
+
+      writeln!(f)?;
+    }
+
+    writeln!(
+      f,
+      "    {:width$} {:.3}s",
+      "total",
+      self.started.elapsed().as_secs_f64(),
+    )
+  }
+}
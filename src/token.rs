@@ -19,6 +19,41 @@ impl<'src> Token<'src> {
   pub(crate) fn error(&self, kind: CompileErrorKind<'src>) -> CompileError<'src> {
     CompileError::new(*self, kind)
   }
+
+  /// A token spanning from the start of this token to the end of `end`
+  pub(crate) fn through(self, end: Token<'src>) -> Self {
+    Self {
+      length: end.offset + end.length - self.offset,
+      ..self
+    }
+  }
+
+  /// Render `text` as a hyperlink to the file containing this token. Token
+  /// paths are relative to the root justfile's directory, which is usually
+  /// the current directory or one of its ancestors, so relative paths are
+  /// resolved by searching upwards, like `just` does when looking for a
+  /// justfile.
+  fn hyperlink(&self, color: Color, text: &str) -> String {
+    if !color.hyperlinks() {
+      return text.into();
+    }
+
+    let path = if self.path.is_absolute() {
+      Some(self.path.to_owned())
+    } else {
+      env::current_dir().ok().and_then(|directory| {
+        directory
+          .ancestors()
+          .map(|ancestor| ancestor.join(self.path))
+          .find(|path| path.is_file())
+      })
+    };
+
+    match path {
+      Some(path) => color.hyperlink(&path, text),
+      None => text.into(),
+    }
+  }
 }
 
 impl<'src> ColorDisplay for Token<'src> {
@@ -53,14 +88,18 @@ impl<'src> ColorDisplay for Token<'src> {
           i += c.len_utf8();
         }
         let line_number_width = line_number.to_string().len();
+        let location = format!(
+          "{}:{}:{}",
+          self.path.display(),
+          line_number,
+          self.column.ordinal()
+        );
         writeln!(
           f,
-          "{:width$}{} {}:{}:{}",
+          "{:width$}{} {}",
           "",
           color.context().paint("——▶"),
-          self.path.display(),
-          line_number,
-          self.column.ordinal(),
+          self.hyperlink(color, &location),
           width = line_number_width
         )?;
         writeln!(
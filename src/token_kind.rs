@@ -27,6 +27,7 @@ pub(crate) enum TokenKind {
   Indent,
   InterpolationEnd,
   InterpolationStart,
+  MinusMinus,
   ParenL,
   ParenR,
   Plus,
@@ -70,6 +71,7 @@ impl Display for TokenKind {
         Indent => "indent",
         InterpolationEnd => "'}}'",
         InterpolationStart => "'{{'",
+        MinusMinus => "'--'",
         ParenL => "'('",
         ParenR => "')'",
         Plus => "'+'",
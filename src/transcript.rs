@@ -0,0 +1,115 @@
+use super::*;
+
+/// Structured record of a run, written to the path given with `--transcript`
+/// for build observability systems
+#[derive(Debug, Default)]
+pub(crate) struct Transcript {
+  recipes: Mutex<Vec<TranscriptRecipe>>,
+}
+
+#[derive(Debug, Serialize)]
+struct TranscriptRecipe {
+  recipe: String,
+  success: Option<bool>,
+  duration: f64,
+  environment: BTreeMap<String, String>,
+  lines: Vec<TranscriptLine>,
+  #[serde(skip)]
+  started: Instant,
+}
+
+#[derive(Debug, Serialize)]
+struct TranscriptLine {
+  line: Option<usize>,
+  command: String,
+  exit_code: Option<i32>,
+  duration: f64,
+  #[serde(skip)]
+  spawned: Instant,
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+  success: bool,
+  error: Option<String>,
+  recipes: &'a [TranscriptRecipe],
+}
+
+impl Transcript {
+  /// Write the transcript to `path`, along with `error`, if the run failed
+  pub(crate) fn write(&self, path: &Path, error: Option<&Error>) -> Result<(), Error<'static>> {
+    let recipes = self.recipes.lock().unwrap();
+
+    let record = Record {
+      success: error.is_none(),
+      error: error.map(|error| error.color_display(Color::never()).to_string()),
+      recipes: &recipes,
+    };
+
+    let mut json = serde_json::to_string_pretty(&record).expect("transcript serialization failed");
+    json.push('\n');
+
+    fs::write(path, json).map_err(|io_error| Error::TranscriptWrite {
+      path: path.into(),
+      io_error,
+    })
+  }
+
+  fn with_recipe(&self, recipe: &str, f: impl FnOnce(&mut TranscriptRecipe)) {
+    if let Some(record) = self
+      .recipes
+      .lock()
+      .unwrap()
+      .iter_mut()
+      .rev()
+      .find(|record| record.recipe == recipe && record.success.is_none())
+    {
+      f(record);
+    }
+  }
+}
+
+impl Events for Transcript {
+  fn recipe_started(&self, recipe: &str) {
+    self.recipes.lock().unwrap().push(TranscriptRecipe {
+      recipe: recipe.into(),
+      success: None,
+      duration: 0.0,
+      environment: BTreeMap::new(),
+      lines: Vec::new(),
+      started: Instant::now(),
+    });
+  }
+
+  fn recipe_environment(&self, recipe: &str, variables: &BTreeMap<String, String>) {
+    self.with_recipe(recipe, |record| record.environment = variables.clone());
+  }
+
+  fn line_spawned(&self, recipe: &str, line: Option<usize>, command: &str) {
+    self.with_recipe(recipe, |record| {
+      record.lines.push(TranscriptLine {
+        line,
+        command: command.into(),
+        exit_code: None,
+        duration: 0.0,
+        spawned: Instant::now(),
+      });
+    });
+  }
+
+  fn line_finished(&self, recipe: &str, line: Option<usize>, status: ExitStatus) {
+    self.with_recipe(recipe, |record| {
+      if let Some(last) = record.lines.last_mut().filter(|last| last.line == line) {
+        last.exit_code = status.code();
+        last.duration = last.spawned.elapsed().as_secs_f64();
+      }
+    });
+  }
+
+  fn recipe_finished(&self, recipe: &str, success: bool) {
+    self.with_recipe(recipe, |record| {
+      record.success = Some(success);
+      record.duration = record.started.elapsed().as_secs_f64();
+    });
+  }
+}
@@ -4,11 +4,12 @@ use super::*;
 pub(crate) struct UnresolvedDependency<'src> {
   pub(crate) recipe: Name<'src>,
   pub(crate) arguments: Vec<Expression<'src>>,
+  pub(crate) condition: Option<Condition<'src>>,
 }
 
 impl<'src> Display for UnresolvedDependency<'src> {
   fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-    if self.arguments.is_empty() {
+    if self.arguments.is_empty() && self.condition.is_none() {
       write!(f, "{}", self.recipe)
     } else {
       write!(f, "({}", self.recipe)?;
@@ -17,6 +18,10 @@ impl<'src> Display for UnresolvedDependency<'src> {
         write!(f, " {argument}")?;
       }
 
+      if let Some(condition) = &self.condition {
+        write!(f, " if {condition}")?;
+      }
+
       write!(f, ")")
     }
   }
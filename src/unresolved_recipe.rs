@@ -4,8 +4,8 @@ pub(crate) type UnresolvedRecipe<'src> = Recipe<'src, UnresolvedDependency<'src>
 
 impl<'src> UnresolvedRecipe<'src> {
   pub(crate) fn resolve(
-    self,
-    resolved: Vec<Rc<Recipe<'src>>>,
+    mut self,
+    resolved: Vec<Arc<Recipe<'src>>>,
   ) -> CompileResult<'src, Recipe<'src>> {
     assert_eq!(
       self.dependencies.len(),
@@ -15,6 +15,14 @@ impl<'src> UnresolvedRecipe<'src> {
       resolved.len()
     );
 
+    if self.attributes.contains(&Attribute::ForwardArguments) {
+      for (unresolved, resolved) in self.dependencies.iter_mut().zip(&resolved) {
+        if unresolved.arguments.is_empty() {
+          unresolved.arguments = Self::forwarded_arguments(&self.parameters, resolved);
+        }
+      }
+    }
+
     for (unresolved, resolved) in self.dependencies.iter().zip(&resolved) {
       assert_eq!(unresolved.recipe.lexeme(), resolved.name.lexeme());
       if !resolved
@@ -41,12 +49,15 @@ impl<'src> UnresolvedRecipe<'src> {
       .map(|(unresolved, resolved)| Dependency {
         recipe: resolved,
         arguments: unresolved.arguments,
+        condition: unresolved.condition,
       })
       .collect();
 
     Ok(Recipe {
+      attribute_lines: self.attribute_lines,
       attributes: self.attributes,
       body: self.body,
+      comment: self.comment,
       dependencies,
       depth: self.depth,
       doc: self.doc,
@@ -61,4 +72,23 @@ impl<'src> UnresolvedRecipe<'src> {
       working_directory: self.working_directory,
     })
   }
+
+  /// Arguments for the leading parameters of `dependency` which share a name
+  /// with one of `parameters`, stopping at the first which does not, since
+  /// dependency arguments are positional.
+  fn forwarded_arguments(
+    parameters: &[Parameter<'src>],
+    dependency: &Recipe<'src>,
+  ) -> Vec<Expression<'src>> {
+    dependency
+      .parameters
+      .iter()
+      .map_while(|parameter| {
+        parameters
+          .iter()
+          .find(|caller| caller.name.lexeme() == parameter.name.lexeme())
+          .map(|caller| Expression::Variable { name: caller.name })
+      })
+      .collect()
+  }
 }
@@ -4,3 +4,81 @@ pub(crate) enum UseColor {
   Always,
   Never,
 }
+
+impl UseColor {
+  /// Resolve `auto` using the standard color environment variables. In order
+  /// of precedence, a non-empty `NO_COLOR` disables color, `FORCE_COLOR` or
+  /// `CLICOLOR_FORCE` set to anything other than `0` or `false` enables it,
+  /// and `CLICOLOR=0` disables it. Otherwise, color is used if the output
+  /// stream is a terminal.
+  pub(crate) fn from_env(var: impl Fn(&str) -> Option<String>) -> Self {
+    let var = |name| var(name).filter(|value| !value.is_empty());
+
+    if var("NO_COLOR").is_some() {
+      return Self::Never;
+    }
+
+    for name in ["FORCE_COLOR", "CLICOLOR_FORCE"] {
+      if var(name).map_or(false, |value| value != "0" && value != "false") {
+        return Self::Always;
+      }
+    }
+
+    if var("CLICOLOR").map_or(false, |value| value == "0") {
+      return Self::Never;
+    }
+
+    Self::Auto
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, std::collections::BTreeMap};
+
+  fn from_env(vars: &[(&str, &str)]) -> UseColor {
+    let vars = vars.iter().copied().collect::<BTreeMap<&str, &str>>();
+    UseColor::from_env(|name| vars.get(name).map(|value| (*value).to_owned()))
+  }
+
+  #[test]
+  fn unset() {
+    assert_eq!(from_env(&[]), UseColor::Auto);
+  }
+
+  #[test]
+  fn no_color() {
+    assert_eq!(from_env(&[("NO_COLOR", "1")]), UseColor::Never);
+    assert_eq!(from_env(&[("NO_COLOR", "")]), UseColor::Auto);
+  }
+
+  #[test]
+  fn force_color() {
+    assert_eq!(from_env(&[("FORCE_COLOR", "1")]), UseColor::Always);
+    assert_eq!(from_env(&[("FORCE_COLOR", "0")]), UseColor::Auto);
+    assert_eq!(from_env(&[("FORCE_COLOR", "false")]), UseColor::Auto);
+    assert_eq!(from_env(&[("CLICOLOR_FORCE", "1")]), UseColor::Always);
+  }
+
+  #[test]
+  fn clicolor() {
+    assert_eq!(from_env(&[("CLICOLOR", "0")]), UseColor::Never);
+    assert_eq!(from_env(&[("CLICOLOR", "1")]), UseColor::Auto);
+  }
+
+  #[test]
+  fn no_color_takes_precedence() {
+    assert_eq!(
+      from_env(&[("NO_COLOR", "1"), ("FORCE_COLOR", "1")]),
+      UseColor::Never
+    );
+  }
+
+  #[test]
+  fn force_color_overrides_clicolor() {
+    assert_eq!(
+      from_env(&[("CLICOLOR", "0"), ("CLICOLOR_FORCE", "1")]),
+      UseColor::Always
+    );
+  }
+}
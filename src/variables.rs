@@ -17,7 +17,7 @@ impl<'expression, 'src> Iterator for Variables<'expression, 'src> {
     loop {
       match self.stack.pop()? {
         Expression::StringLiteral { .. } | Expression::Backtick { .. } => {}
-        Expression::Call { thunk } => match thunk {
+        Expression::Call { thunk, .. } => match thunk {
           Thunk::Nullary { .. } => {}
           Thunk::Unary { arg, .. } => self.stack.push(arg),
           Thunk::UnaryOpt {
@@ -28,6 +28,14 @@ impl<'expression, 'src> Iterator for Variables<'expression, 'src> {
               self.stack.push(b);
             }
           }
+          Thunk::UnaryPlus {
+            args: (a, rest), ..
+          } => {
+            let first: &[&Expression] = &[a];
+            for arg in first.iter().copied().chain(rest).rev() {
+              self.stack.push(arg);
+            }
+          }
           Thunk::Binary { args, .. } => {
             for arg in args.iter().rev() {
               self.stack.push(arg);
@@ -47,6 +55,11 @@ impl<'expression, 'src> Iterator for Variables<'expression, 'src> {
               self.stack.push(arg);
             }
           }
+          Thunk::Quaternary { args, .. } => {
+            for arg in args.iter().rev() {
+              self.stack.push(arg);
+            }
+          }
         },
         Expression::Conditional {
           condition:
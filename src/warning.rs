@@ -1,13 +1,54 @@
 use super::*;
 
 #[derive(Clone, Debug, PartialEq)]
-pub(crate) enum Warning {}
+pub(crate) enum Warning {
+  DotenvOutsideProject { path: PathBuf },
+  ShortSecret { name: String },
+  WarnDirective { message: String },
+}
 
 impl Warning {
+  pub(crate) fn code(&self) -> WarningCode {
+    match self {
+      Self::DotenvOutsideProject { .. } => WarningCode::DotenvOutsideProject,
+      Self::ShortSecret { .. } => WarningCode::ShortSecret,
+      Self::WarnDirective { .. } => WarningCode::WarnDirective,
+    }
+  }
+
   #[allow(clippy::unused_self)]
   fn context(&self) -> Option<&Token> {
     None
   }
+
+  pub(crate) fn message(&self) -> String {
+    match self {
+      Self::DotenvOutsideProject { path } => format!(
+        "Loading environment file `{}` from outside the justfile directory",
+        path.display()
+      ),
+      Self::ShortSecret { name } => format!(
+        "Value of secret `{name}` is shorter than {} characters and will not be masked",
+        Scope::MIN_MASKED_LENGTH,
+      ),
+      Self::WarnDirective { message } => message.clone(),
+    }
+  }
+
+  /// Print this warning, or return it as an error if `--warn-as-error` was
+  /// passed. Callers are responsible for skipping warnings whose code is
+  /// allowed.
+  pub(crate) fn report<'src>(self, config: &Config, verbosity: Verbosity) -> RunResult<'src, ()> {
+    if config.warn_as_error {
+      return Err(Error::Warning { warning: self });
+    }
+
+    if verbosity.loud() {
+      eprintln!("{}", self.color_display(config.color.stderr()));
+    }
+
+    Ok(())
+  }
 }
 
 impl ColorDisplay for Warning {
@@ -17,6 +58,8 @@ impl ColorDisplay for Warning {
 
     write!(f, "{} {}", warning.paint("warning:"), message.prefix())?;
 
+    write!(f, "{}", self.message())?;
+
     write!(f, "{}", message.suffix())?;
 
     if let Some(token) = self.context() {
@@ -35,6 +78,7 @@ impl Serialize for Warning {
   {
     let mut map = serializer.serialize_map(None)?;
 
+    map.serialize_entry("code", self.code().name())?;
     map.serialize_entry("message", &self.color_display(Color::never()).to_string())?;
 
     map.end()
@@ -0,0 +1,43 @@
+use super::*;
+
+/// The code identifying a kind of warning, which may be passed to
+/// `set allow` or `[allow(...)]` to suppress it
+#[derive(Clone, Copy, Debug, EnumString, IntoStaticStr, PartialEq, VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum WarningCode {
+  DotenvOutsideProject,
+  ShortSecret,
+  WarnDirective,
+}
+
+impl WarningCode {
+  pub(crate) fn name(self) -> &'static str {
+    self.into()
+  }
+
+  pub(crate) fn suggest(name: &str) -> Option<Suggestion<'static>> {
+    Suggestion::closest(name, Self::VARIANTS.iter().copied())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn name() {
+    assert_eq!(
+      WarningCode::DotenvOutsideProject.name(),
+      "dotenv-outside-project"
+    );
+  }
+
+  #[test]
+  fn parse() {
+    assert_eq!(
+      "warn-directive".parse::<WarningCode>().ok(),
+      Some(WarningCode::WarnDirective),
+    );
+    assert_eq!("warn_directive".parse::<WarningCode>().ok(), None);
+  }
+}
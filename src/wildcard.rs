@@ -0,0 +1,55 @@
+use super::*;
+
+/// Convert a pattern in which `*` matches any sequence of characters and
+/// `?` matches any single character into an anchored regular expression
+pub(crate) fn wildcard_regex(pattern: &str) -> Regex {
+  let mut regex = String::from("^");
+
+  for c in pattern.chars() {
+    match c {
+      '*' => regex.push_str(".*"),
+      '?' => regex.push('.'),
+      c => regex.push_str(&regex::escape(&c.to_string())),
+    }
+  }
+
+  regex.push('$');
+
+  Regex::new(&regex).expect("escaped wildcard pattern is a valid regex")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn matches() {
+    #[track_caller]
+    fn case(pattern: &str, name: &str, expected: bool) {
+      assert_eq!(
+        wildcard_regex(pattern).is_match(name),
+        expected,
+        "{pattern} {name}"
+      );
+    }
+
+    case("HOME", "HOME", true);
+    case("HOME", "HOMEDIR", false);
+    case("HOME", "MY_HOME", false);
+    case("AWS_*", "AWS_SECRET_ACCESS_KEY", true);
+    case("AWS_*", "AWS_", true);
+    case("AWS_*", "MY_AWS_KEY", false);
+    case("*_TOKEN", "GITHUB_TOKEN", true);
+    case("*_TOKEN", "GITHUB_TOKENS", false);
+    case("*SECRET*", "MY_SECRET_KEY", true);
+    case("A*B*C", "AXBYC", true);
+    case("A*B*C", "ABC", true);
+    case("A*B*C", "ACB", false);
+    case("A*A", "A", false);
+    case("*", "", true);
+    case("KEY_?", "KEY_1", true);
+    case("KEY_?", "KEY_", false);
+    case("KEY_?", "KEY_12", false);
+    case("a.b", "axb", false);
+  }
+}
@@ -0,0 +1,79 @@
+use super::*;
+
+#[test]
+fn missing_arguments_are_an_error_when_stdin_is_not_a_terminal() {
+  Test::new()
+    .justfile(
+      "
+        set allow-missing-recipe-args := 'prompt'
+
+        foo a b:
+      ",
+    )
+    .arg("foo")
+    .stdin("x\ny\n")
+    .stderr(
+      "
+        error: Recipe `foo` got 0 arguments but takes 2
+        usage:
+            just foo a b
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn default_recipe_requiring_arguments_is_an_error_when_stdin_is_not_a_terminal() {
+  Test::new()
+    .justfile(
+      "
+        set allow-missing-recipe-args := 'prompt'
+
+        foo a:
+      ",
+    )
+    .stderr(
+      "error: Recipe `foo` cannot be used as default recipe since it requires at least 1 argument.\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn error_value_is_accepted() {
+  Test::new()
+    .justfile(
+      "
+        set allow-missing-recipe-args := 'error'
+
+        foo a:
+          echo {{ a }}
+      ",
+    )
+    .args(["foo", "bar"])
+    .stdout("bar\n")
+    .stderr("echo bar\n")
+    .run();
+}
+
+#[test]
+fn invalid_value() {
+  Test::new()
+    .justfile(
+      "
+        set allow-missing-recipe-args := 'ask'
+      ",
+    )
+    .stderr(
+      "
+        error: Invalid value `ask` for setting `allow-missing-recipe-args`, expected `error` or `prompt`
+         ——▶ justfile:1:34
+          │
+        1 │ set allow-missing-recipe-args := 'ask'
+          │                                  ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -59,6 +59,25 @@ fn multiple_attributes_one_line() {
     .run();
 }
 
+#[test]
+fn multiple_attributes_one_line_with_arguments() {
+  Test::new()
+    .justfile(
+      "
+      [confirm('sure?'), no-exit-message, dotenv('.env')]
+      foo:
+        @echo $FOO
+        exit 1
+    ",
+    )
+    .write(".env", "FOO=bar")
+    .arg("--yes")
+    .stdout("bar\n")
+    .stderr("exit 1\n")
+    .status(1)
+    .run();
+}
+
 #[test]
 fn multiple_attributes_one_line_error_message() {
   Test::new()
@@ -129,3 +148,89 @@ fn unexpected_attribute_argument() {
     .status(1)
     .run();
 }
+
+#[test]
+fn missing_attribute_argument() {
+  Test::new()
+    .justfile(
+      "
+      [dotenv]
+      foo:
+        exit 1
+    ",
+    )
+    .stderr(
+      "
+        error: Attribute `dotenv` requires an argument
+         ——▶ justfile:1:2
+          │
+        1 │ [dotenv]
+          │  ^^^^^^
+          ",
+    )
+    .status(1)
+    .run();
+}
+
+#[test]
+fn attribute_argument_count_mismatch() {
+  Test::new()
+    .justfile(
+      "
+      [confirm('foo', 'bar')]
+      foo:
+        exit 1
+    ",
+    )
+    .stderr(
+      "
+        error: Attribute `confirm` got 2 arguments but takes at most 1 argument
+         ——▶ justfile:1:2
+          │
+        1 │ [confirm('foo', 'bar')]
+          │  ^^^^^^^
+          ",
+    )
+    .status(1)
+    .run();
+}
+
+#[test]
+fn attribute_arguments_may_have_trailing_comma() {
+  Test::new()
+    .justfile(
+      "
+      [confirm('sure?',)]
+      foo:
+        echo foo
+    ",
+    )
+    .arg("--yes")
+    .stdout("foo\n")
+    .stderr("echo foo\n")
+    .run();
+}
+
+#[test]
+fn unknown_attribute_suggestion() {
+  Test::new()
+    .justfile(
+      "
+      [no-exitmessage]
+      foo:
+        exit 1
+    ",
+    )
+    .stderr(
+      "
+      error: Unknown attribute `no-exitmessage`
+      Did you mean `no-exit-message`?
+       ——▶ justfile:1:2
+        │
+      1 │ [no-exitmessage]
+        │  ^^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,140 @@
+use super::*;
+
+#[test]
+fn block_comment_disables_recipes() {
+  Test::new()
+    .justfile(
+      "
+        /*
+        foo:
+          echo foo
+        */
+
+        bar:
+          echo bar
+      ",
+    )
+    .args(["--list"])
+    .stdout(
+      "
+        Available recipes:
+            bar
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn block_comment_on_one_line() {
+  Test::new()
+    .justfile(
+      "
+        /* build everything */
+        foo: /* no dependencies */
+          echo foo
+      ",
+    )
+    .stdout("foo\n")
+    .stderr("echo foo\n")
+    .run();
+}
+
+#[test]
+fn block_comment_after_assignment() {
+  Test::new()
+    .justfile(
+      "
+        x := 'a' /* the letter a */
+
+        foo:
+          echo {{ x }}
+      ",
+    )
+    .stdout("a\n")
+    .stderr("echo a\n")
+    .run();
+}
+
+#[test]
+fn block_comment_is_not_doc_comment() {
+  Test::new()
+    .justfile(
+      "
+        /* not documentation */
+        foo:
+      ",
+    )
+    .args(["--list"])
+    .stdout(
+      "
+        Available recipes:
+            foo
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn block_comment_in_recipe_body_is_text() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo '/* hello */'
+      ",
+    )
+    .stdout("/* hello */\n")
+    .stderr("echo '/* hello */'\n")
+    .run();
+}
+
+#[test]
+fn block_comment_is_preserved_by_dump() {
+  Test::new()
+    .justfile(
+      "
+        /*
+          foo:
+            echo foo
+        */
+        bar:
+          echo bar
+      ",
+    )
+    .args(["--dump"])
+    .stdout(
+      "
+        /*
+          foo:
+            echo foo
+        */
+
+        bar:
+            echo bar
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn unterminated_block_comment() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+
+        /* hello
+      ",
+    )
+    .stderr(
+      "
+        error: Unterminated block comment
+         ——▶ justfile:3:1
+          │
+        3 │ /* hello
+          │ ^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
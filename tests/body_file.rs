@@ -0,0 +1,147 @@
+use super::*;
+
+#[test]
+fn body_is_written_to_file() {
+  Test::new()
+    .justfile(
+      "
+        [body-file('cat \"$JUST_BODY_FILE\"')]
+        foo:
+          echo {{ 'bar' }}
+          baz
+      ",
+    )
+    .stdout("echo bar\nbaz\n")
+    .run();
+}
+
+#[test]
+fn body_is_run_by_command() {
+  Test::new()
+    .justfile(
+      "
+        [body-file('sh \"$JUST_BODY_FILE\"')]
+        foo:
+          x=bar
+          echo $x
+      ",
+    )
+    .stdout("bar\n")
+    .run();
+}
+
+#[test]
+fn file_has_no_shebang_line() {
+  Test::new()
+    .justfile(
+      "
+        [body-file('head -n 1 \"$JUST_BODY_FILE\"')]
+        foo:
+          first
+          second
+      ",
+    )
+    .stdout("first\n")
+    .run();
+}
+
+#[test]
+fn dry_run() {
+  Test::new()
+    .justfile(
+      "
+        [body-file('sh \"$JUST_BODY_FILE\"')]
+        foo:
+          echo {{ 'bar' }}
+      ",
+    )
+    .arg("--dry-run")
+    .stderr("echo bar\n")
+    .run();
+}
+
+#[test]
+fn command_failure() {
+  Test::new()
+    .justfile(
+      "
+        [body-file('sh \"$JUST_BODY_FILE\"')]
+        foo:
+          exit 3
+      ",
+    )
+    .stderr("error: Recipe `foo` failed with exit code 3\n")
+    .status(3)
+    .run();
+}
+
+#[test]
+fn command_is_required() {
+  Test::new()
+    .justfile(
+      "
+        [body-file]
+        foo:
+      ",
+    )
+    .stderr(
+      "
+        error: Attribute `body-file` requires an argument
+         ——▶ justfile:1:2
+          │
+        1 │ [body-file]
+          │  ^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+#[cfg(unix)]
+fn tempdir_with_space() {
+  Test::new()
+    .justfile(
+      "
+        [tempdir('dir with space')]
+        [body-file('cat \"$JUST_BODY_FILE\"')]
+        foo:
+          bar
+      ",
+    )
+    .tree(tree! {
+      "dir with space": {}
+    })
+    .stdout("bar\n")
+    .run();
+}
+
+#[test]
+fn positional_arguments() {
+  Test::new()
+    .justfile(
+      "
+        set positional-arguments
+
+        [body-file('sh \"$JUST_BODY_FILE\" \"$@\"')]
+        foo bar baz:
+          echo $1 $2
+      ",
+    )
+    .args(["foo", "a", "b"])
+    .stdout("a b\n")
+    .run();
+}
+
+#[test]
+fn body_file_is_not_set_for_other_recipes() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo ${JUST_BODY_FILE:-unset}
+      ",
+    )
+    .stdout("unset\n")
+    .run();
+}
@@ -0,0 +1,108 @@
+use super::*;
+
+#[test]
+fn valid_justfile() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo foo
+      ",
+    )
+    .arg("--check")
+    .run();
+}
+
+#[test]
+fn invalid_justfile() {
+  Test::new()
+    .justfile(
+      "
+        foo: bar
+          echo foo
+      ",
+    )
+    .arg("--check")
+    .stderr(
+      "
+        error: Recipe `foo` has unknown dependency `bar`
+         ——▶ justfile:1:6
+          │
+        1 │ foo: bar
+          │      ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn assignments_are_not_evaluated() {
+  Test::new()
+    .justfile(
+      "
+        x := `exit 1`
+        y := error('evaluated')
+
+        foo:
+          echo {{ x }} {{ y }}
+      ",
+    )
+    .arg("--check")
+    .run();
+}
+
+#[test]
+fn submodules_are_checked() {
+  Test::new()
+    .write("foo.just", "bar: baz\n")
+    .justfile(
+      "
+        mod foo
+      ",
+    )
+    .args(["--check", "--unstable"])
+    .stderr(
+      "
+        error: Recipe `bar` has unknown dependency `baz`
+         ——▶ foo.just:1:6
+          │
+        1 │ bar: baz
+          │      ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn recipes_are_not_run() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          exit 1
+      ",
+    )
+    .arg("--check")
+    .run();
+}
+
+#[test]
+fn arguments_are_rejected() {
+  Test::new()
+    .arg("--check")
+    .arg("foo")
+    .stderr("error: `--check` used with unexpected argument: `foo`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn conflicts_with_other_subcommands() {
+  Test::new()
+    .args(["--check", "--list"])
+    .stderr_regex("error: the argument '--check' cannot be used with '--list'(.|\\n)+")
+    .status(2)
+    .run();
+}
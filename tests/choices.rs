@@ -0,0 +1,128 @@
+use super::*;
+
+#[test]
+fn override_in_choices() {
+  Test::new()
+    .justfile(
+      "
+        [choices('debug', 'release')]
+        mode := 'debug'
+
+        @foo:
+          echo {{ mode }}
+      ",
+    )
+    .arg("mode=release")
+    .stdout("release\n")
+    .run();
+}
+
+#[test]
+fn override_not_in_choices() {
+  Test::new()
+    .justfile(
+      "
+        [choices('debug', 'release')]
+        mode := 'debug'
+
+        @foo:
+          echo {{ mode }}
+      ",
+    )
+    .args(["--set", "mode", "fast"])
+    .stderr(
+      "
+        error: Value `fast` for variable `mode` must be `debug` or `release`
+         ——▶ justfile:2:1
+          │
+        2 │ mode := 'debug'
+          │ ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn overrides_are_checked_before_evaluation() {
+  Test::new()
+    .justfile(
+      "
+        [choices('debug', 'release')]
+        mode := 'debug'
+
+        x := `echo evaluated >&2`
+
+        foo:
+      ",
+    )
+    .arg("mode=fast")
+    .stderr(
+      "
+        error: Value `fast` for variable `mode` must be `debug` or `release`
+         ——▶ justfile:2:1
+          │
+        2 │ mode := 'debug'
+          │ ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn default_value_is_not_checked() {
+  Test::new()
+    .justfile(
+      "
+        [choices('debug', 'release')]
+        mode := 'fast'
+
+        @foo:
+          echo {{ mode }}
+      ",
+    )
+    .stdout("fast\n")
+    .run();
+}
+
+#[test]
+fn choices_require_arguments() {
+  Test::new()
+    .justfile(
+      "
+        [choices]
+        mode := 'debug'
+      ",
+    )
+    .stderr(
+      "
+        error: Attribute `choices` requires an argument
+         ——▶ justfile:1:2
+          │
+        1 │ [choices]
+          │  ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        [choices('debug', 'release')]
+        mode := 'debug'
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        [choices('debug', 'release')]
+        mode := 'debug'
+      ",
+    )
+    .run();
+}
@@ -39,6 +39,69 @@ fn chooser() {
     .run();
 }
 
+#[test]
+fn setting() {
+  Test::new()
+    .arg("--choose")
+    .justfile(
+      "
+        set chooser := 'head -n1'
+
+        foo:
+          echo foo
+
+        bar:
+          echo bar
+      ",
+    )
+    .stderr("echo bar\n")
+    .stdout("bar\n")
+    .run();
+}
+
+#[test]
+fn chooser_overrides_setting() {
+  Test::new()
+    .arg("--choose")
+    .arg("--chooser")
+    .arg("tail -n1")
+    .justfile(
+      "
+        set chooser := 'head -n1'
+
+        foo:
+          echo foo
+
+        bar:
+          echo bar
+      ",
+    )
+    .stderr("echo foo\n")
+    .stdout("foo\n")
+    .run();
+}
+
+#[test]
+fn env_overrides_setting() {
+  Test::new()
+    .arg("--choose")
+    .env("JUST_CHOOSER", "tail -n1")
+    .justfile(
+      "
+        set chooser := 'head -n1'
+
+        foo:
+          echo foo
+
+        bar:
+          echo bar
+      ",
+    )
+    .stderr("echo foo\n")
+    .stdout("foo\n")
+    .run();
+}
+
 #[test]
 fn override_variable() {
   Test::new()
@@ -223,3 +286,60 @@ fn default() {
 
   assert_stdout(&output, "foo\n");
 }
+
+#[test]
+fn patterns_filter_recipes() {
+  Test::new()
+    .args(["--choose", "--chooser", "cat", "test-*", "b?ild"])
+    .justfile(
+      "
+        build:
+          echo build
+
+        deploy:
+          echo deploy
+
+        test-unit:
+          echo unit
+
+        test-integration:
+          echo integration
+      ",
+    )
+    .stderr("echo build\necho integration\necho unit\n")
+    .stdout("build\nintegration\nunit\n")
+    .run();
+}
+
+#[test]
+fn patterns_match_whole_name() {
+  Test::new()
+    .args(["--choose", "--chooser", "cat", "test"])
+    .justfile(
+      "
+        test:
+          echo test
+
+        test-unit:
+          echo unit
+      ",
+    )
+    .stderr("echo test\n")
+    .stdout("test\n")
+    .run();
+}
+
+#[test]
+fn no_matching_recipes() {
+  Test::new()
+    .args(["--choose", "--chooser", "cat", "lint-*", "fmt"])
+    .justfile(
+      "
+        build:
+          echo build
+      ",
+    )
+    .stderr("error: No choosable recipes match `lint-*` or `fmt`.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
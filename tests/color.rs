@@ -0,0 +1,125 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  # doc
+  foo:
+";
+
+const COLORED: &str = "
+  Available recipes:
+      foo \u{1b}[34m#\u{1b}[0m \u{1b}[34mdoc\u{1b}[0m
+";
+
+const PLAIN: &str = "
+  Available recipes:
+      foo # doc
+";
+
+#[test]
+fn auto_when_redirected() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--list")
+    .stdout(PLAIN)
+    .run();
+}
+
+#[test]
+fn force_color() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--list")
+    .env("FORCE_COLOR", "1")
+    .stdout(COLORED)
+    .run();
+}
+
+#[test]
+fn force_color_zero() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--list")
+    .env("FORCE_COLOR", "0")
+    .stdout(PLAIN)
+    .run();
+}
+
+#[test]
+fn clicolor_force() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--list")
+    .env("CLICOLOR_FORCE", "1")
+    .stdout(COLORED)
+    .run();
+}
+
+#[test]
+fn no_color_overrides_force_color() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--list")
+    .env("FORCE_COLOR", "1")
+    .env("NO_COLOR", "1")
+    .stdout(PLAIN)
+    .run();
+}
+
+#[test]
+fn clicolor_zero() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--list"])
+    .env("CLICOLOR", "0")
+    .env("FORCE_COLOR", "0")
+    .stdout(PLAIN)
+    .run();
+}
+
+#[test]
+fn color_always_overrides_no_color() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--list", "--color", "always"])
+    .env("NO_COLOR", "1")
+    .stdout(COLORED)
+    .run();
+}
+
+#[test]
+fn color_never_overrides_force_color() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--list", "--color", "never"])
+    .env("FORCE_COLOR", "1")
+    .stdout(PLAIN)
+    .run();
+}
+
+#[test]
+fn errors() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("bar")
+    .env("FORCE_COLOR", "1")
+    .stderr(
+      "\u{1b}[1;31merror\u{1b}[0m: \u{1b}[1mJustfile does not contain recipe `bar`.\u{1b}[0m\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn echoed_commands() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo foo
+      ",
+    )
+    .env("FORCE_COLOR", "1")
+    .stdout("foo\n")
+    .stderr("\u{1b}[1mecho foo\u{1b}[0m\n")
+    .run();
+}
@@ -53,6 +53,20 @@ test_complete_recipes_from_subdirs() {
 }
 test_complete_recipes_from_subdirs
 
+test_complete_recipes_with_justfile() {
+  COMP_WORDS=(just --justfile subdir/justfile s)
+  COMP_CWORD=3 _just just
+  reply_equals 'declare -a COMPREPLY=([0]="special" [1]="surprise")'
+}
+test_complete_recipes_with_justfile
+
+test_complete_recipes_with_justfile_and_working_directory() {
+  COMP_WORDS=(just -f subdir/justfile -d subdir "")
+  COMP_CWORD=5 _just just
+  reply_equals 'declare -a COMPREPLY=([0]="special" [1]="surprise")'
+}
+test_complete_recipes_with_justfile_and_working_directory
+
 # --- Conclusion ---
 if [ $exit_code = 0 ]; then
   echo "All tests passed."
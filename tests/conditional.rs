@@ -205,3 +205,77 @@ test! {
   ",
   status: EXIT_FAILURE,
 }
+
+#[test]
+fn conditional_dependency() {
+  Test::new()
+    .justfile(
+      "
+        env := 'dev'
+
+        deploy: build (migrate if env == 'prod')
+          @echo deploy
+
+        build:
+          @echo build
+
+        migrate:
+          @echo migrate
+      ",
+    )
+    .stdout("build\ndeploy\n")
+    .run();
+}
+
+#[test]
+fn conditional_dependency_with_arguments() {
+  Test::new()
+    .justfile(
+      "
+        env := 'dev'
+
+        deploy: (migrate 'fast' if env =~ 'prod|dev')
+          @echo deploy
+
+        migrate speed:
+          @echo migrate {{speed}}
+      ",
+    )
+    .stdout("migrate fast\ndeploy\n")
+    .run();
+}
+
+#[test]
+fn conditional_dependency_can_use_parameters() {
+  Test::new()
+    .justfile(
+      "
+        deploy env: (migrate if env == 'prod') && (notify if env != 'dev')
+          @echo deploy {{env}}
+
+        migrate:
+          @echo migrate
+
+        notify:
+          @echo notify
+      ",
+    )
+    .args(["deploy", "prod"])
+    .stdout("migrate\ndeploy prod\nnotify\n")
+    .run();
+}
+
+#[test]
+fn conditional_dependency_is_shown() {
+  Test::new()
+    .justfile(
+      "
+        deploy: (migrate if 'a' == 'b')
+
+        migrate:
+      ",
+    )
+    .args(["--show", "deploy"])
+    .stdout("deploy: (migrate if 'a' == 'b')\n")
+    .run();
+}
@@ -0,0 +1,139 @@
+use super::*;
+
+#[test]
+fn trailing_plus() {
+  Test::new()
+    .justfile(
+      "
+        x := 'a' +
+          'b' +
+          'c'
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("abc")
+    .run();
+}
+
+#[test]
+fn trailing_slash() {
+  Test::new()
+    .justfile(
+      "
+        x := 'a' /
+          'b'
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("a/b")
+    .run();
+}
+
+#[test]
+fn trailing_comparison_operator() {
+  Test::new()
+    .justfile(
+      "
+        x := if 'a' ==
+          'a' { 'yes' } else { 'no' }
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("yes")
+    .run();
+}
+
+#[test]
+fn conditional_chain() {
+  Test::new()
+    .justfile(
+      "
+        os := 'linux'
+
+        x := if os == 'macos' {
+          'darwin'
+        } else if os == 'linux' {
+          'gnu'
+        } else {
+          'unknown'
+        }
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("gnu")
+    .run();
+}
+
+#[test]
+fn parenthesized_default() {
+  Test::new()
+    .justfile(
+      "
+        foo x=(
+          'a' +
+          'b'
+        ):
+          echo {{ x }}
+      ",
+    )
+    .stdout("ab\n")
+    .stderr("echo ab\n")
+    .run();
+}
+
+#[test]
+fn parentheses_in_interpolation() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo {{ (
+            'a'
+            + 'b'
+          ) }}
+          echo c
+      ",
+    )
+    .stdout("ab\nc\n")
+    .stderr("echo ab\necho c\n")
+    .run();
+}
+
+#[test]
+fn trailing_operator_in_interpolation() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo {{ 'a' +
+            'b' }}
+          echo c
+      ",
+    )
+    .stdout("ab\nc\n")
+    .stderr("echo ab\necho c\n")
+    .run();
+}
+
+#[test]
+fn interpolation_without_continuation_is_unterminated() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo {{ 'a'
+            + 'b' }}
+      ",
+    )
+    .stderr(
+      "
+        error: Unterminated interpolation
+         ——▶ justfile:2:8
+          │
+        2 │   echo {{ 'a'
+          │        ^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+#[test]
+fn default_setting_selects_default_recipe() {
+  Test::new()
+    .justfile(
+      "
+      set default := 'bar'
+
+      foo:
+        @echo foo
+
+      bar:
+        @echo bar
+      ",
+    )
+    .stdout("bar\n")
+    .run();
+}
+
+#[test]
+fn default_setting_may_name_alias() {
+  Test::new()
+    .justfile(
+      "
+      set default := 'b'
+
+      alias b := bar
+
+      foo:
+        @echo foo
+
+      bar:
+        @echo bar
+      ",
+    )
+    .stdout("bar\n")
+    .run();
+}
+
+#[test]
+fn default_setting_does_not_affect_explicit_recipes() {
+  Test::new()
+    .justfile(
+      "
+      set default := 'bar'
+
+      foo:
+        @echo foo
+
+      bar:
+        @echo bar
+      ",
+    )
+    .arg("foo")
+    .stdout("foo\n")
+    .run();
+}
+
+#[test]
+fn default_setting_recipe_must_exist() {
+  Test::new()
+    .justfile(
+      "
+      set default := 'baz'
+
+      foo:
+      ",
+    )
+    .stderr(
+      "
+      error: Default recipe `baz` does not exist
+       ——▶ justfile:1:5
+        │
+      1 │ set default := 'baz'
+        │     ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn default_setting_recipe_may_not_require_arguments() {
+  Test::new()
+    .justfile(
+      "
+      set default := 'bar'
+
+      foo:
+
+      bar baz:
+      ",
+    )
+    .stderr(
+      "
+      error: Recipe `bar` cannot be used as default recipe since it requires at least 1 argument.
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
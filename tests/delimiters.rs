@@ -89,19 +89,12 @@ test! {
 }
 
 test! {
-  name: no_interpolation_continuation,
+  name: interpolation_continuation,
   justfile: "
     foo:
       echo {{ (
         'a' + 'b')}}
   ",
-  stdout: "",
-  stderr: "
-    error: Unterminated interpolation
-     ——▶ justfile:2:8
-      │
-    2 │   echo {{ (
-      │        ^^
-  ",
-  status: EXIT_FAILURE,
+  stdout: "ab\n",
+  stderr: "echo ab\n",
 }
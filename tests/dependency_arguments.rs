@@ -0,0 +1,156 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  build target='debug':
+    @echo build {{ target }}
+
+  deploy: build
+    @echo deploy
+";
+
+#[test]
+fn replaces_default() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--dependency-argument", "build::target=release", "deploy"])
+    .stdout("build release\ndeploy\n")
+    .run();
+}
+
+#[test]
+fn replaces_dependency_argument() {
+  Test::new()
+    .justfile(
+      "
+        build target:
+          @echo build {{ target }}
+
+        deploy: (build 'debug')
+          @echo deploy
+      ",
+    )
+    .args(["--dependency-argument", "build::target=release", "deploy"])
+    .stdout("build release\ndeploy\n")
+    .run();
+}
+
+#[test]
+fn applies_to_recipes_run_directly() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--dependency-argument", "build::target=release", "build"])
+    .stdout("build release\n")
+    .run();
+}
+
+#[test]
+fn other_parameters_are_unchanged() {
+  Test::new()
+    .justfile(
+      "
+        build target='debug' jobs='1':
+          @echo build {{ target }} {{ jobs }}
+
+        deploy: (build 'debug' '4')
+      ",
+    )
+    .args(["--dependency-argument", "build::target=release", "deploy"])
+    .stdout("build release 4\n")
+    .run();
+}
+
+#[test]
+fn variadic() {
+  Test::new()
+    .justfile(
+      "
+        build +flags='-g':
+          @echo build {{ flags }}
+
+        deploy: (build '-a' '-b')
+      ",
+    )
+    .args(["--dependency-argument", "build::flags=-O", "deploy"])
+    .stdout("build -O\n")
+    .run();
+}
+
+#[test]
+fn module_recipe() {
+  Test::new()
+    .write(
+      "foo.just",
+      "build target='debug':\n @echo build {{ target }}\ndeploy: build",
+    )
+    .justfile(
+      "
+        mod foo
+      ",
+    )
+    .test_round_trip(false)
+    .arg("--unstable")
+    .args([
+      "--dependency-argument",
+      "foo::build::target=release",
+      "foo",
+      "deploy",
+    ])
+    .stdout("build release\n")
+    .run();
+}
+
+#[test]
+fn checks_pattern() {
+  Test::new()
+    .justfile(
+      "
+        build target=~'debug|release'='debug':
+
+        deploy: build
+      ",
+    )
+    .args(["--dependency-argument", "build::target=fast", "deploy"])
+    .stderr(
+      "
+        error: Argument `fast` for parameter `target` does not match pattern `debug|release`
+         ——▶ justfile:1:7
+          │
+        1 │ build target=~'debug|release'='debug':
+          │       ^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_recipe() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--dependency-argument", "bulid::target=release", "deploy"])
+    .stderr("error: Justfile does not contain recipe `bulid`.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_parameter() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--dependency-argument", "build::mode=release", "deploy"])
+    .stderr("error: Recipe `build` does not have parameter `mode`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn malformed() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--dependency-argument", "target=release", "deploy"])
+    .stderr(
+      "error: Dependency argument `target=release` is not of the form `RECIPE::PARAMETER=VALUE`\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,252 @@
+use super::*;
+
+#[test]
+fn warn() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~warn careful
+          echo bar
+      ",
+    )
+    .stdout("bar\n")
+    .stderr("warning: careful\necho bar\n")
+    .run();
+}
+
+#[test]
+fn warn_with_interpolation() {
+  Test::new()
+    .justfile(
+      "
+        target := 'debug'
+
+        foo:
+          ~warn building {{ target }} is slow
+      ",
+    )
+    .stderr("warning: building debug is slow\n")
+    .run();
+}
+
+#[test]
+fn warn_color() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~warn careful
+      ",
+    )
+    .args(["--color", "always"])
+    .stderr("\u{1b}[1;33mwarning:\u{1b}[0m \u{1b}[1mcareful\u{1b}[0m\n")
+    .run();
+}
+
+#[test]
+fn warn_quiet() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~warn careful
+      ",
+    )
+    .args(["--quiet"])
+    .run();
+}
+
+#[test]
+fn error() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~error not supported
+          echo bar
+      ",
+    )
+    .stderr("error: Recipe `foo` failed on line 2: not supported\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn error_infallible() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          -~error not supported
+          echo bar
+      ",
+    )
+    .stdout("bar\n")
+    .stderr("error: Recipe `foo` failed on line 2: not supported\necho bar\n")
+    .run();
+}
+
+#[test]
+fn error_quiet() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~error not supported
+      ",
+    )
+    .args(["--quiet"])
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn directives_are_not_echoed_with_dry_run() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~warn careful
+          echo bar
+      ",
+    )
+    .args(["--dry-run"])
+    .stderr("warning: careful\necho bar\n")
+    .run();
+}
+
+#[test]
+fn open_dry_run() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~open {{ 'index.html' }}
+      ",
+    )
+    .arg("--dry-run")
+    .stderr("~open index.html\n")
+    .run();
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn open() {
+  let test = Test::new()
+    .justfile(
+      "
+        foo:
+          ~open index.html
+      ",
+    )
+    .write("bin/xdg-open", "#!/bin/sh\necho opened $1\n");
+
+  let bin = test.tempdir.path().join("bin");
+
+  ("chmod", "+x", bin.join("xdg-open")).run();
+
+  let path =
+    env::join_paths(iter::once(bin).chain(env::split_paths(&env::var_os("PATH").unwrap())))
+      .unwrap();
+
+  test
+    .env("PATH", path.to_str().unwrap())
+    .stdout("opened index.html\n")
+    .stderr("~open index.html\n")
+    .run();
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn open_url_with_shell_metacharacters() {
+  let test = Test::new()
+    .justfile(
+      "
+        foo:
+          ~open https://example.com/?a=1&b=2
+      ",
+    )
+    .write("bin/xdg-open", "#!/bin/sh\necho \"opened $1\"\n");
+
+  let bin = test.tempdir.path().join("bin");
+
+  ("chmod", "+x", bin.join("xdg-open")).run();
+
+  let path =
+    env::join_paths(iter::once(bin).chain(env::split_paths(&env::var_os("PATH").unwrap())))
+      .unwrap();
+
+  test
+    .env("PATH", path.to_str().unwrap())
+    .stdout("opened https://example.com/?a=1&b=2\n")
+    .stderr("~open https://example.com/?a=1&b=2\n")
+    .run();
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn open_missing_opener() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~open index.html
+      ",
+    )
+    .env("PATH", "")
+    .stderr_regex(
+      "~open index.html\nerror: Recipe `foo` could not open target because `xdg-open` could not be run: .*\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn sleep() {
+  let start = std::time::Instant::now();
+
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~sleep 0.25
+          echo bar
+      ",
+    )
+    .stdout("bar\n")
+    .stderr("~sleep 0.25\necho bar\n")
+    .run();
+
+  assert!(start.elapsed() >= std::time::Duration::from_millis(250));
+}
+
+#[test]
+fn sleep_dry_run() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~sleep 1000
+      ",
+    )
+    .arg("--dry-run")
+    .stderr("~sleep 1000\n")
+    .run();
+}
+
+#[test]
+fn sleep_invalid_duration() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~sleep 1s
+      ",
+    )
+    .stderr(
+      "error: Recipe `foo` failed on line 2: invalid `~sleep` duration `1s`, expected seconds\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -67,7 +67,7 @@ fn executable_directory() {
            ——▶ justfile:1:6
             │
           1 │ x := executable_directory()
-            │      ^^^^^^^^^^^^^^^^^^^^
+            │      ^^^^^^^^^^^^^^^^^^^^^^
         ",
       )
       .status(EXIT_FAILURE)
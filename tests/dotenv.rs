@@ -202,6 +202,45 @@ fn can_set_dotenv_path_from_justfile() {
     .run();
 }
 
+#[test]
+fn can_set_multiple_dotenv_paths_from_justfile() {
+  Test::new()
+    .justfile(
+      r#"
+        set dotenv-path := [".env.defaults", ".env.local"]
+
+        foo:
+          @echo $JUST_TEST_VARIABLE $JUST_TEST_VARIABLE_2
+      "#,
+    )
+    .tree(tree! {
+      ".env.defaults": "JUST_TEST_VARIABLE=foo\nJUST_TEST_VARIABLE_2=bar",
+      ".env.local": "JUST_TEST_VARIABLE=baz",
+    })
+    .stdout("baz bar\n")
+    .status(EXIT_SUCCESS)
+    .run();
+}
+
+#[test]
+fn missing_dotenv_path_in_list_is_an_error() {
+  Test::new()
+    .justfile(
+      r#"
+        set dotenv-path := [".env.defaults", ".env.local"]
+
+        foo:
+          @echo $JUST_TEST_VARIABLE
+      "#,
+    )
+    .tree(tree! {
+      ".env.defaults": "JUST_TEST_VARIABLE=foo",
+    })
+    .stderr_regex("error: Failed to load environment file: .*\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
 #[test]
 fn program_argument_has_priority_for_dotenv_filename() {
   Test::new()
@@ -262,3 +301,296 @@ fn dotenv_path_is_relative_to_working_directory() {
     .stdout("dotenv-value\n")
     .run();
 }
+
+#[test]
+fn values_can_reference_earlier_keys() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        foo:
+          @echo $JUST_TEST_VARIABLE
+      ",
+    )
+    .write(
+      ".env",
+      "JUST_TEST_HOST=localhost\nJUST_TEST_VARIABLE=http://${JUST_TEST_HOST}:80",
+    )
+    .stdout("http://localhost:80\n")
+    .run();
+}
+
+#[test]
+fn values_can_reference_environment_variables() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        foo:
+          @echo $JUST_TEST_VARIABLE
+      ",
+    )
+    .write(".env", "JUST_TEST_VARIABLE=${JUST_TEST_HOST}:80")
+    .env("JUST_TEST_HOST", "example.com")
+    .stdout("example.com:80\n")
+    .run();
+}
+
+#[test]
+fn values_can_reference_keys_in_earlier_files() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-path := ['.env.defaults', '.env.local']
+
+        foo:
+          @echo $JUST_TEST_VARIABLE
+      ",
+    )
+    .write(".env.defaults", "JUST_TEST_HOST=localhost")
+    .write(".env.local", "JUST_TEST_VARIABLE=${JUST_TEST_HOST}:80")
+    .stdout("localhost:80\n")
+    .run();
+}
+
+#[test]
+fn export_prefix_is_ignored() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        foo:
+          @echo $JUST_TEST_VARIABLE $JUST_TEST_VARIABLE_2
+      ",
+    )
+    .write(
+      ".env",
+      "export JUST_TEST_VARIABLE=foo\nexport   JUST_TEST_VARIABLE_2=${JUST_TEST_VARIABLE}bar",
+    )
+    .stdout("foo foobar\n")
+    .run();
+}
+
+#[test]
+fn quoted_values_may_span_multiple_lines() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        foo:
+          @printf '%s|%s\\n' \"$JUST_TEST_VARIABLE\" \"$JUST_TEST_VARIABLE_2\"
+      ",
+    )
+    .write(
+      ".env",
+      "JUST_TEST_VARIABLE=\"foo\n# not a comment\nbar\"\nJUST_TEST_VARIABLE_2='baz\nqux'\n",
+    )
+    .stdout("foo\n# not a comment\nbar|baz\nqux\n")
+    .run();
+}
+
+#[test]
+fn environment_takes_precedence_over_dotenv_by_default() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        foo := env_var('JUST_TEST_VARIABLE')
+      ",
+    )
+    .write(".env", "JUST_TEST_VARIABLE=dotenv")
+    .env("JUST_TEST_VARIABLE", "environment")
+    .args(["--evaluate", "foo"])
+    .stdout("environment")
+    .run();
+}
+
+#[test]
+fn dotenv_override_takes_precedence_over_environment() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-override
+
+        foo := env_var('JUST_TEST_VARIABLE')
+      ",
+    )
+    .write(".env", "JUST_TEST_VARIABLE=dotenv")
+    .env("JUST_TEST_VARIABLE", "environment")
+    .args(["--evaluate", "foo"])
+    .stdout("dotenv")
+    .run();
+}
+
+#[test]
+fn dotenv_override_exports_to_recipes() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-override
+
+        foo:
+          @echo $JUST_TEST_VARIABLE
+      ",
+    )
+    .write(".env", "JUST_TEST_VARIABLE=dotenv")
+    .env("JUST_TEST_VARIABLE", "environment")
+    .stdout("dotenv\n")
+    .run();
+}
+
+#[test]
+fn dotenv_attribute_loads_file_for_recipe() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        [dotenv('deploy/.env.prod')]
+        deploy:
+          @echo $JUST_TEST_VARIABLE $JUST_TEST_VARIABLE_2
+
+        build:
+          @echo $JUST_TEST_VARIABLE ${JUST_TEST_VARIABLE_2:-unset}
+      ",
+    )
+    .write(".env", "JUST_TEST_VARIABLE=foo\nJUST_TEST_VARIABLE_2=bar")
+    .write("deploy/.env.prod", "JUST_TEST_VARIABLE_2=prod")
+    .args(["deploy", "build"])
+    .stdout("foo prod\nfoo bar\n")
+    .run();
+}
+
+#[test]
+fn dotenv_attribute_does_not_affect_other_recipes() {
+  Test::new()
+    .justfile(
+      "
+        [dotenv('.env.prod')]
+        deploy: build
+          @echo $JUST_TEST_VARIABLE
+
+        build:
+          @echo ${JUST_TEST_VARIABLE:-unset}
+      ",
+    )
+    .write(".env.prod", "JUST_TEST_VARIABLE=prod")
+    .stdout("unset\nprod\n")
+    .run();
+}
+
+#[test]
+fn dotenv_attribute_missing_file_is_an_error() {
+  Test::new()
+    .justfile(
+      "
+        [dotenv('.env.prod')]
+        deploy:
+          @echo $JUST_TEST_VARIABLE
+      ",
+    )
+    .stderr_regex("error: Failed to load environment file: .*\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dotenv_outside_project_warns_by_default() {
+  Test::new()
+    .write("sub/justfile", "set dotenv-load\nfoo:\n @echo $DOTENV_KEY")
+    .args(["sub/foo"])
+    .stdout("dotenv-value\n")
+    .stderr_regex(
+      "warning: Loading environment file `.*\\.env` from outside the justfile directory\n",
+    )
+    .run();
+}
+
+#[test]
+fn dotenv_outside_project_warning_is_suppressed_by_quiet() {
+  Test::new()
+    .write("sub/justfile", "set dotenv-load\nfoo:\n @echo $DOTENV_KEY")
+    .args(["--quiet", "sub/foo"])
+    .run();
+}
+
+#[test]
+fn dotenv_outside_project_warn() {
+  Test::new()
+    .write(
+      "sub/justfile",
+      "set dotenv-load\nset dotenv-outside-project := 'warn'\nfoo:\n @echo $DOTENV_KEY",
+    )
+    .args(["sub/foo"])
+    .stdout("dotenv-value\n")
+    .stderr_regex(
+      "warning: Loading environment file `.*\\.env` from outside the justfile directory\n",
+    )
+    .run();
+}
+
+#[test]
+fn dotenv_outside_project_allow_is_invalid() {
+  Test::new()
+    .justfile("set dotenv-outside-project := 'allow'")
+    .stderr(
+      "
+        error: Invalid value `allow` for setting `dotenv-outside-project`, expected `warn` or `error`
+         ——▶ justfile:1:31
+          │
+        1 │ set dotenv-outside-project := 'allow'
+          │                               ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dotenv_outside_project_error() {
+  Test::new()
+    .write(
+      "sub/justfile",
+      "set dotenv-load\nset dotenv-outside-project := 'error'\nfoo:\n @echo $DOTENV_KEY",
+    )
+    .args(["sub/foo"])
+    .stderr_regex(
+      "error: Refusing to load environment file `.*\\.env` from outside the justfile directory\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dotenv_outside_project_error_is_not_suppressed_by_allow() {
+  Test::new()
+    .write(
+      "sub/justfile",
+      "set dotenv-load\nset dotenv-outside-project := 'error'\n\
+       set allow := ['dotenv-outside-project']\nfoo:\n @echo $DOTENV_KEY",
+    )
+    .args(["sub/foo"])
+    .stderr_regex(
+      "error: Refusing to load environment file `.*\\.env` from outside the justfile directory\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dotenv_in_justfile_directory_is_not_outside_project() {
+  Test::new()
+    .write("sub/.env", "DOTENV_KEY=sub")
+    .write(
+      "sub/justfile",
+      "set dotenv-load\nset dotenv-outside-project := 'error'\nfoo:\n @echo $DOTENV_KEY",
+    )
+    .args(["sub/foo"])
+    .stdout("sub\n")
+    .run();
+}
@@ -0,0 +1,70 @@
+use super::*;
+
+#[test]
+fn is_dry_run() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo {{ is_dry_run() }}
+      ",
+    )
+    .stdout("false\n")
+    .stderr("echo false\n")
+    .run();
+
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo {{ is_dry_run() }}
+      ",
+    )
+    .arg("--dry-run")
+    .stderr("echo true\n")
+    .run();
+}
+
+#[test]
+fn environment_variable_is_set_for_commands() {
+  Test::new()
+    .justfile("")
+    .args([
+      "--dry-run",
+      "--command",
+      "sh",
+      "-c",
+      "echo ${JUST_DRY_RUN:-unset}",
+    ])
+    .stdout("1\n")
+    .run();
+}
+
+#[test]
+fn environment_variable_is_not_set_by_default() {
+  Test::new()
+    .justfile(
+      "
+        x := `echo ${JUST_DRY_RUN:-unset}`
+
+        foo:
+          @echo {{ x }}
+      ",
+    )
+    .stdout("unset\n")
+    .run();
+}
+
+#[test]
+fn environment_variable_enables_dry_run() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo foo
+      ",
+    )
+    .env("JUST_DRY_RUN", "1")
+    .stderr("echo foo\n")
+    .run();
+}
@@ -0,0 +1,158 @@
+use super::*;
+
+#[test]
+fn recipe_json() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo foo
+
+        # bar
+        bar:
+          echo bar
+      ",
+    )
+    .args(["--dump", "--dump-format", "json", "--recipe", "bar"])
+    .stdout(format!(
+      "{}\n",
+      serde_json::to_string(&json!({
+        "attributes": [],
+        "body": [["echo bar"]],
+        "dependencies": [],
+        "doc": "bar",
+        "name": "bar",
+        "namepath": "bar",
+        "parameters": [],
+        "priors": 0,
+        "private": false,
+        "quiet": false,
+        "shebang": false,
+      }))
+      .unwrap()
+    ))
+    .run();
+}
+
+#[test]
+fn recipe_just() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo foo
+
+        # bar
+        bar:
+          echo bar
+      ",
+    )
+    .args(["--dump", "--recipe", "bar"])
+    .stdout(
+      "
+        # bar
+        bar:
+            echo bar
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn recipe_alias() {
+  Test::new()
+    .justfile(
+      "
+        alias b := bar
+
+        bar:
+          echo bar
+      ",
+    )
+    .args(["--dump", "--recipe", "b"])
+    .stdout(
+      "
+        bar:
+            echo bar
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn unknown_recipe() {
+  Test::new()
+    .justfile("foo:")
+    .args(["--dump", "--recipe", "fooo"])
+    .stderr("error: Justfile does not contain recipe `fooo`.\nDid you mean `foo`?\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn variable_json() {
+  Test::new()
+    .justfile(
+      "
+        foo := 'foo'
+        export bar := 'bar'
+      ",
+    )
+    .args(["--dump", "--dump-format", "json", "--variable", "bar"])
+    .stdout(format!(
+      "{}\n",
+      serde_json::to_string(&json!({
+        "choices": [],
+        "doc": null,
+        "export": true,
+        "name": "bar",
+        "secret": false,
+        "value": "bar",
+        "depth": 0,
+      }))
+      .unwrap()
+    ))
+    .run();
+}
+
+#[test]
+fn variable_just() {
+  Test::new()
+    .justfile(
+      "
+        foo := 'foo'
+        export bar := 'bar' + foo
+      ",
+    )
+    .args(["--dump", "--variable", "bar"])
+    .stdout("export bar := 'bar' + foo\n")
+    .run();
+}
+
+#[test]
+fn unknown_variable() {
+  Test::new()
+    .justfile("foo := 'foo'")
+    .args(["--dump", "--variable", "fooo"])
+    .stderr("error: Justfile does not contain variable `fooo`.\nDid you mean `foo`?\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn lossless_is_unsupported() {
+  Test::new()
+    .justfile("foo:")
+    .args([
+      "--dump",
+      "--dump-format",
+      "json-lossless",
+      "--recipe",
+      "foo",
+    ])
+    .stderr(
+      "error: `--recipe` and `--variable` may not be used with `--dump-format json-lossless`\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -170,3 +170,85 @@ fn editor_working_directory() {
 
   assert_stdout(&output, &want);
 }
+
+#[test]
+fn recipe_opens_editor_at_line() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo foo
+
+        bar:
+          echo bar
+      ",
+    )
+    .args(["--edit", "bar"])
+    .env("VISUAL", "echo")
+    .stdout_regex(r"\+4 .*justfile\n")
+    .run();
+}
+
+#[test]
+fn recipe_alias_opens_editor_at_target() {
+  Test::new()
+    .justfile(
+      "
+        alias b := bar
+
+        foo:
+
+        bar:
+      ",
+    )
+    .args(["--edit", "b"])
+    .env("VISUAL", "echo")
+    .stdout_regex(r"\+5 .*justfile\n")
+    .run();
+}
+
+#[test]
+fn recipe_in_import_opens_imported_file() {
+  Test::new()
+    .justfile("import 'imported.just'")
+    .write("imported.just", "\nfoo:\n")
+    .args(["--edit", "foo"])
+    .env("VISUAL", "echo")
+    .test_round_trip(false)
+    .stdout_regex(r"\+2 .*imported\.just\n")
+    .run();
+}
+
+#[test]
+#[cfg(not(windows))]
+fn recipe_with_editor_using_path_and_line() {
+  let tmp = temptree! {
+    justfile: "\nfoo:\n",
+    code: "#!/bin/sh\necho \"$@\"\n",
+  };
+
+  ("chmod", "+x", tmp.path().join("code")).run();
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(tmp.path())
+    .args(["--edit", "foo"])
+    .env("VISUAL", tmp.path().join("code"))
+    .output()
+    .unwrap();
+
+  assert_stdout(
+    &output,
+    &format!("--goto {}:2\n", tmp.path().join("justfile").display()),
+  );
+}
+
+#[test]
+fn unknown_recipe() {
+  Test::new()
+    .justfile("foo:")
+    .args(["--edit", "bar"])
+    .env("VISUAL", "echo")
+    .stderr("error: Justfile does not contain recipe `bar`.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,120 @@
+use super::*;
+
+#[test]
+fn env_deny_removes_matching_variables() {
+  Test::new()
+    .justfile(
+      "
+        set env-deny := ['JUST_TEST_SECRET_*']
+
+        foo:
+          @echo ${JUST_TEST_SECRET_KEY:-unset} $JUST_TEST_PUBLIC
+      ",
+    )
+    .env("JUST_TEST_SECRET_KEY", "hunter2")
+    .env("JUST_TEST_PUBLIC", "public")
+    .stdout("unset public\n")
+    .run();
+}
+
+#[test]
+fn env_allow_removes_other_variables() {
+  Test::new()
+    .justfile(
+      "
+        set env-allow := ['PATH', 'JUST_TEST_ALLOWED']
+
+        foo:
+          @echo $JUST_TEST_ALLOWED ${JUST_TEST_OTHER:-unset}
+      ",
+    )
+    .env("JUST_TEST_ALLOWED", "allowed")
+    .env("JUST_TEST_OTHER", "other")
+    .stdout("allowed unset\n")
+    .run();
+}
+
+#[test]
+fn env_patterns_match_single_character_wildcard() {
+  Test::new()
+    .justfile(
+      "
+        set env-deny := ['JUST_TEST_KEY_?']
+
+        foo:
+          @echo ${JUST_TEST_KEY_1:-unset} $JUST_TEST_KEY_12
+      ",
+    )
+    .env("JUST_TEST_KEY_1", "one")
+    .env("JUST_TEST_KEY_12", "twelve")
+    .stdout("unset twelve\n")
+    .run();
+}
+
+#[test]
+fn env_deny_takes_precedence_over_env_allow() {
+  Test::new()
+    .justfile(
+      "
+        set env-allow := ['PATH', 'JUST_TEST_*']
+        set env-deny := ['JUST_TEST_SECRET']
+
+        foo:
+          @echo $JUST_TEST_PUBLIC ${JUST_TEST_SECRET:-unset}
+      ",
+    )
+    .env("JUST_TEST_PUBLIC", "public")
+    .env("JUST_TEST_SECRET", "hunter2")
+    .stdout("public unset\n")
+    .run();
+}
+
+#[test]
+fn env_deny_applies_to_backticks() {
+  Test::new()
+    .justfile(
+      "
+        set env-deny := ['JUST_TEST_SECRET']
+
+        foo := `echo ${JUST_TEST_SECRET:-unset}`
+      ",
+    )
+    .env("JUST_TEST_SECRET", "hunter2")
+    .args(["--evaluate", "foo"])
+    .stdout("unset")
+    .run();
+}
+
+#[test]
+fn env_deny_applies_to_dotenv() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+        set env-deny := ['JUST_TEST_SECRET']
+
+        foo:
+          @echo ${JUST_TEST_SECRET:-unset}
+      ",
+    )
+    .write(".env", "JUST_TEST_SECRET=hunter2")
+    .stdout("unset\n")
+    .run();
+}
+
+#[test]
+fn exported_variables_are_not_filtered() {
+  Test::new()
+    .justfile(
+      "
+        set env-deny := ['JUST_TEST_*']
+
+        export JUST_TEST_VARIABLE := 'exported'
+
+        foo:
+          @echo $JUST_TEST_VARIABLE
+      ",
+    )
+    .stdout("exported\n")
+    .run();
+}
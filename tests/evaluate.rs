@@ -38,7 +38,75 @@ test! {
     c := 'z'
   ",
   args:   ("--evaluate", "a", "c"),
-  stderr: "error: `--evaluate` used with unexpected argument: `c`\n",
+  stderr: "error: Justfile does not contain recipe `a`.\n",
+  status: EXIT_FAILURE,
+}
+
+test! {
+  name:     evaluate_recipe_parameter,
+  justfile: "
+    a := 'x'
+
+    foo bar baz='default':
+  ",
+  args:   ("--evaluate", "foo", "bar", "hello"),
+  stdout: "hello",
+}
+
+test! {
+  name:     evaluate_recipe_parameter_default,
+  justfile: "
+    a := 'x'
+
+    foo bar baz=(a + '-default'):
+  ",
+  args:   ("--evaluate", "foo", "baz", "hello"),
+  stdout: "x-default",
+}
+
+test! {
+  name:     evaluate_recipe_global_variable,
+  justfile: "
+    a := 'x'
+
+    foo bar:
+  ",
+  args:   ("--evaluate", "foo", "a", "hello"),
+  stdout: "x",
+}
+
+test! {
+  name:     evaluate_recipe_variadic_parameter,
+  justfile: "
+    foo +bar:
+  ",
+  args:   ("--evaluate", "foo", "bar", "a", "b"),
+  stdout: "a b",
+}
+
+test! {
+  name:     evaluate_recipe_argument_count_mismatch,
+  justfile: "
+    foo bar:
+  ",
+  args:   ("--evaluate", "foo", "bar"),
+  stderr: "
+    error: Recipe `foo` got 0 arguments but takes 1
+    usage:
+        just foo bar
+  ",
+  status: EXIT_FAILURE,
+}
+
+test! {
+  name:     evaluate_recipe_unknown_variable,
+  justfile: "
+    foo bar:
+  ",
+  args:   ("--evaluate", "foo", "baz", "x"),
+  stderr: "
+    error: Justfile does not contain variable `baz`.
+  ",
   status: EXIT_FAILURE,
 }
 
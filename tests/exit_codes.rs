@@ -0,0 +1,151 @@
+use super::*;
+
+#[test]
+fn passthrough_by_default() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          exit 100
+      ",
+    )
+    .stderr("exit 100\nerror: Recipe `foo` failed on line 2 with exit code 100\n")
+    .status(100)
+    .run();
+}
+
+#[test]
+fn passthrough() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          exit 100
+      ",
+    )
+    .args(["--exit-codes", "passthrough"])
+    .stderr("exit 100\nerror: Recipe `foo` failed on line 2 with exit code 100\n")
+    .status(100)
+    .run();
+}
+
+#[test]
+fn normalized_command_failure() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          exit 100
+      ",
+    )
+    .args(["--exit-codes", "normalized"])
+    .stderr("exit 100\nerror: Recipe `foo` failed on line 2 with exit code 100\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn normalized_just_failure() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+      ",
+    )
+    .args(["--exit-codes", "normalized", "bar"])
+    .stderr("error: Justfile does not contain recipe `bar`.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn categorized_command_failure() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          exit 100
+      ",
+    )
+    .args(["--exit-codes", "categorized"])
+    .stderr("exit 100\nerror: Recipe `foo` failed on line 2 with exit code 100\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn categorized_backtick_failure() {
+  Test::new()
+    .justfile(
+      "
+        x := `exit 100`
+
+        foo:
+          echo {{ x }}
+      ",
+    )
+    .args(["--exit-codes", "categorized"])
+    .stderr(
+      "
+        error: Backtick failed with exit code 100
+         ——▶ justfile:1:6
+          │
+        1 │ x := `exit 100`
+          │      ^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn categorized_just_failure() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+      ",
+    )
+    .args(["--exit-codes", "categorized", "bar"])
+    .stderr("error: Justfile does not contain recipe `bar`.\n")
+    .status(2)
+    .run();
+}
+
+#[test]
+fn categorized_compile_error() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo {{ bar }}
+      ",
+    )
+    .args(["--exit-codes", "categorized"])
+    .stderr(
+      "
+        error: Variable `bar` not defined
+         ——▶ justfile:2:11
+          │
+        2 │   echo {{ bar }}
+          │           ^^^
+      ",
+    )
+    .status(2)
+    .run();
+}
+
+#[test]
+fn environment_variable() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          exit 100
+      ",
+    )
+    .env("JUST_EXIT_CODES", "categorized")
+    .stderr("exit 100\nerror: Recipe `foo` failed on line 2 with exit code 100\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
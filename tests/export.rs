@@ -25,6 +25,26 @@ test! {
   stderr: "echo $FOO\necho b\nif [ -n \"${BAR+1}\" ]; then echo defined; else echo undefined; fi\n",
 }
 
+test! {
+  name:     parameter_export_as,
+  justfile: r#"
+    wut $token as JUST_TEST_TOKEN:
+      @echo $JUST_TEST_TOKEN {{token}}
+      @if [ -n "${token+1}" ]; then echo defined; else echo undefined; fi
+  "#,
+  args:   ("wut", "secret"),
+  stdout: "secret secret\nundefined\n",
+}
+
+test! {
+  name:     parameter_export_as_with_default,
+  justfile: r#"
+    wut $token as JUST_TEST_TOKEN='default':
+      @echo $JUST_TEST_TOKEN
+  "#,
+  stdout: "default\n",
+}
+
 test! {
   name:     parameter_not_visible_to_backtick,
   justfile: r#"
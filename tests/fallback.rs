@@ -362,3 +362,61 @@ fn stop_fallback_when_fallback_is_false() {
     .status(EXIT_FAILURE)
     .run();
 }
+
+#[test]
+fn fallback_depth_limits_fallback_search() {
+  Test::new()
+    .justfile("bar:\n @echo bar")
+    .write(
+      "a/b/justfile",
+      "set fallback\nset fallback-depth := '1'\n\nfoo:\n @echo foo",
+    )
+    .args(["bar"])
+    .current_dir("a/b")
+    .stderr("error: Justfile does not contain recipe `bar`.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn fallback_depth_allows_fallback_within_limit() {
+  Test::new()
+    .justfile("bar:\n @echo bar")
+    .write(
+      "a/b/justfile",
+      "set fallback\nset fallback-depth := '2'\n\nfoo:\n @echo foo",
+    )
+    .args(["bar"])
+    .current_dir("a/b")
+    .stdout("bar\n")
+    .run();
+}
+
+#[test]
+fn fallback_respects_search_depth() {
+  Test::new()
+    .justfile("bar:\n @echo bar")
+    .write("a/b/justfile", "set fallback\n\nfoo:\n @echo foo")
+    .args(["--search-depth", "1", "bar"])
+    .current_dir("a/b")
+    .stderr("error: Justfile does not contain recipe `bar`.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn fallback_depth_must_be_integer() {
+  Test::new()
+    .justfile("set fallback-depth := 'two'")
+    .stderr(
+      "
+      error: Invalid value `two` for setting `fallback-depth`, expected a non-negative integer
+       ——▶ justfile:1:23
+        │
+      1 │ set fallback-depth := 'two'
+        │                       ^^^^^
+    ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,163 @@
+use super::*;
+
+#[test]
+fn name() {
+  Test::new()
+    .justfile(
+      "
+        build:
+          cargo build
+
+        test:
+          cargo test
+
+        build-docs:
+          mdbook build
+      ",
+    )
+    .args(["--find", "build"])
+    .stdout(
+      "
+        justfile:1: build
+        justfile:7: build-docs
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn doc() {
+  Test::new()
+    .justfile(
+      "
+        # publish to crates.io
+        publish:
+          ./bin/publish
+
+        test:
+          cargo test
+      ",
+    )
+    .args(["--find", "crates.io"])
+    .stdout("justfile:2: publish\n")
+    .run();
+}
+
+#[test]
+fn body() {
+  Test::new()
+    .justfile(
+      "
+        build:
+          cargo build
+
+        test:
+          cargo test --workspace
+      ",
+    )
+    .args(["--find", "workspace"])
+    .stdout("justfile:4: test\n")
+    .run();
+}
+
+#[test]
+fn interpolation() {
+  Test::new()
+    .justfile(
+      "
+        target := 'release'
+
+        build:
+          cargo build --profile {{ target }}
+      ",
+    )
+    .args(["--find", "target"])
+    .stdout("justfile:3: build\n")
+    .run();
+}
+
+#[test]
+fn private_recipes_are_skipped() {
+  Test::new()
+    .justfile(
+      "
+        _build:
+          cargo build
+      ",
+    )
+    .args(["--find", "cargo"])
+    .stderr("No recipes contain `cargo`.\n")
+    .run();
+}
+
+#[test]
+fn no_matches() {
+  Test::new()
+    .justfile(
+      "
+        build:
+          cargo build
+      ",
+    )
+    .args(["--find", "npm"])
+    .stderr("No recipes contain `npm`.\n")
+    .run();
+}
+
+#[test]
+fn no_matches_quiet() {
+  Test::new()
+    .justfile(
+      "
+        build:
+          cargo build
+      ",
+    )
+    .args(["--quiet", "--find", "npm"])
+    .run();
+}
+
+#[test]
+fn modules() {
+  Test::new()
+    .write("foo.just", "# deploy to production\ndeploy:\n @./deploy")
+    .justfile(
+      "
+        mod foo
+
+        bar:
+          ./deploy --dry-run
+      ",
+    )
+    .test_round_trip(false)
+    .args(["--unstable", "--find", "deploy"])
+    .stdout(
+      "
+        justfile:3: bar
+        foo.just:2: foo::deploy
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn unsorted() {
+  Test::new()
+    .justfile(
+      "
+        b:
+          cargo build
+
+        a:
+          cargo test
+      ",
+    )
+    .args(["--unsorted", "--find", "cargo"])
+    .stdout(
+      "
+        justfile:1: b
+        justfile:4: a
+      ",
+    )
+    .run();
+}
@@ -11,16 +11,6 @@ test! {
   status: EXIT_FAILURE,
 }
 
-test! {
-  name: check_without_fmt,
-  justfile: "",
-  args: ("--check"),
-  stderr_regex: "error: the following required arguments were not provided:
-  --fmt
-(.|\\n)+",
-  status: 2,
-}
-
 test! {
   name: check_ok,
   justfile: r#"
@@ -1070,3 +1060,425 @@ fn exported_parameter() {
     .stdout("foo +$f:\n")
     .run();
 }
+
+test! {
+  name: trailing_comments,
+  justfile: "
+    set quiet # be quiet
+    alias f := foo # alias
+    x := 'a' # variable
+    export y := 'b' # exported
+    # doc
+    foo: # header
+        echo foo # body
+  ",
+  args: ("--dump"),
+  stdout: "
+    set quiet := true # be quiet
+
+    alias f := foo # alias
+
+    x := 'a' # variable
+    export y := 'b' # exported
+
+    # doc
+    foo: # header
+        echo foo # body
+  ",
+}
+
+test! {
+  name: trailing_comment_after_setting_is_not_doc_comment,
+  justfile: "
+    set quiet # be quiet
+    foo:
+  ",
+  args: ("--dump"),
+  stdout: "
+    set quiet := true # be quiet
+
+    foo:
+  ",
+}
+
+test! {
+  name: alias_attributes,
+  justfile: "
+    [private]
+    alias f := foo
+
+    foo:
+  ",
+  args: ("--dump"),
+  stdout: "
+    [private]
+    alias f := foo
+
+    foo:
+  ",
+}
+
+#[test]
+fn module_and_import_trailing_comments() {
+  Test::new()
+    .write("foo.just", "")
+    .write("bar.just", "")
+    .justfile(
+      "
+      import 'foo.just' # import
+
+      mod bar # module
+    ",
+    )
+    .test_round_trip(false)
+    .args(["--unstable", "--dump"])
+    .stdout(
+      "
+      import 'foo.just' # import
+
+      mod bar # module
+    ",
+    )
+    .run();
+}
+
+#[test]
+fn align_assignments() {
+  let tmp = tempdir();
+
+  let justfile = tmp.path().join("justfile");
+
+  fs::write(
+    &justfile,
+    "a := 'a'\nexport foo := 'foo' # comment\nbazzle := 'bazzle'\n\nfoo:\n\nx := 'x'\nyy := 'y'\n",
+  )
+  .unwrap();
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(tmp.path())
+    .arg("--fmt")
+    .arg("--align")
+    .arg("--unstable")
+    .output()
+    .unwrap();
+
+  if !output.status.success() {
+    eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+    eprintln!("{}", String::from_utf8_lossy(&output.stdout));
+    panic!("justfile failed with status: {}", output.status);
+  }
+
+  assert_eq!(
+    fs::read_to_string(&justfile).unwrap(),
+    "a          := 'a'\nexport foo := 'foo' # comment\nbazzle     := 'bazzle'\n\nfoo:\n\nx  := 'x'\nyy := 'y'\n",
+  );
+}
+
+test! {
+  name: check_aligned,
+  justfile: "
+    a   := 'a'
+    bcd := 'bcd'
+  ",
+  args: ("--unstable", "--fmt", "--check", "--align"),
+}
+
+test! {
+  name: align_without_fmt,
+  justfile: "",
+  args: ("--align"),
+  stderr_regex: "error: the following required arguments were not provided:
+  --fmt
+(.|\\n)+",
+  status: 2,
+}
+
+#[track_caller]
+fn format(justfile: &str) -> String {
+  format_with_args(justfile, &[])
+}
+
+#[track_caller]
+fn format_with_args(justfile: &str, args: &[&str]) -> String {
+  let tmp = tempdir();
+
+  let path = tmp.path().join("justfile");
+
+  fs::write(&path, justfile).unwrap();
+
+  let output = Command::new(executable_path("just"))
+    .current_dir(tmp.path())
+    .args(["--fmt", "--unstable"])
+    .args(args)
+    .output()
+    .unwrap();
+
+  if !output.status.success() {
+    eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+    panic!("justfile failed with status: {}", output.status);
+  }
+
+  fs::read_to_string(&path).unwrap()
+}
+
+#[test]
+fn recipe_body_trailing_whitespace_is_removed() {
+  assert_eq!(
+    format("foo:\n    echo foo  \n    echo {{ 'bar' }}\t\n"),
+    "foo:\n    echo foo\n    echo {{ 'bar' }}\n",
+  );
+}
+
+#[test]
+fn recipe_body_blank_line_runs_are_collapsed() {
+  assert_eq!(
+    format("foo:\n    echo foo\n\n\n\n    echo bar\n"),
+    "foo:\n    echo foo\n\n    echo bar\n",
+  );
+}
+
+#[test]
+fn recipe_body_whitespace_before_continuation_is_preserved() {
+  assert_eq!(
+    format("foo:\n    echo foo \\\n      bar\n"),
+    "foo:\n    echo foo \\\n      bar\n",
+  );
+}
+
+#[test]
+fn recipe_body_whitespace_after_backslash_is_preserved() {
+  assert_eq!(format("foo:\n    echo foo\\ \n"), "foo:\n    echo foo\\ \n",);
+}
+
+#[test]
+fn shebang_recipe_body_is_preserved() {
+  let justfile = "foo:\n    #!/usr/bin/env python3  \n    x = '''a  \n\n\n    b'''\n";
+  assert_eq!(format(justfile), justfile);
+}
+
+#[test]
+fn recipe_body_formatting_is_idempotent() {
+  let formatted =
+    format("foo:\n    echo a   \n\n\n    -@echo b \\\n      c  \n\n\nbar:\n    echo c\n");
+  assert_eq!(
+    formatted,
+    "foo:\n    echo a\n\n    -@echo b \\\n      c\n\nbar:\n    echo c\n",
+  );
+  assert_eq!(format(&formatted), formatted);
+}
+
+#[test]
+fn comments_round_trip() {
+  let formatted = format(
+    "set quiet := true # be quiet
+# after settings
+
+# doc for x
+[secret]
+# secret value
+x := 'a'
+
+[private]
+# hidden alias
+alias f := foo
+
+# doc for foo
+[no-cd]
+# between attributes
+[private] # on attribute
+foo:
+    echo foo
+",
+  );
+
+  assert_eq!(
+    formatted,
+    "set quiet := true # be quiet
+
+# after settings
+
+# doc for x
+[secret]
+# secret value
+x := 'a'
+
+[private]
+# hidden alias
+alias f := foo
+
+# doc for foo
+[no-cd]
+# between attributes
+[private] # on attribute
+foo:
+    echo foo
+",
+  );
+
+  assert_eq!(format(&formatted), formatted);
+}
+
+#[test]
+fn attribute_line_comments_round_trip() {
+  let justfile = "[no-cd, private] # both
+# between
+[group('a')] # group
+foo:
+    echo foo
+";
+
+  assert_eq!(format(justfile), justfile);
+}
+
+#[test]
+fn expression_comments_round_trip() {
+  let justfile = "x := (
+  'a' + # first
+  # own line
+  'b' /* block */ + 'c'
+)
+
+foo y=('d' + # parameter
+    'e'):
+  echo {{ x }} {{ y }}
+
+set shell := [
+  'bash', # shell
+  '-c',
+]
+";
+
+  assert_eq!(format(justfile), justfile);
+}
+
+#[test]
+fn expression_comments_are_ignored() {
+  Test::new()
+    .justfile(
+      "
+        x := (
+          'a' + # first
+          # own line
+          'b' /* block */ + 'c'
+        )
+
+        foo y=('d' + # parameter
+          'e'):
+          @echo {{ x }} {{ y }}
+      ",
+    )
+    .stdout("abc de\n")
+    .run();
+}
+
+#[test]
+fn assignment_doc_comment_is_kept_with_assignment() {
+  let justfile = "# doc for x\nx := \"a\"\n\n# doc for y\n[secret]\nexport y := \"b\"\n";
+  assert_eq!(format(justfile), justfile);
+}
+
+#[test]
+fn align_keeps_secret_attribute() {
+  assert_eq!(
+    format_with_args("[secret]\ntoken := \"s\"\nab := 'x'\n", &["--align"]),
+    "[secret]\ntoken := \"s\"\nab    := 'x'\n",
+  );
+}
+
+#[test]
+fn align_keeps_choices_attribute() {
+  assert_eq!(
+    format_with_args(
+      "[choices('debug', 'release')]\nmode := 'debug'\nx := 'x'\n",
+      &["--align"],
+    ),
+    "[choices('debug', 'release')]\nmode := 'debug'\nx    := 'x'\n",
+  );
+}
+
+#[test]
+fn assignment_doc_comment_is_shown_after_formatting() {
+  Test::new()
+    .justfile(format("# doc for x\nx := 'a'\n"))
+    .args(["--variables", "--verbose"])
+    .stdout("x := 'a' # doc for x\n")
+    .run();
+}
+
+#[test]
+fn stdin() {
+  Test::new()
+    .no_justfile()
+    .args(["--unstable", "--fmt", "--stdin"])
+    .stdin("x:=``\n")
+    .stdout("x := ``\n")
+    .test_round_trip(false)
+    .run();
+}
+
+#[test]
+fn stdin_does_not_modify_justfile() {
+  let output = Test::new()
+    .justfile("x:=``\n")
+    .args(["--unstable", "--fmt", "--stdin"])
+    .stdin("y:='a'\n")
+    .stdout("y := 'a'\n")
+    .test_round_trip(false)
+    .run();
+
+  assert_eq!(
+    fs::read_to_string(output.tempdir.path().join("justfile")).unwrap(),
+    "x:=``\n",
+  );
+}
+
+#[test]
+fn stdin_check() {
+  Test::new()
+    .no_justfile()
+    .args(["--unstable", "--fmt", "--stdin", "--check"])
+    .stdin("x:=``\n")
+    .stdout(
+      "
+        -x:=``
+        +x := ``
+      ",
+    )
+    .stderr("error: Formatted justfile differs from original.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn stdin_check_ok() {
+  Test::new()
+    .no_justfile()
+    .args(["--unstable", "--fmt", "--stdin", "--check"])
+    .stdin("x := ``\n")
+    .test_round_trip(false)
+    .run();
+}
+
+#[test]
+fn stdin_parse_error() {
+  Test::new()
+    .no_justfile()
+    .args(["--unstable", "--fmt", "--stdin"])
+    .stdin("x := \n")
+    .stderr_regex("error: .*\n ——▶ <stdin>:1:6\n(.|\n)+")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn stdin_requires_fmt() {
+  Test::new()
+    .args(["--stdin"])
+    .stderr_regex(
+      "error: the following required arguments were not provided:
+  --fmt
+(.|\\n)+",
+    )
+    .status(2)
+    .run();
+}
@@ -0,0 +1,105 @@
+use super::*;
+
+#[test]
+fn parameters_are_forwarded_to_dependencies() {
+  Test::new()
+    .justfile(
+      "
+      [forward-arguments]
+      build target: configure && (test target 'fast')
+        @echo build {{target}}
+
+      configure target:
+        @echo configure {{target}}
+
+      test target speed:
+        @echo test {{target}} {{speed}}
+      ",
+    )
+    .args(["build", "release"])
+    .stdout("configure release\nbuild release\ntest release fast\n")
+    .run();
+}
+
+#[test]
+fn forwarding_stops_at_first_unmatched_parameter() {
+  Test::new()
+    .justfile(
+      "
+      [forward-arguments]
+      build target profile: configure
+        @echo build
+
+      configure target mode='debug' profile='none':
+        @echo configure {{target}} {{mode}} {{profile}}
+      ",
+    )
+    .args(["build", "release", "fast"])
+    .stdout("configure release debug none\nbuild\n")
+    .run();
+}
+
+#[test]
+fn parameters_are_not_forwarded_without_attribute() {
+  Test::new()
+    .justfile(
+      "
+      build target: configure
+
+      configure target:
+      ",
+    )
+    .args(["build", "release"])
+    .stderr(
+      "
+      error: Dependency `configure` got 0 arguments but takes 1 argument
+       ——▶ justfile:1:15
+        │
+      1 │ build target: configure
+        │               ^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn missing_forwarded_parameters_are_an_error() {
+  Test::new()
+    .justfile(
+      "
+      [forward-arguments]
+      build target: configure
+
+      configure target profile:
+      ",
+    )
+    .args(["build", "release"])
+    .stderr(
+      "
+      error: Dependency `configure` got 1 argument but takes 2 arguments
+       ——▶ justfile:2:15
+        │
+      2 │ build target: configure
+        │               ^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn forwarded_arguments_are_shown() {
+  Test::new()
+    .justfile(
+      "
+      [forward-arguments]
+      build target: configure
+
+      configure target:
+      ",
+    )
+    .args(["--show", "build"])
+    .stdout("[forward-arguments]\nbuild target: (configure target)\n")
+    .run();
+}
@@ -91,7 +91,7 @@ foo:
     " ——▶ justfile:1:8",
     "  │",
     "1 │ we  := without_extension(\'\')",
-    "  │        ^^^^^^^^^^^^^^^^^").as_str(),
+    "  │        ^^^^^^^^^^^^^^^^^^^^^").as_str(),
   status:   EXIT_FAILURE,
 }
 
@@ -110,7 +110,7 @@ foo:
     " ——▶ justfile:1:8",
     "  │",
     "1 │ we  := extension(\'\')",
-    "  │        ^^^^^^^^^").as_str(),
+    "  │        ^^^^^^^^^^^^^").as_str(),
   status:   EXIT_FAILURE,
 }
 
@@ -129,7 +129,7 @@ foo:
     " ——▶ justfile:1:8",
     "  │",
     "1 │ we  := extension(\'foo\')",
-    "  │        ^^^^^^^^^").as_str(),
+    "  │        ^^^^^^^^^^^^^^^^").as_str(),
   status:   EXIT_FAILURE,
 }
 
@@ -148,7 +148,7 @@ foo:
     " ——▶ justfile:1:8",
     "  │",
     "1 │ we  := file_stem(\'\')",
-    "  │        ^^^^^^^^^").as_str(),
+    "  │        ^^^^^^^^^^^^^").as_str(),
   status:   EXIT_FAILURE,
 }
 
@@ -167,7 +167,7 @@ foo:
     " ——▶ justfile:1:8",
     "  │",
     "1 │ we  := file_name(\'\')",
-    "  │        ^^^^^^^^^").as_str(),
+    "  │        ^^^^^^^^^^^^^").as_str(),
   status:   EXIT_FAILURE,
 }
 
@@ -187,7 +187,7 @@ foo:
     " ——▶ justfile:1:8",
     "  │",
     "1 │ we  := parent_directory(\'\')",
-    "  │        ^^^^^^^^^^^^^^^^").as_str(),
+    "  │        ^^^^^^^^^^^^^^^^^^^^").as_str(),
   status:   EXIT_FAILURE,
 }
 
@@ -207,7 +207,7 @@ foo:
     " ——▶ justfile:1:8",
     "  │",
     "1 │ we  := parent_directory(\'/\')",
-    "  │        ^^^^^^^^^^^^^^^^").as_str(),
+    "  │        ^^^^^^^^^^^^^^^^^^^^^").as_str(),
   status:   EXIT_FAILURE,
 }
 
@@ -235,7 +235,7 @@ test! {
  ——▶ justfile:2:10
   │
 2 │   echo {{env_var('ZADDY')}}
-  │          ^^^^^^^
+  │          ^^^^^^^^^^^^^^^^
 ",
   status:   EXIT_FAILURE,
 }
@@ -392,6 +392,44 @@ test! {
   stderr: "echo foofoofoo\n",
 }
 
+test! {
+  name: replace_first,
+  justfile: "
+    foo:
+      echo {{ replace_first('barbarbar', 'bar', 'foo') }}
+  ",
+  stdout: "foobarbar\n",
+  stderr: "echo foobarbar\n",
+}
+
+test! {
+  name: replace_n,
+  justfile: "
+    foo:
+      echo {{ replace_n('barbarbar', 'bar', 'foo', '2') }}
+  ",
+  stdout: "foofoobar\n",
+  stderr: "echo foofoobar\n",
+}
+
+#[test]
+fn replace_n_invalid_count() {
+  Test::new()
+    .justfile("x := replace_n('a', 'b', 'c', 'x')")
+    .args(["--evaluate"])
+    .stderr(
+      "
+      error: Call to function `replace_n` failed: invalid count `x`: invalid digit found in string
+       ——▶ justfile:1:6
+        │
+      1 │ x := replace_n('a', 'b', 'c', 'x')
+        │      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
 test! {
   name: invalid_replace_regex,
   justfile: "
@@ -406,7 +444,7 @@ error: incomplete escape sequence, reached end of pattern prematurely
  ——▶ justfile:2:11
   │
 2 │   echo {{ replace_regex('barbarbar', 'foo\\', 'foo') }}
-  │           ^^^^^^^^^^^^^
+  │           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
 ",
   status: EXIT_FAILURE,
 }
@@ -421,6 +459,218 @@ test! {
     stderr: "echo Bar\n",
 }
 
+#[test]
+fn uppercase_first() {
+  assert_eval_eq("uppercase_first('gitHub')", "GitHub");
+  assert_eval_eq("uppercase_first('BAR')", "BAR");
+  assert_eval_eq("uppercase_first('ß')", "SS");
+  assert_eval_eq("uppercase_first('')", "");
+}
+
+#[test]
+fn lowercase_first() {
+  assert_eval_eq("lowercase_first('GitHub')", "gitHub");
+  assert_eval_eq("lowercase_first('BAR')", "bAR");
+  assert_eval_eq("lowercase_first('')", "");
+}
+
+#[test]
+fn json_encode() {
+  assert_eval_eq(r#"json_encode('say "hi"')"#, r#""say \"hi\"""#);
+  assert_eval_eq(r#"json_encode("a\tb\nc\\d")"#, r#""a\tb\nc\\d""#);
+  assert_eval_eq("json_encode('')", r#""""#);
+}
+
+#[test]
+fn json_object() {
+  assert_eval_eq("json_object('a', 'b')", r#"{"a":"b"}"#);
+  assert_eval_eq(
+    r#"json_object('text', 'say "hi"', 'user', 'bob')"#,
+    r#"{"text":"say \"hi\"","user":"bob"}"#,
+  );
+}
+
+#[test]
+fn json_object_unpaired_key() {
+  Test::new()
+    .justfile("x := json_object('a', 'b', 'c')")
+    .args(["--evaluate"])
+    .stderr(
+      "
+      error: Call to function `json_object` failed: key `c` has no value, keys and values must be given in pairs
+       ——▶ justfile:1:6
+        │
+      1 │ x := json_object('a', 'b', 'c')
+        │      ^^^^^^^^^^^^^^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn shell_expand_variables() {
+  Test::new()
+    .justfile("x := shell_expand('$FOO/${FOO}bar/$ $/${BAZ}')")
+    .env("FOO", "foo")
+    .env("BAZ", "baz")
+    .args(["--evaluate", "x"])
+    .stdout("foo/foobar/$ $/baz")
+    .run();
+}
+
+#[test]
+fn shell_expand_dotenv() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        x := shell_expand('$FOO')
+      ",
+    )
+    .write(".env", "FOO=dotenv")
+    .args(["--evaluate", "x"])
+    .stdout("dotenv")
+    .run();
+}
+
+#[test]
+#[cfg(unix)]
+fn shell_expand_tilde() {
+  Test::new()
+    .justfile("x := shell_expand('~/foo') + ' ' + shell_expand('~') + ' ' + shell_expand('a~')")
+    .env("HOME", "/home/user")
+    .args(["--evaluate", "x"])
+    .stdout("/home/user/foo /home/user a~")
+    .run();
+}
+
+#[test]
+fn shell_expand_unset_variable() {
+  Test::new()
+    .justfile("x := shell_expand('$JUST_TEST_UNSET')")
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+      error: Call to function `shell_expand` failed: environment variable `JUST_TEST_UNSET` not present
+       ——▶ justfile:1:6
+        │
+      1 │ x := shell_expand('$JUST_TEST_UNSET')
+        │      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn humanize_bytes() {
+  assert_eval_eq("humanize_bytes('0')", "0 B");
+  assert_eval_eq("humanize_bytes('1023')", "1023 B");
+  assert_eval_eq("humanize_bytes('1536')", "1.5 KiB");
+  assert_eval_eq("humanize_bytes('10485760')", "10.0 MiB");
+  assert_eval_eq("humanize_bytes('18446744073709551615')", "16.0 EiB");
+}
+
+#[test]
+fn humanize_duration() {
+  assert_eval_eq("humanize_duration('0.25')", "250ms");
+  assert_eval_eq("humanize_duration('1.5')", "1.5s");
+  assert_eval_eq("humanize_duration('42')", "42s");
+  assert_eval_eq("humanize_duration('3600')", "1h");
+  assert_eval_eq("humanize_duration('3725')", "1h 2m 5s");
+  assert_eval_eq("humanize_duration('90061')", "1d 1h 1m 1s");
+}
+
+#[test]
+fn humanize_duration_invalid() {
+  Test::new()
+    .justfile("x := humanize_duration('-1')")
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+      error: Call to function `humanize_duration` failed: invalid duration `-1`: expected non-negative seconds
+       ——▶ justfile:1:6
+        │
+      1 │ x := humanize_duration('-1')
+        │      ^^^^^^^^^^^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn line() {
+  assert_eval_eq(r#"line("a\nb\nc", '1')"#, "a");
+  assert_eval_eq(r#"line("a\nb\nc\n", '3')"#, "c");
+  assert_eval_eq(r#"line("a\r\nb", '-1')"#, "b");
+  assert_eval_eq(r#"line("a\nb\nc", '-3')"#, "a");
+}
+
+#[test]
+fn line_out_of_range() {
+  Test::new()
+    .justfile("x := line('a', '3')")
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+      error: Call to function `line` failed: line 3 out of range, string has 1 line
+       ——▶ justfile:1:6
+        │
+      1 │ x := line('a', '3')
+        │      ^^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn line_count() {
+  assert_eval_eq("line_count('')", "0");
+  assert_eval_eq("line_count('a')", "1");
+  assert_eval_eq(r#"line_count("a\nb\n")"#, "2");
+  assert_eval_eq(r#"line_count("a\n\nb")"#, "3");
+}
+
+#[test]
+fn env_flag() {
+  Test::new()
+    .justfile(
+      "
+        x := env_flag('A') + env_flag('B') + env_flag('C') + env_flag('D') + env_flag('JUST_TEST_UNSET')
+      ",
+    )
+    .env("A", "1")
+    .env("B", " Yes ")
+    .env("C", "off")
+    .env("D", "")
+    .args(["--evaluate", "x"])
+    .stdout("truetruefalsefalsefalse")
+    .run();
+}
+
+#[test]
+fn env_flag_invalid() {
+  Test::new()
+    .justfile("x := env_flag('A')")
+    .env("A", "maybe")
+    .args(["--evaluate", "x"])
+    .stderr(
+      "
+      error: Call to function `env_flag` failed: environment variable `A` is not a boolean flag: `maybe`
+       ——▶ justfile:1:6
+        │
+      1 │ x := env_flag('A')
+        │      ^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
 #[test]
 fn semver_matches() {
   Test::new()
@@ -499,6 +749,39 @@ fn join() {
   assert_eval_eq("join('a', '\\b', '\\c', '\\d')", "\\d");
 }
 
+#[test]
+#[cfg(windows)]
+fn verbatim_paths() {
+  assert_eval_eq(r"clean('\\?\C:\foo/bar/../baz')", r"\\?\C:\foo\baz");
+  assert_eval_eq(r"join('\\?\C:\foo', 'bar/baz')", r"\\?\C:\foo\bar\baz");
+  assert_eval_eq(r"parent_directory('\\?\C:\foo/bar')", r"\\?\C:\foo");
+  assert_eval_eq(
+    r"without_extension('\\?\C:\foo/bar.txt')",
+    r"\\?\C:\foo\bar",
+  );
+}
+
+#[test]
+#[cfg(windows)]
+fn unc_paths() {
+  assert_eval_eq(
+    r"clean('\\server\share/foo/./bar')",
+    r"\\server\share\foo\bar",
+  );
+  assert_eval_eq(
+    r"join('\\server\share', 'foo/bar')",
+    r"\\server\share\foo\bar",
+  );
+  assert_eval_eq(
+    r"parent_directory('\\server\share/foo/bar')",
+    r"\\server\share\foo",
+  );
+  assert_eval_eq(
+    r"without_extension('\\server\share/foo/bar.txt')",
+    r"\\server\share\foo\bar",
+  );
+}
+
 #[test]
 fn join_argument_count_error() {
   Test::new()
@@ -550,7 +833,54 @@ fn error_errors_with_message() {
        ——▶ justfile:1:6
         │
       1 │ x := error ('Thing Not Supported')
-        │      ^^^^^
+        │      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ",
+    )
+    .run();
+}
+
+#[test]
+fn error_span_covers_nested_call() {
+  Test::new()
+    .justfile(
+      "
+        target := 'wasm'
+        triple := target + '-' + error('unsupported target ' + target) + '-none'
+      ",
+    )
+    .args(["--evaluate"])
+    .status(1)
+    .stderr(
+      "
+      error: Call to function `error` failed: unsupported target wasm
+       ——▶ justfile:2:26
+        │
+      2 │ triple := target + '-' + error('unsupported target ' + target) + '-none'
+        │                          ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+    ",
+    )
+    .run();
+}
+
+#[test]
+fn error_span_is_truncated_to_first_line() {
+  Test::new()
+    .justfile(
+      "
+        x := error(
+          'Thing Not Supported',
+        )
+      ",
+    )
+    .args(["--evaluate"])
+    .status(1)
+    .stderr(
+      "
+      error: Call to function `error` failed: Thing Not Supported
+       ——▶ justfile:1:6
+        │
+      1 │ x := error(
+        │      ^^^^^^
     ",
     )
     .run();
@@ -576,6 +906,32 @@ fn test_absolute_path_resolves() {
     .run();
 }
 
+#[test]
+fn test_absolute_path_invocation_resolves() {
+  let test_object = Test::new()
+    .justfile("path := absolute_path_invocation('./test_file')")
+    .tree(tree! { subdir: { } })
+    .current_dir("subdir")
+    .args(["--evaluate", "path"]);
+
+  let mut tempdir = test_object.tempdir.path().to_owned();
+
+  if cfg!(unix) {
+    tempdir = tempdir.canonicalize().unwrap();
+  }
+
+  test_object
+    .stdout(
+      tempdir
+        .join("subdir")
+        .join("test_file")
+        .to_str()
+        .unwrap()
+        .to_owned(),
+    )
+    .run();
+}
+
 #[test]
 fn test_absolute_path_resolves_parent() {
   let test_object = Test::new()
@@ -628,6 +984,30 @@ fn uuid() {
     .run();
 }
 
+#[test]
+fn uuid_v5() {
+  assert_eval_eq(
+    "uuid_v5('dns', 'python.org')",
+    "886313e1-3b8a-5372-9b90-0c9aee199e5d",
+  );
+  assert_eval_eq(
+    "uuid_v5('6ba7b811-9dad-11d1-80b4-00c04fd430c8', 'https://just.systems')",
+    "f7870a6b-3655-56a8-b1a0-fb3f2794e86e",
+  );
+}
+
+#[test]
+fn uuid_v5_invalid_namespace() {
+  Test::new()
+    .justfile("x := uuid_v5('foo', 'bar')")
+    .args(["--evaluate", "x"])
+    .stderr_regex(
+      "error: Call to function `uuid_v5` failed: invalid namespace `foo`, expected `dns`, `oid`, `url`, `x500`, or a UUID: .*\n(.|\n)*",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
 #[test]
 fn sha256() {
   Test::new()
@@ -697,3 +1077,169 @@ fn canonicalize() {
     .stdout_regex(".*/justfile")
     .run();
 }
+
+#[test]
+fn run() {
+  Test::new()
+    .justfile(
+      "
+        version:
+          @echo 1.2.3
+
+        foo:
+          @echo v{{run('version')}}
+      ",
+    )
+    .arg("foo")
+    .stdout("v1.2.3\n")
+    .run();
+}
+
+#[test]
+fn run_with_arguments() {
+  Test::new()
+    .justfile(
+      "
+        greet name greeting='hello':
+          @echo {{greeting}}, {{name}}
+
+        foo message=run('greet', 'world', 'goodbye'):
+          @echo {{message}}
+      ",
+    )
+    .arg("foo")
+    .stdout("goodbye, world\n")
+    .run();
+}
+
+#[test]
+fn run_failure() {
+  Test::new()
+    .justfile(
+      "
+        fail:
+          @exit 3
+
+        foo:
+          echo {{run('fail')}}
+      ",
+    )
+    .arg("foo")
+    .stderr(
+      "
+        error: Recipe `fail` failed on line 2 with exit code 3
+        error: Call to function `run` failed: Recipe `fail` failed: Process exited with status code 3
+         ——▶ justfile:5:10
+          │
+        5 │   echo {{run('fail')}}
+          │          ^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn run_forwards_overrides() {
+  Test::new()
+    .justfile(
+      "
+        x := 'default'
+
+        show:
+          @echo {{x}}
+
+        foo:
+          @echo {{run('show')}}
+      ",
+    )
+    .args(["--set", "x", "hello", "foo"])
+    .stdout("hello\n")
+    .run();
+}
+
+#[test]
+fn run_forwards_dotenv_filename() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        show:
+          @echo $X
+
+        foo:
+          @echo {{run('show')}}
+      ",
+    )
+    .write(".custom-env", "X=hello")
+    .args(["--dotenv-filename", ".custom-env", "foo"])
+    .stdout("hello\n")
+    .run();
+}
+
+#[test]
+fn run_forwards_shell() {
+  Test::new()
+    .justfile(
+      "
+        show:
+          @world
+
+        foo:
+          @{{run('show')}}
+      ",
+    )
+    .shell(false)
+    .args(["--shell", "echo", "--shell-arg", "hello", "foo"])
+    .stdout("hello hello world\n")
+    .run();
+}
+
+#[test]
+fn run_in_module() {
+  Test::new()
+    .write(
+      "foo.just",
+      "
+bar:
+  @echo BAR
+
+baz:
+  @echo {{run('bar')}}
+",
+    )
+    .justfile(
+      "
+        mod foo
+      ",
+    )
+    .test_round_trip(false)
+    .args(["--unstable", "foo", "baz"])
+    .stdout("BAR\n")
+    .run();
+}
+
+#[test]
+fn run_in_assignment() {
+  Test::new()
+    .justfile(
+      "
+        x := run('foo')
+
+        foo:
+          @echo foo
+      ",
+    )
+    .stderr(
+      "
+        error: Assignment `x` calls `run()`, which may only be called from recipes
+         ——▶ justfile:1:6
+          │
+        1 │ x := run('foo')
+          │      ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
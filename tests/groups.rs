@@ -0,0 +1,205 @@
+use super::*;
+
+#[test]
+fn list_groups() {
+  Test::new()
+    .justfile(
+      "
+        [group('lint')]
+        check:
+
+        [group('lint')]
+        [group('test')]
+        spellcheck:
+
+        [group('test')]
+        test:
+
+        build:
+      ",
+    )
+    .arg("--list")
+    .stdout(
+      "
+        Available recipes:
+            build
+
+            [lint]
+            check
+            spellcheck
+
+            [test]
+            spellcheck
+            test
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn list_only_grouped_recipes() {
+  Test::new()
+    .justfile(
+      "
+        [group('b')]
+        foo:
+
+        [group('a')]
+        bar:
+      ",
+    )
+    .arg("--list")
+    .stdout(
+      "
+        Available recipes:
+            [a]
+            bar
+
+            [b]
+            foo
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn list_groups_unsorted() {
+  Test::new()
+    .justfile(
+      "
+        [group('b')]
+        foo:
+
+        [group('a')]
+        bar:
+      ",
+    )
+    .args(["--list", "--unsorted"])
+    .stdout(
+      "
+        Available recipes:
+            [b]
+            foo
+
+            [a]
+            bar
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn aliases_are_listed_with_their_recipe() {
+  Test::new()
+    .justfile(
+      "
+        alias c := check
+
+        [group('lint')]
+        check:
+      ",
+    )
+    .arg("--list")
+    .stdout(
+      "
+        Available recipes:
+            [lint]
+            check
+            c     # alias for `check`
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn groups() {
+  Test::new()
+    .justfile(
+      "
+        [group('test')]
+        [group('lint')]
+        foo:
+
+        [group('build')]
+        bar:
+
+        [private]
+        [group('hidden')]
+        baz:
+
+        qux:
+      ",
+    )
+    .arg("--groups")
+    .stdout(
+      "
+        Recipe groups:
+            build
+            lint
+            test
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn groups_with_list_prefix() {
+  Test::new()
+    .justfile(
+      "
+        [group('lint')]
+        foo:
+      ",
+    )
+    .args(["--groups", "--list-prefix", "··"])
+    .stdout(
+      "
+        Recipe groups:
+        ··lint
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn duplicate_groups_are_merged() {
+  Test::new()
+    .justfile(
+      "
+        [group('lint')]
+        [group('lint')]
+        foo:
+      ",
+    )
+    .arg("--groups")
+    .stdout(
+      "
+        Recipe groups:
+            lint
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn group_requires_argument() {
+  Test::new()
+    .justfile(
+      "
+        [group]
+        foo:
+      ",
+    )
+    .arg("--list")
+    .stderr(
+      "
+        error: Attribute `group` requires an argument
+         ——▶ justfile:1:2
+          │
+        1 │ [group]
+          │  ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
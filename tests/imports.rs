@@ -196,6 +196,78 @@ fn recipes_in_import_are_overridden_by_recipes_in_parent() {
     .run();
 }
 
+#[test]
+fn duplicate_recipes_in_import_show_both_definitions() {
+  Test::new()
+    .tree(tree! {
+      "import.justfile": "
+        a:
+          @echo IMPORT
+      ",
+    })
+    .justfile(
+      "
+        a:
+          @echo ROOT
+
+        import './import.justfile'
+      ",
+    )
+    .test_round_trip(false)
+    .arg("a")
+    .status(EXIT_FAILURE)
+    .stderr(
+      "
+      error: Recipe `a` first defined on line 1 of `justfile` is redefined on line 1 of `import.justfile`
+       ——▶ import.justfile:1:1
+        │
+      1 │ a:
+        │ ^
+       ——▶ justfile:1:1
+        │
+      1 │ a:
+        │ ^
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn duplicate_variables_in_import_show_both_definitions() {
+  Test::new()
+    .tree(tree! {
+      "import.justfile": "
+        f := 'foo'
+      ",
+    })
+    .justfile(
+      "
+        f := 'bar'
+
+        import './import.justfile'
+
+        a:
+      ",
+    )
+    .test_round_trip(false)
+    .arg("a")
+    .status(EXIT_FAILURE)
+    .stderr(
+      "
+      error: Variable `f` has multiple definitions
+       ——▶ import.justfile:1:1
+        │
+      1 │ f := 'foo'
+        │ ^
+       ——▶ justfile:1:1
+        │
+      1 │ f := 'bar'
+        │ ^
+      ",
+    )
+    .run();
+}
+
 #[test]
 fn variables_in_import_are_overridden_by_variables_in_parent() {
   Test::new()
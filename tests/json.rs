@@ -43,18 +43,33 @@ fn alias() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -71,8 +86,11 @@ fn assignment() {
       "aliases": {},
       "assignments": {
         "foo": {
+          "choices": [],
+          "doc": null,
           "export": false,
           "name": "foo",
+          "secret": false,
           "value": "bar",
           "depth": 0,
         }
@@ -81,18 +99,33 @@ fn assignment() {
       "modules": {},
       "recipes": {},
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -133,18 +166,33 @@ fn body() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -174,6 +222,7 @@ fn dependencies() {
           "body": [],
           "dependencies": [{
             "arguments": [],
+            "condition": null,
             "recipe": "foo"
           }],
           "parameters": [],
@@ -197,18 +246,33 @@ fn dependencies() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -242,8 +306,11 @@ fn dependency_argument() {
       "first": "foo",
       "assignments": {
         "x": {
+          "choices": [],
+          "doc": null,
           "export": false,
           "name": "x",
+          "secret": false,
           "value": "foo",
           "depth": 0,
         },
@@ -268,6 +335,7 @@ fn dependency_argument() {
               ["call", "join", "a", "b"],
               ["call", "replace", "a", "b", "c"],
             ],
+            "condition": null,
             "recipe": "foo"
           }],
           "parameters": [],
@@ -287,6 +355,9 @@ fn dependency_argument() {
             {
               "name": "args",
               "export": false,
+              "export_name": null,
+              "pattern": null,
+              "secret": false,
               "default": null,
               "kind": "star",
             }
@@ -299,18 +370,33 @@ fn dependency_argument() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -351,6 +437,9 @@ fn duplicate_recipes() {
             {
               "name": "bar",
               "export": false,
+              "export_name": null,
+              "pattern": null,
+              "secret": false,
               "default": null,
               "kind": "singular",
             },
@@ -363,18 +452,33 @@ fn duplicate_recipes() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": true,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -395,8 +499,11 @@ fn duplicate_variables() {
       "aliases": {},
       "assignments": {
         "x": {
+          "choices": [],
+          "doc": null,
           "export": false,
           "name": "x",
+          "secret": false,
           "value": "bar",
           "depth": 0,
         }
@@ -405,18 +512,33 @@ fn duplicate_variables() {
       "modules": {},
       "recipes": {},
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": true,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -450,18 +572,33 @@ fn doc_comment() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -481,18 +618,33 @@ fn empty_justfile() {
       "modules": {},
       "recipes": {},
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -541,6 +693,9 @@ fn parameters() {
             {
               "name": "x",
               "export": false,
+              "export_name": null,
+              "pattern": null,
+              "secret": false,
               "default": null,
               "kind": "singular",
             },
@@ -561,6 +716,9 @@ fn parameters() {
             {
               "name": "x",
               "export": false,
+              "export_name": null,
+              "pattern": null,
+              "secret": false,
               "default": "y",
               "kind": "singular",
             }
@@ -581,6 +739,9 @@ fn parameters() {
             {
               "name": "x",
               "export": false,
+              "export_name": null,
+              "pattern": null,
+              "secret": false,
               "default": null,
               "kind": "plus",
             }
@@ -601,6 +762,9 @@ fn parameters() {
             {
               "name": "x",
               "export": false,
+              "export_name": null,
+              "pattern": null,
+              "secret": false,
               "default": null,
               "kind": "star",
             }
@@ -621,6 +785,9 @@ fn parameters() {
             {
               "name": "x",
               "export": true,
+              "export_name": null,
+              "pattern": null,
+              "secret": false,
               "default": null,
               "kind": "singular",
             }
@@ -633,18 +800,33 @@ fn parameters() {
         },
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -685,10 +867,12 @@ fn priors() {
           "dependencies": [
             {
               "arguments": [],
+              "condition": null,
               "recipe": "a",
             },
             {
               "arguments": [],
+              "condition": null,
               "recipe": "c",
             }
           ],
@@ -718,18 +902,33 @@ fn priors() {
         },
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -763,18 +962,33 @@ fn private() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -808,18 +1022,33 @@ fn quiet() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -865,21 +1094,36 @@ fn settings() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": "filename",
         "dotenv_load": true,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": "path",
+        "env_allow": null,
+        "env_deny": [],
         "export": true,
         "fallback": true,
+        "fallback_depth": null,
         "ignore_comments": true,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": true,
+        "prefix_match": false,
         "quiet": true,
         "shell": {
           "arguments": ["b", "c"],
           "command": "a",
         },
+        "shell_args": [],
         "tempdir": null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -916,18 +1160,33 @@ fn shebang() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir": null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -961,18 +1220,33 @@ fn simple() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir": null,
+        "trace_redact": [],
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -1009,18 +1283,33 @@ fn attribute() {
         }
       },
       "settings": {
+        "allow": [],
         "allow_duplicate_recipes": false,
         "allow_duplicate_variables": false,
+        "allow_missing_recipe_args": null,
+        "chooser": null,
+        "default": null,
         "dotenv_filename": null,
         "dotenv_load": null,
+        "dotenv_outside_project": null,
+        "dotenv_override": false,
         "dotenv_path": null,
+        "env_allow": null,
+        "env_deny": [],
         "export": false,
         "fallback": false,
+        "fallback_depth": null,
+        "parallel": false,
         "positional_arguments": false,
+        "prefix_match": false,
         "quiet": false,
         "shell": null,
+        "shell_args": [],
         "tempdir" : null,
+        "trace_redact": [],
         "ignore_comments": false,
+        "list_order": null,
+        "normalize_recipe_names": false,
         "windows_powershell": false,
         "windows_shell": null,
       },
@@ -1070,18 +1359,33 @@ fn module() {
               }
             },
             "settings": {
+              "allow": [],
               "allow_duplicate_recipes": false,
               "allow_duplicate_variables": false,
+              "allow_missing_recipe_args": null,
+              "chooser": null,
+              "default": null,
               "dotenv_filename": null,
               "dotenv_load": null,
+              "dotenv_outside_project": null,
+              "dotenv_override": false,
               "dotenv_path": null,
+              "env_allow": null,
+              "env_deny": [],
               "export": false,
               "fallback": false,
+              "fallback_depth": null,
+              "parallel": false,
               "positional_arguments": false,
+              "prefix_match": false,
               "quiet": false,
               "shell": null,
+              "shell_args": [],
               "tempdir" : null,
+              "trace_redact": [],
               "ignore_comments": false,
+              "list_order": null,
+              "normalize_recipe_names": false,
               "windows_powershell": false,
               "windows_shell": null,
             },
@@ -1090,18 +1394,33 @@ fn module() {
         },
         "recipes": {},
         "settings": {
+          "allow": [],
           "allow_duplicate_recipes": false,
           "allow_duplicate_variables": false,
+          "allow_missing_recipe_args": null,
+          "chooser": null,
+          "default": null,
           "dotenv_filename": null,
           "dotenv_load": null,
+          "dotenv_outside_project": null,
+          "dotenv_override": false,
           "dotenv_path": null,
+          "env_allow": null,
+          "env_deny": [],
           "export": false,
           "fallback": false,
+          "fallback_depth": null,
+          "parallel": false,
           "positional_arguments": false,
+          "prefix_match": false,
           "quiet": false,
           "shell": null,
+          "shell_args": [],
           "tempdir" : null,
+          "trace_redact": [],
           "ignore_comments": false,
+          "list_order": null,
+          "normalize_recipe_names": false,
           "windows_powershell": false,
           "windows_shell": null,
         },
@@ -1111,3 +1430,70 @@ fn module() {
     ))
     .run();
 }
+
+#[test]
+fn lossless() {
+  Test::new()
+    .justfile(
+      "
+        # comment
+        foo := 'bar' # baz
+
+        # doc
+        bar:
+          echo bar
+      ",
+    )
+    .args(["--dump", "--dump-format", "json-lossless"])
+    .stdout(format!(
+      "{}\n",
+      serde_json::to_string(&json!({
+        "entries": [
+          {
+            "kind": "assignment",
+            "name": "foo",
+            "start": 0,
+            "end": 22,
+            "text": "# comment\nfoo := 'bar'",
+          },
+          {
+            "kind": "whitespace",
+            "name": null,
+            "start": 22,
+            "end": 23,
+            "text": " ",
+          },
+          {
+            "kind": "trailing-comment",
+            "name": null,
+            "start": 23,
+            "end": 28,
+            "text": "# baz",
+          },
+          {
+            "kind": "whitespace",
+            "name": null,
+            "start": 28,
+            "end": 30,
+            "text": "\n\n",
+          },
+          {
+            "kind": "recipe",
+            "name": "bar",
+            "start": 30,
+            "end": 51,
+            "text": "# doc\nbar:\n  echo bar",
+          },
+          {
+            "kind": "whitespace",
+            "name": null,
+            "start": 51,
+            "end": 52,
+            "text": "\n",
+          },
+        ],
+      }))
+      .unwrap()
+    ))
+    .run();
+}
@@ -1,3 +1,5 @@
+#![recursion_limit = "256"]
+
 pub(crate) use {
   crate::{
     assert_stdout::assert_stdout,
@@ -34,30 +36,47 @@ mod test;
 
 mod allow_duplicate_recipes;
 mod allow_duplicate_variables;
+mod allow_missing_recipe_args;
 mod assert_stdout;
 mod assert_success;
 mod assertions;
 mod attributes;
 mod backticks;
+mod block_comments;
+mod body_file;
 mod byte_order_mark;
 mod changelog;
+mod check;
+mod choices;
 mod choose;
+mod color;
 mod command;
 mod completions;
 mod conditional;
 mod confirm;
+mod continuations;
+mod default;
 mod delimiters;
+mod dependency_arguments;
+mod directives;
 mod directories;
 mod dotenv;
+mod dry_run;
+mod dump;
 mod edit;
+mod env_filter;
 mod equals;
 mod error_messages;
 mod evaluate;
 mod examples;
+mod exit_codes;
 mod export;
 mod fallback;
+mod find;
 mod fmt;
+mod forward_arguments;
 mod functions;
+mod groups;
 mod ignore_comments;
 mod imports;
 mod init;
@@ -66,6 +85,9 @@ mod interrupts;
 mod invocation_directory;
 mod json;
 mod line_prefixes;
+mod list_json;
+mod list_order;
+mod locations;
 mod man;
 mod misc;
 mod modules;
@@ -75,9 +97,16 @@ mod no_aliases;
 mod no_cd;
 mod no_dependencies;
 mod no_exit_message;
+mod no_list;
+mod normalize_recipe_names;
 mod os_attributes;
+mod overrides_file;
+mod parallel;
+mod parameter_patterns;
 mod parser;
+mod passthrough;
 mod positional_arguments;
+mod prefix_match;
 mod private;
 mod quiet;
 mod quote;
@@ -85,19 +114,29 @@ mod readme;
 mod recursion_limit;
 mod regexes;
 mod run;
+mod schema;
 mod search;
 mod search_arguments;
+mod secret;
+mod settings;
 mod shadowing_parameters;
 mod shebang;
 mod shell;
 mod show;
+mod silence;
 mod slash_operator;
 mod string;
 mod subsequents;
 mod summary;
 mod tempdir;
+mod timings;
+mod trace_evaluation;
+mod transcript;
 mod undefined_variables;
 mod unstable;
+mod usage;
+mod verbatim;
+mod warnings;
 #[cfg(target_family = "windows")]
 mod windows_shell;
 mod working_directory;
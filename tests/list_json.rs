@@ -0,0 +1,104 @@
+use super::*;
+
+#[test]
+fn list_json() {
+  Test::new()
+    .justfile(
+      "
+        alias b := build
+
+        # Build the project
+        [group('dev')]
+        [no-cd]
+        build target mode='release' *$flags=~'^-'='-v':
+
+        _hidden:
+
+        [no-list]
+        unlisted:
+
+        test:
+      ",
+    )
+    .args(["--list", "--json"])
+    .stdout(
+      r#"[{"aliases":["b"],"attributes":["group('dev')","no-cd"],"doc":"Build the project","groups":["dev"],"name":"build","parameters":[{"default":null,"export":false,"kind":"singular","name":"target","pattern":null},{"default":"'release'","export":false,"kind":"singular","name":"mode","pattern":null},{"default":"'-v'","export":true,"kind":"star","name":"flags","pattern":"^-"}]},{"aliases":[],"attributes":[],"doc":null,"groups":[],"name":"test","parameters":[]}]
+"#,
+    )
+    .run();
+}
+
+#[test]
+fn unsorted() {
+  Test::new()
+    .justfile(
+      "
+        b:
+        a:
+      ",
+    )
+    .args(["--list", "--json", "--unsorted"])
+    .stdout(
+      r#"[{"aliases":[],"attributes":[],"doc":null,"groups":[],"name":"b","parameters":[]},{"aliases":[],"attributes":[],"doc":null,"groups":[],"name":"a","parameters":[]}]
+"#,
+    )
+    .run();
+}
+
+#[test]
+fn no_aliases() {
+  Test::new()
+    .justfile(
+      "
+        alias f := foo
+        foo:
+      ",
+    )
+    .args(["--list", "--json", "--no-aliases"])
+    .stdout(
+      r#"[{"aliases":[],"attributes":[],"doc":null,"groups":[],"name":"foo","parameters":[]}]
+"#,
+    )
+    .run();
+}
+
+#[test]
+fn secret_defaults_are_masked() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        login password='hunter2':
+      ",
+    )
+    .args(["--list", "--json"])
+    .stdout(
+      r#"[{"aliases":[],"attributes":["secret"],"doc":null,"groups":[],"name":"login","parameters":[{"default":"***","export":false,"kind":"singular","name":"password","pattern":null}]}]
+"#,
+    )
+    .run();
+}
+
+#[test]
+fn submodules() {
+  Test::new()
+    .write("foo.just", "alias b := bar\nbar x:")
+    .justfile(
+      "
+        mod foo
+      ",
+    )
+    .test_round_trip(false)
+    .arg("--unstable")
+    .args(["--list", "--json"])
+    .stdout(
+      r#"[{"aliases":["foo::b"],"attributes":[],"doc":null,"groups":[],"name":"foo::bar","parameters":[{"default":null,"export":false,"kind":"singular","name":"x","pattern":null}]}]
+"#,
+    )
+    .run();
+}
+
+#[test]
+fn no_recipes() {
+  Test::new().args(["--list", "--json"]).stdout("[]\n").run();
+}
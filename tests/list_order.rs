@@ -0,0 +1,120 @@
+use super::*;
+
+#[test]
+fn alpha_by_default() {
+  Test::new()
+    .justfile(
+      "
+        b:
+        a:
+      ",
+    )
+    .args(["--list"])
+    .stdout(
+      "
+        Available recipes:
+            a
+            b
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn alpha() {
+  Test::new()
+    .justfile(
+      "
+        set list-order := 'alpha'
+
+        b:
+        a:
+      ",
+    )
+    .args(["--summary"])
+    .stdout("a b\n")
+    .run();
+}
+
+#[test]
+fn source() {
+  Test::new()
+    .justfile(
+      "
+        set list-order := 'source'
+
+        b:
+        c:
+        a:
+      ",
+    )
+    .args(["--list"])
+    .stdout(
+      "
+        Available recipes:
+            b
+            c
+            a
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn source_summary() {
+  Test::new()
+    .justfile(
+      "
+        set list-order := 'source'
+
+        b:
+        a:
+      ",
+    )
+    .args(["--summary"])
+    .stdout("b a\n")
+    .run();
+}
+
+#[test]
+fn unsorted_overrides_alpha() {
+  Test::new()
+    .justfile(
+      "
+        set list-order := 'alpha'
+
+        b:
+        a:
+      ",
+    )
+    .args(["--list", "--unsorted"])
+    .stdout(
+      "
+        Available recipes:
+            b
+            a
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn invalid_value() {
+  Test::new()
+    .justfile(
+      "
+        set list-order := 'reverse'
+      ",
+    )
+    .stderr(
+      "
+        error: Invalid value `reverse` for setting `list-order`, expected `alpha` or `source`
+         ——▶ justfile:1:19
+          │
+        1 │ set list-order := 'reverse'
+          │                   ^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,162 @@
+use super::*;
+
+#[test]
+fn list() {
+  Test::new()
+    .justfile(
+      "
+        # build stuff
+        build:
+          cargo build
+
+        test filter:
+          cargo test {{filter}}
+      ",
+    )
+    .args(["--list", "--locations"])
+    .stdout(
+      "
+        Available recipes:
+            build justfile:2       # build stuff
+            test filter justfile:5
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn list_aliases() {
+  Test::new()
+    .justfile(
+      "
+        alias b := build
+
+        build:
+          cargo build
+      ",
+    )
+    .args(["--list", "--locations"])
+    .stdout(
+      "
+        Available recipes:
+            build justfile:3
+            b justfile:3     # alias for `build`
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn list_modules() {
+  Test::new()
+    .write("foo.just", "\nbar:\n @echo BAR")
+    .justfile(
+      "
+        mod foo
+      ",
+    )
+    .test_round_trip(false)
+    .args(["--unstable", "--list", "--locations"])
+    .stdout(
+      "
+        Available recipes:
+            foo:
+                bar foo.just:2
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        x := 'a'
+
+        # build stuff
+        build:
+          cargo build
+      ",
+    )
+    .args(["--dump", "--locations"])
+    .stdout(
+      "
+        x := 'a'
+
+        # justfile:4
+        # build stuff
+        build:
+            cargo build
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn dump_json() {
+  let output = Test::new()
+    .write("foo.just", "\nbar:\n @echo BAR")
+    .justfile(
+      "
+        mod foo
+
+        # build stuff
+        build:
+          cargo build
+      ",
+    )
+    .test_round_trip(false)
+    .args([
+      "--unstable",
+      "--dump",
+      "--dump-format",
+      "json",
+      "--locations",
+    ])
+    .stdout_regex(".*\n")
+    .run();
+
+  let dump: Value = serde_json::from_str(&output.stdout).unwrap();
+
+  assert_eq!(dump["recipes"]["build"]["location"], "justfile:4");
+  assert_eq!(
+    dump["modules"]["foo"]["recipes"]["bar"]["location"],
+    "foo.just:2",
+  );
+}
+
+#[test]
+fn dump_json_recipe() {
+  Test::new()
+    .justfile(
+      "
+        x := 'a'
+
+        build:
+      ",
+    )
+    .args([
+      "--dump",
+      "--dump-format",
+      "json",
+      "--recipe",
+      "build",
+      "--locations",
+    ])
+    .stdout_regex(r#".*"location":"justfile:3".*\n"#)
+    .run();
+}
+
+#[test]
+fn dump_json_without_locations() {
+  let output = Test::new()
+    .justfile("build:")
+    .args(["--dump", "--dump-format", "json"])
+    .stdout_regex(".*\n")
+    .run();
+
+  let dump: Value = serde_json::from_str(&output.stdout).unwrap();
+
+  assert_eq!(dump["recipes"]["build"].get("location"), None);
+}
@@ -65,6 +65,35 @@ test! {
   stderr: "echo bar\n",
 }
 
+test! {
+  name: alias_to_alias,
+  justfile: "foo:\n  echo foo\nalias f := foo\nalias ff := f",
+  args: ("ff"),
+  stdout: "foo\n",
+  stderr: "echo foo\n",
+}
+
+test! {
+  name: circular_alias,
+  justfile: "alias a := b\nalias b := a\n",
+  stderr: "
+    error: Alias `a` has circular target `a -> b -> a`
+     ——▶ justfile:1:7
+      │
+    1 │ alias a := b
+      │       ^
+     ——▶ justfile:1:12
+      │
+    1 │ alias a := b
+      │            ^
+     ——▶ justfile:2:12
+      │
+    2 │ alias b := a
+      │            ^
+  ",
+  status: EXIT_FAILURE,
+}
+
 test! {
   name: bad_setting,
   justfile: "
@@ -80,6 +109,22 @@ test! {
   status: EXIT_FAILURE,
 }
 
+test! {
+  name: bad_setting_with_suggestion,
+  justfile: "
+    set exprt
+  ",
+  stderr: "
+  error: Unknown setting `exprt`
+  Did you mean `export`?
+   ——▶ justfile:1:5
+    │
+  1 │ set exprt
+    │     ^^^^^
+  ",
+  status: EXIT_FAILURE,
+}
+
 test! {
   name: bad_setting_with_keyword_name,
   justfile: "
@@ -1163,6 +1208,7 @@ bar:"#,
   args:     ("bar"),
   stdout:   "",
   stderr:   r#"error: Call to unknown function `foo`
+Did you mean `os`?
  ——▶ justfile:1:8
   │
 1 │ foo := foo() + "hello"
@@ -1284,7 +1330,7 @@ test! {
   justfile: "foo 'bar'",
   args:     ("foo"),
   stdout:   "",
-  stderr:   "error: Expected '*', ':', '$', identifier, or '+', but found string
+  stderr:   "error: Expected '*', ':', '$', identifier, '--', or '+', but found string
  ——▶ justfile:1:5
   │
 1 │ foo 'bar'
@@ -1317,6 +1363,18 @@ test! {
   │
 4 │ d: a
   │    ^
+ ——▶ justfile:1:4
+  │
+1 │ a: b
+  │    ^
+ ——▶ justfile:2:4
+  │
+2 │ b: c
+  │    ^
+ ——▶ justfile:3:4
+  │
+3 │ c: d
+  │    ^
 ",
   status:   EXIT_FAILURE,
 }
@@ -1331,6 +1389,10 @@ test! {
   │
 1 │ z := z
   │ ^
+ ——▶ justfile:1:6
+  │
+1 │ z := z
+  │      ^
 ",
   status:   EXIT_FAILURE,
 }
@@ -1345,6 +1407,18 @@ test! {
   │
 1 │ x := y
   │ ^
+ ——▶ justfile:1:6
+  │
+1 │ x := y
+  │      ^
+ ——▶ justfile:2:6
+  │
+2 │ y := z
+  │      ^
+ ——▶ justfile:3:6
+  │
+3 │ z := x
+  │      ^
 ",
   status:   EXIT_FAILURE,
 }
@@ -1365,6 +1439,14 @@ test! {
   │
 2 │ x := y
   │ ^
+ ——▶ justfile:2:6
+  │
+2 │ x := y
+  │      ^
+ ——▶ justfile:3:6
+  │
+3 │ y := x
+  │      ^
 ",
   status:   EXIT_FAILURE,
 }
@@ -1465,7 +1547,7 @@ foo *a +b:
   echo {{a}} {{b}}
 ",
   stdout:   "",
-  stderr:   "error: Expected \':\' or \'=\', but found \'+\'
+  stderr:   "error: Expected \':\', \'=\', or \'=~\', but found \'+\'
  ——▶ justfile:1:8
   │
 1 │ foo *a +b:
@@ -1481,7 +1563,7 @@ foo +a *b:
   echo {{a}} {{b}}
 ",
   stdout:   "",
-  stderr:   "error: Expected \':\' or \'=\', but found \'*\'
+  stderr:   "error: Expected \':\', \'=\', or \'=~\', but found \'*\'
  ——▶ justfile:1:8
   │
 1 │ foo +a *b:
@@ -1697,6 +1779,20 @@ test! {
    status:   EXIT_FAILURE,
 }
 
+test! {
+  name:     unknown_function_with_suggestion,
+  justfile: "foo := replce('bar', 'a', 'o')",
+  stderr:   "
+    error: Call to unknown function `replce`
+    Did you mean `replace`?
+     ——▶ justfile:1:8
+      │
+    1 │ foo := replce('bar', 'a', 'o')
+      │        ^^^^^^
+  ",
+  status:   EXIT_FAILURE,
+}
+
 test! {
    name:     unknown_function_in_default,
    justfile: "
@@ -1890,7 +1986,7 @@ test! {
       echo {{foo}}
   ",
   stderr: "
-    error: Expected '*', ':', '$', identifier, or '+', but found '='
+    error: Expected '*', ':', '$', identifier, '--', or '+', but found '='
      ——▶ justfile:1:5
       │
     1 │ foo = 'bar'
@@ -2126,6 +2222,61 @@ test! {
   shell: false,
 }
 
+test! {
+  name: variables_verbose,
+  justfile: "
+    # the zed
+    z := 'a'
+
+    # not a doc comment
+
+    abc := 'z'
+    # exported
+    export b := 'y'
+  ",
+  args: ("--variables", "--verbose"),
+  stdout: "
+    abc := 'z'
+    b   := 'y' # exported
+    z   := 'a' # the zed
+  ",
+  stderr: "",
+  shell: false,
+}
+
+test! {
+  name: variables_verbose_align_documented_values,
+  justfile: "
+    # short
+    a := 'x'
+    bb := 'a much longer value'
+    # long
+    c := 'xyz'
+  ",
+  args: ("--variables", "--verbose"),
+  stdout: "
+    a  := 'x'   # short
+    bb := 'a much longer value'
+    c  := 'xyz' # long
+  ",
+  stderr: "",
+  shell: false,
+}
+
+test! {
+  name: variables_verbose_colors,
+  justfile: "
+    # doc
+    a := 'x'
+  ",
+  args: ("--variables", "--verbose", "--color", "always"),
+  stdout: "
+    \u{1b}[36ma\u{1b}[0m := \u{1b}[32m'x'\u{1b}[0m \u{1b}[34m#\u{1b}[0m \u{1b}[34mdoc\u{1b}[0m
+  ",
+  stderr: "",
+  shell: false,
+}
+
 test! {
   name: interpolation_evaluation_ignore_quiet,
   justfile: r#"
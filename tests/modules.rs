@@ -534,6 +534,76 @@ fn list_displays_recipes_in_submodules() {
     .run();
 }
 
+#[test]
+fn list_flat_displays_qualified_recipe_names() {
+  Test::new()
+    .write("foo.just", "mod bar\n\n# foo baz\nbaz:\n @echo FOO")
+    .write("bar.just", "qux:\n @echo BAR")
+    .justfile(
+      "
+        # root
+        root:
+
+        mod foo
+      ",
+    )
+    .test_round_trip(false)
+    .arg("--unstable")
+    .arg("--list")
+    .arg("--list-flat")
+    .stdout(
+      "
+      Available recipes:
+          root          # root
+          foo::baz      # foo baz
+          foo::bar::qux
+    ",
+    )
+    .run();
+}
+
+#[test]
+fn list_flat_includes_module_aliases() {
+  Test::new()
+    .write("foo.just", "alias b := bar\n\nbar:\n @echo FOO")
+    .justfile(
+      "
+        mod foo
+      ",
+    )
+    .test_round_trip(false)
+    .arg("--unstable")
+    .arg("--list")
+    .arg("--list-flat")
+    .stdout(
+      "
+      Available recipes:
+          foo::bar
+          foo::b   # alias for `bar`
+    ",
+    )
+    .run();
+}
+
+#[test]
+fn summary_displays_qualified_recipe_names() {
+  Test::new()
+    .write("foo.just", "mod bar\n\nbaz:\n @echo FOO")
+    .write("bar.just", "qux:\n @echo BAR")
+    .justfile(
+      "
+        root:
+
+        mod foo
+      ",
+    )
+    .test_round_trip(false)
+    .arg("--unstable")
+    .arg("--summary")
+    .stdout("root foo::baz foo::bar::qux\n")
+    .run();
+}
+
 #[test]
 fn root_dotenv_is_available_to_submodules() {
   Test::new()
@@ -0,0 +1,96 @@
+use super::*;
+
+#[test]
+fn no_list_recipes_are_not_listed() {
+  Test::new()
+    .justfile(
+      "
+        [no-list]
+        foo:
+
+        bar:
+      ",
+    )
+    .arg("--list")
+    .stdout(
+      "
+        Available recipes:
+            bar
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn no_list_recipes_are_summarized() {
+  Test::new()
+    .justfile(
+      "
+        [no-list]
+        foo:
+
+        bar:
+      ",
+    )
+    .arg("--summary")
+    .stdout("bar foo\n")
+    .run();
+}
+
+#[test]
+fn no_list_recipes_can_be_run() {
+  Test::new()
+    .justfile(
+      "
+        [no-list]
+        foo:
+          echo foo
+      ",
+    )
+    .arg("foo")
+    .stdout("foo\n")
+    .stderr("echo foo\n")
+    .run();
+}
+
+#[test]
+fn no_list_recipes_are_not_chosen() {
+  Test::new()
+    .justfile(
+      "
+        [no-list]
+        foo:
+          echo foo
+
+        bar:
+          echo bar
+      ",
+    )
+    .args(["--choose", "--chooser", "cat"])
+    .stdout("bar\n")
+    .stderr("echo bar\n")
+    .run();
+}
+
+#[test]
+fn no_list_aliases_are_not_listed() {
+  Test::new()
+    .justfile(
+      "
+        alias f := foo
+
+        [no-list]
+        foo:
+
+        bar:
+      ",
+    )
+    .arg("--list")
+    .stdout(
+      "
+        Available recipes:
+            bar
+      ",
+    )
+    .run();
+}
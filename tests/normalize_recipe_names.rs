@@ -0,0 +1,151 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  set normalize-recipe-names
+
+  build-all:
+    @echo build-all
+";
+
+#[test]
+fn camel_case() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("buildAll")
+    .stdout("build-all\n")
+    .run();
+}
+
+#[test]
+fn snake_case() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("build_all")
+    .stdout("build-all\n")
+    .run();
+}
+
+#[test]
+fn kebab_case() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("build-all")
+    .stdout("build-all\n")
+    .run();
+}
+
+#[test]
+fn upper_case() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("BUILD_ALL")
+    .stdout("build-all\n")
+    .run();
+}
+
+#[test]
+fn disabled_by_default() {
+  Test::new()
+    .justfile(
+      "
+        build-all:
+          @echo build-all
+      ",
+    )
+    .arg("BUILD_ALL")
+    .stderr("error: Justfile does not contain recipe `BUILD_ALL`.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn aliases() {
+  Test::new()
+    .justfile(
+      "
+        set normalize-recipe-names
+
+        alias build-all := build
+
+        build:
+          @echo build
+      ",
+    )
+    .arg("buildAll")
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn ambiguous() {
+  Test::new()
+    .justfile(
+      "
+        set normalize-recipe-names
+
+        build-all:
+        build_all:
+      ",
+    )
+    .arg("buildAll")
+    .stderr("error: Recipe name `buildAll` is ambiguous, could be `build-all` or `build_all`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn exact_match_preferred() {
+  Test::new()
+    .justfile(
+      "
+        set normalize-recipe-names
+
+        build-all:
+          @echo build-all
+
+        build_all:
+          @echo build_all
+      ",
+    )
+    .arg("build_all")
+    .stdout("build_all\n")
+    .run();
+}
+
+#[test]
+fn suggestions_use_normalized_names() {
+  Test::new()
+    .justfile(
+      "
+        set normalize-recipe-names
+
+        build-all-targets:
+      ",
+    )
+    .arg("BuildAllTarget")
+    .stderr(
+      "
+        error: Justfile does not contain recipe `BuildAllTarget`.
+        Did you mean `build-all-targets`?
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn with_prefix_match() {
+  Test::new()
+    .justfile(
+      "
+        set normalize-recipe-names
+        set prefix-match
+
+        build-all:
+          @echo build-all
+      ",
+    )
+    .arg("buildA")
+    .stdout("build-all\n")
+    .run();
+}
@@ -101,3 +101,227 @@ fn none() {
     .stderr("echo bar\n")
     .run();
 }
+
+#[test]
+fn bsds() {
+  Test::new()
+    .justfile(
+      "
+      [freebsd]
+      foo:
+        echo freebsd
+
+      [openbsd]
+      foo:
+        echo openbsd
+
+      [windows, linux, macos]
+      foo:
+        echo other
+    ",
+    )
+    .stdout(if cfg!(target_os = "freebsd") {
+      "freebsd\n"
+    } else if cfg!(target_os = "openbsd") {
+      "openbsd\n"
+    } else {
+      "other\n"
+    })
+    .stderr(if cfg!(target_os = "freebsd") {
+      "echo freebsd\n"
+    } else if cfg!(target_os = "openbsd") {
+      "echo openbsd\n"
+    } else {
+      "echo other\n"
+    })
+    .run();
+}
+
+#[test]
+fn arch() {
+  Test::new()
+    .justfile(
+      "
+      [arch('aarch64')]
+      foo:
+        echo aarch64
+
+      [arch('x86_64', 'x86')]
+      foo:
+        echo x86
+    ",
+    )
+    .stdout(if cfg!(target_arch = "aarch64") {
+      "aarch64\n"
+    } else if cfg!(any(target_arch = "x86_64", target_arch = "x86")) {
+      "x86\n"
+    } else {
+      panic!("unexpected architecture")
+    })
+    .stderr(if cfg!(target_arch = "aarch64") {
+      "echo aarch64\n"
+    } else if cfg!(any(target_arch = "x86_64", target_arch = "x86")) {
+      "echo x86\n"
+    } else {
+      panic!("unexpected architecture")
+    })
+    .run();
+}
+
+#[test]
+fn arch_and_os_must_both_match() {
+  Test::new()
+    .justfile(
+      "
+      [unix, windows]
+      [arch('not-an-architecture')]
+      foo:
+        echo foo
+
+      bar:
+        echo bar
+    ",
+    )
+    .stdout("bar\n")
+    .stderr("echo bar\n")
+    .run();
+}
+
+#[test]
+fn arch_requires_argument() {
+  Test::new()
+    .justfile(
+      "
+      [arch]
+      foo:
+    ",
+    )
+    .stderr(
+      "
+      error: Attribute `arch` requires an argument
+       ——▶ justfile:1:2
+        │
+      1 │ [arch]
+        │  ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn wsl_is_more_specific_than_linux() {
+  let wsl = cfg!(target_os = "linux")
+    && fs::read_to_string("/proc/sys/kernel/osrelease").map_or(false, |release| {
+      release.to_lowercase().contains("microsoft")
+    });
+
+  Test::new()
+    .justfile(
+      "
+      [wsl]
+      foo:
+        echo wsl
+
+      [linux]
+      foo:
+        echo linux
+
+      [macos, windows, freebsd, openbsd]
+      foo:
+        echo other
+    ",
+    )
+    .stdout(if wsl {
+      "wsl\n"
+    } else if cfg!(target_os = "linux") {
+      "linux\n"
+    } else {
+      "other\n"
+    })
+    .stderr(if wsl {
+      "echo wsl\n"
+    } else if cfg!(target_os = "linux") {
+      "echo linux\n"
+    } else {
+      "echo other\n"
+    })
+    .run();
+}
+
+#[test]
+fn os_is_more_specific_than_unix() {
+  Test::new()
+    .justfile(
+      "
+      [unix]
+      foo:
+        echo unix
+
+      [linux, macos, windows]
+      foo:
+        echo os
+    ",
+    )
+    .stdout(
+      if cfg!(any(target_os = "linux", target_os = "macos", windows)) {
+        "os\n"
+      } else {
+        "unix\n"
+      },
+    )
+    .stderr(
+      if cfg!(any(target_os = "linux", target_os = "macos", windows)) {
+        "echo os\n"
+      } else {
+        "echo unix\n"
+      },
+    )
+    .run();
+}
+
+#[test]
+fn arch_is_more_specific_than_none() {
+  Test::new()
+    .justfile(format!(
+      "
+      foo:
+        echo any
+
+      [arch('{}')]
+      foo:
+        echo arch
+    ",
+      env::consts::ARCH,
+    ))
+    .stdout("arch\n")
+    .stderr("echo arch\n")
+    .run();
+}
+
+#[test]
+fn equally_specific_recipes_are_duplicates() {
+  Test::new()
+    .justfile(
+      "
+      [unix, windows]
+      foo:
+        echo a
+
+      [windows, unix]
+      foo:
+        echo b
+    ",
+    )
+    .stderr(
+      "
+      error: Recipe `foo` first defined on line 2 is redefined on line 6
+       ——▶ justfile:6:1
+        │
+      6 │ foo:
+        │ ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
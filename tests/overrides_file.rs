@@ -0,0 +1,88 @@
+use super::*;
+
+#[test]
+fn overrides_variables() {
+  Test::new()
+    .justfile(
+      "
+        x := 'a'
+        y := 'b'
+        z := 'c'
+
+        @foo:
+          echo {{ x }} {{ y }} {{ z }}
+      ",
+    )
+    .write("vars.json", r#"{"x": "d", "y": 1, "z": true}"#)
+    .args(["--overrides-file", "vars.json"])
+    .stdout("d 1 true\n")
+    .run();
+}
+
+#[test]
+fn command_line_overrides_take_precedence() {
+  Test::new()
+    .justfile(
+      "
+        x := 'a'
+        y := 'b'
+
+        @foo:
+          echo {{ x }} {{ y }}
+      ",
+    )
+    .write("vars.json", r#"{"x": "c", "y": "d"}"#)
+    .args(["--overrides-file", "vars.json", "--set", "x", "e", "y=f"])
+    .stdout("e f\n")
+    .run();
+}
+
+#[test]
+fn unknown_variable() {
+  Test::new()
+    .justfile(
+      "
+        @foo:
+          echo foo
+      ",
+    )
+    .write("vars.json", r#"{"x": "a"}"#)
+    .args(["--overrides-file", "vars.json"])
+    .stderr("error: Variable `x` overridden on the command line but not present in justfile\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn missing_file() {
+  Test::new()
+    .justfile("foo:")
+    .args(["--overrides-file", "vars.json"])
+    .stderr_regex("error: Failed to read overrides file `vars.json`: .*\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn not_an_object() {
+  Test::new()
+    .justfile("foo:")
+    .write("vars.json", r#"["a"]"#)
+    .args(["--overrides-file", "vars.json"])
+    .stderr("error: Invalid overrides file `vars.json`: expected a JSON object\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn invalid_value() {
+  Test::new()
+    .justfile("foo:")
+    .write("vars.json", r#"{"x": ["a"]}"#)
+    .args(["--overrides-file", "vars.json"])
+    .stderr(
+      "error: Invalid overrides file `vars.json`: value of `x` must be a string, number, or boolean\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
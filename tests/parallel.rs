@@ -0,0 +1,254 @@
+use super::*;
+
+#[test]
+fn setting_runs_priors_concurrently() {
+  Test::new()
+    .justfile(
+      "
+        set parallel
+
+        wait := 'for i in $(seq 100); do [ -f $0 ] && exit 0; sleep 0.1; done; exit 1'
+
+        foo: a b
+          @echo foo
+
+        a:
+          @touch a
+          @sh -c '{{ wait }}' b
+
+        b:
+          @touch b
+          @sh -c '{{ wait }}' a
+      ",
+    )
+    .args(["--jobs", "2"])
+    .stdout("foo\n")
+    .run();
+}
+
+#[test]
+fn attribute_runs_priors_concurrently() {
+  Test::new()
+    .justfile(
+      "
+        wait := 'for i in $(seq 100); do [ -f $0 ] && exit 0; sleep 0.1; done; exit 1'
+
+        [parallel]
+        foo: a b
+          @echo foo
+
+        a:
+          @touch a
+          @sh -c '{{ wait }}' b
+
+        b:
+          @touch b
+          @sh -c '{{ wait }}' a
+      ",
+    )
+    .args(["--jobs", "2"])
+    .stdout("foo\n")
+    .run();
+}
+
+#[test]
+fn output_is_buffered_per_dependency() {
+  Test::new()
+    .justfile(
+      "
+        wait := 'for i in $(seq 100); do [ -f $0 ] && exit 0; sleep 0.1; done; exit 1'
+
+        [parallel]
+        foo: a b
+          @echo foo
+
+        a:
+          @echo a1
+          @touch a
+          @sh -c '{{ wait }}' b
+          @echo a2
+
+        b:
+          @sh -c '{{ wait }}' a
+          @echo b1
+          @touch b
+          @echo b2
+      ",
+    )
+    .args(["--jobs", "2"])
+    .stdout_regex("(a1\na2\nb1\nb2\n|b1\nb2\na1\na2\n)foo\n")
+    .run();
+}
+
+#[test]
+fn echoed_lines_are_buffered() {
+  Test::new()
+    .justfile(
+      "
+        [parallel]
+        foo: a
+          @echo foo
+
+        a:
+          echo a
+      ",
+    )
+    .stdout("a\nfoo\n")
+    .stderr("echo a\n")
+    .run();
+}
+
+#[test]
+fn shared_dependencies_run_once() {
+  Test::new()
+    .justfile(
+      "
+        [parallel]
+        foo: a b
+          @echo foo
+
+        a: c
+          @echo a
+
+        b: c
+          @echo b
+
+        c:
+          @echo c
+      ",
+    )
+    .stdout_regex("(c\na\nb\n|c\nb\na\n|a\nc\nb\n|b\nc\na\n)foo\n")
+    .run();
+}
+
+#[test]
+fn dependency_failure() {
+  Test::new()
+    .justfile(
+      "
+        [parallel]
+        foo: a b
+          @echo foo
+
+        a:
+          @exit 3
+
+        b:
+          @echo b
+      ",
+    )
+    .stdout("b\n")
+    .stderr("error: Recipe `a` failed on line 6 with exit code 3\n")
+    .status(3)
+    .run();
+}
+
+#[test]
+fn waiting_on_failed_dependency() {
+  Test::new()
+    .justfile(
+      "
+        [parallel]
+        foo: a b
+          @echo foo
+
+        a: c
+
+        b: c
+
+        c:
+          @exit 3
+      ",
+    )
+    .stderr("error: Recipe `c` failed on line 10 with exit code 3\n")
+    .status(3)
+    .run();
+}
+
+#[test]
+fn subsequents_are_not_parallel() {
+  Test::new()
+    .justfile(
+      "
+        [parallel]
+        foo: && a b
+          @echo foo
+
+        a:
+          @echo a
+
+        b:
+          @echo b
+      ",
+    )
+    .stdout("foo\na\nb\n")
+    .run();
+}
+
+#[test]
+fn dependencies_are_sequential_by_default() {
+  Test::new()
+    .justfile(
+      "
+        foo: a b
+
+        a:
+          @sleep 0.2
+          @echo a
+
+        b:
+          @echo b
+      ",
+    )
+    .stdout("a\nb\n")
+    .run();
+}
+
+#[test]
+fn jobs_limits_concurrency() {
+  Test::new()
+    .justfile(
+      "
+        [parallel]
+        foo: a b
+          @echo foo
+
+        a:
+          @mkdir lock
+          @sleep 0.2
+          @echo a
+          @rmdir lock
+
+        b:
+          @mkdir lock
+          @sleep 0.2
+          @echo b
+          @rmdir lock
+      ",
+    )
+    .args(["--jobs", "1", "foo"])
+    .stdout_regex("(a\nb\n|b\na\n)foo\n")
+    .run();
+}
+
+#[test]
+fn waiting_recipes_do_not_hold_jobs() {
+  Test::new()
+    .justfile(
+      "
+        [parallel]
+        foo: a b
+          @echo foo
+
+        a: c
+
+        b: c
+
+        c:
+          @echo c
+      ",
+    )
+    .args(["--jobs", "1", "foo"])
+    .stdout("c\nfoo\n")
+    .run();
+}
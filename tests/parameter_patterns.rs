@@ -0,0 +1,132 @@
+use super::*;
+
+#[test]
+fn matching_argument() {
+  Test::new()
+    .justfile(
+      r"
+        release version=~'^v\d+$':
+          echo {{ version }}
+      ",
+    )
+    .args(["release", "v1"])
+    .stdout("v1\n")
+    .stderr("echo v1\n")
+    .run();
+}
+
+#[test]
+fn mismatched_argument() {
+  Test::new()
+    .justfile(
+      r"
+        release version=~'^v\d+$':
+          echo {{ version }}
+      ",
+    )
+    .args(["release", "1"])
+    .stderr(
+      r"
+        error: Argument `1` for parameter `version` does not match pattern `^v\d+$`
+         ——▶ justfile:1:9
+          │
+        1 │ release version=~'^v\d+$':
+          │         ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn pattern_with_default() {
+  Test::new()
+    .justfile(
+      "
+        foo bar=~'^[a-z]+$'='BAZ':
+          echo {{ bar }}
+      ",
+    )
+    .stdout("BAZ\n")
+    .stderr("echo BAZ\n")
+    .run();
+}
+
+#[test]
+fn variadic_arguments_are_checked_individually() {
+  Test::new()
+    .justfile(
+      "
+        foo +bar=~'^[a-z]+$':
+          echo {{ bar }}
+      ",
+    )
+    .args(["foo", "a", "b", "C"])
+    .stderr(
+      "
+        error: Argument `C` for parameter `bar` does not match pattern `^[a-z]+$`
+         ——▶ justfile:1:6
+          │
+        1 │ foo +bar=~'^[a-z]+$':
+          │      ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dependency_arguments_are_checked() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar 'x')
+
+        bar baz=~'^y$':
+          echo {{ baz }}
+      ",
+    )
+    .stderr(
+      "
+        error: Argument `x` for parameter `baz` does not match pattern `^y$`
+         ——▶ justfile:3:5
+          │
+        3 │ bar baz=~'^y$':
+          │     ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn invalid_pattern() {
+  Test::new()
+    .justfile(
+      "
+        foo bar=~'(':
+      ",
+    )
+    .stderr_regex("(?s)error: Invalid pattern for parameter `bar`: .*")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        foo bar=~'^[a-z]+$'='baz':
+          echo {{ bar }}
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        foo bar=~'^[a-z]+$'='baz':
+            echo {{ bar }}
+      ",
+    )
+    .run();
+}
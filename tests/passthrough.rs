@@ -0,0 +1,193 @@
+use super::*;
+
+#[test]
+fn arguments_after_double_dash_are_passed_verbatim() {
+  Test::new()
+    .justfile(
+      "
+        test --args:
+          echo {{ args }}
+      ",
+    )
+    .args(["test", "--", "--release", "foo=bar"])
+    .stdout("--release foo=bar\n")
+    .stderr("echo --release foo=bar\n")
+    .run();
+}
+
+#[test]
+fn flags_after_double_dash_are_passed_verbatim() {
+  Test::new()
+    .justfile(
+      "
+        test --args:
+          @echo {{ args }}
+      ",
+    )
+    .args(["test", "--", "--list", "--", "-v"])
+    .stdout("--list -- -v\n")
+    .run();
+}
+
+#[test]
+fn with_other_parameters() {
+  Test::new()
+    .justfile(
+      "
+        test target --args:
+          echo {{ target }} {{ args }}
+      ",
+    )
+    .args(["test", "x86", "--", "-v"])
+    .stdout("x86 -v\n")
+    .stderr("echo x86 -v\n")
+    .run();
+}
+
+#[test]
+fn empty_without_double_dash() {
+  Test::new()
+    .justfile(
+      "
+        test --args:
+          @echo 'args: {{ args }}'
+      ",
+    )
+    .args(["test"])
+    .stdout("args: \n")
+    .run();
+}
+
+#[test]
+fn default() {
+  Test::new()
+    .justfile(
+      "
+        test --args='--quiet':
+          echo {{ args }}
+      ",
+    )
+    .args(["test"])
+    .stdout("--quiet\n")
+    .stderr("echo --quiet\n")
+    .run();
+}
+
+#[test]
+fn positional_arguments() {
+  Test::new()
+    .justfile(
+      "
+        set positional-arguments
+
+        test --args:
+          @printf '%s\\n' \"$@\"
+      ",
+    )
+    .args(["test", "--", "a b", "c"])
+    .stdout("a b\nc\n")
+    .run();
+}
+
+#[test]
+fn exported() {
+  Test::new()
+    .justfile(
+      "
+        test --$ARGS:
+          @echo $ARGS
+      ",
+    )
+    .args(["test", "--", "-k", "slow"])
+    .stdout("-k slow\n")
+    .run();
+}
+
+#[test]
+fn only_recipe_before_double_dash_receives_arguments() {
+  Test::new()
+    .justfile(
+      "
+        build --args:
+          @echo 'build: {{ args }}'
+
+        test --args:
+          @echo 'test: {{ args }}'
+      ",
+    )
+    .args(["build", "test", "--", "-v"])
+    .stdout("build: \ntest: -v\n")
+    .run();
+}
+
+#[test]
+fn recipes_without_passthrough_parameter_take_arguments_after_double_dash() {
+  Test::new()
+    .justfile(
+      "
+        foo a:
+          @echo {{ a }}
+      ",
+    )
+    .args(["foo", "--", "bar"])
+    .stdout("bar\n")
+    .run();
+}
+
+#[test]
+fn parameter_may_not_follow_passthrough_parameter() {
+  Test::new()
+    .justfile(
+      "
+        test --args target:
+      ",
+    )
+    .stderr(
+      "
+        error: Parameter `target` follows variadic parameter
+         ——▶ justfile:1:13
+          │
+        1 │ test --args target:
+          │             ^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn list() {
+  Test::new()
+    .justfile(
+      "
+        test target --args:
+      ",
+    )
+    .args(["--list"])
+    .stdout(
+      "
+        Available recipes:
+            test target --args
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        test --args:
+          echo {{ args }}
+      ",
+    )
+    .args(["--dump"])
+    .stdout(
+      "
+        test --args:
+            echo {{ args }}
+      ",
+    )
+    .run();
+}
@@ -0,0 +1,137 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  set prefix-match
+
+  build:
+    @echo build
+
+  bundle:
+    @echo bundle
+
+  test:
+    @echo test
+";
+
+#[test]
+fn unique_prefix() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("bui")
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn exact_match_preferred() {
+  Test::new()
+    .justfile(
+      "
+        set prefix-match
+
+        b:
+          @echo b
+
+        build:
+          @echo build
+      ",
+    )
+    .arg("b")
+    .stdout("b\n")
+    .run();
+}
+
+#[test]
+fn ambiguous_prefix() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("bu")
+    .stderr("error: Recipe prefix `bu` is ambiguous, could be `build` or `bundle`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn alias_prefix() {
+  Test::new()
+    .justfile(
+      "
+        set prefix-match
+
+        alias check := test
+
+        test:
+          @echo test
+      ",
+    )
+    .arg("ch")
+    .stdout("test\n")
+    .run();
+}
+
+#[test]
+fn alias_and_target_are_not_ambiguous() {
+  Test::new()
+    .justfile(
+      "
+        set prefix-match
+
+        alias tst := test
+
+        test:
+          @echo test
+      ",
+    )
+    .arg("t")
+    .stdout("test\n")
+    .run();
+}
+
+#[test]
+fn private_recipes_are_not_matched() {
+  Test::new()
+    .justfile(
+      "
+        set prefix-match
+
+        [private]
+        build:
+          @echo build
+      ",
+    )
+    .arg("bu")
+    .stderr("error: Justfile does not contain recipe `bu`.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn disabled_by_default() {
+  Test::new()
+    .justfile(
+      "
+        build:
+          @echo build
+      ",
+    )
+    .arg("bu")
+    .stderr("error: Justfile does not contain recipe `bu`.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn with_arguments() {
+  Test::new()
+    .justfile(
+      "
+        set prefix-match
+
+        build target:
+          @echo {{target}}
+      ",
+    )
+    .args(["b", "release"])
+    .stdout("release\n")
+    .run();
+}
@@ -237,3 +237,90 @@ fn quiet_setting_with_no_quiet_attribute_and_quiet_line() {
     .stdout("FOO\n")
     .run();
 }
+
+#[test]
+fn quiet_attribute_suppresses_echo_and_output() {
+  Test::new()
+    .justfile(
+      "
+      [quiet]
+      foo:
+        echo FOO
+        echo BAR 1>&2
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn quiet_attribute_only_applies_to_recipe() {
+  Test::new()
+    .justfile(
+      "
+      foo: bar
+        echo FOO
+
+      [quiet]
+      bar:
+        echo BAR
+      ",
+    )
+    .stdout("FOO\n")
+    .stderr("echo FOO\n")
+    .run();
+}
+
+#[test]
+fn verbose_attribute_prints_banner() {
+  Test::new()
+    .justfile(
+      "
+      [verbose]
+      foo:
+        @echo FOO
+      ",
+    )
+    .stdout("FOO\n")
+    .stderr("===> Running recipe `foo`...\necho FOO\n")
+    .run();
+}
+
+#[test]
+fn verbose_attribute_overrides_quiet_flag() {
+  Test::new()
+    .arg("--quiet")
+    .justfile(
+      "
+      [verbose]
+      foo:
+        echo FOO
+      ",
+    )
+    .stdout("FOO\n")
+    .stderr("===> Running recipe `foo`...\necho FOO\n")
+    .run();
+}
+
+#[test]
+fn quiet_and_verbose_attributes_conflict() {
+  Test::new()
+    .justfile(
+      "
+      [quiet]
+      [verbose]
+      foo:
+        echo FOO
+      ",
+    )
+    .stderr(
+      "
+      error: Recipe `foo` has conflicting attributes `quiet` and `verbose`
+       ——▶ justfile:3:1
+        │
+      3 │ foo:
+        │ ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,123 @@
+use {
+  super::*,
+  jsonschema::{Draft, JSONSchema},
+};
+
+const SCHEMA: &str = include_str!("../schema.json");
+
+fn validate(instance: &Value) -> Result<(), String> {
+  let schema: Value = serde_json::from_str(SCHEMA).unwrap();
+
+  let schema = JSONSchema::options()
+    .with_draft(Draft::Draft202012)
+    .compile(&schema)
+    .unwrap();
+
+  schema.validate(instance).map_err(|errors| {
+    errors
+      .map(|error| format!("{}: {error}", error.instance_path))
+      .collect::<Vec<String>>()
+      .join("\n")
+  })
+}
+
+fn dump(test: Test) -> Value {
+  let output = test
+    .args(["--dump", "--dump-format", "json", "--unstable"])
+    .stdout_regex(".*\n")
+    .run();
+
+  serde_json::from_str(&output.stdout).unwrap()
+}
+
+fn assert_valid(test: Test) {
+  let instance = dump(test);
+  if let Err(error) = validate(&instance) {
+    panic!("dump does not match schema: {error}\n{instance}");
+  }
+}
+
+#[test]
+fn dump_schema() {
+  Test::new()
+    .no_justfile()
+    .arg("--dump-schema")
+    .stdout(SCHEMA)
+    .unindent_stdout(false)
+    .test_round_trip(false)
+    .run();
+}
+
+#[test]
+fn schema_is_valid_json() {
+  serde_json::from_str::<Value>(SCHEMA).unwrap();
+}
+
+#[test]
+fn empty_justfile_matches_schema() {
+  assert_valid(Test::new().justfile(""));
+}
+
+#[test]
+fn justfile_matches_schema() {
+  assert_valid(Test::new().justfile(
+    "
+      set allow-missing-recipe-args := 'prompt'
+      set dotenv-load
+      set dotenv-outside-project := 'error'
+      set export
+      set list-order := 'source'
+      set shell := ['bash', '-c']
+      set windows-shell := ['powershell.exe', '-c']
+      set tempdir := '/tmp'
+
+      export a := 'a' + `echo b`
+      b := if a == 'a' { 'x' } else if a =~ '.*' { / 'y' } else { a / 'z' }
+      c := assert(a != 'b', 'error')
+      d := env_var_or_default('FOO', 'bar') + uuid()
+
+      alias f := foo
+
+      # a recipe
+      [confirm('really?')]
+      [no-cd]
+      [private]
+      foo x $y='y' +z='z': (bar x) bar
+        echo {{x}} {{ y + z }}
+
+      [linux]
+      [tempdir('/tmp')]
+      @bar *args:
+        #!/bin/sh
+        echo {{args}}
+    ",
+  ));
+}
+
+#[test]
+fn module_matches_schema() {
+  assert_valid(
+    Test::new()
+      .write("foo.just", "bar:\n echo bar")
+      .justfile("mod foo")
+      .test_round_trip(false),
+  );
+}
+
+#[test]
+fn locations_match_schema() {
+  assert_valid(
+    Test::new()
+      .write("foo.just", "bar:\n echo bar")
+      .justfile("mod foo\n\nbaz:")
+      .arg("--locations")
+      .test_round_trip(false),
+  );
+}
+
+#[test]
+fn validate_rejects_mismatches() {
+  let mut instance = dump(Test::new().justfile("foo:"));
+  instance["recipes"]["foo"]["bogus"] = json!(true);
+  assert!(validate(&instance).is_err());
+}
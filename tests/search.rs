@@ -183,3 +183,88 @@ fn dot_justfile_conflicts_with_justfile() {
     .status(EXIT_FAILURE)
     .run();
 }
+
+#[test]
+fn search_depth_limits_upward_search() {
+  Test::new()
+    .justfile("foo:\n @echo foo")
+    .write("a/b/.keep", "")
+    .current_dir("a/b")
+    .args(["--search-depth", "1", "foo"])
+    .stderr("error: No justfile found\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn search_depth_allows_search_within_limit() {
+  Test::new()
+    .justfile("foo:\n @echo foo")
+    .write("a/b/.keep", "")
+    .current_dir("a/b")
+    .args(["--search-depth", "2", "foo"])
+    .stdout("foo\n")
+    .run();
+}
+
+#[test]
+fn search_depth_zero_searches_only_current_directory() {
+  Test::new()
+    .justfile("foo:\n @echo foo")
+    .write("a/.keep", "")
+    .current_dir("a")
+    .args(["--search-depth", "0", "foo"])
+    .stderr("error: No justfile found\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn stop_at_project_root() {
+  Test::new()
+    .justfile("foo:\n @echo foo")
+    .write("a/.git/HEAD", "")
+    .write("a/b/.keep", "")
+    .current_dir("a/b")
+    .args(["--stop-at-project-root", "foo"])
+    .stderr("error: No justfile found\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn stop_at_project_root_searches_project_root() {
+  Test::new()
+    .justfile("")
+    .write("a/.git/HEAD", "")
+    .write("a/justfile", "foo:\n @echo foo")
+    .write("a/b/.keep", "")
+    .current_dir("a/b")
+    .args(["--stop-at-project-root", "foo"])
+    .stdout("foo\n")
+    .run();
+}
+
+#[test]
+fn justignore_skips_ignored_directories() {
+  Test::new()
+    .justfile("foo:\n @echo root")
+    .write(".justignore", "# dependencies\nnode_modules/\n")
+    .write("node_modules/pkg/justfile", "foo:\n @echo pkg")
+    .current_dir("node_modules/pkg")
+    .arg("foo")
+    .stdout("root\n")
+    .run();
+}
+
+#[test]
+fn justignore_only_applies_below_its_directory() {
+  Test::new()
+    .justfile("foo:\n @echo root")
+    .write("sub/.justignore", "sub\n")
+    .write("sub/justfile", "foo:\n @echo sub")
+    .current_dir("sub")
+    .arg("foo")
+    .stdout("sub\n")
+    .run();
+}
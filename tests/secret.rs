@@ -0,0 +1,278 @@
+use super::*;
+
+#[test]
+fn secret_assignment_is_masked_in_echoed_commands() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+
+        foo:
+          echo {{ token }}
+      ",
+    )
+    .stdout("hunter2\n")
+    .stderr("echo ***\n")
+    .run();
+}
+
+#[test]
+fn secret_assignment_is_masked_in_dry_run() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        export token := 'hunter2'
+
+        foo:
+          curl -H 'Authorization: {{ token }}' example.com
+      ",
+    )
+    .arg("--dry-run")
+    .stderr("curl -H 'Authorization: ***' example.com\n")
+    .run();
+}
+
+#[test]
+fn values_containing_secrets_are_masked() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+
+        header := 'Bearer ' + token
+
+        foo:
+          echo {{ header }}
+      ",
+    )
+    .stdout("Bearer hunter2\n")
+    .stderr("echo Bearer ***\n")
+    .run();
+}
+
+#[test]
+fn secret_assignment_is_masked_in_evaluate() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+        user := 'alice'
+      ",
+    )
+    .arg("--evaluate")
+    .stdout("token := \"***\"\nuser  := \"alice\"\n")
+    .run();
+}
+
+#[test]
+fn evaluating_single_secret_variable_prints_value() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+      ",
+    )
+    .args(["--evaluate", "token"])
+    .stdout("hunter2")
+    .run();
+}
+
+#[test]
+fn secret_parameter_is_masked() {
+  Test::new()
+    .justfile(
+      "
+        [secret('password')]
+        login user password:
+          echo {{ user }} {{ password }}
+      ",
+    )
+    .args(["login", "alice", "hunter2"])
+    .stdout("alice hunter2\n")
+    .stderr("echo alice ***\n")
+    .run();
+}
+
+#[test]
+fn secret_without_arguments_masks_all_parameters() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        login user password:
+          echo {{ user }} {{ password }}
+      ",
+    )
+    .args(["login", "alice", "hunter2"])
+    .stdout("alice hunter2\n")
+    .stderr("echo *** ***\n")
+    .run();
+}
+
+#[test]
+fn secret_is_redacted_in_trace() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := `echo hunter2`
+
+        foo:
+          @echo {{ token }}
+      ",
+    )
+    .arg("--trace-evaluation")
+    .stdout("hunter2\n")
+    .stderr(
+      "
+        trace: backtick `echo hunter2` = <redacted>
+        trace: variable `token` = <redacted>
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn derived_values_are_not_masked() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        token := 'hunter2'
+
+        foo:
+          echo {{ uppercase(token) }}
+      ",
+    )
+    .stdout("HUNTER2\n")
+    .stderr("echo HUNTER2\n")
+    .run();
+}
+
+#[test]
+fn short_secret_is_not_masked() {
+  Test::new()
+    .justfile(
+      "
+        [secret]
+        pin := '123'
+
+        foo:
+          echo {{ pin }} 12345
+      ",
+    )
+    .stdout("123 12345\n")
+    .stderr(
+      "
+        warning: Value of secret `pin` is shorter than 4 characters and will not be masked
+        echo 123 12345
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn short_secret_warning_can_be_allowed() {
+  Test::new()
+    .justfile(
+      "
+        set allow := ['short-secret']
+
+        [secret]
+        pin := '123'
+
+        foo:
+          echo {{ pin }}
+      ",
+    )
+    .stdout("123\n")
+    .stderr("echo 123\n")
+    .run();
+}
+
+#[test]
+fn short_secret_parameter() {
+  Test::new()
+    .justfile(
+      "
+        [secret('pin')]
+        login user pin:
+          echo {{ user }} {{ pin }}
+      ",
+    )
+    .args(["login", "alice", "123"])
+    .stdout("alice 123\n")
+    .stderr(
+      "
+        warning: Value of secret `pin` is shorter than 4 characters and will not be masked
+        echo alice 123
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn short_secret_parameter_warning_can_be_allowed_on_recipe() {
+  Test::new()
+    .justfile(
+      "
+        [allow('short-secret')]
+        [secret('pin')]
+        login user pin:
+          echo {{ user }} {{ pin }}
+      ",
+    )
+    .args(["login", "alice", "123"])
+    .stdout("alice 123\n")
+    .stderr("echo alice 123\n")
+    .run();
+}
+
+#[test]
+fn unknown_secret_parameter() {
+  Test::new()
+    .justfile(
+      "
+        [secret('password')]
+        login user:
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `login` has no parameter `password` to mark `secret`
+         ——▶ justfile:2:1
+          │
+        2 │ login user:
+          │ ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn assignment_invalid_attribute() {
+  Test::new()
+    .justfile(
+      "
+        [private]
+        token := 'hunter2'
+      ",
+    )
+    .stderr(
+      "
+        error: Assignment `token` has invalid attribute `private`
+         ——▶ justfile:2:1
+          │
+        2 │ token := 'hunter2'
+          │ ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,88 @@
+use super::*;
+
+#[test]
+fn defaults() {
+  Test::new()
+    .arg("--settings")
+    .stdout(
+      r#"
+        allow                     []
+        allow-duplicate-recipes   false
+        allow-duplicate-variables false
+        allow-missing-recipe-args null
+        chooser                   null
+        default                   null
+        dotenv-filename           null
+        dotenv-load               null
+        dotenv-outside-project    null
+        dotenv-override           false
+        dotenv-path               null
+        env-allow                 null
+        env-deny                  []
+        export                    false
+        fallback                  false
+        fallback-depth            null
+        ignore-comments           false
+        list-order                null
+        normalize-recipe-names    false
+        parallel                  false
+        positional-arguments      false
+        prefix-match              false
+        quiet                     false
+        shell                     ["bash","-cu"]
+        shell-args                []
+        tempdir                   null
+        trace-redact              []
+        windows-powershell        false
+        windows-shell             null
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn set() {
+  Test::new()
+    .justfile(
+      "
+        set export
+        set positional-arguments
+        set shell := ['zsh', '-c']
+      ",
+    )
+    .shell(false)
+    .arg("--settings")
+    .stdout_regex(
+      r#"(?s).*export                    true
+.*positional-arguments      true
+.*shell                     \["zsh","-c"\]
+.*"#,
+    )
+    .run();
+}
+
+#[test]
+fn json() {
+  Test::new()
+    .justfile(
+      "
+        set quiet
+        set shell-args := ['-x']
+      ",
+    )
+    .args(["--settings", "--json"])
+    .stdout_regex(
+      r#"\{.*"quiet":true,"shell":\{"arguments":\["-cu","-x"\],"command":"bash"\},"shell_args":\["-x"\],.*\}
+"#,
+    )
+    .run();
+}
+
+#[test]
+fn arguments_are_rejected() {
+  Test::new()
+    .args(["--settings", "foo"])
+    .stderr("error: `--settings` used with unexpected argument: `foo`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
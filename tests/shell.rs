@@ -151,3 +151,151 @@ test! {
   stderr: "echo bar\necho foo\n",
   shell: false,
 }
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn shell_args_are_appended_to_default_shell_args() {
+  Test::new()
+    .justfile(
+      "
+        set shell-args := ['-e']
+
+        foo:
+          false; echo foo
+      ",
+    )
+    .stderr("false; echo foo\nerror: Recipe `foo` failed on line 4 with exit code 1\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn shell_args_are_appended_to_shell_setting() {
+  Test::new()
+    .justfile(
+      "
+        set shell := ['bash', '-c']
+        set shell-args := ['-o', 'pipefail']
+
+        foo:
+          false | true
+      ",
+    )
+    .shell(false)
+    .stderr("false | true\nerror: Recipe `foo` failed on line 5 with exit code 1\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn shell_args_apply_to_backticks() {
+  Test::new()
+    .justfile(
+      "
+        set shell-args := ['-e']
+
+        x := `false; echo foo`
+
+        foo:
+          echo {{ x }}
+      ",
+    )
+    .stderr(
+      "
+        error: Backtick failed with exit code 1
+         ——▶ justfile:3:6
+          │
+        3 │ x := `false; echo foo`
+          │      ^^^^^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn shell_args_are_not_appended_to_command_line_shell_args() {
+  Test::new()
+    .justfile(
+      "
+        set shell-args := ['-e']
+
+        foo:
+          false; echo foo
+      ",
+    )
+    .args(["--shell-arg", "-cu"])
+    .stdout("foo\n")
+    .stderr("false; echo foo\n")
+    .run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn shell_overrides_are_exported() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo $JUST_SHELL $JUST_SHELL_ARGS
+      ",
+    )
+    .args(["--shell-arg", "-cu", "foo"])
+    .stdout("bash [\"-cu\"]\n")
+    .run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn clear_shell_args_is_exported() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+      ",
+    )
+    .args([
+      "--clear-shell-args",
+      "--command",
+      "printenv",
+      "JUST_SHELL_ARGS",
+    ])
+    .stdout("[]\n")
+    .run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn shell_overrides_are_inherited_by_recursive_invocations() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @{{ just_executable() }} --justfile {{ justfile() }} bar
+
+        bar:
+          @echo bar
+      ",
+    )
+    .args(["--shell-arg", "-cux", "foo"])
+    .stdout("bar\n")
+    .stderr_regex(r"\+ .* bar\n\+ echo bar\n")
+    .run();
+}
+
+#[test]
+fn invalid_shell_args_environment_variable() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+      ",
+    )
+    .env("JUST_SHELL_ARGS", "-cu")
+    .stderr("error: `$JUST_SHELL_ARGS` is not a JSON array of strings: `-cu`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,67 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  foo:
+    echo stdout
+    echo stderr >&2
+";
+
+#[test]
+fn silence_stdout() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--silence-stdout")
+    .stderr("echo stdout\necho stderr >&2\nstderr\n")
+    .run();
+}
+
+#[test]
+fn silence_stderr() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--silence-stderr")
+    .stdout("stdout\n")
+    .stderr("echo stdout\necho stderr >&2\n")
+    .run();
+}
+
+#[test]
+fn silence_both() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--silence-stdout", "--silence-stderr"])
+    .stderr("echo stdout\necho stderr >&2\n")
+    .run();
+}
+
+#[test]
+fn shebang_recipe() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          #!/usr/bin/env sh
+          echo stdout
+          echo stderr >&2
+      ",
+    )
+    .arg("--silence-stdout")
+    .stderr("stderr\n")
+    .run();
+}
+
+#[test]
+fn backticks_are_not_silenced() {
+  Test::new()
+    .justfile(
+      "
+        x := `echo backtick`
+
+        foo:
+          @echo {{x}} >&2
+      ",
+    )
+    .arg("--silence-stdout")
+    .stderr("backtick\n")
+    .run();
+}
@@ -71,3 +71,79 @@ fn submodule_recipes() {
     .stdout("bar foo::foo foo::bar::bar foo::bar::baz::baz foo::bar::baz::biz::biz\n")
     .run();
 }
+
+#[test]
+fn json() {
+  Test::new()
+    .justfile(
+      "
+        alias b := bar
+        alias c := bar
+
+        foo a b='x':
+
+        bar *args:
+
+        _baz:
+      ",
+    )
+    .args(["--summary", "--json"])
+    .stdout(
+      r#"[{"aliases":[],"min_arguments":0,"name":"_baz","private":true},{"aliases":["b","c"],"min_arguments":0,"name":"bar","private":false},{"aliases":[],"min_arguments":1,"name":"foo","private":false}]
+"#,
+    )
+    .run();
+}
+
+#[test]
+fn json_unsorted() {
+  Test::new()
+    .justfile(
+      "
+        b:
+        a:
+      ",
+    )
+    .args(["--summary", "--json", "--unsorted"])
+    .stdout(
+      r#"[{"aliases":[],"min_arguments":0,"name":"b","private":false},{"aliases":[],"min_arguments":0,"name":"a","private":false}]
+"#,
+    )
+    .run();
+}
+
+#[test]
+fn json_submodules() {
+  Test::new()
+    .write("foo.just", "alias b := bar\nbar x:")
+    .justfile(
+      "
+        mod foo
+      ",
+    )
+    .test_round_trip(false)
+    .arg("--unstable")
+    .args(["--summary", "--json"])
+    .stdout(
+      r#"[{"aliases":["foo::b"],"min_arguments":1,"name":"foo::bar","private":false}]
+"#,
+    )
+    .run();
+}
+
+#[test]
+fn json_no_recipes() {
+  Test::new()
+    .args(["--summary", "--json"])
+    .stdout("[]\n")
+    .run();
+}
+
+#[test]
+fn json_requires_summary() {
+  Test::new()
+    .arg("--json")
+    .stderr_regex("error: the following required arguments were not provided:\n  <--list\\|--settings\\|--summary>(.|\\n)+")
+    .status(2)
+    .run();
+}
@@ -42,3 +42,130 @@ fn test_tempdir_is_set() {
     })
     .run();
 }
+
+#[test]
+#[cfg(unix)]
+fn tempdir_setting_may_be_an_expression() {
+  Test::new()
+    .justfile(
+      "
+        set tempdir := justfile_directory() / 'tmp'
+
+        foo:
+          #!/usr/bin/env bash
+          basename $(dirname $(dirname $0))
+      ",
+    )
+    .tree(tree! {
+      tmp: {}
+    })
+    .stdout("tmp\n")
+    .run();
+}
+
+#[test]
+#[cfg(unix)]
+fn tempdir_setting_may_reference_variables() {
+  Test::new()
+    .justfile(
+      "
+        dir := 'tmp'
+
+        set tempdir := dir
+
+        foo:
+          #!/usr/bin/env bash
+          basename $(dirname $(dirname $0))
+      ",
+    )
+    .tree(tree! {
+      tmp: {}
+    })
+    .stdout("tmp\n")
+    .run();
+}
+
+#[test]
+fn tempdir_setting_undefined_variable() {
+  Test::new()
+    .justfile(
+      "
+        set tempdir := dir
+
+        foo:
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `dir` not defined
+         ——▶ justfile:1:16
+          │
+        1 │ set tempdir := dir
+          │                ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+#[cfg(unix)]
+fn tempdir_attribute() {
+  Test::new()
+    .justfile(
+      "
+        [tempdir('scratch')]
+        foo:
+          #!/usr/bin/env bash
+          basename $(dirname $(dirname $0))
+      ",
+    )
+    .tree(tree! {
+      scratch: {}
+    })
+    .stdout("scratch\n")
+    .run();
+}
+
+#[test]
+#[cfg(unix)]
+fn tempdir_attribute_overrides_setting() {
+  Test::new()
+    .justfile(
+      "
+        set tempdir := 'tmp'
+
+        [tempdir('scratch')]
+        foo:
+          #!/usr/bin/env bash
+          basename $(dirname $(dirname $0))
+      ",
+    )
+    .tree(tree! {
+      scratch: {}
+    })
+    .stdout("scratch\n")
+    .run();
+}
+
+#[test]
+fn tempdir_attribute_requires_argument() {
+  Test::new()
+    .justfile(
+      "
+        [tempdir]
+        foo:
+      ",
+    )
+    .stderr(
+      "
+        error: Attribute `tempdir` requires an argument
+         ——▶ justfile:1:2
+          │
+        1 │ [tempdir]
+          │  ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -203,7 +203,10 @@ impl Test {
     };
     let stderr = unindent(&self.stderr);
 
-    fs::write(self.tempdir.path().join(".env"), "DOTENV_KEY=dotenv-value").unwrap();
+    let dotenv = self.tempdir.path().join(".env");
+    if !dotenv.exists() {
+      fs::write(dotenv, "DOTENV_KEY=dotenv-value").unwrap();
+    }
 
     let mut command = Command::new(executable_path("just"));
 
@@ -213,6 +216,10 @@ impl Test {
 
     let mut child = command
       .args(self.args)
+      .env_remove("CLICOLOR")
+      .env_remove("CLICOLOR_FORCE")
+      .env_remove("FORCE_COLOR")
+      .env_remove("NO_COLOR")
       .envs(&self.env)
       .current_dir(self.tempdir.path().join(self.current_dir))
       .stdin(Stdio::piped())
@@ -0,0 +1,81 @@
+use super::*;
+
+#[test]
+fn prints_timings() {
+  Test::new()
+    .justfile(
+      "
+        foo: bar
+          @echo foo
+
+        bar:
+          @echo bar
+      ",
+    )
+    .args(["--timings", "foo"])
+    .stdout("bar\nfoo\n")
+    .stderr_regex(
+      "Timings:
+    bar   [0-9]+\\.[0-9]{3}s
+    foo   [0-9]+\\.[0-9]{3}s
+    total [0-9]+\\.[0-9]{3}s
+",
+    )
+    .run();
+}
+
+#[test]
+fn failed_recipes_are_marked() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @exit 3
+      ",
+    )
+    .arg("--timings")
+    .stderr_regex(
+      "Timings:
+    foo   [0-9]+\\.[0-9]{3}s \\(failed\\)
+    total [0-9]+\\.[0-9]{3}s
+error: Recipe `foo` failed on line 2 with exit code 3
+",
+    )
+    .status(3)
+    .run();
+}
+
+#[test]
+fn quiet() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo foo
+      ",
+    )
+    .args(["--timings", "--quiet"])
+    .run();
+}
+
+#[test]
+fn with_transcript() {
+  let output = Test::new()
+    .justfile(
+      "
+        foo:
+          @echo foo
+      ",
+    )
+    .args(["--timings", "--transcript", "transcript.json"])
+    .stdout("foo\n")
+    .stderr_regex("Timings:\n    foo   .*\n    total .*\n")
+    .run();
+
+  let transcript: Value = serde_json::from_str(
+    &fs::read_to_string(output.tempdir.path().join("transcript.json")).unwrap(),
+  )
+  .unwrap();
+
+  assert_eq!(transcript["recipes"][0]["recipe"], "foo");
+}
@@ -0,0 +1,94 @@
+use super::*;
+
+#[test]
+fn variables_calls_and_backticks_are_traced() {
+  Test::new()
+    .justfile(
+      "
+        foo := uppercase('a')
+        bar := foo + `echo b`
+
+        baz:
+          echo {{ bar }}
+      ",
+    )
+    .arg("--trace-evaluation")
+    .stdout("Ab\n")
+    .stderr(
+      "
+        trace: call `uppercase('a')` = \"A\"
+        trace: variable `foo` = \"A\"
+        trace: backtick `echo b` = \"b\"
+        trace: variable `bar` = \"Ab\"
+        echo Ab
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn trace_evaluation_env_var() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ uppercase('a') }}
+      ",
+    )
+    .env("JUST_TRACE_EVALUATION", "true")
+    .stdout("A\n")
+    .stderr("trace: call `uppercase('a')` = \"A\"\n")
+    .run();
+}
+
+#[test]
+fn nothing_is_traced_by_default() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ uppercase('a') }}
+      ",
+    )
+    .stdout("A\n")
+    .run();
+}
+
+#[test]
+fn redacted_variables_are_hidden() {
+  Test::new()
+    .justfile(
+      "
+        set trace-redact := ['*TOKEN*']
+
+        GITHUB_TOKEN := `echo secret`
+
+        foo:
+          @echo {{ if GITHUB_TOKEN == '' { 'missing' } else { 'present' } }}
+      ",
+    )
+    .arg("--trace-evaluation")
+    .stdout("present\n")
+    .stderr(
+      "
+        trace: backtick `echo secret` = <redacted>
+        trace: variable `GITHUB_TOKEN` = <redacted>
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn trace_color() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ uppercase('a') }}
+      ",
+    )
+    .args(["--trace-evaluation", "--color", "always"])
+    .stdout("A\n")
+    .stderr("\u{1b}[1;34mtrace:\u{1b}[0m call `uppercase('a')` = \u{1b}[32m\"A\"\u{1b}[0m\n")
+    .run();
+}
@@ -0,0 +1,159 @@
+use super::*;
+
+fn transcript(output: &Output) -> Value {
+  serde_json::from_str(&fs::read_to_string(output.tempdir.path().join("transcript.json")).unwrap())
+    .unwrap()
+}
+
+#[test]
+fn records_recipes_and_lines() {
+  let output = Test::new()
+    .justfile(
+      "
+        foo: bar
+          echo foo
+
+        bar:
+          echo bar
+          echo baz
+      ",
+    )
+    .args(["--transcript", "transcript.json", "foo"])
+    .stdout("bar\nbaz\nfoo\n")
+    .stderr("echo bar\necho baz\necho foo\n")
+    .run();
+
+  let transcript = transcript(&output);
+
+  assert_eq!(transcript["success"], true);
+  assert_eq!(transcript["error"], Value::Null);
+
+  let recipes = transcript["recipes"].as_array().unwrap();
+  assert_eq!(recipes.len(), 2);
+
+  assert_eq!(recipes[0]["recipe"], "bar");
+  assert_eq!(recipes[0]["success"], true);
+  assert!(recipes[0]["duration"].is_f64());
+  assert_eq!(recipes[0]["lines"][0]["line"], 5);
+  assert_eq!(recipes[0]["lines"][0]["command"], "echo bar");
+  assert_eq!(recipes[0]["lines"][0]["exit_code"], 0);
+  assert_eq!(recipes[0]["lines"][1]["line"], 6);
+  assert_eq!(recipes[0]["lines"][1]["command"], "echo baz");
+
+  assert_eq!(recipes[1]["recipe"], "foo");
+  assert_eq!(recipes[1]["success"], true);
+  assert_eq!(recipes[1]["lines"][0]["command"], "echo foo");
+}
+
+#[test]
+fn records_exported_environment() {
+  let output = Test::new()
+    .justfile(
+      "
+        export FOO := 'bar'
+
+        baz $QUX='quux':
+          @echo $FOO $QUX
+      ",
+    )
+    .args(["--transcript", "transcript.json"])
+    .stdout("bar quux\n")
+    .run();
+
+  assert_eq!(
+    transcript(&output)["recipes"][0]["environment"],
+    json!({
+      "FOO": "bar",
+      "QUX": "quux",
+    }),
+  );
+}
+
+#[test]
+fn masks_secrets_and_dotenv_values_in_environment() {
+  let output = Test::new()
+    .write(".env", "DOTENV_KEY=dotenv-value")
+    .justfile(
+      "
+        set dotenv-load
+
+        [secret]
+        export TOKEN := 'hunter2'
+
+        export USER := 'alice'
+
+        [secret('PASSWORD')]
+        login $PASSWORD:
+          @echo $TOKEN $PASSWORD
+      ",
+    )
+    .args(["--transcript", "transcript.json", "login", "swordfish"])
+    .stdout("hunter2 swordfish\n")
+    .run();
+
+  assert_eq!(
+    transcript(&output)["recipes"][0]["environment"],
+    json!({
+      "DOTENV_KEY": "***",
+      "PASSWORD": "***",
+      "TOKEN": "***",
+      "USER": "alice",
+    }),
+  );
+}
+
+#[test]
+fn records_failure() {
+  let output = Test::new()
+    .justfile(
+      "
+        foo:
+          @exit 3
+      ",
+    )
+    .args(["--transcript", "transcript.json"])
+    .stderr("error: Recipe `foo` failed on line 2 with exit code 3\n")
+    .status(3)
+    .run();
+
+  let transcript = transcript(&output);
+
+  assert_eq!(transcript["success"], false);
+  assert_eq!(
+    transcript["error"],
+    "error: Recipe `foo` failed on line 2 with exit code 3",
+  );
+  assert_eq!(transcript["recipes"][0]["success"], false);
+  assert_eq!(transcript["recipes"][0]["lines"][0]["exit_code"], 3);
+}
+
+#[test]
+fn not_written_by_default() {
+  let output = Test::new()
+    .justfile(
+      "
+        foo:
+          @echo foo
+      ",
+    )
+    .stdout("foo\n")
+    .run();
+
+  assert!(!output.tempdir.path().join("transcript.json").exists());
+}
+
+#[test]
+fn write_error() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo foo
+      ",
+    )
+    .args(["--transcript", "missing/transcript.json"])
+    .stdout("foo\n")
+    .stderr_regex("error: Failed to write transcript to `.*missing.*transcript.json`: .*\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
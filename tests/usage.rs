@@ -0,0 +1,89 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  # Build the project
+  [no-cd]
+  build target mode='release' *$flags=~'^-'='-v':
+    echo {{ target }} {{ mode }} {{ flags }}
+";
+
+const USAGE: &str = "
+  Build the project
+
+  Usage: just build <target> [mode] [flags]...
+
+  Arguments:
+    <target>
+    [mode]      default: 'release'
+    [flags]...  pattern: '^-', default: '-v', exported as `$flags`
+
+  Attributes:
+    [no-cd]
+";
+
+#[test]
+fn usage() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--usage", "build"])
+    .stdout(USAGE)
+    .run();
+}
+
+#[test]
+fn recipe_help() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "--help"])
+    .stdout(USAGE)
+    .run();
+}
+
+#[test]
+fn no_parameters() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo foo
+      ",
+    )
+    .args(["foo", "--help"])
+    .stdout("Usage: just foo\n")
+    .run();
+}
+
+#[test]
+fn optional_variadic_and_secret() {
+  Test::new()
+    .justfile(
+      "
+        [secret('token')]
+        foo token='hunter2' *args='-v':
+      ",
+    )
+    .args(["--usage", "foo"])
+    .stdout(
+      "
+        Usage: just foo [token] [args]...
+
+        Arguments:
+          [token]    default: ***
+          [args]...  default: '-v'
+
+        Attributes:
+          [secret('token')]
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn unknown_recipe() {
+  Test::new()
+    .justfile("foo:")
+    .args(["--usage", "frobnicate"])
+    .stderr("error: Justfile does not contain recipe `frobnicate`.\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,114 @@
+use super::*;
+
+#[test]
+fn interpolations_are_not_evaluated() {
+  Test::new()
+    .justfile(
+      "
+      [verbatim]
+      foo:
+        @echo '{{ name }}' '{{{{'
+      ",
+    )
+    .stdout("{{ name }} {{{{\n")
+    .run();
+}
+
+#[test]
+fn shebang_recipe() {
+  Test::new()
+    .justfile(
+      "
+      [verbatim]
+      foo:
+        #!/usr/bin/env sh
+        echo '{{ name | upper }}'
+      ",
+    )
+    .stdout("{{ name | upper }}\n")
+    .run();
+}
+
+#[test]
+fn parameters_are_available_as_environment_variables() {
+  Test::new()
+    .justfile(
+      "
+      [verbatim]
+      foo $bar:
+        @echo $bar '{{bar}}'
+      ",
+    )
+    .args(["foo", "baz"])
+    .stdout("baz {{bar}}\n")
+    .run();
+}
+
+#[test]
+fn continued_lines() {
+  Test::new()
+    .justfile(
+      "
+      [verbatim]
+      foo:
+        @echo '{{a}}' \\
+          '{{b}}'
+      ",
+    )
+    .stdout("{{a}} {{b}}\n")
+    .run();
+}
+
+#[test]
+fn other_recipes_are_unaffected() {
+  Test::new()
+    .justfile(
+      "
+      x := 'y'
+
+      [verbatim]
+      foo:
+        @echo '{{x}}'
+
+      bar:
+        @echo '{{x}}'
+      ",
+    )
+    .args(["foo", "bar"])
+    .stdout("{{x}}\ny\n")
+    .run();
+}
+
+#[test]
+fn recipe_is_shown_verbatim() {
+  Test::new()
+    .justfile(
+      "
+      [verbatim]
+      foo:
+        echo '{{ x }}'
+      ",
+    )
+    .args(["--show", "foo"])
+    .stdout("[verbatim]\nfoo:\n    echo '{{ x }}'\n")
+    .run();
+}
+
+#[test]
+fn comments_between_attributes() {
+  Test::new()
+    .justfile(
+      "
+      name := 'bob'
+
+      [verbatim] # templates
+      # use the same delimiters
+      [private]
+      foo:
+        @echo '{{ name }}'
+      ",
+    )
+    .arg("foo")
+    .stdout("{{ name }}\n")
+    .run();
+}
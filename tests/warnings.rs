@@ -0,0 +1,161 @@
+use super::*;
+
+#[test]
+fn allow_setting_suppresses_warning() {
+  Test::new()
+    .justfile(
+      "
+        set allow := ['warn-directive']
+
+        foo:
+          ~warn careful
+          echo bar
+      ",
+    )
+    .stdout("bar\n")
+    .stderr("echo bar\n")
+    .run();
+}
+
+#[test]
+fn allow_attribute_suppresses_warning() {
+  Test::new()
+    .justfile(
+      "
+        [allow('warn-directive')]
+        foo:
+          ~warn careful
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn allow_attribute_only_applies_to_recipe() {
+  Test::new()
+    .justfile(
+      "
+        foo: bar
+          ~warn careful
+
+        [allow('warn-directive')]
+        bar:
+          ~warn ignored
+      ",
+    )
+    .stderr("warning: careful\n")
+    .run();
+}
+
+#[test]
+fn allow_setting_suppresses_dotenv_outside_project() {
+  Test::new()
+    .write(
+      "sub/justfile",
+      "set dotenv-load\nset allow := ['dotenv-outside-project']\nfoo:\n @echo $DOTENV_KEY",
+    )
+    .args(["sub/foo"])
+    .stdout("dotenv-value\n")
+    .run();
+}
+
+#[test]
+fn warn_as_error() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~warn careful
+          echo bar
+      ",
+    )
+    .arg("--warn-as-error")
+    .stderr("error: Warning `warn-directive` denied by `--warn-as-error`: careful\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn warn_as_error_env_var() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          ~warn careful
+      ",
+    )
+    .env("JUST_WARN_AS_ERROR", "true")
+    .stderr("error: Warning `warn-directive` denied by `--warn-as-error`: careful\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn warn_as_error_ignores_allowed_warnings() {
+  Test::new()
+    .justfile(
+      "
+        [allow('warn-directive')]
+        foo:
+          ~warn careful
+          echo bar
+      ",
+    )
+    .arg("--warn-as-error")
+    .stdout("bar\n")
+    .stderr("echo bar\n")
+    .run();
+}
+
+#[test]
+fn warn_as_error_dotenv_outside_project() {
+  Test::new()
+    .write("sub/justfile", "set dotenv-load\nfoo:\n @echo $DOTENV_KEY")
+    .args(["--warn-as-error", "sub/foo"])
+    .stderr_regex(
+      "error: Warning `dotenv-outside-project` denied by `--warn-as-error`: \
+       Loading environment file `.*\\.env` from outside the justfile directory\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_code_in_setting() {
+  Test::new()
+    .justfile("set allow := ['warn-directiv']")
+    .stderr(
+      "
+        error: Unknown warning code `warn-directiv`
+        Did you mean `warn-directive`?
+         ——▶ justfile:1:15
+          │
+        1 │ set allow := ['warn-directiv']
+          │               ^^^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_code_in_attribute() {
+  Test::new()
+    .justfile(
+      "
+        [allow('bogus')]
+        foo:
+      ",
+    )
+    .stderr(
+      "
+        error: Unknown warning code `bogus`
+         ——▶ justfile:1:2
+          │
+        1 │ [allow('bogus')]
+          │  ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -181,3 +181,40 @@ fn search_dir_parent() -> Result<(), Box<dyn Error>> {
 
   Ok(())
 }
+
+#[test]
+fn justfile_from_environment() {
+  Test::new()
+    .justfile("foo:\n @echo justfile")
+    .write("other.just", "foo:\n @echo other")
+    .env("JUST_JUSTFILE", "other.just")
+    .arg("foo")
+    .stdout("other\n")
+    .test_round_trip(false)
+    .run();
+}
+
+#[test]
+fn justfile_flag_overrides_environment() {
+  Test::new()
+    .justfile("foo:\n @echo justfile")
+    .write("other.just", "foo:\n @echo other")
+    .env("JUST_JUSTFILE", "other.just")
+    .args(["--justfile", "justfile", "foo"])
+    .stdout("justfile\n")
+    .test_round_trip(false)
+    .run();
+}
+
+#[test]
+fn working_directory_from_environment() {
+  Test::new()
+    .justfile("foo:\n @cat data")
+    .write("sub/data", "sub")
+    .env("JUST_JUSTFILE", "justfile")
+    .env("JUST_WORKING_DIRECTORY", "sub")
+    .arg("foo")
+    .stdout("sub")
+    .test_round_trip(false)
+    .run();
+}